@@ -0,0 +1,49 @@
+//! Base32 encoding and decoding ([RFC 4648](https://datatracker.ietf.org/doc/html/rfc4648)), eight symbols for every five bytes.
+//!
+//! ```
+//! use nstr::base32;
+//!
+//! let s = base32::encode::<16>(b"nstr", base32::STANDARD).unwrap();
+//! assert_eq!(s, "NZZXI4Q=");
+//! assert_eq!(base32::decode::<4>("NZZXI4Q", base32::STANDARD_NO_PAD).unwrap().as_slice(), b"nstr");
+//! ```
+
+use crate::{codec, CapacityError, DecodeError, String, Vec};
+
+/// The alphabet and padding of a Base32 variant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Config {
+    alphabet: &'static [u8; 32],
+    pad: bool,
+}
+
+impl Config {
+    /// Returns this variant with or without `=` padding.
+    pub const fn with_padding(self, pad: bool) -> Self {
+        Config { pad, ..self }
+    }
+}
+
+const STANDARD_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const HEX_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHIJKLMNOPQRSTUV";
+
+/// The standard alphabet, `A`-`Z` and `2`-`7`, padded with `=`
+pub const STANDARD: Config = Config { alphabet: STANDARD_ALPHABET, pad: true };
+/// The standard alphabet, `A`-`Z` and `2`-`7`, without padding
+pub const STANDARD_NO_PAD: Config = Config { alphabet: STANDARD_ALPHABET, pad: false };
+/// The "extended hex" alphabet, `0`-`9` and `A`-`V`, padded with `=`
+pub const HEX: Config = Config { alphabet: HEX_ALPHABET, pad: true };
+/// The "extended hex" alphabet, `0`-`9` and `A`-`V`, without padding
+pub const HEX_NO_PAD: Config = Config { alphabet: HEX_ALPHABET, pad: false };
+
+/// Encodes `data`, failing if more than `N` symbols are needed.
+pub fn encode<const N: usize>(data: &[u8], config: Config) -> Result<String<N>, CapacityError> {
+    codec::encode(data, 5, config.alphabet, if config.pad { 8 } else { 1 })
+}
+
+/// Decodes `s`, failing if it is not valid for `config` (padding included), or if more than `N` bytes are decoded.
+///
+/// Only uppercase symbols are accepted, as they are the only ones RFC 4648 defines.
+pub fn decode<const N: usize>(s: &str, config: Config) -> Result<Vec<u8, N>, DecodeError> {
+    codec::decode(s, 5, if config.pad { 8 } else { 1 }, |b| config.alphabet.iter().position(|&c| c == b).map(|i| i as u8))
+}
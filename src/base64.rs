@@ -0,0 +1,47 @@
+//! Base64 encoding and decoding ([RFC 4648](https://datatracker.ietf.org/doc/html/rfc4648)), four symbols for every three bytes.
+//!
+//! ```
+//! use nstr::base64;
+//!
+//! let s = base64::encode::<8>(b"nstr", base64::STANDARD).unwrap();
+//! assert_eq!(s, "bnN0cg==");
+//! assert_eq!(base64::decode::<4>("bnN0cg", base64::URL_SAFE_NO_PAD).unwrap().as_slice(), b"nstr");
+//! ```
+
+use crate::{codec, CapacityError, DecodeError, String, Vec};
+
+/// The alphabet and padding of a Base64 variant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Config {
+    alphabet: &'static [u8; 64],
+    pad: bool,
+}
+
+impl Config {
+    /// Returns this variant with or without `=` padding.
+    pub const fn with_padding(self, pad: bool) -> Self {
+        Config { pad, ..self }
+    }
+}
+
+const STANDARD_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_SAFE_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// The standard alphabet, with `+` and `/`, padded with `=`
+pub const STANDARD: Config = Config { alphabet: STANDARD_ALPHABET, pad: true };
+/// The standard alphabet, with `+` and `/`, without padding
+pub const STANDARD_NO_PAD: Config = Config { alphabet: STANDARD_ALPHABET, pad: false };
+/// The URL and filename safe alphabet, with `-` and `_`, padded with `=`
+pub const URL_SAFE: Config = Config { alphabet: URL_SAFE_ALPHABET, pad: true };
+/// The URL and filename safe alphabet, with `-` and `_`, without padding
+pub const URL_SAFE_NO_PAD: Config = Config { alphabet: URL_SAFE_ALPHABET, pad: false };
+
+/// Encodes `data`, failing if more than `N` symbols are needed.
+pub fn encode<const N: usize>(data: &[u8], config: Config) -> Result<String<N>, CapacityError> {
+    codec::encode(data, 6, config.alphabet, if config.pad { 4 } else { 1 })
+}
+
+/// Decodes `s`, failing if it is not valid for `config` (padding included), or if more than `N` bytes are decoded.
+pub fn decode<const N: usize>(s: &str, config: Config) -> Result<Vec<u8, N>, DecodeError> {
+    codec::decode(s, 6, if config.pad { 4 } else { 1 }, |b| config.alphabet.iter().position(|&c| c == b).map(|i| i as u8))
+}
@@ -0,0 +1,146 @@
+use crate::len::LenType;
+use crate::{CapacityError, String};
+
+mod tables;
+
+/// Returns true if `c` is in one of the sorted `ranges`.
+fn in_ranges(ranges: &[(u32, u32)], c: char) -> bool {
+    let c = c as u32;
+    let i = ranges.partition_point(|&(_, end)| end < c);
+    ranges.get(i).is_some_and(|&(start, _)| start <= c)
+}
+
+/// Returns true if `c` has the Unicode `Cased` property: it is lowercase, uppercase or titlecase.
+fn is_cased(c: char) -> bool {
+    in_ranges(tables::CASED, c)
+}
+
+/// Returns true if `c` has the Unicode `Case_Ignorable` property, such as combining marks and apostrophes.
+fn is_case_ignorable(c: char) -> bool {
+    in_ranges(tables::CASE_IGNORABLE, c)
+}
+
+/// Returns true if the first char of `chars` that is not case-ignorable is cased.
+fn case_ignorable_then_cased(mut chars: impl Iterator<Item = char>) -> bool {
+    chars.find(|&c| !is_case_ignorable(c)).is_some_and(is_cased)
+}
+
+/// Maps chars to lowercase or uppercase one at a time, with the full mappings of `char::to_lowercase()` and
+/// `char::to_uppercase()` (so `'ß'` uppercases to `"SS"`), and with `'Σ'` lowercased to `'ς'` at the end of a word.
+struct CaseMapper {
+    upper: bool,
+    /// Whether the last char mapped that is not case-ignorable is cased
+    cased_before: bool,
+}
+
+impl CaseMapper {
+    fn new(upper: bool) -> Self {
+        CaseMapper { upper, cased_before: false }
+    }
+
+    /// Passes `c` mapped to `emit`, where `after` is the text that follows `c`.
+    fn map(&mut self, c: char, after: &str, emit: &mut impl FnMut(char)) {
+        if self.upper {
+            c.to_uppercase().for_each(emit);
+            return;
+        }
+        if c == 'Σ' {
+            // the final sigma rule of the Unicode standard, as in `str::to_lowercase()`
+            let final_sigma = self.cased_before && !case_ignorable_then_cased(after.chars());
+            emit(if final_sigma { 'ς' } else { 'σ' });
+        } else {
+            c.to_lowercase().for_each(emit);
+        }
+        if !is_case_ignorable(c) {
+            self.cased_before = is_cased(c);
+        }
+    }
+}
+
+/// Passes the chars of `s` mapped to lowercase or uppercase to `emit`.
+fn map_case(s: &str, upper: bool, mut emit: impl FnMut(char)) {
+    let mut mapper = CaseMapper::new(upper);
+    for (i, c) in s.char_indices() {
+        mapper.map(c, &s[i + c.len_utf8()..], &mut emit);
+    }
+}
+
+impl<const N: usize, L: LenType> String<N, L> {
+    /// Returns the string mapped to lowercase or uppercase, failing if it no longer fits.
+    pub(crate) fn try_map_case(&self, upper: bool) -> Result<Self, CapacityError> {
+        let mut requested = 0;
+        map_case(self, upper, |c| requested += c.len_utf8());
+        if requested > N {
+            return Err(CapacityError::new(requested, N));
+        }
+        let mut s = Self::new();
+        map_case(self, upper, |c| s.push(c));
+        Ok(s)
+    }
+
+    /// Converts the string to lowercase in place, as [`String::to_lowercase()`] does.
+    ///
+    /// Panics if the lowercase text does not fit.
+    pub fn make_lowercase(&mut self) {
+        self.try_make_lowercase().expect("String is full")
+    }
+
+    /// Converts the string to uppercase in place, as [`String::to_uppercase()`] does.
+    ///
+    /// Panics if the uppercase text does not fit.
+    pub fn make_uppercase(&mut self) {
+        self.try_make_uppercase().expect("String is full")
+    }
+
+    /// Fallible version of [`String::make_lowercase()`], leaving the string unchanged if the lowercase text does not fit.
+    ///
+    /// See [`String::try_make_uppercase()`] for when this fails.
+    pub fn try_make_lowercase(&mut self) -> Result<(), CapacityError> {
+        self.try_map_case_in_place(false)
+    }
+
+    /// Fallible version of [`String::make_uppercase()`], leaving the string unchanged if the uppercase text does not fit.
+    ///
+    /// The text is mapped in place, from the front, so the string must also have room for every intermediate result.
+    /// This can only be larger than the final result when a char that grows (such as `'ŉ'` to `"ʼN"`) comes before one that shrinks (such as `'ı'` to `'I'`).
+    pub fn try_make_uppercase(&mut self) -> Result<(), CapacityError> {
+        self.try_map_case_in_place(true)
+    }
+
+    /// Maps the string to lowercase or uppercase in place, failing if the result or an intermediate result does not fit.
+    fn try_map_case_in_place(&mut self, upper: bool) -> Result<(), CapacityError> {
+        // measure the result, and how far the mapped text gets ahead of the text it is mapped from
+        let len = self.len();
+        let (mut requested, mut ahead) = (0, 0);
+        let mut mapper = CaseMapper::new(upper);
+        for (i, c) in self.char_indices() {
+            let end = i + c.len_utf8();
+            mapper.map(c, &self[end..], &mut |m| requested += m.len_utf8());
+            ahead = ahead.max(requested.saturating_sub(end));
+        }
+        if requested > N || ahead > N - len {
+            return Err(CapacityError::new(requested.max(len + ahead), N));
+        }
+
+        // move the text to the end of the buffer, then write the mapped text from the start, which never overtakes the text still to map
+        let start = N - len;
+        self.vec.items.copy_within(..len, start);
+        let (mut read, mut written) = (start, 0);
+        let mut mapper = CaseMapper::new(upper);
+        while read < N {
+            // Safety: the bytes from `read` are the end of the original text, starting at a char boundary
+            let rest = unsafe { core::str::from_utf8_unchecked(&self.vec.items[read..]) };
+            let Some(c) = rest.chars().next() else {
+                break;
+            };
+            // a char maps to at most 3 chars
+            let (mut mapped, mut mapped_len) = ([0; 12], 0);
+            mapper.map(c, &rest[c.len_utf8()..], &mut |m| mapped_len += m.encode_utf8(&mut mapped[mapped_len..]).len());
+            read += c.len_utf8();
+            self.vec.items[written..written + mapped_len].copy_from_slice(&mapped[..mapped_len]);
+            written += mapped_len;
+        }
+        self.vec.set_len(written);
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+//! The shared engine of the [`hex`](crate::hex), [`base32`](crate::base32) and [`base64`](crate::base64) codecs,
+//! which all write each byte as a number of `bits`-bit symbols.
+
+use crate::{CapacityError, DecodeError, String, Vec};
+
+/// Encodes `data` with one symbol from `alphabet` for every `bits` bits, padding with `=` to a multiple of `block` symbols.
+pub(crate) fn encode<const N: usize>(data: &[u8], bits: u32, alphabet: &[u8], block: usize) -> Result<String<N>, CapacityError> {
+    let symbols = (data.len() * 8).div_ceil(bits as usize);
+    let requested = symbols.next_multiple_of(block);
+    if requested > N {
+        return Err(CapacityError::new(requested, N));
+    }
+
+    let mut s = String::new();
+    let mask = (1 << bits) - 1;
+    let (mut acc, mut pending) = (0u32, 0);
+    for &b in data {
+        acc = (acc << 8) | b as u32;
+        pending += 8;
+        while pending >= bits {
+            pending -= bits;
+            s.push(alphabet[((acc >> pending) & mask) as usize] as char);
+        }
+        acc &= (1 << pending) - 1;
+    }
+    if pending > 0 {
+        s.push(alphabet[((acc << (bits - pending)) & mask) as usize] as char);
+    }
+    (symbols..requested).for_each(|_| s.push('='));
+    Ok(s)
+}
+
+/// Decodes `bits`-bit symbols, mapped to their values by `lookup`.
+///
+/// With a `block` above 1 the input must be padded with `=` to a multiple of `block` symbols, otherwise `=` is not accepted.
+pub(crate) fn decode<const N: usize>(s: &str, bits: u32, block: usize, lookup: impl Fn(u8) -> Option<u8>) -> Result<Vec<u8, N>, DecodeError> {
+    let mut input = s.as_bytes();
+    if block > 1 {
+        if input.len() % block != 0 {
+            return Err(DecodeError::InvalidLength);
+        }
+        let symbols = input.iter().rposition(|&b| b != b'=').map_or(0, |i| i + 1);
+        if input.len() - symbols != (block - symbols % block) % block {
+            return Err(DecodeError::InvalidPadding);
+        }
+        input = &input[..symbols];
+    }
+
+    // the last symbol must hold at least one bit of data, the rest of it is unused
+    if input.len() * bits as usize % 8 >= bits as usize {
+        return Err(DecodeError::InvalidLength);
+    }
+    let requested = input.len() * bits as usize / 8;
+    if requested > N {
+        return Err(DecodeError::Capacity(CapacityError::new(requested, N)));
+    }
+
+    let mut v = Vec::new();
+    let (mut acc, mut pending) = (0u32, 0);
+    for (index, &byte) in input.iter().enumerate() {
+        let value = lookup(byte).ok_or(DecodeError::InvalidByte { index, byte })?;
+        acc = (acc << bits) | value as u32;
+        pending += bits;
+        if pending >= 8 {
+            pending -= 8;
+            v.push((acc >> pending) as u8);
+        }
+        acc &= (1 << pending) - 1;
+    }
+    if acc != 0 {
+        // unused bits must be zero, so that every byte string has exactly one encoding
+        let index = input.len() - 1;
+        return Err(DecodeError::InvalidByte { index, byte: input[index] });
+    }
+    Ok(v)
+}
@@ -0,0 +1,225 @@
+use crate::len::LenType;
+use crate::{CapacityError, FromUtf8Error, NulError, String};
+use core::ffi::{c_char, CStr};
+
+/// Returns the position of the first NUL byte in `bytes`, if any.
+fn find_nul(bytes: &[u8]) -> Option<usize> {
+    bytes.iter().position(|&b| b == 0)
+}
+
+impl<const N: usize, L: LenType> String<N, L> {
+    /// Returns the string as a `&CStr`, without copying it, so it can be passed to C code.
+    ///
+    /// A NUL terminator is written into the buffer just past the end of the string, which is why this needs `&mut self`.
+    /// This fails if the string contains a NUL byte, or if it is full and there is no room for the terminator.
+    /// Use [`CString`] for a string that is always NUL-terminated.
+    pub fn as_c_str(&mut self) -> Result<&CStr, NulError> {
+        let len = self.len();
+        if let Some(i) = find_nul(self.as_bytes()) {
+            return Err(NulError::Interior(i));
+        }
+        if len == N {
+            return Err(NulError::Capacity(CapacityError::new(1, 0)));
+        }
+        self.vec.items[len] = 0;
+        // Safety: there is exactly one NUL, at the end
+        Ok(unsafe { CStr::from_bytes_with_nul_unchecked(&self.vec.items[..=len]) })
+    }
+
+    /// Copies a `&CStr` into a new string, failing if it is not valid UTF-8 or is longer than `N` bytes.
+    pub fn from_c_str(s: &CStr) -> Result<Self, FromUtf8Error> {
+        Self::from_utf8(s.to_bytes())
+    }
+
+    /// Copies a NUL-terminated C string into a new string, failing if it is not valid UTF-8 or is longer than `N` bytes.
+    ///
+    /// At most `N + 1` bytes are read, so a missing terminator in a long string is caught as a `CapacityError` rather than reading on forever.
+    ///
+    /// # Safety
+    /// `ptr` must point to a NUL-terminated string, or to at least `N + 1` readable bytes.
+    pub unsafe fn from_ptr(ptr: *const c_char) -> Result<Self, FromUtf8Error> {
+        let mut len = 0;
+        while *ptr.add(len) != 0 {
+            len += 1;
+            if len > N {
+                return Err(FromUtf8Error::Capacity(CapacityError::new(len, N)));
+            }
+        }
+        Self::from_utf8(core::slice::from_raw_parts(ptr as *const u8, len))
+    }
+}
+
+/// A UTF-8 string that is always NUL-terminated, so it can be handed to C code at any time.
+///
+/// One byte of the buffer is always reserved for the terminator, so it can hold at most `N - 1` bytes of text, and it can never contain a NUL itself.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CString<const N: usize, L: LenType = usize> {
+    string: String<N, L>,
+}
+
+impl<const N: usize, L: LenType> CString<N, L> {
+    /// Creates a new, empty C string.
+    pub const fn new() -> Self {
+        const { assert!(N > 0, "a CString needs room for its NUL terminator") };
+        CString { string: String::new() }
+    }
+
+    /// Returns the string as a `&CStr`.
+    pub fn as_c_str(&self) -> &CStr {
+        // Safety: the text contains no NUL, and is always followed by one
+        unsafe { CStr::from_bytes_with_nul_unchecked(&self.string.vec.items[..=self.len()]) }
+    }
+
+    /// Returns a pointer to the NUL-terminated string, valid for as long as the `CString` is not modified or moved.
+    pub fn as_ptr(&self) -> *const c_char {
+        self.string.vec.items.as_ptr() as *const c_char
+    }
+
+    /// Returns the text, without the terminator.
+    pub fn as_str(&self) -> &str {
+        self.string.as_str()
+    }
+
+    /// Returns the text as a `String`, without the terminator.
+    pub fn as_string(&self) -> &String<N, L> {
+        &self.string
+    }
+
+    /// Returns the largest number of bytes of text this can hold, which is `N - 1`.
+    pub const fn capacity(&self) -> usize {
+        N - 1
+    }
+
+    /// Empties the string.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Copies a `&CStr` into a new C string, failing if it is not valid UTF-8 or does not fit.
+    pub fn from_c_str(s: &CStr) -> Result<Self, FromUtf8Error> {
+        let bytes = s.to_bytes();
+        if bytes.len() >= N {
+            return Err(FromUtf8Error::Capacity(CapacityError::new(bytes.len(), N - 1)));
+        }
+        let string = String::from_utf8(bytes)?;
+        Ok(CString::from_string_unchecked(string))
+    }
+
+    /// Wraps a string that is known to have room for the terminator and to contain no NUL.
+    fn from_string_unchecked(mut string: String<N, L>) -> Self {
+        let len = string.len();
+        string.vec.items[len] = 0;
+        CString { string }
+    }
+
+    /// Returns the text, without the terminator.
+    pub fn into_string(self) -> String<N, L> {
+        self.string
+    }
+
+    /// Returns the length of the text in bytes, not counting the terminator.
+    pub const fn len(&self) -> usize {
+        self.string.len()
+    }
+
+    /// Returns true if there is no text.
+    pub const fn is_empty(&self) -> bool {
+        self.string.is_empty()
+    }
+
+    /// Removes the last char and returns it, or `None` if the string is empty.
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.string.pop()?;
+        self.string.vec.items[self.len()] = 0;
+        Some(c)
+    }
+
+    /// Appends a char. Panics if it is NUL or if the string is full.
+    pub fn push(&mut self, c: char) {
+        self.try_push(c).expect("cannot push to CString")
+    }
+
+    /// Appends a string slice. Panics if it contains a NUL or does not fit.
+    pub fn push_str(&mut self, s: &str) {
+        self.try_push_str(s).expect("cannot push to CString")
+    }
+
+    /// Truncates the text to `new_len` bytes. Panics if `new_len` does not lie on a `char` boundary.
+    pub fn truncate(&mut self, new_len: usize) {
+        self.string.truncate(new_len);
+        self.string.vec.items[self.len()] = 0;
+    }
+
+    /// Fallible version of [`CString::push()`].
+    pub fn try_push(&mut self, c: char) -> Result<(), NulError> {
+        let dst = &mut [0; 4];
+        self.try_push_str(c.encode_utf8(dst))
+    }
+
+    /// Fallible version of [`CString::push_str()`], leaving the string unchanged on failure.
+    pub fn try_push_str(&mut self, s: &str) -> Result<(), NulError> {
+        if let Some(i) = find_nul(s.as_bytes()) {
+            return Err(NulError::Interior(self.len() + i));
+        }
+        let available = N - 1 - self.len();
+        if s.len() > available {
+            return Err(NulError::Capacity(CapacityError::new(s.len(), available)));
+        }
+        self.string.push_str(s);
+        self.string.vec.items[self.len()] = 0;
+        Ok(())
+    }
+}
+
+impl<const N: usize, L: LenType> Default for CString<N, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, L: LenType> core::ops::Deref for CString<N, L> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize, L: LenType> TryFrom<&str> for CString<N, L> {
+    type Error = NulError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let mut c = Self::new();
+        c.try_push_str(s)?;
+        Ok(c)
+    }
+}
+
+impl<const N: usize, L: LenType> TryFrom<String<N, L>> for CString<N, L> {
+    type Error = NulError;
+
+    /// Converts a string in place, failing if it contains a NUL or has no room left for the terminator.
+    fn try_from(s: String<N, L>) -> Result<Self, Self::Error> {
+        if let Some(i) = find_nul(s.as_bytes()) {
+            return Err(NulError::Interior(i));
+        }
+        if s.len() == N {
+            return Err(NulError::Capacity(CapacityError::new(1, 0)));
+        }
+        Ok(CString::from_string_unchecked(s))
+    }
+}
+
+/// Implementation of `core::fmt::Display` for `CString`
+impl<const N: usize, L: LenType> core::fmt::Display for CString<N, L> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.string, f)
+    }
+}
+
+/// Implementation of `core::fmt::Debug` for `CString`
+impl<const N: usize, L: LenType> core::fmt::Debug for CString<N, L> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.string, f)
+    }
+}
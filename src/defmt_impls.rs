@@ -0,0 +1,16 @@
+use crate::len::LenType;
+use crate::{String, Vec};
+
+/// Implementation of `defmt::Format` for `String`, logged like a `str`
+impl<const N: usize, L: LenType> defmt::Format for String<N, L> {
+    fn format(&self, fmt: defmt::Formatter) {
+        <str as defmt::Format>::format(self, fmt)
+    }
+}
+
+/// Implementation of `defmt::Format` for `Vec`, logged like a slice of its items
+impl<T: defmt::Format, const N: usize, L: LenType> defmt::Format for Vec<T, N, L> {
+    fn format(&self, fmt: defmt::Formatter) {
+        <[T] as defmt::Format>::format(self, fmt)
+    }
+}
@@ -0,0 +1,227 @@
+/// Error returned by the fallible (`try_*`) methods of `String` when an operation would grow the string beyond its capacity `N`.
+///
+/// The string is left unchanged when this error is returned, except by [`String::replace_in_place()`](crate::String::replace_in_place) and [`String::replacen_in_place()`](crate::String::replacen_in_place),
+/// which keep the replacements made before running out of space.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CapacityError {
+    /// The number of bytes the operation needed to write
+    pub requested: usize,
+    /// The number of bytes that were still free in the string
+    pub available: usize,
+}
+
+impl CapacityError {
+    pub(crate) fn new(requested: usize, available: usize) -> Self {
+        CapacityError { requested, available }
+    }
+}
+
+/// Implementation of `core::fmt::Display` for `CapacityError`
+impl core::fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "insufficient capacity: {} bytes requested, {} bytes available", self.requested, self.available)
+    }
+}
+
+impl core::error::Error for CapacityError {}
+
+/// Error returned when building a `String` from raw bytes, as in [`String::from_utf8()`](crate::String::from_utf8).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FromUtf8Error {
+    /// The bytes were not valid UTF-8
+    Utf8(core::str::Utf8Error),
+    /// The bytes were valid, but did not fit in the string
+    Capacity(CapacityError),
+}
+
+/// Implementation of `core::fmt::Display` for `FromUtf8Error`
+impl core::fmt::Display for FromUtf8Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FromUtf8Error::Utf8(e) => write!(f, "{}", e),
+            FromUtf8Error::Capacity(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl core::error::Error for FromUtf8Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            FromUtf8Error::Utf8(e) => Some(e),
+            FromUtf8Error::Capacity(e) => Some(e),
+        }
+    }
+}
+
+/// Error returned when a string cannot be used as a NUL-terminated C string, as in [`String::as_c_str()`](crate::String::as_c_str).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NulError {
+    /// The string contains a NUL byte at this position
+    Interior(usize),
+    /// There is no room for the text, or for the terminating NUL after it
+    Capacity(CapacityError),
+}
+
+/// Implementation of `core::fmt::Display` for `NulError`
+impl core::fmt::Display for NulError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            NulError::Interior(i) => write!(f, "nul byte found at position {}", i),
+            NulError::Capacity(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl core::error::Error for NulError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            NulError::Interior(_) => None,
+            NulError::Capacity(e) => Some(e),
+        }
+    }
+}
+
+/// Error returned when building a `String` from UTF-16, as in [`String::from_utf16()`](crate::String::from_utf16).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FromUtf16Error {
+    /// The input contained an unpaired surrogate
+    Utf16(core::char::DecodeUtf16Error),
+    /// The input was given as bytes, and there was an odd number of them
+    OddLength,
+    /// The input was valid, but did not fit in the string
+    Capacity(CapacityError),
+}
+
+/// Implementation of `core::fmt::Display` for `FromUtf16Error`
+impl core::fmt::Display for FromUtf16Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FromUtf16Error::Utf16(e) => write!(f, "{}", e),
+            FromUtf16Error::OddLength => write!(f, "odd number of bytes in UTF-16 input"),
+            FromUtf16Error::Capacity(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl core::error::Error for FromUtf16Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            FromUtf16Error::Utf16(e) => Some(e),
+            FromUtf16Error::OddLength => None,
+            FromUtf16Error::Capacity(e) => Some(e),
+        }
+    }
+}
+
+/// Error returned by the decoders of the [`hex`](crate::hex), [`base32`](crate::base32) and [`base64`](crate::base64) modules.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DecodeError {
+    /// The input has a byte that is not in the alphabet at this position,
+    /// or its last symbol has unused bits that are not zero
+    InvalidByte {
+        /// The position of the byte in the input
+        index: usize,
+        /// The byte itself
+        byte: u8,
+    },
+    /// The input has a number of symbols that no encoding can produce
+    InvalidLength,
+    /// The input has missing or extra `=` padding
+    InvalidPadding,
+    /// The input was valid, but decoded to more bytes than fit
+    Capacity(CapacityError),
+}
+
+/// Implementation of `core::fmt::Display` for `DecodeError`
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DecodeError::InvalidByte { index, byte } => write!(f, "invalid byte {:#04x} at position {}", byte, index),
+            DecodeError::InvalidLength => write!(f, "invalid input length"),
+            DecodeError::InvalidPadding => write!(f, "invalid padding"),
+            DecodeError::Capacity(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            DecodeError::Capacity(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Error returned by [`String::percent_decode()`](crate::String::percent_decode). The positions are in the encoded string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PercentDecodeError {
+    /// A `%` at this position is not followed by two hex digits
+    InvalidEscape {
+        /// The position of the `%`
+        index: usize,
+    },
+    /// The bytes decoded from this position on are not valid UTF-8
+    InvalidUtf8 {
+        /// The position where the invalid sequence starts
+        index: usize,
+    },
+}
+
+/// Implementation of `core::fmt::Display` for `PercentDecodeError`
+impl core::fmt::Display for PercentDecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PercentDecodeError::InvalidEscape { index } => write!(f, "invalid percent escape at position {}", index),
+            PercentDecodeError::InvalidUtf8 { index } => write!(f, "invalid UTF-8 in percent-decoded text at position {}", index),
+        }
+    }
+}
+
+impl core::error::Error for PercentDecodeError {}
+
+/// Error returned by [`String::json_unescape()`](crate::String::json_unescape). The positions are in the literal, quotes included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum JsonError {
+    /// The literal does not start and end with a `"`
+    NotQuoted,
+    /// A `"` or a control character at this position is not escaped
+    UnescapedChar {
+        /// The position of the char
+        index: usize,
+    },
+    /// The escape sequence at this position is not valid
+    InvalidEscape {
+        /// The position of the `\`
+        index: usize,
+    },
+    /// The `\u` escape at this position is a surrogate that is not part of a valid pair
+    UnpairedSurrogate {
+        /// The position of the `\`
+        index: usize,
+    },
+    /// The literal was valid, but did not fit in the string
+    Capacity(CapacityError),
+}
+
+/// Implementation of `core::fmt::Display` for `JsonError`
+impl core::fmt::Display for JsonError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            JsonError::NotQuoted => write!(f, "JSON string is not quoted"),
+            JsonError::UnescapedChar { index } => write!(f, "unescaped character in JSON string at position {}", index),
+            JsonError::InvalidEscape { index } => write!(f, "invalid escape in JSON string at position {}", index),
+            JsonError::UnpairedSurrogate { index } => write!(f, "unpaired surrogate in JSON string at position {}", index),
+            JsonError::Capacity(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl core::error::Error for JsonError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            JsonError::Capacity(e) => Some(e),
+            _ => None,
+        }
+    }
+}
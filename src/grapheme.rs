@@ -0,0 +1,77 @@
+use crate::len::LenType;
+use crate::String;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Iterator over the extended grapheme clusters of a `String`, returned by [`String::graphemes()`].
+///
+/// Requires the `graphemes` feature.
+#[derive(Clone, Debug)]
+pub struct Graphemes<'a> {
+    inner: unicode_segmentation::Graphemes<'a>,
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for Graphemes<'a> {
+    fn next_back(&mut self) -> Option<&'a str> {
+        self.inner.next_back()
+    }
+}
+
+impl<const N: usize, L: LenType> String<N, L> {
+    /// Returns the number of extended grapheme clusters (user-perceived characters) in the string.
+    pub fn grapheme_count(&self) -> usize {
+        self.graphemes().count()
+    }
+
+    /// Returns an iterator over the extended grapheme clusters of the string, as defined by
+    /// [UAX #29](https://www.unicode.org/reports/tr29/).
+    ///
+    /// A cluster is what a user sees as one character, such as a letter with combining accents or an emoji sequence.
+    ///
+    /// ```
+    /// let s = nstr::String::<32>::from("e\u{301}👍🏽!");
+    /// let mut graphemes = s.graphemes();
+    /// assert_eq!(graphemes.next(), Some("e\u{301}"));
+    /// assert_eq!(graphemes.next(), Some("👍🏽"));
+    /// assert_eq!(graphemes.next(), Some("!"));
+    /// assert_eq!(graphemes.next(), None);
+    /// ```
+    pub fn graphemes(&self) -> Graphemes<'_> {
+        Graphemes { inner: self.as_str().graphemes(true) }
+    }
+
+    /// Removes the last extended grapheme cluster and returns it, or `None` if the string is empty.
+    pub fn pop_grapheme(&mut self) -> Option<Self> {
+        let (start, _) = self.as_str().grapheme_indices(true).next_back()?;
+        Some(self.drain(start..))
+    }
+
+    /// Removes the extended grapheme cluster starting at byte position `idx` and returns it.
+    ///
+    /// Panics if `idx` is not the start of a grapheme cluster.
+    pub fn remove_grapheme(&mut self, idx: usize) -> Self {
+        let len = match self.as_str().grapheme_indices(true).find(|&(start, _)| start >= idx) {
+            Some((start, g)) if start == idx => g.len(),
+            _ => panic!("index is not the start of a grapheme cluster"),
+        };
+        self.drain(idx..idx + len)
+    }
+
+    /// Shortens the string to its first `n` extended grapheme clusters. Does nothing if it has `n` or fewer.
+    pub fn truncate_graphemes(&mut self, n: usize) {
+        if let Some((start, _)) = self.as_str().grapheme_indices(true).nth(n) {
+            self.truncate(start);
+        }
+    }
+}
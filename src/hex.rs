@@ -0,0 +1,27 @@
+//! Hexadecimal (base 16) encoding and decoding, two symbols per byte.
+//!
+//! ```
+//! let s = nstr::hex::encode::<8>(&[0xDE, 0xAD, 0xBE, 0xEF]).unwrap();
+//! assert_eq!(s, "deadbeef");
+//! assert_eq!(nstr::hex::decode::<4>("DEADbeef").unwrap().as_slice(), &[0xDE, 0xAD, 0xBE, 0xEF]);
+//! ```
+
+use crate::{codec, CapacityError, DecodeError, String, Vec};
+
+const LOWER: &[u8; 16] = b"0123456789abcdef";
+const UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Encodes `data` as lowercase hex, failing if more than `N` symbols are needed.
+pub fn encode<const N: usize>(data: &[u8]) -> Result<String<N>, CapacityError> {
+    codec::encode(data, 4, LOWER, 1)
+}
+
+/// Encodes `data` as uppercase hex, failing if more than `N` symbols are needed.
+pub fn encode_upper<const N: usize>(data: &[u8]) -> Result<String<N>, CapacityError> {
+    codec::encode(data, 4, UPPER, 1)
+}
+
+/// Decodes hex in either case, failing on an odd number of symbols, on any other symbol, or if more than `N` bytes are decoded.
+pub fn decode<const N: usize>(s: &str) -> Result<Vec<u8, N>, DecodeError> {
+    codec::decode(s, 4, 1, |b| LOWER.iter().position(|&c| c == b.to_ascii_lowercase()).map(|i| i as u8))
+}
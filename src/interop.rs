@@ -0,0 +1,22 @@
+use crate::len::LenType;
+use crate::{CapacityError, Vec};
+
+#[cfg(feature = "alloc")]
+mod alloc;
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+#[cfg(feature = "heapless")]
+mod heapless;
+
+/// Copies `items` into a new `Vec`, failing if there are more than `N` of them.
+fn vec_from_slice<T, const N: usize, L: LenType>(items: &[T]) -> Result<Vec<T, N, L>, CapacityError>
+where
+    T: Default + Copy + PartialEq,
+{
+    if items.len() > N {
+        return Err(CapacityError::new(items.len(), N));
+    }
+    let mut v = Vec::new();
+    v.extend_from_slice(items);
+    Ok(v)
+}
@@ -0,0 +1,77 @@
+use super::vec_from_slice;
+use crate::len::LenType;
+use crate::{CapacityError, String, Vec};
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+
+impl<const N: usize, L: LenType> From<String<N, L>> for alloc::string::String {
+    fn from(s: String<N, L>) -> Self {
+        alloc::string::String::from(s.as_str())
+    }
+}
+
+impl<const N: usize, L: LenType> From<String<N, L>> for Box<str> {
+    fn from(s: String<N, L>) -> Self {
+        Box::from(s.as_str())
+    }
+}
+
+impl<const N: usize, L: LenType> From<String<N, L>> for Cow<'_, str> {
+    fn from(s: String<N, L>) -> Self {
+        Cow::Owned(s.into())
+    }
+}
+
+/// Borrows the string, without allocating.
+impl<'a, const N: usize, L: LenType> From<&'a String<N, L>> for Cow<'a, str> {
+    fn from(s: &'a String<N, L>) -> Self {
+        Cow::Borrowed(s.as_str())
+    }
+}
+
+impl<const N: usize, L: LenType> TryFrom<alloc::string::String> for String<N, L> {
+    type Error = CapacityError;
+
+    /// Copies an `alloc::string::String`, returning a `CapacityError` if it does not fit.
+    fn try_from(s: alloc::string::String) -> Result<Self, Self::Error> {
+        String::try_from(s.as_str())
+    }
+}
+
+impl<const N: usize, L: LenType> TryFrom<Box<str>> for String<N, L> {
+    type Error = CapacityError;
+
+    /// Copies a `Box<str>`, returning a `CapacityError` if it does not fit.
+    fn try_from(s: Box<str>) -> Result<Self, Self::Error> {
+        String::try_from(&*s)
+    }
+}
+
+impl<const N: usize, L: LenType> TryFrom<Cow<'_, str>> for String<N, L> {
+    type Error = CapacityError;
+
+    /// Copies a `Cow<str>`, returning a `CapacityError` if it does not fit.
+    fn try_from(s: Cow<'_, str>) -> Result<Self, Self::Error> {
+        String::try_from(&*s)
+    }
+}
+
+/// The items are moved, not copied.
+impl<T, const N: usize, L: LenType> From<Vec<T, N, L>> for alloc::vec::Vec<T> {
+    fn from(v: Vec<T, N, L>) -> Self {
+        let len = v.len();
+        v.into_iter().take(len).collect()
+    }
+}
+
+impl<T, const N: usize, L: LenType> TryFrom<alloc::vec::Vec<T>> for Vec<T, N, L>
+where
+    T: Default + Copy + PartialEq,
+{
+    type Error = CapacityError;
+
+    /// Copies an `alloc::vec::Vec`, returning a `CapacityError` if there are more than `N` items.
+    fn try_from(v: alloc::vec::Vec<T>) -> Result<Self, Self::Error> {
+        vec_from_slice(&v)
+    }
+}
@@ -0,0 +1,82 @@
+use super::vec_from_slice;
+use crate::len::LenType;
+use crate::{CapacityError, String, Vec};
+use arrayvec::{ArrayString, ArrayVec};
+
+/// Converts an `ArrayString` of the same capacity, which always fits.
+impl<const N: usize, L: LenType> From<ArrayString<N>> for String<N, L> {
+    fn from(s: ArrayString<N>) -> Self {
+        String::from(s.as_str())
+    }
+}
+
+/// Converts to an `ArrayString` of the same capacity, which always fits.
+impl<const N: usize, L: LenType> From<String<N, L>> for ArrayString<N> {
+    fn from(s: String<N, L>) -> Self {
+        let mut a = ArrayString::new();
+        a.push_str(&s);
+        a
+    }
+}
+
+impl<const N: usize, L: LenType, const M: usize> TryFrom<&ArrayString<M>> for String<N, L> {
+    type Error = CapacityError;
+
+    /// Copies an `ArrayString` of any capacity, returning a `CapacityError` if it does not fit.
+    fn try_from(s: &ArrayString<M>) -> Result<Self, Self::Error> {
+        String::try_from(s.as_str())
+    }
+}
+
+impl<const N: usize, L: LenType, const M: usize> TryFrom<&String<M, L>> for ArrayString<N> {
+    type Error = CapacityError;
+
+    /// Copies into an `ArrayString` of any capacity, returning a `CapacityError` if it does not fit.
+    fn try_from(s: &String<M, L>) -> Result<Self, Self::Error> {
+        ArrayString::from(s.as_str()).map_err(|_| CapacityError::new(s.len(), N))
+    }
+}
+
+/// Converts an `ArrayVec` of the same capacity, which always fits.
+impl<T, const N: usize, L: LenType> From<ArrayVec<T, N>> for Vec<T, N, L>
+where
+    T: Default + Copy + PartialEq,
+{
+    fn from(v: ArrayVec<T, N>) -> Self {
+        let mut nv = Vec::new();
+        nv.extend_from_slice(&v);
+        nv
+    }
+}
+
+/// Converts to an `ArrayVec` of the same capacity, which always fits. The items are moved, not copied.
+impl<T, const N: usize, L: LenType> From<Vec<T, N, L>> for ArrayVec<T, N> {
+    fn from(v: Vec<T, N, L>) -> Self {
+        let len = v.len();
+        v.into_iter().take(len).collect()
+    }
+}
+
+impl<T, const N: usize, L: LenType, const M: usize> TryFrom<&ArrayVec<T, M>> for Vec<T, N, L>
+where
+    T: Default + Copy + PartialEq,
+{
+    type Error = CapacityError;
+
+    /// Copies an `ArrayVec` of any capacity, returning a `CapacityError` if it does not fit.
+    fn try_from(v: &ArrayVec<T, M>) -> Result<Self, Self::Error> {
+        vec_from_slice(v)
+    }
+}
+
+impl<T: Clone, const N: usize, L: LenType, const M: usize> TryFrom<&Vec<T, M, L>> for ArrayVec<T, N> {
+    type Error = CapacityError;
+
+    /// Copies into an `ArrayVec` of any capacity, returning a `CapacityError` if it does not fit.
+    fn try_from(v: &Vec<T, M, L>) -> Result<Self, Self::Error> {
+        if v.len() > N {
+            return Err(CapacityError::new(v.len(), N));
+        }
+        Ok(v.iter().cloned().collect())
+    }
+}
@@ -0,0 +1,81 @@
+use super::vec_from_slice;
+use crate::len::LenType;
+use crate::{CapacityError, String, Vec};
+
+/// Converts a `heapless::String` of the same capacity, which always fits.
+impl<const N: usize, L: LenType> From<heapless::String<N>> for String<N, L> {
+    fn from(s: heapless::String<N>) -> Self {
+        String::from(s.as_str())
+    }
+}
+
+/// Converts to a `heapless::String` of the same capacity, which always fits.
+impl<const N: usize, L: LenType> From<String<N, L>> for heapless::String<N> {
+    fn from(s: String<N, L>) -> Self {
+        let mut h = heapless::String::new();
+        // the capacities are equal, so this cannot fail
+        let _ = h.push_str(&s);
+        h
+    }
+}
+
+impl<const N: usize, L: LenType, const M: usize> TryFrom<&heapless::String<M>> for String<N, L> {
+    type Error = CapacityError;
+
+    /// Copies a `heapless::String` of any capacity, returning a `CapacityError` if it does not fit.
+    fn try_from(s: &heapless::String<M>) -> Result<Self, Self::Error> {
+        String::try_from(s.as_str())
+    }
+}
+
+impl<const N: usize, L: LenType, const M: usize> TryFrom<&String<M, L>> for heapless::String<N> {
+    type Error = CapacityError;
+
+    /// Copies into a `heapless::String` of any capacity, returning a `CapacityError` if it does not fit.
+    fn try_from(s: &String<M, L>) -> Result<Self, Self::Error> {
+        let mut h = heapless::String::new();
+        h.push_str(s).map_err(|_| CapacityError::new(s.len(), N))?;
+        Ok(h)
+    }
+}
+
+/// Converts a `heapless::Vec` of the same capacity, which always fits.
+impl<T, const N: usize, L: LenType> From<heapless::Vec<T, N>> for Vec<T, N, L>
+where
+    T: Default + Copy + PartialEq,
+{
+    fn from(v: heapless::Vec<T, N>) -> Self {
+        let mut nv = Vec::new();
+        nv.extend_from_slice(&v);
+        nv
+    }
+}
+
+/// Converts to a `heapless::Vec` of the same capacity, which always fits. The items are moved, not copied.
+impl<T, const N: usize, L: LenType> From<Vec<T, N, L>> for heapless::Vec<T, N> {
+    fn from(v: Vec<T, N, L>) -> Self {
+        let len = v.len();
+        v.into_iter().take(len).collect()
+    }
+}
+
+impl<T, const N: usize, L: LenType, const M: usize> TryFrom<&heapless::Vec<T, M>> for Vec<T, N, L>
+where
+    T: Default + Copy + PartialEq,
+{
+    type Error = CapacityError;
+
+    /// Copies a `heapless::Vec` of any capacity, returning a `CapacityError` if it does not fit.
+    fn try_from(v: &heapless::Vec<T, M>) -> Result<Self, Self::Error> {
+        vec_from_slice(v)
+    }
+}
+
+impl<T: Clone, const N: usize, L: LenType, const M: usize> TryFrom<&Vec<T, M, L>> for heapless::Vec<T, N> {
+    type Error = CapacityError;
+
+    /// Copies into a `heapless::Vec` of any capacity, returning a `CapacityError` if it does not fit.
+    fn try_from(v: &Vec<T, M, L>) -> Result<Self, Self::Error> {
+        heapless::Vec::from_slice(v).map_err(|_| CapacityError::new(v.len(), N))
+    }
+}
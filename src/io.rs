@@ -0,0 +1,146 @@
+use crate::len::LenType;
+use crate::{CapacityError, FromUtf8Error, String, Vec};
+use embedded_io::{BufRead, ErrorKind, ErrorType, Read, Write};
+
+/// Running out of capacity is reported as `WriteZero`
+impl embedded_io::Error for CapacityError {
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::WriteZero
+    }
+}
+
+/// Invalid UTF-8 is reported as `InvalidData`, and running out of capacity as `WriteZero`
+impl embedded_io::Error for FromUtf8Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            FromUtf8Error::Utf8(_) => ErrorKind::InvalidData,
+            FromUtf8Error::Capacity(_) => ErrorKind::WriteZero,
+        }
+    }
+}
+
+impl<const N: usize, L: LenType> ErrorType for Vec<u8, N, L> {
+    type Error = CapacityError;
+}
+
+/// Implementation of `embedded_io::Write` for `Vec<u8, N>`, appending the bytes
+///
+/// Writes that do not fit are rejected whole: nothing is written and a `CapacityError` is returned.
+impl<const N: usize, L: LenType> Write for Vec<u8, N, L> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, CapacityError> {
+        if buf.len() > N - self.len() {
+            return Err(CapacityError::new(buf.len(), N - self.len()));
+        }
+        self.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<(), CapacityError> {
+        Ok(())
+    }
+}
+
+impl<const N: usize, L: LenType> ErrorType for String<N, L> {
+    type Error = FromUtf8Error;
+}
+
+/// Implementation of `embedded_io::Write` for `String`, appending the bytes as text
+///
+/// Each write must be valid UTF-8 on its own, so a char cannot be split across two writes.
+/// Writes that are not valid UTF-8 or do not fit are rejected whole, leaving the string unchanged.
+impl<const N: usize, L: LenType> Write for String<N, L> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, FromUtf8Error> {
+        let s = core::str::from_utf8(buf).map_err(FromUtf8Error::Utf8)?;
+        self.try_push_str(s).map_err(FromUtf8Error::Capacity)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<(), FromUtf8Error> {
+        Ok(())
+    }
+}
+
+/// A reader over the bytes of a `String` or `Vec<u8, N>`, implementing `embedded_io::Read` and `BufRead`.
+///
+/// Requires the `embedded-io` feature.
+///
+/// ```
+/// use embedded_io::Read;
+///
+/// let s = nstr::String::<16>::from("hello");
+/// let mut cursor = nstr::Cursor::from(&s);
+/// let mut buf = [0; 3];
+/// assert_eq!(cursor.read(&mut buf).unwrap(), 3);
+/// assert_eq!(&buf, b"hel");
+/// assert_eq!(cursor.read(&mut buf).unwrap(), 2);
+/// assert_eq!(cursor.read(&mut buf).unwrap(), 0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    /// Creates a cursor at the start of `bytes`.
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Cursor { bytes, pos: 0 }
+    }
+
+    /// Returns the bytes the cursor reads from, including those already read.
+    pub const fn get_ref(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns the number of bytes read so far.
+    pub const fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Moves the cursor to `pos`, which may be past the end (the next read then returns nothing).
+    pub fn set_position(&mut self, pos: usize) {
+        self.pos = pos;
+    }
+
+    /// Returns the bytes that are left to read.
+    pub fn remaining(&self) -> &'a [u8] {
+        &self.bytes[self.pos.min(self.bytes.len())..]
+    }
+}
+
+impl<'a, const N: usize, L: LenType> From<&'a String<N, L>> for Cursor<'a> {
+    fn from(s: &'a String<N, L>) -> Self {
+        Cursor::new(s.as_bytes())
+    }
+}
+
+impl<'a, const N: usize, L: LenType> From<&'a Vec<u8, N, L>> for Cursor<'a> {
+    fn from(v: &'a Vec<u8, N, L>) -> Self {
+        Cursor::new(v)
+    }
+}
+
+impl ErrorType for Cursor<'_> {
+    type Error = core::convert::Infallible;
+}
+
+impl Read for Cursor<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let remaining = self.remaining();
+        let n = buf.len().min(remaining.len());
+        buf[..n].copy_from_slice(&remaining[..n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+impl BufRead for Cursor<'_> {
+    fn fill_buf(&mut self) -> Result<&[u8], Self::Error> {
+        Ok(self.remaining())
+    }
+
+    fn consume(&mut self, amt: usize) {
+        // stop at the end of the bytes, or where the cursor is if `set_position` moved it past the end
+        self.pos = self.pos.saturating_add(amt).min(self.bytes.len().max(self.pos));
+    }
+}
@@ -0,0 +1,151 @@
+use crate::len::LenType;
+use crate::{CapacityError, JsonError, String};
+
+const HEX: &[u8; 16] = b"0123456789abcdef";
+
+/// Writes `c` as it appears inside a JSON string to `buf`, returning it.
+///
+/// With `ascii_only`, chars outside ASCII are written as `\uXXXX` escapes, chars outside the Basic Multilingual Plane as a surrogate pair of them.
+fn escape_char(c: char, ascii_only: bool, buf: &mut [u8; 12]) -> &str {
+    let simple = match c {
+        '"' => Some(b'"'),
+        '\\' => Some(b'\\'),
+        '\u{8}' => Some(b'b'),
+        '\u{c}' => Some(b'f'),
+        '\n' => Some(b'n'),
+        '\r' => Some(b'r'),
+        '\t' => Some(b't'),
+        _ => None,
+    };
+    let len = if let Some(e) = simple {
+        buf[..2].copy_from_slice(&[b'\\', e]);
+        2
+    } else if c < ' ' || (ascii_only && !c.is_ascii()) {
+        let mut len = 0;
+        for &mut unit in c.encode_utf16(&mut [0; 2]) {
+            let digits = [unit >> 12, unit >> 8, unit >> 4, unit].map(|d| HEX[(d & 0xF) as usize]);
+            buf[len..len + 6].copy_from_slice(&[b'\\', b'u', digits[0], digits[1], digits[2], digits[3]]);
+            len += 6;
+        }
+        len
+    } else {
+        c.encode_utf8(&mut buf[..]).len()
+    };
+    // Safety: the buffer holds either an ASCII escape or the UTF-8 encoding of `c`
+    unsafe { core::str::from_utf8_unchecked(&buf[..len]) }
+}
+
+/// Reads the four hex digits of a `\u` escape starting at `i`.
+fn hex4(bytes: &[u8], i: usize) -> Option<u16> {
+    let digits = bytes.get(i..i + 4)?;
+    digits.iter().try_fold(0, |acc, &b| Some(acc * 16 + (b as char).to_digit(16)? as u16))
+}
+
+/// Unescapes the body of a JSON string literal, whose first byte is at `offset` in the literal, passing each char to `emit`.
+fn unescape(body: &str, offset: usize, mut emit: impl FnMut(char)) -> Result<(), JsonError> {
+    let bytes = body.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let index = offset + i;
+        let c = match bytes[i] {
+            b'\\' => {
+                let (c, len) = match bytes.get(i + 1) {
+                    Some(b'"') => ('"', 2),
+                    Some(b'\\') => ('\\', 2),
+                    Some(b'/') => ('/', 2),
+                    Some(b'b') => ('\u{8}', 2),
+                    Some(b'f') => ('\u{c}', 2),
+                    Some(b'n') => ('\n', 2),
+                    Some(b'r') => ('\r', 2),
+                    Some(b't') => ('\t', 2),
+                    Some(b'u') => {
+                        let unit = hex4(bytes, i + 2).ok_or(JsonError::InvalidEscape { index })?;
+                        match unit {
+                            0xD800..=0xDBFF => {
+                                // a high surrogate must be followed by an escaped low one
+                                let low = match bytes.get(i + 6..i + 8) {
+                                    Some(b"\\u") => hex4(bytes, i + 8).ok_or(JsonError::InvalidEscape { index: index + 6 })?,
+                                    _ => return Err(JsonError::UnpairedSurrogate { index }),
+                                };
+                                if !(0xDC00..=0xDFFF).contains(&low) {
+                                    return Err(JsonError::UnpairedSurrogate { index });
+                                }
+                                let c = 0x10000 + ((unit as u32 - 0xD800) << 10) + (low as u32 - 0xDC00);
+                                (char::from_u32(c).ok_or(JsonError::UnpairedSurrogate { index })?, 12)
+                            }
+                            0xDC00..=0xDFFF => return Err(JsonError::UnpairedSurrogate { index }),
+                            _ => (char::from_u32(unit as u32).ok_or(JsonError::InvalidEscape { index })?, 6),
+                        }
+                    }
+                    _ => return Err(JsonError::InvalidEscape { index }),
+                };
+                i += len;
+                c
+            }
+            b'"' | 0x00..=0x1F => return Err(JsonError::UnescapedChar { index }),
+            _ => {
+                let c = body[i..].chars().next().expect("i is on a char boundary");
+                i += c.len_utf8();
+                c
+            }
+        };
+        emit(c);
+    }
+    Ok(())
+}
+
+impl<const N: usize, L: LenType> String<N, L> {
+    /// Appends `s` escaped for use inside a JSON string: `"`, `\` and control characters are escaped, the rest is written as is.
+    /// The surrounding quotes are not written.
+    ///
+    /// Nothing is written if the result does not fit.
+    ///
+    /// ```
+    /// let mut s = nstr::String::<32>::from("{\"msg\":\"");
+    /// s.push_json_escaped("say \"hi\"\n").unwrap();
+    /// s.push_str("\"}");
+    /// assert_eq!(s, r#"{"msg":"say \"hi\"\n"}"#);
+    /// ```
+    pub fn push_json_escaped(&mut self, s: &str) -> Result<(), CapacityError> {
+        self.push_json_escaped_inner(s, false)
+    }
+
+    /// Version of [`String::push_json_escaped()`] whose output is pure ASCII: every other char is also written as a `\uXXXX` escape,
+    /// and those outside the Basic Multilingual Plane as a surrogate pair.
+    pub fn push_json_escaped_ascii(&mut self, s: &str) -> Result<(), CapacityError> {
+        self.push_json_escaped_inner(s, true)
+    }
+
+    fn push_json_escaped_inner(&mut self, s: &str, ascii_only: bool) -> Result<(), CapacityError> {
+        let buf = &mut [0; 12];
+        let requested = s.chars().map(|c| escape_char(c, ascii_only, buf).len()).sum();
+        if requested > N - self.len() {
+            return Err(CapacityError::new(requested, N - self.len()));
+        }
+        s.chars().for_each(|c| self.push_str(escape_char(c, ascii_only, buf)));
+        Ok(())
+    }
+
+    /// Decodes a JSON string literal, surrounding quotes included, into a new string.
+    ///
+    /// Fails on malformed input (the positions in the error are in `literal`), or if the result is longer than `N` bytes.
+    ///
+    /// ```
+    /// let s = nstr::String::<16>::json_unescape(r#""café 😀""#).unwrap();
+    /// assert_eq!(s, "café 😀");
+    /// ```
+    pub fn json_unescape(literal: &str) -> Result<Self, JsonError> {
+        let body = literal
+            .strip_prefix('"')
+            .and_then(|s| s.strip_suffix('"'))
+            .ok_or(JsonError::NotQuoted)?;
+        let mut requested = 0;
+        unescape(body, 1, |c| requested += c.len_utf8())?;
+        if requested > N {
+            return Err(JsonError::Capacity(CapacityError::new(requested, N)));
+        }
+        let mut s = Self::new();
+        unescape(body, 1, |c| s.push(c))?;
+        Ok(s)
+    }
+}
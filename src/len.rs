@@ -0,0 +1,80 @@
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for u8 {}
+    impl Sealed for u16 {}
+    impl Sealed for u32 {}
+    impl Sealed for usize {}
+}
+
+/// An unsigned integer type used to store the length of a `String` or `Vec`.
+///
+/// The length of a `String<N>` or `Vec<T, N>` is stored as a `usize` by default, but any `LenType` that can hold `N` may be used instead, to save space in small strings:
+/// `String<8, u8>` takes 9 bytes rather than the 16 bytes (on a 64-bit target) of `String<8>`.
+/// Using a type that cannot hold `N` is a compile error:
+///
+/// ```compile_fail
+/// let s = nstr::String::<300, u8>::new();
+/// ```
+///
+/// This trait is sealed, and is implemented for `u8`, `u16`, `u32` and `usize`.
+pub trait LenType: sealed::Sealed + Copy + Default + Eq + Ord + core::hash::Hash + core::fmt::Debug {
+    /// The largest length this type can hold
+    const MAX: usize;
+    /// A length of zero
+    const ZERO: Self;
+}
+
+impl LenType for u8 {
+    const MAX: usize = u8::MAX as usize;
+    const ZERO: Self = 0;
+}
+
+impl LenType for u16 {
+    const MAX: usize = u16::MAX as usize;
+    const ZERO: Self = 0;
+}
+
+impl LenType for u32 {
+    const MAX: usize = if usize::BITS < 32 { usize::MAX } else { u32::MAX as usize };
+    const ZERO: Self = 0;
+}
+
+impl LenType for usize {
+    const MAX: usize = usize::MAX;
+    const ZERO: Self = 0;
+}
+
+// Trait methods cannot be called in a `const fn`, so the conversions go through the size of the type instead.
+// This is sound because the trait is sealed, and every implementor is a plain unsigned integer of that size.
+
+/// Converts a length to a `usize`.
+pub(crate) const fn to_usize<L: LenType>(len: &L) -> usize {
+    let ptr = len as *const L;
+    // Safety: `L` is an unsigned integer of exactly this size
+    unsafe {
+        match core::mem::size_of::<L>() {
+            1 => *(ptr as *const u8) as usize,
+            2 => *(ptr as *const u16) as usize,
+            4 => *(ptr as *const u32) as usize,
+            _ => *(ptr as *const usize),
+        }
+    }
+}
+
+/// Converts a `usize` to a length. `n` must be at most `L::MAX`.
+pub(crate) const fn from_usize<L: LenType>(n: usize) -> L {
+    debug_assert!(n <= L::MAX, "length does not fit in the length type");
+    let mut len = L::ZERO;
+    let ptr = &mut len as *mut L;
+    // Safety: `L` is an unsigned integer of exactly this size
+    unsafe {
+        match core::mem::size_of::<L>() {
+            1 => *(ptr as *mut u8) = n as u8,
+            2 => *(ptr as *mut u16) = n as u16,
+            4 => *(ptr as *mut u32) = n as u32,
+            _ => *(ptr as *mut usize) = n,
+        }
+    }
+    len
+}
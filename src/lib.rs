@@ -15,7 +15,7 @@ This library is not dissimilar, in that there is a fixed maximum length and the
 
 One thing we do not have to worry about is null bytes, as we instead keep track of the length of the string, which is handy for easy truncation.
 
-Because of the nature of the way this library must be implemented, it is not possible to be sure that any of the functions won't panic. If you want to do this, just ensure you keep track of the capacity of the string yourself, then place guards in your code before you call any method such as `push` or `push_str` that may cause the string to grow beyond its capacity.
+Methods such as `push` or `push_str` that may cause the string to grow beyond its capacity panic when it is full, just like running out of memory would with `std`. If you cannot afford to panic, every one of them has a `try_*` counterpart that returns a `CapacityError` and leaves the string unchanged, and the `*_with` methods (such as `push_str_with`) take an `Overflow` policy that can instead truncate the text, optionally ending it with a marker such as `…`.
 
 Please note that this library is not a complete drop-in replacement for `std::string::String`, but in most cases it should be sufficient. It implements the majority of the API, but there are some things that are not possible to implement without a global allocator, or some iterators such as `Drain` that are not in the `core` library.
 
@@ -24,35 +24,122 @@ It is also worth noting that in some places, the function signatures differ slig
 Please note that this library lacks the optimizations that the `std` library has, and is not intended to be used in performance critical code.  
 
 # Missing APIs
-- pattern matching (unstable in std)
 - the ability to grow in place (requires a global allocator)
 
 # Supported APIs
 - Everything else
+- pattern matching, through the nstr `Pattern` trait (as the `std` one is unstable), implemented for `char`, `&str`, `&String<M>`, `&[char]` and `FnMut(char) -> bool`
+- C string interop: `String::as_c_str()`, `String::from_c_str()` and `String::from_ptr()`, and the always NUL-terminated `CString<N>`
+- UTF-16 decoding (`String::from_utf16()` and friends, including little and big-endian bytes) and encoding into a fixed `Vec` (`String::encode_utf16_into()`)
+- lossy UTF-8 decoding, in one go (`String::from_utf8_lossy()`) or incrementally over chunks (`Utf8Decoder`)
+- the `nformat!` and `try_nformat!` macros, like `alloc::format!`
+- number formatting without `core::fmt` (`String::push_int()`, `push_uint()`, `push_float()` and `push_hex()`, with `NumFormat` options)
+- hex, Base32 and Base64 codecs, in the `hex`, `base32` and `base64` modules
+- percent-encoding for URLs and form bodies (`String::percent_encode_into()` with a `PercentEncodeSet`, and `String::percent_decode()` in place)
+- JSON string escaping (`String::push_json_escaped()`) and unescaping (`String::json_unescape()`)
+- `core::fmt::Write` for `Vec<u8, N>`, so that `write!` can fill a byte buffer
 - the `ToString` trait (implemented for everything which implements `core::fmt::Display`)
+
+# Optional features
+- `normalization`: Unicode normalization (`String::normalize_into()`, `String::normalize()` and `String::is_normalized()`), with built-in tables
+- `graphemes`: extended grapheme cluster iteration (`String::graphemes()`) and editing (`String::pop_grapheme()`, `String::remove_grapheme()` and `String::truncate_graphemes()`)
+- `width`: display width measurement for terminals and character displays (`String::display_width()`), and width-aware truncation at grapheme cluster boundaries and padding (`String::truncate_to_width()`, `String::truncate_to_width_with()` and `String::pad_to_width()`)
+- `serde`: `Serialize` and `Deserialize` for `String` (as a string) and `Vec` (as a sequence); input that does not fit is an `invalid_length` error rather than a panic
+- `defmt`: `defmt::Format` for `String` and `Vec`, for logging them with [defmt](https://defmt.ferrous-systems.com/)
+- `ufmt`: `uDisplay` and `uDebug` for `String` and `Vec`, and `uWrite` for `String`, for formatting with [ufmt](https://docs.rs/ufmt) without `core::fmt`
+- `embedded-io`: `embedded_io::Write` for `String` and `Vec<u8, N>`, and `Cursor`, an `embedded_io::Read` over their bytes
+- `heapless`: conversions between `String`/`Vec` and `heapless::String`/`heapless::Vec` (`From` for the same capacity, `TryFrom` by reference for any capacity)
+- `arrayvec`: conversions between `String`/`Vec` and `arrayvec::ArrayString`/`arrayvec::ArrayVec`, in the same way
+- `alloc`: conversions between `String` and `alloc::string::String`, `Box<str>` and `Cow<str>`, and between `Vec` and `alloc::vec::Vec` (`From` to the heap types, `TryFrom` back)
+
+# Unicode version
+The built-in Unicode normalization tables, and the `Cased` and `Case_Ignorable` properties used for the final sigma rule of `String::to_lowercase()`, are generated by `tools/unicode-tables` from Unicode 17.0.0, the version given by `UNICODE_VERSION`.
+The case mappings of single chars come from `char::to_lowercase()` and `char::to_uppercase()`, and so follow the Unicode version of the Rust toolchain (`char::UNICODE_VERSION`).
+The `graphemes` and `width` features use the tables of the `unicode-segmentation` and `unicode-width` crates, which follow Unicode 17.0.0 from the versions required here (unicode-segmentation 1.13.2 and unicode-width 0.2.2). The minimum supported Rust version is 1.85, as set by `rust-version` in `Cargo.toml`.
 */
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::default::Default;
 
 pub const DEFAULT_BUFFER_SIZE: usize = 4096;
 
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct String<const N: usize> {
-    pub chars: [u8; N],
-    pub len: usize,
+/// The version of Unicode that the built-in tables follow, in the same form as `char::UNICODE_VERSION`.
+///
+/// The tables are generated by `tools/unicode-tables` in the repository.
+pub const UNICODE_VERSION: (u8, u8, u8) = (17, 0, 0);
+
+/// A UTF-8 string stored inline, with a maximum byte length of `N`.
+///
+/// The contents are always valid UTF-8: the buffer can only be modified through methods that uphold this.
+///
+/// The length is stored as an `L`, which defaults to `usize`; see [`LenType`] for using a smaller type.
+/// The layout is `#[repr(transparent)]` over `Vec<u8, N, L>`.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct String<const N: usize, L: LenType = usize> {
+    vec: Vec<u8, N, L>,
 }
 
+pub mod base32;
+pub mod base64;
+mod case;
+mod codec;
+mod cstr;
+#[cfg(feature = "defmt")]
+mod defmt_impls;
+mod error;
+#[cfg(feature = "graphemes")]
+mod grapheme;
+pub mod hex;
+#[cfg(any(feature = "alloc", feature = "arrayvec", feature = "heapless"))]
+mod interop;
+#[cfg(feature = "embedded-io")]
+mod io;
+mod json;
+mod len;
 mod methods;
+#[cfg(feature = "normalization")]
+mod normalize;
+mod number;
+mod overflow;
+mod pattern;
+mod percent;
+#[cfg(feature = "serde")]
+mod serde;
 mod tostring;
+#[cfg(feature = "ufmt")]
+mod ufmt_impls;
+mod utf16;
+mod utf8;
 mod vec;
+#[cfg(feature = "width")]
+mod width;
 
+pub use cstr::CString;
+pub use error::{CapacityError, DecodeError, FromUtf16Error, FromUtf8Error, JsonError, NulError, PercentDecodeError};
+#[cfg(feature = "graphemes")]
+pub use grapheme::Graphemes;
+#[cfg(feature = "embedded-io")]
+pub use io::Cursor;
+pub use len::LenType;
+#[cfg(feature = "normalization")]
+pub use normalize::NormalizationForm;
+pub use number::NumFormat;
+pub use overflow::{Overflow, OverflowWriter};
+pub use pattern::{MatchIndices, Matches, Pattern, RMatchIndices, RMatches, RSplit, RSplitN, Split, SplitN};
+pub use percent::PercentEncodeSet;
 pub use tostring::ToString;
-pub use methods::*;
+pub use utf8::Utf8Decoder;
 pub use vec::Vec;
+#[cfg(feature = "width")]
+pub use width::Align;
 
 /// Macro for creating a `Vec`, similar to the `vec!` macro in the `std` library
+#[allow(unused_macros)]
 macro_rules! vec {
     ($($x:expr),*) => {
         {
@@ -66,6 +153,7 @@ macro_rules! vec {
 }
 
 /// Macro for creating a `String`, similar to the `string!` macro in the `std` library
+#[allow(unused_macros)]
 macro_rules! string {
     ($x:expr) => {
         {
@@ -87,21 +175,65 @@ macro_rules! string {
 }
 
 /// Default implementation of `String`
-impl<const N: usize> Default for String<N> {
+impl<const N: usize, L: LenType> Default for String<N, L> {
     fn default() -> Self {
-        String::<N>::from("")
+        Self::from("")
+    }
+}
+
+/// Implementation of `PartialEq` for `String`, comparing only the contents (not the unused part of the buffer), so strings of different capacities can be compared
+impl<const N: usize, L: LenType, const M: usize, L2: LenType> PartialEq<String<M, L2>> for String<N, L> {
+    fn eq(&self, other: &String<M, L2>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize, L: LenType> Eq for String<N, L> {}
+
+/// Implementation of `PartialEq<str>` for `String`
+impl<const N: usize, L: LenType> PartialEq<str> for String<N, L> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+/// Implementation of `PartialEq<&str>` for `String`
+impl<const N: usize, L: LenType> PartialEq<&str> for String<N, L> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+/// Implementation of `PartialOrd` for `String`, comparing only the contents, so strings of different capacities can be compared
+impl<const N: usize, L: LenType, const M: usize, L2: LenType> PartialOrd<String<M, L2>> for String<N, L> {
+    fn partial_cmp(&self, other: &String<M, L2>) -> Option<core::cmp::Ordering> {
+        Some(self.as_str().cmp(other.as_str()))
+    }
+}
+
+/// Implementation of `Ord` for `String`, comparing only the contents
+impl<const N: usize, L: LenType> Ord for String<N, L> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+/// Implementation of `Hash` for `String`, hashing only the contents (the same way as `str`)
+impl<const N: usize, L: LenType> core::hash::Hash for String<N, L> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
     }
 }
 
 /// Implementation of `core::fmt::Display` for `String`
-impl<const N: usize> core::fmt::Display for String<N> {
+impl<const N: usize, L: LenType> core::fmt::Display for String<N, L> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.as_str())
     }
 }
 
 /// Implementation of `core::fmt::Debug` for `String`
-impl<const N: usize> core::fmt::Debug for String<N> {
+impl<const N: usize, L: LenType> core::fmt::Debug for String<N, L> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:#?}", self.as_str())
     }
@@ -110,6 +242,7 @@ impl<const N: usize> core::fmt::Debug for String<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use core::fmt::Write;
 
     extern crate std;
 
@@ -147,4 +280,947 @@ mod tests {
         s.remove(2);
         std::println!("s: {}", s);
     }
+
+    #[test]
+    fn test_try_capacity() {
+        let mut s = String::<4>::from("ab");
+        assert_eq!(s.try_push_str("xyz"), Err(CapacityError { requested: 3, available: 2 }));
+        assert_eq!(s.as_str(), "ab");
+        assert_eq!(s.try_push('ö'), Ok(()));
+        assert!(s.try_push('c').is_err());
+        assert!(s.try_insert(0, 'c').is_err());
+        assert!(s.try_extend_from_within(0..1).is_err());
+        assert_eq!(s.as_str(), "abö");
+
+        let s = String::<4>::from("ab");
+        assert!(s.try_repeat(3).is_err());
+        assert_eq!(s.try_repeat(2).unwrap().as_str(), "abab");
+        assert!(s.try_replace("b", "wxyz").is_err());
+        assert_eq!(s.try_replace("b", "xy").unwrap().as_str(), "axy");
+        assert!(String::<4>::try_from("hello").is_err());
+    }
+
+    #[test]
+    fn test_utf8_mutation() {
+        let mut s = String::<32>::from("aöb€");
+        s.insert(1, 'ß');
+        assert_eq!(s.as_str(), "aßöb€");
+        assert_eq!(s.remove(3), 'ö');
+        assert_eq!(s.pop(), Some('€'));
+        assert_eq!(s.as_str(), "aßb");
+
+        s.push_str("çd");
+        assert_eq!(s.drain(1..3).as_str(), "ß");
+        s.extend_from_within(1..);
+        assert_eq!(s.as_str(), "abçdbçd");
+        s.retain(|c| c != 'ç');
+        assert_eq!(s.as_str(), "abdbd");
+
+        let result = std::panic::catch_unwind(|| String::<8>::from("ö").insert(1, 'x'));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_utf8_invariant() {
+        let s = String::<8>::from_utf8("añb".as_bytes()).unwrap();
+        assert_eq!(s.as_bytes(), "añb".as_bytes());
+        assert!(matches!(String::<8>::from_utf8(&[0x61, 0xFF]), Err(FromUtf8Error::Utf8(_))));
+        assert!(matches!(String::<2>::from_utf8(b"abc"), Err(FromUtf8Error::Capacity(_))));
+
+        let (buf, len) = s.into_raw_parts();
+        assert_eq!(String::<8>::from_raw_parts(buf, len).unwrap(), s);
+        assert!(String::<8>::from_raw_parts(buf, 2).is_err());
+        assert!(String::<8>::from_raw_parts(buf, 9).is_err());
+
+        // equality ignores whatever is left in the buffer past the length
+        let mut t = String::<8>::from("añbc");
+        t.pop();
+        assert_eq!(t, s);
+        assert_eq!(t, "añb");
+    }
+
+    #[test]
+    fn test_panic_safety() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        // a panicking callback leaves the chars kept so far, followed by those not looked at yet
+        let mut s = String::<8>::from("éaéb");
+        let result = catch_unwind(AssertUnwindSafe(|| s.retain(|c| if c == 'b' { panic!() } else { c == 'a' })));
+        assert!(result.is_err());
+        assert_eq!(s, "ab");
+        assert!(core::str::from_utf8(s.as_bytes()).is_ok());
+
+        let mut s = String::<8>::from("aébéb");
+        let mut calls = 0;
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            s.remove_matches(|c: char| {
+                calls += 1;
+                if calls == 4 {
+                    panic!();
+                }
+                c == 'a' || c == 'b'
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(s, "ééb");
+        assert!(core::str::from_utf8(s.as_bytes()).is_ok());
+    }
+
+    #[test]
+    fn test_const() {
+        const EMPTY: String<4> = String::new();
+        static TABLE: [String<8>; 3] = [String::from_str("zero"), String::from_str("one"), String::from("two")];
+        const ABC: String<8> = String::from_str("abc");
+        const LEN: usize = ABC.len();
+
+        assert!(EMPTY.is_empty());
+        assert_eq!(TABLE[1].as_str(), "one");
+        assert_eq!(TABLE[2], "two");
+        assert_eq!(LEN, 3);
+        assert_eq!(TABLE[0].capacity(), 8);
+    }
+
+    #[test]
+    fn test_overflow_policy() {
+        let mut s = String::<8>::from("abc");
+        assert!(s.push_str_with("defghi", Overflow::Error).is_err());
+        assert_eq!(s, "abc");
+        s.push_str_with("deföhi", Overflow::Truncate).unwrap();
+        assert_eq!(s, "abcdefö");
+        assert!(std::panic::catch_unwind(|| String::<2>::from("ab").push_with('c', Overflow::Panic)).is_err());
+
+        let mut s = String::<8>::from("abcdef");
+        s.insert_str_with(1, "xyz", Overflow::TruncateWith("…")).unwrap();
+        assert_eq!(s, "axyzb…");
+
+        let mut s = String::<10>::new();
+        let mut w = s.writer(Overflow::TruncateWith("..."));
+        let unit = 'C';
+        write!(w, "temp={} unit={}", 21, unit).unwrap();
+        assert!(w.is_truncated());
+        assert_eq!(s, "temp=21...");
+
+        assert_eq!(12345.to_string_with::<4>(Overflow::Truncate).unwrap(), "1234");
+        assert!(12345.to_string_with::<4>(Overflow::Error).is_err());
+        assert!(write!(String::<4>::new(), "ö!!!").is_err());
+    }
+
+    #[test]
+    fn test_pattern() {
+        let s = String::<32>::from("a,b;c,,d");
+        let other = String::<4>::from(",,");
+        assert_eq!(s.find(','), Some(1));
+        assert_eq!(s.rfind(&[',', ';'][..]), Some(6));
+        assert_eq!(s.find(&other), Some(5));
+        assert!(s.contains(|c: char| c == ';'));
+        assert!(s.starts_with("a,") && s.ends_with('d') && !s.ends_with("c"));
+
+        let parts: std::vec::Vec<&str> = s.split([',', ';']).collect();
+        assert_eq!(parts, ["a", "b", "c", "", "d"]);
+        let parts: std::vec::Vec<&str> = s.rsplitn(2, ',').collect();
+        assert_eq!(parts, ["d", "a,b;c,"]);
+        assert_eq!(s.split_once(';'), Some(("a,b", "c,,d")));
+        let ab = String::<8>::from("ab");
+        let parts: std::vec::Vec<&str> = ab.split("").collect();
+        assert_eq!(parts, ["", "a", "b", ""]);
+        assert_eq!(s.matches(char::is_alphabetic).count(), 4);
+
+        let t = String::<16>::from("xxhixx");
+        assert_eq!(t.trim_matches('x'), "hi");
+        assert_eq!(t.trim_start_matches("xx"), "hixx");
+        assert_eq!(t.replace('x', "ab"), "ababhiabab");
+        assert_eq!(t.replacen("xx", "", 1), "hixx");
+
+        let mut u = String::<16>::from("a-b--cö-");
+        u.remove_matches('-');
+        assert_eq!(u, "abcö");
+        u.remove_matches("");
+        assert_eq!(u, "abcö");
+
+        // char patterns only look at the chars next to the end they match at
+        let v = String::<16>::from("aaaaaaaab");
+        let mut calls = 0;
+        assert!(!v.starts_with(|c: char| {
+            calls += 1;
+            c == 'b'
+        }));
+        assert!(v.ends_with(|c: char| {
+            calls += 1;
+            c == 'b'
+        }));
+        assert_eq!(calls, 2);
+        assert_eq!(
+            v.trim_start_matches(|c: char| {
+                calls += 1;
+                c == 'a'
+            }),
+            "b"
+        );
+        assert_eq!(calls, 2 + 9);
+        assert_eq!(v.trim_end_matches(['a', 'b']), "");
+    }
+
+    #[test]
+    fn test_replace_in_place() {
+        let s = String::<16>::from("abcabc");
+        assert_eq!(s.replace("b", "x"), "axcaxc");
+        assert_eq!(s.replacen("bc", "", 1), "aabc");
+        assert_eq!(s.replace("", "-"), "-a-b-c-a-b-c-");
+
+        let mut t = String::<16>::from("a.b.c");
+        assert_eq!(t.replace_in_place('.', "::"), Ok(2));
+        assert_eq!(t, "a::b::c");
+        assert_eq!(t.replacen_in_place("::", "/", 1), Ok(1));
+        assert_eq!(t, "a/b::c");
+        assert_eq!(t.replace_in_place("", "_"), Ok(7));
+        assert_eq!(t, "_a_/_b_:_:_c_");
+
+        // the matches before the one that does not fit stay replaced
+        let mut u = String::<8>::from("a€b");
+        assert_eq!(u.replace_in_place(['a', 'b'], "xyz"), Err(CapacityError { requested: 2, available: 1 }));
+        assert_eq!(u, "xyz€b");
+        let mut u = String::<8>::from("a€b");
+        assert_eq!(u.replace_in_place('€', "e"), Ok(1));
+        assert_eq!(u, "aeb");
+
+        // the pattern is only run once, so a stateful pattern is replaced where it matched
+        let mut v = String::<8>::from("aaa");
+        let mut calls = 0;
+        let first_only = |_: char| {
+            calls += 1;
+            calls == 1
+        };
+        assert_eq!(v.replace_in_place(first_only, "bb"), Ok(1));
+        assert_eq!(v, "bbaa");
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "length is larger than the capacity")]
+    fn test_set_len_beyond_capacity() {
+        Vec::<u8, 4, u8>::new().set_len(5);
+    }
+
+    #[test]
+    fn test_cross_capacity() {
+        let small = String::<4>::from("abc");
+        let big: String<16> = small.widen();
+        assert_eq!(big, small);
+        assert!(small < String::<32>::from("abd"));
+
+        assert_eq!(big.try_resize::<3>().unwrap(), "abc");
+        assert_eq!(big.try_resize::<2>(), Err(CapacityError { requested: 3, available: 2 }));
+        assert!(String::<2>::try_from(&big).is_err());
+        assert_eq!(String::<8>::try_from(&big).unwrap(), "abc");
+
+        let mut s = String::<8>::from("x");
+        s.push_str(&small);
+        assert_eq!(s, "xabc");
+    }
+
+    #[test]
+    fn test_len_type() {
+        use core::mem::size_of;
+        assert_eq!(size_of::<String<8, u8>>(), 9);
+        assert_eq!(size_of::<String<300, u16>>(), 302);
+        assert_eq!(size_of::<String<8>>(), 8 + size_of::<usize>());
+        assert_eq!(size_of::<Vec<u8, 8, u8>>(), 9);
+        assert_eq!(size_of::<Vec<u16, 4, u16>>(), 10);
+
+        const ID: String<8, u8> = String::from_str("id");
+        let mut s = ID;
+        s.push_str("-ö42");
+        assert_eq!(s.len(), 7);
+        assert_eq!(s.pop(), Some('2'));
+        assert_eq!(s, String::<64>::from("id-ö4"));
+        assert!(s.try_push_str("xyz").is_err());
+
+        let mut full = String::<255, u8>::new();
+        full.push_str(&"a".repeat(255));
+        assert_eq!(full.len(), 255);
+
+        let mut v = Vec::<u32, 4, u8>::new();
+        v.push(1);
+        v.insert(0, 2);
+        assert_eq!(v.as_slice(), &[2, 1]);
+    }
+
+    #[test]
+    fn test_c_str() {
+        use core::ffi::CStr;
+        let mut s = String::<4>::from("abc");
+        assert_eq!(s.as_c_str().unwrap(), c"abc");
+        s.push('d');
+        assert_eq!(s.as_c_str(), Err(NulError::Capacity(CapacityError { requested: 1, available: 0 })));
+        let mut s = String::<8>::from("a\0b");
+        assert_eq!(s.as_c_str(), Err(NulError::Interior(1)));
+
+        assert_eq!(String::<3>::from_c_str(c"abc").unwrap(), "abc");
+        assert!(String::<2>::from_c_str(c"abc").is_err());
+        let bytes = b"hello\0";
+        assert_eq!(unsafe { String::<5>::from_ptr(bytes.as_ptr().cast()) }.unwrap(), "hello");
+        assert!(matches!(unsafe { String::<4>::from_ptr(bytes.as_ptr().cast()) }, Err(FromUtf8Error::Capacity(_))));
+
+        let mut c = CString::<4>::new();
+        assert_eq!(c.capacity(), 3);
+        c.push_str("ab");
+        assert_eq!(c.as_c_str(), c"ab");
+        assert_eq!(c.try_push_str("cd"), Err(NulError::Capacity(CapacityError { requested: 2, available: 1 })));
+        assert_eq!(c.try_push('\0'), Err(NulError::Interior(2)));
+        c.push('c');
+        assert_eq!(unsafe { CStr::from_ptr(c.as_ptr()) }, c"abc");
+        assert_eq!(c.pop(), Some('c'));
+        assert_eq!(c.as_c_str(), c"ab");
+        assert!(CString::<4>::try_from(String::<4>::from("abcd")).is_err());
+        assert_eq!(CString::<8>::from_c_str(c"xyz").unwrap().into_string(), "xyz");
+    }
+
+    #[test]
+    fn test_utf16() {
+        let s = String::<16>::from("a€𝄞");
+        let units = s.encode_utf16_into::<4>().unwrap();
+        assert_eq!(units.as_slice(), &[0x61, 0x20AC, 0xD834, 0xDD1E]);
+        assert_eq!(s.encode_utf16_into::<3>(), Err(CapacityError { requested: 4, available: 3 }));
+        assert_eq!(String::<16>::from_utf16(&units).unwrap(), s);
+        assert!(matches!(String::<7>::from_utf16(&units), Err(FromUtf16Error::Capacity(_))));
+        assert!(matches!(String::<16>::from_utf16(&[0x61, 0xD834]), Err(FromUtf16Error::Utf16(_))));
+        assert_eq!(String::<16>::from_utf16_lossy(&[0x61, 0xD834, 0x62]).unwrap(), "a\u{FFFD}b");
+
+        let le = s.encode_utf16le_into::<8>().unwrap();
+        assert_eq!(&le[0..4], &[0x61, 0x00, 0xAC, 0x20]);
+        let be = s.encode_utf16be_into::<8>().unwrap();
+        assert_eq!(&be[0..4], &[0x00, 0x61, 0x20, 0xAC]);
+        assert!(s.encode_utf16le_into::<7>().is_err());
+        assert_eq!(String::<16>::from_utf16le(&le).unwrap(), s);
+        assert_eq!(String::<16>::from_utf16be(&be).unwrap(), s);
+        assert_eq!(String::<16>::from_utf16le(&le[0..3]), Err(FromUtf16Error::OddLength));
+        assert_eq!(String::<16>::from_utf16be_lossy(&be[0..3]).unwrap(), "a\u{FFFD}");
+        assert_eq!(String::<16>::from_utf16le_lossy(&[0x62, 0x00]).unwrap(), "b");
+    }
+
+    #[test]
+    fn test_utf8_lossy() {
+        assert_eq!(String::<16>::from_utf8_lossy(b"ab\xFFc").unwrap(), "ab\u{FFFD}c");
+        assert_eq!(String::<16>::from_utf8_lossy(b"\xE2\x82").unwrap(), "\u{FFFD}");
+        assert_eq!(String::<4>::from_utf8_lossy(b"ab\xFF"), Err(CapacityError { requested: 5, available: 4 }));
+        assert_eq!(String::<4>::from_utf8_lossy(b"ab\xE2"), Err(CapacityError { requested: 5, available: 4 }));
+
+        // "€" split over three chunks, then a truncated sequence cut short by a new char
+        let mut decoder = Utf8Decoder::new();
+        let mut s = String::<16>::new();
+        decoder.decode(b"x\xE2", &mut s).unwrap();
+        decoder.decode(b"\x82", &mut s).unwrap();
+        assert_eq!((s.as_str(), decoder.pending()), ("x", 2));
+        decoder.decode(b"\xAC\xF0\x9F", &mut s).unwrap();
+        decoder.decode(b"y", &mut s).unwrap();
+        decoder.finish(&mut s).unwrap();
+        assert_eq!(s, "x€\u{FFFD}y");
+        assert_eq!(decoder.replacements(), 1);
+
+        // a chunk that does not fit leaves everything unchanged
+        let mut s = String::<4>::from("ab");
+        decoder.decode(b"\xC3", &mut s).unwrap();
+        let before = decoder;
+        assert!(decoder.decode(b"\xA9\xFF", &mut s).is_err());
+        assert_eq!((s.as_str(), decoder), ("ab", before));
+        decoder.decode(b"\xA9", &mut s).unwrap();
+        assert_eq!(s, "abé");
+    }
+
+    #[test]
+    fn test_nformat() {
+        let s = nformat!(8; "{}:{}", "a", 1);
+        assert_eq!(s, "a:1");
+        assert_eq!(s.capacity(), 8);
+        let t: String<4, u8> = nformat!("{:04}", 7);
+        assert_eq!(t, "0007");
+        assert_eq!(try_nformat!(3; "{}", 1234), Err(CapacityError { requested: 4, available: 3 }));
+        let u: Result<String<4>, _> = try_nformat!("{}", 12);
+        assert_eq!(u.unwrap(), "12");
+    }
+
+    #[test]
+    #[should_panic(expected = "formatting trait implementation returned an error")]
+    fn test_nformat_failing_display() {
+        struct Failing;
+        impl core::fmt::Display for Failing {
+            fn fmt(&self, _: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                Err(core::fmt::Error)
+            }
+        }
+        let _ = Failing.to_string_with::<8>(Overflow::Error);
+    }
+
+    #[test]
+    fn test_number() {
+        let mut s = String::<64>::new();
+        s.push_int(-1234).unwrap();
+        s.push(' ');
+        s.push_int(i64::MIN).unwrap();
+        s.push(' ');
+        s.push_uint(u64::MAX).unwrap();
+        s.push(' ');
+        s.push_hex(0xBEEF).unwrap();
+        assert_eq!(s, "-1234 -9223372036854775808 18446744073709551615 beef");
+
+        let mut s = String::<64>::new();
+        s.push_uint_fmt(5, NumFormat::new().radix(2).width(8).zero_pad(true)).unwrap();
+        s.push_int_fmt(7, NumFormat::new().plus_sign(true).width(4)).unwrap();
+        s.push_int_fmt(-7, NumFormat::new().width(4).zero_pad(true)).unwrap();
+        s.push_uint_fmt(35, NumFormat::new().radix(36).uppercase(true)).unwrap();
+        assert_eq!(s, "00000101  +7-007Z");
+
+        let mut s = String::<128>::new();
+        for (v, p) in [(1.23456, 2), (-0.5, 0), (2.5, 0), (0.999, 2), (1e-7, 3), (0.05, 1), (1e20, 1), (-0.0, 1), (0.1, 22)] {
+            s.push_float(v, p).unwrap();
+            s.push(' ');
+        }
+        assert_eq!(s, "1.23 -1 3 1.00 0.000 0.1 100000000000000000000.0 -0.0 0.1000000000000000000000 ");
+        let mut s = String::<16>::new();
+        s.push_float(f64::NAN, 2).unwrap();
+        s.push_float_fmt(f64::NEG_INFINITY, 2, NumFormat::new().width(5).zero_pad(true)).unwrap();
+        s.push_float_fmt(1.5, 1, NumFormat::new().width(6).zero_pad(true).plus_sign(true)).unwrap();
+        assert_eq!(s, "NaN -inf+001.5");
+
+        let mut s = String::<4>::from("ab");
+        assert_eq!(s.push_int(-10), Err(CapacityError { requested: 3, available: 2 }));
+        assert_eq!(s.push_uint_fmt(1, NumFormat::new().width(3)), Err(CapacityError { requested: 3, available: 2 }));
+        assert_eq!(s, "ab");
+    }
+
+    #[test]
+    fn test_codecs() {
+        let data = [0x00, 0xFF, 0x10, 0xAB];
+        assert_eq!(hex::encode::<8>(&data).unwrap(), "00ff10ab");
+        assert_eq!(hex::encode_upper::<8>(&data).unwrap(), "00FF10AB");
+        assert_eq!(hex::encode::<7>(&data), Err(CapacityError { requested: 8, available: 7 }));
+        assert_eq!(hex::decode::<4>("00Ff10aB").unwrap().as_slice(), &data);
+        assert_eq!(hex::decode::<4>("abc"), Err(DecodeError::InvalidLength));
+        assert_eq!(hex::decode::<4>("0g"), Err(DecodeError::InvalidByte { index: 1, byte: b'g' }));
+        assert!(matches!(hex::decode::<1>("0000"), Err(DecodeError::Capacity(_))));
+
+        // the RFC 4648 test vectors
+        let vectors = [("", "", ""), ("f", "Zg==", "MY======"), ("fo", "Zm8=", "MZXQ===="), ("foo", "Zm9v", "MZXW6==="),
+            ("foob", "Zm9vYg==", "MZXW6YQ="), ("fooba", "Zm9vYmE=", "MZXW6YTB"), ("foobar", "Zm9vYmFy", "MZXW6YTBOI======")];
+        for (plain, b64, b32) in vectors {
+            assert_eq!(base64::encode::<16>(plain.as_bytes(), base64::STANDARD).unwrap(), b64);
+            assert_eq!(base64::decode::<8>(b64, base64::STANDARD).unwrap().as_slice(), plain.as_bytes());
+            let unpadded = b64.trim_end_matches('=');
+            assert_eq!(base64::encode::<16>(plain.as_bytes(), base64::STANDARD_NO_PAD).unwrap(), unpadded);
+            assert_eq!(base64::decode::<8>(unpadded, base64::STANDARD_NO_PAD).unwrap().as_slice(), plain.as_bytes());
+            assert_eq!(base32::encode::<16>(plain.as_bytes(), base32::STANDARD).unwrap(), b32);
+            assert_eq!(base32::decode::<8>(b32, base32::STANDARD).unwrap().as_slice(), plain.as_bytes());
+        }
+        assert_eq!(base64::encode::<4>(&[0xFB, 0xFF], base64::URL_SAFE).unwrap(), "-_8=");
+        assert_eq!(base64::encode::<4>(&[0xFB, 0xFF], base64::STANDARD).unwrap(), "+/8=");
+        assert_eq!(base32::encode::<8>(b"f", base32::HEX_NO_PAD).unwrap(), "CO");
+        assert_eq!(base64::decode::<8>("Zg=", base64::STANDARD), Err(DecodeError::InvalidLength));
+        assert_eq!(base64::decode::<8>("Zg=a", base64::STANDARD), Err(DecodeError::InvalidByte { index: 2, byte: b'=' }));
+        assert_eq!(base64::decode::<8>("Zm9v====", base64::STANDARD), Err(DecodeError::InvalidPadding));
+        assert_eq!(base64::decode::<8>("Zg==", base64::STANDARD_NO_PAD), Err(DecodeError::InvalidByte { index: 2, byte: b'=' }));
+        assert_eq!(base64::decode::<8>("Zh==", base64::STANDARD), Err(DecodeError::InvalidByte { index: 1, byte: b'h' }));
+        assert_eq!(base64::decode::<8>("Z", base64::STANDARD_NO_PAD), Err(DecodeError::InvalidLength));
+        assert_eq!(base32::decode::<8>("mzxw6===", base32::STANDARD), Err(DecodeError::InvalidByte { index: 0, byte: b'm' }));
+        assert!(matches!(base64::decode::<2>("Zm9v", base64::STANDARD), Err(DecodeError::Capacity(_))));
+    }
+
+    #[test]
+    fn test_percent() {
+        let encode = |s: &str, set| {
+            let mut out = String::<64>::new();
+            out.percent_encode_into(s, set).unwrap();
+            out
+        };
+        let raw = "a b/c?d=e&f+g:h@%ü~";
+        assert_eq!(encode(raw, PercentEncodeSet::COMPONENT), "a%20b%2Fc%3Fd%3De%26f%2Bg%3Ah%40%25%C3%BC~");
+        assert_eq!(encode(raw, PercentEncodeSet::PATH), "a%20b/c%3Fd=e&f+g:h@%25%C3%BC~");
+        assert_eq!(encode(raw, PercentEncodeSet::QUERY), "a%20b/c?d%3De%26f%2Bg:h@%25%C3%BC~");
+        assert_eq!(encode(raw, PercentEncodeSet::USERINFO), "a%20b%2Fc%3Fd=e&f+g%3Ah%40%25%C3%BC~");
+        assert_eq!(encode(raw, PercentEncodeSet::FORM), "a+b%2Fc%3Fd%3De%26f%2Bg%3Ah%40%25%C3%BC%7E");
+        assert_eq!(encode("a-b", PercentEncodeSet::COMPONENT.add(b'-')), "a%2Db");
+        assert_eq!(encode("a/b", PercentEncodeSet::COMPONENT.remove(b'/')), "a/b");
+        let mut s = String::<4>::from("x");
+        assert_eq!(s.percent_encode_into("ab ", PercentEncodeSet::PATH), Err(CapacityError { requested: 5, available: 3 }));
+        assert_eq!(s, "x");
+
+        for set in [PercentEncodeSet::COMPONENT, PercentEncodeSet::PATH, PercentEncodeSet::QUERY, PercentEncodeSet::USERINFO] {
+            let mut s = encode(raw, set);
+            s.percent_decode().unwrap();
+            assert_eq!(s, raw);
+        }
+        let mut s = encode(raw, PercentEncodeSet::FORM);
+        s.percent_decode_form().unwrap();
+        assert_eq!(s, raw);
+        let mut s = String::<16>::from("a+b%2b%c3%a9");
+        s.percent_decode().unwrap();
+        assert_eq!(s, "a+b+é");
+
+        for (bad, err) in [("ab%2", PercentDecodeError::InvalidEscape { index: 2 }), ("%zz", PercentDecodeError::InvalidEscape { index: 0 }),
+            ("a%C3", PercentDecodeError::InvalidUtf8 { index: 1 }), ("a%C3%", PercentDecodeError::InvalidEscape { index: 4 }),
+            ("%FFa", PercentDecodeError::InvalidUtf8 { index: 0 }), ("%C3a", PercentDecodeError::InvalidUtf8 { index: 0 })] {
+            let mut s = String::<16>::from(bad);
+            assert_eq!(s.percent_decode(), Err(err));
+            assert_eq!(s, bad);
+        }
+    }
+
+    #[test]
+    fn test_json() {
+        let raw = "a\"b\\c/\n\t\u{1}é😀";
+        let mut s = String::<64>::new();
+        s.push_json_escaped(raw).unwrap();
+        assert_eq!(s, r#"a\"b\\c/\n\t\u0001é😀"#);
+        let mut t = String::<64>::new();
+        t.push_json_escaped_ascii(raw).unwrap();
+        assert_eq!(t, r#"a\"b\\c/\n\t\u0001\u00e9\ud83d\ude00"#);
+        let mut u = String::<4>::from("x");
+        assert_eq!(u.push_json_escaped("\n\n"), Err(CapacityError { requested: 4, available: 3 }));
+        assert_eq!(u, "x");
+
+        for escaped in [s, t] {
+            let mut literal = String::<66>::from("\"");
+            literal.push_str(&escaped);
+            literal.push('"');
+            assert_eq!(String::<64>::json_unescape(&literal).unwrap(), raw);
+        }
+        assert_eq!(String::<8>::json_unescape(r#""\/\b\f\r\u0041""#).unwrap(), "/\u{8}\u{c}\rA");
+        assert_eq!(String::<8>::json_unescape("abc"), Err(JsonError::NotQuoted));
+        assert_eq!(String::<8>::json_unescape("\""), Err(JsonError::NotQuoted));
+        assert_eq!(String::<8>::json_unescape(r#""a"b""#), Err(JsonError::UnescapedChar { index: 2 }));
+        assert_eq!(String::<8>::json_unescape("\"a\nb\""), Err(JsonError::UnescapedChar { index: 2 }));
+        assert_eq!(String::<8>::json_unescape(r#""\x""#), Err(JsonError::InvalidEscape { index: 1 }));
+        assert_eq!(String::<8>::json_unescape(r#""a\u12g4""#), Err(JsonError::InvalidEscape { index: 2 }));
+        assert_eq!(String::<8>::json_unescape(r#""a\""#), Err(JsonError::InvalidEscape { index: 2 }));
+        assert_eq!(String::<8>::json_unescape(r#""\ud83d""#), Err(JsonError::UnpairedSurrogate { index: 1 }));
+        assert_eq!(String::<8>::json_unescape(r#""\ud83d\u0041""#), Err(JsonError::UnpairedSurrogate { index: 1 }));
+        assert_eq!(String::<8>::json_unescape(r#""\ude00""#), Err(JsonError::UnpairedSurrogate { index: 1 }));
+        assert_eq!(String::<3>::json_unescape(r#""\u00e9\u00e9""#), Err(JsonError::Capacity(CapacityError { requested: 4, available: 3 })));
+    }
+
+    #[test]
+    fn test_case_mapping() {
+        assert_eq!(String::<8>::from("straße").to_uppercase(), "STRASSE");
+        assert_eq!(String::<8>::from("İ").to_lowercase(), "i\u{307}");
+        assert_eq!(String::<8>::from("ǅ").to_lowercase(), "ǆ");
+        assert_eq!(String::<4>::from("ŉŉ").try_to_uppercase(), Err(CapacityError { requested: 6, available: 4 }));
+
+        // final sigma, with case-ignorable chars around it
+        for (upper, lower) in [("ΟΔΟΣ", "οδος"), ("ΟΔΟΣ ΟΔΟΣ", "οδος οδος"), ("Σ", "σ"), ("ΑΣ'", "ας'"), ("ΑΣ'Α", "ασ'α"), ("Α.ΣΑ", "α.σα"), ("ΑΣ\u{301}", "ας\u{301}"), ("Α\u{897}Σ", "α\u{897}ς")] {
+            assert_eq!(String::<32>::from(upper).to_lowercase(), lower);
+            let mut s = String::<32>::from(upper);
+            s.make_lowercase();
+            assert_eq!(s, lower);
+        }
+
+        let mut s = String::<8>::from("Maße");
+        s.make_uppercase();
+        assert_eq!(s, "MASSE");
+        s.make_lowercase();
+        assert_eq!(s, "masse");
+        let mut s = String::<4>::from("ŉß");
+        assert_eq!(s.try_make_uppercase(), Err(CapacityError { requested: 5, available: 4 }));
+        assert_eq!(s, "ŉß");
+        // mapped in place from the front, so a char that grows needs room even if a later one shrinks
+        let mut s = String::<4>::from("ŉı");
+        assert_eq!(s.try_make_uppercase(), Err(CapacityError { requested: 5, available: 4 }));
+        assert_eq!(s, "ŉı");
+        let mut s = String::<5>::from("ŉı");
+        s.make_uppercase();
+        assert_eq!(s, "ʼNI");
+    }
+
+    #[cfg(feature = "normalization")]
+    #[test]
+    fn test_normalization() {
+        use NormalizationForm::*;
+        let s = String::<32>::from("Å\u{1E0B}\u{323}ﬁ가각\u{FB2C}");
+        assert_eq!(s.normalize_into::<32>(Nfd).unwrap(), "A\u{30A}d\u{323}\u{307}ﬁ\u{1100}\u{1161}\u{1100}\u{1161}\u{11A8}\u{5E9}\u{5BC}\u{5C1}");
+        assert_eq!(s.normalize_into::<32>(Nfc).unwrap(), "Å\u{1E0D}\u{307}ﬁ가각\u{5E9}\u{5BC}\u{5C1}");
+        assert_eq!(s.normalize_into::<32>(Nfkd).unwrap(), "A\u{30A}d\u{323}\u{307}fi\u{1100}\u{1161}\u{1100}\u{1161}\u{11A8}\u{5E9}\u{5BC}\u{5C1}");
+        assert_eq!(s.normalize_into::<32>(Nfkc).unwrap(), "Å\u{1E0D}\u{307}fi가각\u{5E9}\u{5BC}\u{5C1}");
+        assert_eq!(String::<16>::from("\u{1100}\u{1161}\u{11A8}").normalize_into::<8>(Nfc).unwrap(), "각");
+        assert_eq!(String::<4>::from("\u{958}").normalize_into::<4>(Nfc), Err(CapacityError { requested: 6, available: 4 }));
+        // chars added after Unicode 14
+        assert_eq!(String::<8>::from("a\u{897}\u{323}").normalize_into::<8>(Nfd).unwrap(), "a\u{323}\u{897}");
+        assert_eq!(String::<8>::from("\u{105D2}\u{307}").normalize_into::<8>(Nfc).unwrap(), "\u{105C9}");
+
+        assert!(s.normalize_into::<32>(Nfc).unwrap().is_normalized(Nfc));
+        assert!(!s.is_normalized(Nfd));
+        assert!(String::<8>::from("abc").is_normalized(Nfkd));
+        assert!(!String::<8>::from("a\u{301}").is_normalized(Nfc));
+        assert!(String::<8>::from("a\u{301}").is_normalized(Nfd));
+        assert!(!String::<8>::from("a\u{301}\u{316}").is_normalized(Nfd));
+        assert!(String::<8>::from("가").is_normalized(Nfc));
+        assert!(!String::<8>::from("가").is_normalized(Nfd));
+        assert!(!String::<8>::from("\u{1100}\u{1161}").is_normalized(Nfc));
+        assert!(!String::<8>::from("ﬁ").is_normalized(Nfkc));
+
+        // more than 30 non-starters in a row are split by a combining grapheme joiner, as in the stream-safe text format
+        let mut marks = String::<128>::from("a");
+        for _ in 0..20 {
+            marks.push_str("\u{301}\u{316}");
+        }
+        let mut expected = String::<128>::from("a");
+        for (count, mark) in [(15, '\u{316}'), (15, '\u{301}'), (1, '\u{34F}'), (5, '\u{316}'), (5, '\u{301}')] {
+            (0..count).for_each(|_| expected.push(mark));
+        }
+        assert_eq!(marks.normalize_into::<128>(Nfd).unwrap(), expected);
+        assert!(expected.is_normalized(Nfd));
+        marks.truncate(1 + 30 * 2);
+        assert_eq!(marks.normalize_into::<128>(Nfd).unwrap(), expected.as_str()[..1 + 30 * 2]);
+        // a composed starter counts the non-starters it decomposes to
+        let mut marks = String::<128>::from("ǖ");
+        (0..28).for_each(|_| marks.push('\u{316}'));
+        assert!(marks.is_normalized(Nfc));
+        marks.push('\u{316}');
+        assert!(!marks.is_normalized(Nfc));
+
+        let mut t = String::<8>::from("e\u{301}");
+        t.normalize(Nfc);
+        assert_eq!(t, "é");
+        let mut t = String::<2>::from("é");
+        assert!(t.try_normalize(Nfd).is_err());
+        assert_eq!(t, "é");
+        let mut t = String::<6>::from("\u{958}");
+        t.normalize(Nfd);
+        assert_eq!(t, "\u{915}\u{93C}");
+        // the text grows before it shrinks, so it needs room for the intermediate result
+        let mut t = String::<14>::from("¼e\u{301}e\u{301}e\u{301}e\u{301}");
+        assert_eq!(t.try_normalize(Nfkc), Err(CapacityError { requested: 16, available: 14 }));
+        let mut t = String::<16>::from(t.as_str());
+        t.normalize(Nfkc);
+        assert_eq!(t, "1\u{2044}4éééé");
+    }
+
+    #[cfg(feature = "graphemes")]
+    #[test]
+    fn test_graphemes() {
+        let family = "👨\u{200D}👩\u{200D}👧";
+        let mut s = String::<64>::from("a\u{308}🇫🇷");
+        s.push_str(family);
+        s.push_str("\r\nx");
+        assert_eq!(s.grapheme_count(), 5);
+        assert_eq!(s.graphemes().next_back(), Some("x"));
+
+        let mut t = s;
+        t.truncate_graphemes(3);
+        assert_eq!(t.pop_grapheme().unwrap(), family);
+        assert_eq!(t, "a\u{308}🇫🇷");
+        t.truncate_graphemes(5);
+        assert_eq!(t.grapheme_count(), 2);
+
+        assert_eq!(s.remove_grapheme(3), "🇫🇷");
+        assert_eq!(s.remove_grapheme(0), "a\u{308}");
+        assert_eq!(s.pop_grapheme().unwrap(), "x");
+        assert_eq!(s.pop_grapheme().unwrap(), "\r\n");
+        assert_eq!(s, family);
+        s.clear();
+        assert!(s.pop_grapheme().is_none());
+    }
+
+    #[cfg(feature = "graphemes")]
+    #[test]
+    #[should_panic]
+    fn test_remove_grapheme_inside_cluster() {
+        String::<8>::from("a\u{308}").remove_grapheme(1);
+    }
+
+    #[cfg(feature = "width")]
+    #[test]
+    fn test_display_width() {
+        let s = String::<32>::from("a日本\u{301}\t😀");
+        assert_eq!(s.display_width(), 8);
+
+        let mut t = s;
+        t.truncate_to_width(4);
+        assert_eq!(t, "a日");
+        let mut t = s;
+        t.truncate_to_width(5);
+        assert_eq!(t, "a日本\u{301}");
+        let mut t = s;
+        t.truncate_to_width(10);
+        assert_eq!(t, s);
+
+        let mut t = s;
+        t.truncate_to_width_with(5, "…");
+        assert_eq!(t, "a日…");
+        let mut t = s;
+        t.truncate_to_width_with(7, "…");
+        assert_eq!(t, "a日本\u{301}\t…");
+        let mut t = s;
+        t.truncate_to_width_with(8, "…");
+        assert_eq!(t, s);
+
+        // emoji ZWJ sequences, emoji presentation selectors and flags are measured and cut as a whole
+        let zwj = String::<32>::from("a👩\u{200D}🔬b");
+        assert_eq!(zwj.display_width(), 4);
+        let mut t = zwj;
+        t.truncate_to_width(2);
+        assert_eq!(t, "a");
+        let mut t = zwj;
+        t.truncate_to_width(3);
+        assert_eq!(t, "a👩\u{200D}🔬");
+        let vs16 = String::<32>::from("❤\u{FE0F}x");
+        assert_eq!(vs16.display_width(), 3);
+        let mut t = vs16;
+        t.truncate_to_width(1);
+        assert_eq!(t, "");
+        let flags = String::<32>::from("🇫🇷🇩🇪");
+        assert_eq!(flags.display_width(), 4);
+        let mut t = flags;
+        t.truncate_to_width(3);
+        assert_eq!(t, "🇫🇷");
+        let mut t = flags;
+        t.truncate_to_width_with(3, "…");
+        assert_eq!(t, "🇫🇷…");
+        let mut t = String::<4>::from("abcd");
+        t.truncate_to_width_with(3, "…");
+        assert_eq!(t, "a…");
+        let mut t = String::<8>::from("abcd");
+        t.truncate_to_width_with(1, "...");
+        assert_eq!(t, ".");
+
+        let mut t = String::<16>::from("日x");
+        t.pad_to_width(6, Align::Left).unwrap();
+        assert_eq!(t, "日x   ");
+        let mut t = String::<16>::from("日x");
+        t.pad_to_width(6, Align::Right).unwrap();
+        assert_eq!(t, "   日x");
+        let mut t = String::<16>::from("日x");
+        t.pad_to_width(6, Align::Center).unwrap();
+        assert_eq!(t, " 日x  ");
+        t.pad_to_width(2, Align::Right).unwrap();
+        assert_eq!(t, " 日x  ");
+        let mut t = String::<5>::from("日x");
+        assert_eq!(t.pad_to_width(6, Align::Left), Err(CapacityError { requested: 3, available: 1 }));
+        assert_eq!(t, "日x");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        use ::serde::de::value::{Error, SeqDeserializer};
+        use ::serde::Deserialize;
+        use serde_test::{assert_de_tokens_error, assert_ser_tokens, assert_tokens, Token};
+
+        assert_tokens(&String::<8>::from("héllo"), &[Token::Str("héllo")]);
+        assert_de_tokens_error::<String<4>>(&[Token::Str("héllo")], "invalid length 6, expected a string of at most 4 bytes");
+
+        let mut v = Vec::<u16, 4, u8>::new();
+        v.push(1);
+        v.push(2);
+        assert_tokens(&v, &[Token::Seq { len: Some(2) }, Token::U16(1), Token::U16(2), Token::SeqEnd]);
+        // a known length is rejected before reading any item
+        assert_de_tokens_error::<Vec<u16, 1>>(
+            &[Token::Seq { len: Some(2) }],
+            "invalid length 2, expected a sequence of at most 1 items",
+        );
+        assert_de_tokens_error::<Vec<u16, 1>>(
+            &[Token::Seq { len: None }, Token::U16(1), Token::U16(2), Token::U16(3), Token::SeqEnd],
+            "invalid length 3, expected a sequence of at most 1 items",
+        );
+
+        // items that are not `Copy`, round-tripped through a sequence deserializer as `Vec`'s `Debug` needs `Copy`
+        let words = Vec::<std::string::String, 3> { items: ["ab".into(), "cd".into(), Default::default()], len: 2 };
+        assert_ser_tokens(&words, &[Token::Seq { len: Some(2) }, Token::Str("ab"), Token::Str("cd"), Token::SeqEnd]);
+        let seq = SeqDeserializer::<_, Error>::new(["ab", "cd"].into_iter());
+        assert!(Vec::<std::string::String, 3>::deserialize(seq).unwrap() == words);
+        let seq = SeqDeserializer::<_, Error>::new(["ab", "cd", "ef"].into_iter());
+        assert!(Vec::<std::string::String, 2>::deserialize(seq).is_err());
+
+        // the items read before an invalid one are dropped, and no default is made for their slots
+        static DROPS: core::sync::atomic::AtomicUsize = core::sync::atomic::AtomicUsize::new(0);
+        #[derive(Default)]
+        struct Counted;
+        impl<'de> Deserialize<'de> for Counted {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                u8::deserialize(deserializer).map(|_| Counted)
+            }
+        }
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                DROPS.fetch_add(1, core::sync::atomic::Ordering::Relaxed);
+            }
+        }
+        let seq = SeqDeserializer::<_, Error>::new([1, 2, 300].into_iter());
+        assert!(Vec::<Counted, 4>::deserialize(seq).is_err());
+        assert_eq!(DROPS.load(core::sync::atomic::Ordering::Relaxed), 2);
+    }
+
+    #[cfg(feature = "ufmt")]
+    #[test]
+    fn test_ufmt() {
+        use ufmt::{uwrite, uwriteln};
+
+        let mut s = String::<32>::new();
+        uwrite!(s, "{} = {}", "x", 42).unwrap();
+        uwriteln!(s, "!").unwrap();
+        assert_eq!(s, "x = 42!\n");
+
+        let mut out = String::<32>::new();
+        uwrite!(out, "{:?}", s).unwrap();
+        assert_eq!(out, "\"x = 42!\\n\"");
+        out.clear();
+        uwrite!(out, "{:?}", String::<8>::from("it's")).unwrap();
+        assert_eq!(out, std::format!("{:?}", "it's").as_str());
+        // combining accents are escaped wherever they are, as by the `Debug` of `str`
+        for accented in ["e\u{301}", "\u{301}e\u{301}", "\u{301}\u{301}\t"] {
+            out.clear();
+            uwrite!(out, "{:?}", String::<8>::from(accented)).unwrap();
+            assert_eq!(out, std::format!("{:?}", accented).as_str());
+        }
+
+        let mut v = Vec::<i32, 4>::new();
+        v.push(1);
+        v.push(-2);
+        out.clear();
+        uwrite!(out, "{} {:?}", v, v).unwrap();
+        assert_eq!(out, "[1, -2] [1, -2]");
+
+        let mut small = String::<4>::from("ab");
+        assert_eq!(uwrite!(small, "{}", "cde"), Err(CapacityError { requested: 3, available: 2 }));
+        assert_eq!(small, "ab");
+    }
+
+    #[cfg(feature = "defmt")]
+    #[test]
+    fn test_defmt() {
+        // with the `unstable-test` feature, defmt collects the logged bytes instead of sending them
+        fn logged<T: defmt::Format + ?Sized>(value: &T) -> std::vec::Vec<u8> {
+            defmt::export::fetch_bytes();
+            defmt::export::fmt(value);
+            defmt::export::fetch_bytes()
+        }
+
+        // each value is logged as a sequence holding the `str` or slice, and interned tags are numbered anew
+        // every time, so only the data after the tags is compared
+        let s = logged(&String::<8>::from("hi"));
+        let t = logged("hi");
+        assert_eq!(s[4..s.len() - 2], t[2..]);
+        assert_eq!(t[2..], [2, 0, 0, 0, b'h', b'i']);
+
+        let mut v = Vec::<u16, 8, u8>::new();
+        v.push(1);
+        v.push(2);
+        let v = logged(&v);
+        let w = logged(&[1u16, 2][..]);
+        assert_eq!((&v[4..8], &v[10..v.len() - 2]), (&w[2..6], &w[8..]));
+        assert_eq!(w[8..], [1, 0, 2, 0]);
+    }
+
+    #[test]
+    fn test_vec_fmt_write() {
+        use core::fmt::Write;
+
+        let mut v = Vec::<u8, 8>::new();
+        let accent = 'é';
+        write!(v, "{}-{}", 12, accent).unwrap();
+        assert_eq!(v.as_slice(), "12-é".as_bytes());
+        assert!(v.write_str("abcd").is_err());
+        assert_eq!(v.len(), 5);
+    }
+
+    #[cfg(feature = "embedded-io")]
+    #[test]
+    fn test_embedded_io() {
+        use embedded_io::{BufRead, Error, ErrorKind, Read, Write};
+
+        let mut v = Vec::<u8, 4, u8>::new();
+        v.write_all(b"abc").unwrap();
+        let e = v.write_all(b"de").unwrap_err();
+        assert_eq!(e, CapacityError { requested: 2, available: 1 });
+        assert_eq!(e.kind(), ErrorKind::WriteZero);
+        assert_eq!(v.as_slice(), b"abc");
+
+        let mut s = String::<8>::new();
+        s.write_all("hé".as_bytes()).unwrap();
+        assert_eq!(s.write_all(&[0xC3]).unwrap_err().kind(), ErrorKind::InvalidData);
+        assert_eq!(s.write_all(b"abcdef").unwrap_err().kind(), ErrorKind::WriteZero);
+        assert_eq!(s, "hé");
+
+        let mut cursor = Cursor::from(&v);
+        let mut buf = [0; 2];
+        cursor.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"ab");
+        assert_eq!(cursor.fill_buf().unwrap(), b"c");
+        cursor.consume(1);
+        assert_eq!(cursor.read(&mut buf).unwrap(), 0);
+        assert_eq!(cursor.position(), 3);
+        cursor.set_position(10);
+        assert_eq!(cursor.read(&mut buf).unwrap(), 0);
+        // consuming more than is left stops at the end
+        cursor.set_position(1);
+        cursor.consume(usize::MAX);
+        assert_eq!(cursor.position(), 3);
+        assert_eq!(cursor.fill_buf().unwrap(), b"");
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn test_heapless() {
+        let s = String::<8, u8>::from("héllo");
+        let h: heapless::String<8> = s.into();
+        assert_eq!(h, "héllo");
+        let back: String<8, u8> = h.clone().into();
+        assert_eq!(back, s);
+        assert_eq!(String::<16>::try_from(&h).unwrap(), s);
+        assert_eq!(String::<4>::try_from(&h), Err(CapacityError { requested: 6, available: 4 }));
+        assert_eq!(heapless::String::<4>::try_from(&s), Err(CapacityError { requested: 6, available: 4 }));
+
+        let mut v = Vec::<u16, 4>::new();
+        v.push(1);
+        v.push(2);
+        let hv: heapless::Vec<u16, 4> = v.into();
+        assert_eq!(hv.as_slice(), [1, 2]);
+        assert_eq!(Vec::<u16, 4>::from(hv.clone()).as_slice(), [1, 2]);
+        assert_eq!(Vec::<u16, 1>::try_from(&hv), Err(CapacityError { requested: 2, available: 1 }));
+        assert_eq!(heapless::Vec::<u16, 8>::try_from(&v).unwrap(), hv);
+    }
+
+    #[cfg(feature = "arrayvec")]
+    #[test]
+    fn test_arrayvec() {
+        use arrayvec::{ArrayString, ArrayVec};
+
+        let s = String::<8>::from("héllo");
+        let a: ArrayString<8> = s.into();
+        assert_eq!(a.as_str(), "héllo");
+        let back: String<8> = a.into();
+        assert_eq!(back, s);
+        assert_eq!(String::<16, u8>::try_from(&a).unwrap(), s);
+        assert_eq!(ArrayString::<4>::try_from(&s), Err(CapacityError { requested: 6, available: 4 }));
+
+        let mut v = Vec::<u16, 4>::new();
+        v.push(1);
+        v.push(2);
+        let av: ArrayVec<u16, 4> = v.into();
+        assert_eq!(av.as_slice(), [1, 2]);
+        assert_eq!(Vec::<u16, 4>::from(av.clone()).as_slice(), [1, 2]);
+        assert_eq!(Vec::<u16, 8>::try_from(&av).unwrap().as_slice(), [1, 2]);
+        assert_eq!(ArrayVec::<u16, 1>::try_from(&v), Err(CapacityError { requested: 2, available: 1 }));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_alloc() {
+        use alloc::borrow::Cow;
+        use alloc::boxed::Box;
+
+        let s = String::<8>::from("héllo");
+        assert_eq!(alloc::string::String::from(s), "héllo");
+        assert_eq!(&*Box::<str>::from(s), "héllo");
+        assert!(matches!(Cow::from(&s), Cow::Borrowed("héllo")));
+        assert!(matches!(Cow::from(s), Cow::Owned(_)));
+        assert_eq!(String::<8>::try_from(alloc::string::String::from("abc")).unwrap(), "abc");
+        assert_eq!(String::<8>::try_from(Box::<str>::from("abc")).unwrap(), "abc");
+        assert_eq!(String::<2>::try_from(Cow::Borrowed("abc")), Err(CapacityError { requested: 3, available: 2 }));
+
+        let mut v = Vec::<u16, 4>::new();
+        v.push(1);
+        v.push(2);
+        let av = alloc::vec::Vec::from(v);
+        assert_eq!(av, [1, 2]);
+        assert_eq!(Vec::<u16, 4>::try_from(av.clone()).unwrap().as_slice(), [1, 2]);
+        assert_eq!(Vec::<u16, 1>::try_from(av), Err(CapacityError { requested: 2, available: 1 }));
+    }
 }
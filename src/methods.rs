@@ -1,6 +1,84 @@
-use crate::String;
+use crate::pattern::next_match;
+use crate::len::{from_usize, LenType};
+use crate::{CapacityError, FromUtf8Error, Pattern, String, Vec};
+use core::ops::{Bound, Range, RangeBounds};
+
+/// Resolves `range` against a string of length `len`, panicking like slice indexing does if it is out of bounds.
+fn resolve_range(range: impl RangeBounds<usize>, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&i) => i,
+        Bound::Excluded(&i) => i.checked_add(1).expect("attempted to index slice from after maximum usize"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&i) => i.checked_add(1).expect("attempted to index slice up to maximum usize"),
+        Bound::Excluded(&i) => i,
+        Bound::Unbounded => len,
+    };
+    if start > end {
+        panic!("slice index starts at {} but ends at {}", start, end);
+    }
+    if end > len {
+        panic!("range end index {} out of range for slice of length {}", end, len);
+    }
+    start..end
+}
+
+/// Returns the number of bytes in the UTF-8 sequence starting with the leading byte `b`.
+pub(crate) fn utf8_char_width(b: u8) -> usize {
+    match b {
+        0x00..=0x7F => 1,
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        _ => 4,
+    }
+}
+
+/// Removes chars from a string in place, keeping it valid even if a callback panics midway.
+///
+/// The bytes before `write` are the chars kept so far, and the bytes from `read` on have not been looked at yet.
+/// When the compactor is dropped, during unwinding included, the bytes not looked at are moved down after the kept ones and the length is set to match.
+pub(crate) struct Compactor<'a, const N: usize, L: LenType> {
+    string: &'a mut String<N, L>,
+    read: usize,
+    write: usize,
+    end: usize,
+}
+
+impl<'a, const N: usize, L: LenType> Compactor<'a, N, L> {
+    pub(crate) fn new(string: &'a mut String<N, L>) -> Self {
+        let end = string.len();
+        Compactor { string, read: 0, write: 0, end }
+    }
+
+    /// Returns the text not looked at yet.
+    pub(crate) fn rest(&self) -> &str {
+        // Safety: `read` is always on a char boundary, and `keep` only writes below it, so the bytes from there on are still the original text
+        unsafe { core::str::from_utf8_unchecked(&self.string.vec.items[self.read..self.end]) }
+    }
 
-impl<const N: usize> core::ops::Deref for String<N> {
+    /// Keeps the next `n` bytes of the text not looked at yet, moving them down over the removed ones.
+    pub(crate) fn keep(&mut self, n: usize) {
+        self.string.vec.items.copy_within(self.read..self.read + n, self.write);
+        self.read += n;
+        self.write += n;
+    }
+
+    /// Removes the next `n` bytes of the text not looked at yet.
+    pub(crate) fn skip(&mut self, n: usize) {
+        self.read += n;
+    }
+}
+
+impl<const N: usize, L: LenType> Drop for Compactor<'_, N, L> {
+    fn drop(&mut self) {
+        let rest = self.end - self.read;
+        self.string.vec.items.copy_within(self.read..self.end, self.write);
+        self.string.vec.set_len(self.write + rest);
+    }
+}
+
+impl<const N: usize, L: LenType> core::ops::Deref for String<N, L> {
     type Target = str;
 
     fn deref(&self) -> &Self::Target {
@@ -8,283 +86,417 @@ impl<const N: usize> core::ops::Deref for String<N> {
     }
 }
 
-impl<const N: usize> String<N> {
+impl<const N: usize, L: LenType> String<N, L> {
 
     /// Creates a new string, with maximum byte length `N`.
     /// 
     /// Note that this doesn't create a string with a maximum length of `N` characters, but rather of `N` bytes. (UTF-8 characters can be multiple bytes long.)
-    pub fn new() -> Self {
-        String { chars: [0; N], len: 0 }
+    ///
+    /// This is a `const fn`, so it can be used to initialise `static`s and `const`s.
+    pub const fn new() -> Self {
+        const { assert!(N <= L::MAX, "the length type cannot hold the capacity N") };
+        String { vec: Vec { items: [0; N], len: L::ZERO } }
     }
 
+    /// [`std::string::String::as_bytes()`](https://doc.rust-lang.org/std/string/struct.String.html#method.as_bytes)
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.vec.items.split_at(self.vec.len()).0
+    }
 
     /// [`std::string::String::as_mut_str()`](https://doc.rust-lang.org/std/string/struct.String.html#method.as_mut_str)
     pub fn as_mut_str(&mut self) -> &mut str {
-        // Convert the byte slice to a string slice
-        core::str::from_utf8_mut(&mut self.chars[..self.len]).unwrap()
+        // Safety: the first `len` bytes are always valid UTF-8, see `as_str()`
+        unsafe { core::str::from_utf8_unchecked_mut(self.vec.as_mut_slice()) }
+    }
+
+    /// [`std::string::String::as_mut_vec()`](https://doc.rust-lang.org/std/string/struct.String.html#method.as_mut_vec)
+    ///
+    /// # Safety
+    /// The caller must ensure that the first `len` bytes of the returned `Vec` are valid UTF-8 when the borrow ends.
+    pub unsafe fn as_mut_vec(&mut self) -> &mut Vec<u8, N, L> {
+        &mut self.vec
     }
 
     /// [`std::string::String::as_str()`](https://doc.rust-lang.org/std/string/struct.String.html#method.as_str)
-    pub fn as_str(&self) -> &str {
-        // Convert the byte slice to a string slice
-        core::str::from_utf8(&self.chars[..self.len]).unwrap()
+    pub const fn as_str(&self) -> &str {
+        // Safety: the first `len` bytes are always valid UTF-8. Every method that writes to the buffer must keep this true,
+        // even if user code it calls (a `Pattern`, or the closure given to `retain()`) panics partway through:
+        // in-place rewrites either go through `Compactor`, whose drop leaves valid text behind, or only call user code between complete edits
+        unsafe { core::str::from_utf8_unchecked(self.as_bytes()) }
     }
 
 
     /// [`std::string::String::capacity()`](https://doc.rust-lang.org/std/string/struct.String.html#method.capacity)
-    pub fn capacity(&self) -> usize {
+    pub const fn capacity(&self) -> usize {
         N
     }
     
 
     /// [`std::string::String::chars()`](https://doc.rust-lang.org/std/string/struct.String.html#method.chars)
-    pub fn chars(&self) -> core::str::Chars {
+    pub fn chars(&self) -> core::str::Chars<'_> {
         self.as_str().chars()
     }
 
     /// [`std::string::String::clear()`](https://doc.rust-lang.org/std/string/struct.String.html#method.clear)
     pub fn clear(&mut self) {
-        self.len = 0;
+        self.vec.set_len(0);
     }
 
 
     /// [`std::string::String::drain()`](https://doc.rust-lang.org/std/string/struct.String.html#method.drain)
     /// Note that this implementation is not the same as the one in the standard library. It does the same, but rather returns the removed chars as a new string. This is because the Drain iterator is not in the `core` library.
-    pub fn drain(&mut self, range: core::ops::Range<usize>) -> String<N> {
-        // remove the chars in range
-        // return the removed chars
-        let mut i = range.start;
-        let mut j = range.end;
-        let mut s = String::<N>::new();
-        while i < j {
-            s.push(self.chars[i] as char);
-            i+=1;
-        }
-        while j < self.len {
-            self.chars[j-(range.end-range.start)] = self.chars[j];
-            j+=1;
-        }
-        self.len -= range.end-range.start;
-        s
+    ///
+    /// Panics if the start or end of the range do not lie on a `char` boundary, or if they are out of bounds.
+    pub fn drain(&mut self, range: impl RangeBounds<usize>) -> Self {
+        let range = resolve_range(range, self.len());
+        // slicing the str checks that both ends lie on char boundaries
+        let removed = Self::from(&self.as_str()[range.clone()]);
+        let len = self.len();
+        self.vec.items.copy_within(range.end..len, range.start);
+        self.vec.set_len(len - range.len());
+        removed
     }
 
 
     /// [`std::string::String::extend_from_within()`](https://doc.rust-lang.org/std/string/struct.String.html#method.extend_from_within)
-    pub fn extend_from_within(&mut self, range: core::ops::Range<usize>) {
-        // extend the string with the chars in range
-        let mut i = range.start;
-        while i < range.end {
-            self.push(self.chars[i] as char);
-            i+=1;
+    ///
+    /// Panics if the start or end of the range do not lie on a `char` boundary, or if they are out of bounds.
+    pub fn extend_from_within(&mut self, range: impl RangeBounds<usize>) {
+        self.try_extend_from_within(range).expect("String is full")
+    }
+
+    /// Fallible version of [`String::extend_from_within()`], returning a `CapacityError` instead of panicking if the string is full.
+    ///
+    /// Still panics if the range is out of bounds or does not lie on `char` boundaries.
+    pub fn try_extend_from_within(&mut self, range: impl RangeBounds<usize>) -> Result<(), CapacityError> {
+        let len = self.len();
+        let range = resolve_range(range, len);
+        assert!(self.is_char_boundary(range.start));
+        assert!(self.is_char_boundary(range.end));
+        let available = N - len;
+        let count = range.len();
+        if count > available {
+            return Err(CapacityError::new(count, available));
         }
+        self.vec.items.copy_within(range, len);
+        self.vec.set_len(len + count);
+        Ok(())
     }
 
     
     /// [`std::string::String::from()`](https://doc.rust-lang.org/std/string/struct.String.html#method.from)
-    pub fn from(s: &str) -> Self {
-        let mut str = String::<N>::new();
-        str.push_str(s);
-        str
+    ///
+    /// Panics if `s` is longer than `N` bytes. Use `String::try_from()` for a fallible version.
+    pub const fn from(s: &str) -> Self {
+        Self::from_str(s)
+    }
+
+    /// Creates a string from a `&str` in a `const` context.
+    ///
+    /// Panics if `s` is longer than `N` bytes. When evaluated at compile time (in a `const` or `static`) that panic is a compile error, so a literal that does not fit is caught at build time:
+    ///
+    /// ```
+    /// static GREETING: nstr::String<8> = nstr::String::from_str("hello");
+    /// assert_eq!(GREETING.as_str(), "hello");
+    /// ```
+    ///
+    /// ```compile_fail
+    /// static GREETING: nstr::String<4> = nstr::String::from_str("hello");
+    /// ```
+    pub const fn from_str(s: &str) -> Self {
+        const { assert!(N <= L::MAX, "the length type cannot hold the capacity N") };
+        let bytes = s.as_bytes();
+        assert!(bytes.len() <= N, "String is full");
+        let mut items = [0; N];
+        let mut i = 0;
+        while i < bytes.len() {
+            items[i] = bytes[i];
+            i += 1;
+        }
+        String { vec: Vec { items, len: from_usize(bytes.len()) } }
     }
 
-    /// [`std::string::String::get()`](https://doc.rust-lang.org/std/string/struct.String.html#method.get)
+    /// [`std::string::String::from_raw_parts()`](https://doc.rust-lang.org/std/string/struct.String.html#method.from_raw_parts)
+    ///
+    /// Unlike the standard library version this is safe, as the buffer and length are checked: `len` must be at most `N` and the first `len` bytes of `buf` must be valid UTF-8.
+    pub fn from_raw_parts(buf: [u8; N], len: usize) -> Result<Self, FromUtf8Error> {
+        if len > N {
+            return Err(FromUtf8Error::Capacity(CapacityError::new(len, N)));
+        }
+        core::str::from_utf8(&buf[..len]).map_err(FromUtf8Error::Utf8)?;
+        Ok(String { vec: Vec::from_raw_parts(buf, len) })
+    }
+
+    /// [`std::string::String::from_utf8()`](https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf8)
+    ///
+    /// Fails if `bytes` is not valid UTF-8, or if it is longer than `N` bytes.
+    pub fn from_utf8(bytes: &[u8]) -> Result<Self, FromUtf8Error> {
+        let s = core::str::from_utf8(bytes).map_err(FromUtf8Error::Utf8)?;
+        String::try_from(s).map_err(FromUtf8Error::Capacity)
+    }
+
+    /// [`std::string::String::from_utf8_unchecked()`](https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf8_unchecked)
+    ///
+    /// Panics if `bytes` is longer than `N` bytes.
+    ///
+    /// # Safety
+    /// `bytes` must be valid UTF-8.
+    pub unsafe fn from_utf8_unchecked(bytes: &[u8]) -> Self {
+        let mut s = Self::new();
+        s.vec.items[..bytes.len()].copy_from_slice(bytes);
+        s.vec.set_len(bytes.len());
+        s
+    }
+
+    /// [`str::get()`](https://doc.rust-lang.org/std/primitive.str.html#method.get)
     pub fn get<I>(&self, index: I) -> Option<&str>
     where
-        I: core::slice::SliceIndex<[u8], Output = [u8]>,
+        I: core::slice::SliceIndex<str, Output = str>,
     {
-        self.chars.get(index).map(|slice| core::str::from_utf8(slice)).transpose().unwrap()
+        self.as_str().get(index)
     }
 
-    /// [`std::string::String::get_mut()`](https://doc.rust-lang.org/std/string/struct.String.html#method.get_mut)
+    /// [`str::get_mut()`](https://doc.rust-lang.org/std/primitive.str.html#method.get_mut)
     pub fn get_mut<I>(&mut self, index: I) -> Option<&mut str>
     where
-        I: core::slice::SliceIndex<[u8], Output = [u8]>,
+        I: core::slice::SliceIndex<str, Output = str>,
     {
-        self.chars.get_mut(index).map(|slice| core::str::from_utf8_mut(slice)).transpose().unwrap()
+        self.as_mut_str().get_mut(index)
     }
 
-    /// [`std::string::String::get_unchecked()`](https://doc.rust-lang.org/std/string/struct.String.html#method.get_unchecked)
+    /// [`str::get_unchecked()`](https://doc.rust-lang.org/std/primitive.str.html#method.get_unchecked)
+    ///
+    /// Note that unlike the `str` version this is bounds checked, and panics if `index` is out of range or not on a `char` boundary.
     pub fn get_unchecked<I>(&self, index: I) -> &str
     where
-        I: core::slice::SliceIndex<[u8], Output = [u8]>,
+        I: core::slice::SliceIndex<str, Output = str>,
     {
-        core::str::from_utf8(self.chars.get(index).unwrap()).unwrap()
+        &self.as_str()[index]
     }
 
-    /// [`std::string::String::get_unchecked_mut()`](https://doc.rust-lang.org/std/string/struct.String.html#method.get_unchecked_mut)
+    /// [`str::get_unchecked_mut()`](https://doc.rust-lang.org/std/primitive.str.html#method.get_unchecked_mut)
+    ///
+    /// Note that unlike the `str` version this is bounds checked, and panics if `index` is out of range or not on a `char` boundary.
     pub fn get_unchecked_mut<I>(&mut self, index: I) -> &mut str
     where
-        I: core::slice::SliceIndex<[u8], Output = [u8]>,
+        I: core::slice::SliceIndex<str, Output = str>,
     {
-        core::str::from_utf8_mut(self.chars.get_mut(index).unwrap()).unwrap()
+        &mut self.as_mut_str()[index]
     }
 
     /// [`std::string::String::insert()`](https://doc.rust-lang.org/std/string/struct.String.html#method.insert)
+    ///
+    /// Panics if `index` does not lie on a `char` boundary, or if it is larger than the string's length.
     pub fn insert(&mut self, index: usize, c: char) {
-        // iterate while index < self.len
-        // push each char to the array
-        let mut i = index;
-        let mut prev = c;
-        while i < self.len {
-            let tmp = self.chars[i] as char;
-            self.chars[i] = prev as u8;
-            prev = tmp;
-            i+=1;
-        }
-        self.chars[i] = prev as u8;
-        self.len += 1;
+        self.try_insert(index, c).expect("String is full")
+    }
+
+    /// Fallible version of [`String::insert()`], returning a `CapacityError` instead of panicking if the string is full.
+    ///
+    /// Still panics if `index` does not lie on a `char` boundary.
+    pub fn try_insert(&mut self, index: usize, c: char) -> Result<(), CapacityError> {
+        let dst = &mut [0; 4];
+        self.try_insert_str(index, c.encode_utf8(dst))
     }
 
     /// [`std::string::String::insert_str()`](https://doc.rust-lang.org/std/string/struct.String.html#method.insert_str)
+    ///
+    /// Panics if `index` does not lie on a `char` boundary, or if it is larger than the string's length.
     pub fn insert_str(&mut self, index: usize, s: &str) {
-        // iterate while index < s.len
-        // push each char to the array
-        let mut i = index;
-        for c in s.chars() {
-            self.insert(i, c);
-            i+=1;
-        }        
+        self.try_insert_str(index, s).expect("String is full")
+    }
+
+    /// Fallible version of [`String::insert_str()`], returning a `CapacityError` instead of panicking if the string is full.
+    ///
+    /// Still panics if `index` does not lie on a `char` boundary.
+    pub fn try_insert_str(&mut self, index: usize, s: &str) -> Result<(), CapacityError> {
+        assert!(self.is_char_boundary(index));
+        let len = self.len();
+        let available = N - len;
+        if s.len() > available {
+            return Err(CapacityError::new(s.len(), available));
+        }
+        // shift the tail up to make room, then copy the new bytes into the gap
+        self.vec.items.copy_within(index..len, index + s.len());
+        self.vec.items[index..index + s.len()].copy_from_slice(s.as_bytes());
+        self.vec.set_len(len + s.len());
+        Ok(())
+    }
+
+    /// [`std::string::String::into_raw_parts()`](https://doc.rust-lang.org/std/string/struct.String.html#method.into_raw_parts)
+    ///
+    /// Returns the whole backing buffer and the length of the string within it. Bytes past the length are unspecified.
+    pub fn into_raw_parts(self) -> ([u8; N], usize) {
+        self.vec.into_raw_parts()
+    }
+
+    /// [`std::string::String::is_empty()`](https://doc.rust-lang.org/std/string/struct.String.html#method.is_empty)
+    pub const fn is_empty(&self) -> bool {
+        self.vec.len() == 0
+    }
+
+    /// [`std::string::String::len()`](https://doc.rust-lang.org/std/string/struct.String.html#method.len)
+    ///
+    /// Returns the length in bytes, not in `char`s.
+    pub const fn len(&self) -> usize {
+        self.vec.len()
     }
 
     /// [`std::string::String::lines()`](https://doc.rust-lang.org/std/string/struct.String.html#method.lines)
-    pub fn lines(&self) -> core::str::Lines {
+    pub fn lines(&self) -> core::str::Lines<'_> {
         self.as_str().lines()
     }
 
     /// [`std::string::String::make_ascii_lowercase()`](https://doc.rust-lang.org/std/string/struct.String.html#method.make_ascii_lowercase)
     pub fn make_ascii_lowercase(&mut self) {
-        // convert the string to ascii
-        for c in self.clone().as_str().chars() {
-            self.push(c.to_ascii_lowercase());
-        }
+        // ascii case mapping never changes the byte length, so this can be done in place
+        self.as_mut_str().make_ascii_lowercase();
     }
 
     /// [`std::string::String::make_ascii_uppercase()`](https://doc.rust-lang.org/std/string/struct.String.html#method.make_ascii_uppercase)
     pub fn make_ascii_uppercase(&mut self) {
-        // convert the string to ascii
-        for c in self.clone().as_str().chars() {
-            self.push(c.to_ascii_uppercase());
-        }
+        // ascii case mapping never changes the byte length, so this can be done in place
+        self.as_mut_str().make_ascii_uppercase();
     }
 
 
     /// [`std::string::String::pop()`](https://doc.rust-lang.org/std/string/struct.String.html#method.pop)
     pub fn pop(&mut self) -> Option<char> {
-        // remove the last char
+        // remove the last char (which may be several bytes long)
         // return the char
-        if self.len > 0 {
-            let c = self.chars[self.len-1] as char;
-            self.len -= 1;
-            Some(c)
-        } else {
-            None
-        }
+        let c = self.as_str().chars().next_back()?;
+        self.vec.set_len(self.len() - c.len_utf8());
+        Some(c)
     }
 
     /// [`std::string::String::push()`](https://doc.rust-lang.org/std/string/struct.String.html#method.push)
     pub fn push(&mut self, c: char) {
+        self.try_push(c).expect("String is full")
+    }
+
+    /// Fallible version of [`String::push()`], returning a `CapacityError` instead of panicking if the string is full.
+    pub fn try_push(&mut self, c: char) -> Result<(), CapacityError> {
         // Convert the char to a byte sequence
         let dst = &mut [0; 4];
-        let bytes = c.encode_utf8(dst).as_bytes();
-        // Iterate over the bytes and push them to the array
-        for &byte in bytes {
-            // Check if there is enough space in the array
-            if self.len < N {
-                // Add the byte to the array
-                self.chars[self.len] = byte;
-                // Increment the length
-                self.len += 1;
-            } else {
-                panic!("String is full")
-            }
-        }
+        self.try_push_str(c.encode_utf8(dst))
     }
 
     /// [`std::string::String::push_str()`](https://doc.rust-lang.org/std/string/struct.String.html#method.push_str)
     pub fn push_str(&mut self, s: &str) {
-        // Iterate over the chars of the string
-        for c in s.chars() {
-            // Push each char to the array
-            self.push(c);
+        self.try_push_str(s).expect("String is full")
+    }
+
+    /// Fallible version of [`String::push_str()`], returning a `CapacityError` instead of panicking if the string is full.
+    pub fn try_push_str(&mut self, s: &str) -> Result<(), CapacityError> {
+        // Check there is enough space for the whole string before copying anything
+        let len = self.len();
+        let available = N - len;
+        if s.len() > available {
+            return Err(CapacityError::new(s.len(), available));
         }
+        self.vec.items[len..len + s.len()].copy_from_slice(s.as_bytes());
+        self.vec.set_len(len + s.len());
+        Ok(())
     }
 
     /// [`std::string::String::remove()`](https://doc.rust-lang.org/std/string/struct.String.html#method.remove)
+    ///
+    /// Panics if `index` is larger than or equal to the string's length, or if it does not lie on a `char` boundary.
     pub fn remove(&mut self, index: usize) -> char {
         // remove the char at index
         // return the char
-        let mut i = index;
-        let mut c = ' ';
-        while i < self.len {
-            c = self.chars[i] as char;
-            self.chars[i] = self.chars[i+1];
-            i+=1;
-        }
-        self.len -= 1;
+        let c = match self.as_str()[index..].chars().next() {
+            Some(c) => c,
+            None => panic!("cannot remove a char from the end of a string"),
+        };
+        let next = index + c.len_utf8();
+        let len = self.len();
+        self.vec.items.copy_within(next..len, index);
+        self.vec.set_len(len - (next - index));
         c
     }
 
     /// [`std::string::String::repeat()`](https://doc.rust-lang.org/std/string/struct.String.html#method.repeat)
-    pub fn repeat(&self, n: usize) -> String<N> {
+    pub fn repeat(&self, n: usize) -> Self {
+        self.try_repeat(n).expect("String is full")
+    }
+
+    /// Fallible version of [`String::repeat()`], returning a `CapacityError` instead of panicking if the result does not fit.
+    pub fn try_repeat(&self, n: usize) -> Result<Self, CapacityError> {
         // repeat the string n times
-        let mut s = String::<N>::new();
+        let requested = self.len().saturating_mul(n);
+        if requested > N {
+            return Err(CapacityError::new(requested, N));
+        }
+        let mut s = Self::new();
         for _ in 0..n {
-            s.push_str(self.as_str());
+            s.try_push_str(self.as_str())?;
         }
-        s
+        Ok(s)
     }
 
     /// [`std::string::String::replace()`](https://doc.rust-lang.org/std/string/struct.String.html#method.replace)
-    pub fn replace(&self, from: &str, to: &str) -> String<N> {
-        // iterate over the chars of self
-        // check if the chars of self are equal to from
-        // if yes, push to to the string
-        let mut i = 0;
-        let mut j = 0;
-        let mut s = String::<N>::new();
-        while i < self.len {
-            if self.chars[i] == from.as_bytes()[j] {
-                j+=1;
-                if j == from.len() {
-                    s.push_str(to);
-                    j = 0;
-                }
-            } else {
-                j = 0;
-            }
-            s.push(self.chars[i] as char);
-            i+=1;
-        }
-        s
+    pub fn replace<P: Pattern>(&self, from: P, to: &str) -> Self {
+        self.try_replace(from, to).expect("String is full")
+    }
+
+    /// Fallible version of [`String::replace()`], returning a `CapacityError` instead of panicking if the result does not fit.
+    pub fn try_replace<P: Pattern>(&self, from: P, to: &str) -> Result<Self, CapacityError> {
+        self.try_replacen(from, to, usize::MAX)
+    }
+
+    /// Replaces every match of `from` with `to`, rewriting the string in place rather than building a new one.
+    /// Returns the number of replacements made.
+    ///
+    /// See [`String::replacen_in_place()`] for when this fails.
+    pub fn replace_in_place<P: Pattern>(&mut self, from: P, to: &str) -> Result<usize, CapacityError> {
+        self.replacen_in_place(from, to, usize::MAX)
     }
 
     /// [`std::string::String::replacen()`](https://doc.rust-lang.org/std/string/struct.String.html#method.replacen)
-    pub fn replacen(&self, from: &str, to: &str, n: usize) -> String<N> {
-        // iterate over the chars of self
-        // check if the chars of self are equal to from
-        // if yes, push to to the string
-        let mut i = 0;
-        let mut j = 0;
-        let mut s = String::<N>::new();
+    pub fn replacen<P: Pattern>(&self, from: P, to: &str, n: usize) -> Self {
+        self.try_replacen(from, to, n).expect("String is full")
+    }
+
+    /// Fallible version of [`String::replacen()`], returning a `CapacityError` instead of panicking if the result does not fit.
+    pub fn try_replacen<P: Pattern>(&self, from: P, to: &str, n: usize) -> Result<Self, CapacityError> {
+        // copy the text between matches, pushing `to` in place of each match
+        let mut s = Self::new();
+        let mut last = 0;
+        for (i, m) in self.match_indices(from).take(n) {
+            s.try_push_str(&self.as_str()[last..i])?;
+            s.try_push_str(to)?;
+            last = i + m.len();
+        }
+        s.try_push_str(&self.as_str()[last..])?;
+        Ok(s)
+    }
+
+    /// Replaces the first `n` matches of `from` with `to`, rewriting the string in place rather than building a new one.
+    /// Returns the number of replacements made.
+    ///
+    /// The pattern is run over the string once, and each match is replaced as soon as it is found.
+    /// If a replacement does not fit, a `CapacityError` is returned: the matches before it stay replaced, and the rest of the string is left as it was.
+    pub fn replacen_in_place<P: Pattern>(&mut self, mut from: P, to: &str, n: usize) -> Result<usize, CapacityError> {
+        // replace each match as it is found, moving the rest of the string up or down to fit
+        let (mut position, mut finished) = (0, false);
         let mut count = 0;
-        while i < self.len {
-            if self.chars[i] == from.as_bytes()[j] {
-                j+=1;
-                if j == from.len() {
-                    s.push_str(to);
-                    j = 0;
-                    count+=1;
-                    if count == n {
-                        break;
-                    }
-                }
-            } else {
-                j = 0;
+        while count < n {
+            let Some(m) = next_match(&mut from, self, &mut position, &mut finished) else {
+                break;
+            };
+            let len = self.len();
+            if len - m.len() + to.len() > N {
+                return Err(CapacityError::new(to.len() - m.len(), N - len));
             }
-            s.push(self.chars[i] as char);
-            i+=1;
+            self.vec.items.copy_within(m.end..len, m.start + to.len());
+            self.vec.items[m.start..m.start + to.len()].copy_from_slice(to.as_bytes());
+            self.vec.set_len(len - m.len() + to.len());
+            // the search carries on from the same point in the original text, which has moved
+            position = position - m.len() + to.len();
+            count += 1;
         }
-        s
+        Ok(count)
     }
 
     /// [`std::string::String::retain()`](https://doc.rust-lang.org/std/string/struct.String.html#method.retain)
@@ -292,59 +504,21 @@ impl<const N: usize> String<N> {
     where
         F: FnMut(char) -> bool,
     {
-        // iterate over the chars of self
-        // check if the char should be removed
-        let mut i = 0;
-        while i < self.len {
-            if !f(self.chars[i] as char) {
-                self.remove(i);
+        // walk the string one char at a time, moving every kept char down over the removed ones
+        let mut compactor = Compactor::new(self);
+        while let Some(c) = compactor.rest().chars().next() {
+            if f(c) {
+                compactor.keep(c.len_utf8());
             } else {
-                i+=1;
+                compactor.skip(c.len_utf8());
             }
         }
     }
 
-    /// [`std::string::String::rfind()`](https://doc.rust-lang.org/std/string/struct.String.html#method.rfind)
-    pub fn rfind(&self, s: &str) -> Option<usize> {
-        // iterate over the chars of self
-        // check if the chars of self are equal to s
-        let mut i = self.len - 1;
-        let mut j = s.len() - 1;
-        while i > 0 {
-            if self.chars[i] == s.as_bytes()[j] {
-                j-=1;
-                if j == 0 {
-                    return Some(i-j+1);
-                }
-            } else {
-                j = s.len() - 1;
-            }
-            i-=1;
-        }
-        None
-    }
-
-
-    /// [`std::string::String::starts_with()`](https://doc.rust-lang.org/std/string/struct.String.html#method.starts_with)
-    pub fn starts_with(&self, s: &str) -> bool {
-        // iterate over the chars of s
-        // check if the first chars of self are equal to s
-        let mut i = 0;
-        let mut j = 0;
-        while i < s.len() {
-            if self.chars[j] != s.as_bytes()[i] {
-                return false;
-            }
-            i+=1;
-            j+=1;
-        }
-        true
-    }
-
     /// [`std::string::String::to_ascii_lowercase()`](https://doc.rust-lang.org/std/string/struct.String.html#method.to_ascii_lowercase)
-    pub fn to_ascii_lowercase(&self) -> String<N> {
+    pub fn to_ascii_lowercase(&self) -> Self {
         // convert the string to lowercase
-        let mut s = String::<N>::new();
+        let mut s = Self::new();
         for c in self.as_str().chars() {
             s.push(c.to_ascii_lowercase());
         }
@@ -352,9 +526,9 @@ impl<const N: usize> String<N> {
     }
 
     /// [`std::string::String::to_ascii_uppercase()`](https://doc.rust-lang.org/std/string/struct.String.html#method.to_ascii_uppercase)
-    pub fn to_ascii_uppercase(&self) -> String<N> {
+    pub fn to_ascii_uppercase(&self) -> Self {
         // convert the string to uppercase
-        let mut s = String::<N>::new();
+        let mut s = Self::new();
         for c in self.as_str().chars() {
             s.push(c.to_ascii_uppercase());
         }
@@ -362,30 +536,81 @@ impl<const N: usize> String<N> {
     }
 
     /// [`std::string::String::to_lowercase()`](https://doc.rust-lang.org/std/string/struct.String.html#method.to_lowercase)
-    pub fn to_lowercase(&self) -> String<N> {
-        // convert the string to lowercase
-        let mut s = String::<N>::new();
-        for c in self.as_str().chars() {
-            s.push(c.to_lowercase().next().unwrap());
-        }
-        s
+    ///
+    /// Uses the full Unicode mappings, where one char may become several, and lowercases `'Σ'` to `'ς'` at the end of a word.
+    /// Panics if the result does not fit.
+    pub fn to_lowercase(&self) -> Self {
+        self.try_to_lowercase().expect("String is full")
+    }
+
+    /// Fallible version of [`String::to_lowercase()`], returning a `CapacityError` instead of panicking if the result does not fit.
+    pub fn try_to_lowercase(&self) -> Result<Self, CapacityError> {
+        self.try_map_case(false)
     }
 
     /// [`std::string::String::to_uppercase()`](https://doc.rust-lang.org/std/string/struct.String.html#to_uppercase.to_mut)
-    pub fn to_uppercase(&self) -> String<N> {
-        // convert the string to uppercase
-        let mut s = String::<N>::new();
-        for c in self.as_str().chars() {
-            s.push(c.to_uppercase().next().unwrap());
-        }
-        s
+    ///
+    /// Uses the full Unicode mappings, where one char may become several, such as `'ß'` to `"SS"`.
+    /// Panics if the result does not fit.
+    pub fn to_uppercase(&self) -> Self {
+        self.try_to_uppercase().expect("String is full")
+    }
+
+    /// Fallible version of [`String::to_uppercase()`], returning a `CapacityError` instead of panicking if the result does not fit.
+    pub fn try_to_uppercase(&self) -> Result<Self, CapacityError> {
+        self.try_map_case(true)
     }
+
     /// [`std::string::String::truncate()`](https://doc.rust-lang.org/std/string/struct.String.html#method.truncate)
+    ///
+    /// Panics if `new_len` does not lie on a `char` boundary.
     pub fn truncate(&mut self, new_len: usize) {
         // truncate the string to new_len
-        // if new_len < self.len
-        if new_len < self.len {
-            self.len = new_len;
+        // if new_len < self.len()
+        if new_len < self.len() {
+            assert!(self.is_char_boundary(new_len));
+            self.vec.set_len(new_len);
         }
     }
-}
\ No newline at end of file
+
+    /// Copies the string into a string with a different capacity `M`, returning a `CapacityError` if it does not fit.
+    pub fn try_resize<const M: usize>(&self) -> Result<String<M, L>, CapacityError> {
+        String::<M, L>::try_from(self.as_str())
+    }
+
+    /// Copies the string into a string with a capacity `M` at least as large, which can never fail.
+    ///
+    /// Calling this with `M < N` is a compile error (use [`String::try_resize()`] to shrink a string):
+    ///
+    /// ```compile_fail
+    /// let s = nstr::String::<8>::from("abc");
+    /// let t: nstr::String<4> = s.widen();
+    /// ```
+    pub fn widen<const M: usize>(&self) -> String<M, L> {
+        const { assert!(M >= N, "widen() cannot shrink a String, use try_resize()") };
+        let mut s = String::<M, L>::new();
+        s.vec.items[..self.len()].copy_from_slice(self.as_bytes());
+        s.vec.set_len(self.len());
+        s
+    }
+}
+
+impl<const N: usize, L: LenType> TryFrom<&str> for String<N, L> {
+    type Error = CapacityError;
+
+    /// Fallible version of [`String::from()`], returning a `CapacityError` instead of panicking if `s` does not fit.
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let mut str = Self::new();
+        str.try_push_str(s)?;
+        Ok(str)
+    }
+}
+
+impl<const N: usize, L: LenType, const M: usize, L2: LenType> TryFrom<&String<M, L2>> for String<N, L> {
+    type Error = CapacityError;
+
+    /// Copies a string of a different capacity, returning a `CapacityError` if it does not fit.
+    fn try_from(s: &String<M, L2>) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
@@ -0,0 +1,363 @@
+use crate::len::LenType;
+use crate::{CapacityError, String};
+
+mod tables;
+
+/// A Unicode normalization form, as defined by [UAX #15](https://www.unicode.org/reports/tr15/).
+///
+/// Requires the `normalization` feature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NormalizationForm {
+    /// Canonical decomposition, followed by canonical composition
+    Nfc,
+    /// Canonical decomposition
+    Nfd,
+    /// Compatibility decomposition, followed by canonical composition
+    Nfkc,
+    /// Compatibility decomposition
+    Nfkd,
+}
+
+impl NormalizationForm {
+    fn is_compatibility(self) -> bool {
+        matches!(self, NormalizationForm::Nfkc | NormalizationForm::Nfkd)
+    }
+
+    fn is_composed(self) -> bool {
+        matches!(self, NormalizationForm::Nfc | NormalizationForm::Nfkc)
+    }
+}
+
+// Hangul syllables are decomposed and composed algorithmically, see section 3.12 of the Unicode standard
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+/// Converts a code point from the tables, or from the Hangul arithmetic, to a `char`.
+fn to_char(c: u32) -> char {
+    // Safety: the tables only hold valid chars, and the Hangul arithmetic stays within the Hangul blocks
+    unsafe { char::from_u32_unchecked(c) }
+}
+
+/// Returns true if `c` is in one of the sorted `ranges`.
+fn in_ranges(ranges: &[(u32, u32)], c: char) -> bool {
+    let c = c as u32;
+    let i = ranges.partition_point(|&(_, end)| end < c);
+    ranges.get(i).is_some_and(|&(start, _)| start <= c)
+}
+
+fn combining_class(c: char) -> u8 {
+    let c = c as u32;
+    let i = tables::COMBINING_CLASSES.partition_point(|&(_, last, _)| last < c);
+    match tables::COMBINING_CLASSES.get(i) {
+        Some(&(first, _, class)) if first <= c => class,
+        _ => 0,
+    }
+}
+
+/// Passes the full canonical (or compatibility) decomposition of `c` to `emit`, not yet in canonical order.
+fn decompose(c: char, compatibility: bool, emit: &mut impl FnMut(char)) {
+    let cp = c as u32;
+    if (S_BASE..S_BASE + S_COUNT).contains(&cp) {
+        let s = cp - S_BASE;
+        emit(to_char(L_BASE + s / N_COUNT));
+        emit(to_char(V_BASE + s % N_COUNT / T_COUNT));
+        if s % T_COUNT != 0 {
+            emit(to_char(T_BASE + s % T_COUNT));
+        }
+        return;
+    }
+    match tables::DECOMPOSITIONS.binary_search_by_key(&cp, |&(c, ..)| c) {
+        Ok(i) if compatibility || !tables::DECOMPOSITIONS[i].1 => {
+            let (_, _, start, len) = tables::DECOMPOSITIONS[i];
+            for &d in &tables::DECOMPOSITION_DATA[start as usize..start as usize + len as usize] {
+                decompose(to_char(d), compatibility, emit);
+            }
+        }
+        _ => emit(c),
+    }
+}
+
+/// Returns the primary composite of `a` followed by `b`, if there is one.
+fn compose(a: char, b: char) -> Option<char> {
+    let (a, b) = (a as u32, b as u32);
+    if (L_BASE..L_BASE + L_COUNT).contains(&a) && (V_BASE..V_BASE + V_COUNT).contains(&b) {
+        return Some(to_char(S_BASE + ((a - L_BASE) * V_COUNT + (b - V_BASE)) * T_COUNT));
+    }
+    if (S_BASE..S_BASE + S_COUNT).contains(&a) && (a - S_BASE) % T_COUNT == 0 && (T_BASE + 1..T_BASE + T_COUNT).contains(&b) {
+        return Some(to_char(a + (b - T_BASE)));
+    }
+    let i = tables::COMPOSITIONS.binary_search_by_key(&(a, b), |&(a, b, _)| (a, b)).ok()?;
+    Some(to_char(tables::COMPOSITIONS[i].2))
+}
+
+/// The most non-starters in a row, as in the stream-safe text format of UAX #15; real text never comes close.
+///
+/// A longer run is split by a combining grapheme joiner (U+034F) after every 30 non-starters, so that each part can be put in canonical order on its own.
+const MAX_NON_STARTERS: usize = 30;
+
+/// The combining grapheme joiner, a starter that composes with nothing
+const CGJ: char = '\u{34F}';
+
+/// The most chars of one segment: a starter and the non-starters after it, which are put in canonical order and composed together.
+const SEGMENT: usize = MAX_NON_STARTERS + 1;
+
+/// Normalizes a stream of decomposed chars one segment at a time, passing the output to the `emit` given to each call.
+struct Normalizer {
+    form: NormalizationForm,
+    segment: [(char, u8); SEGMENT],
+    len: usize,
+    /// The number of non-starters since the last starter
+    non_starters: usize,
+}
+
+impl Normalizer {
+    fn new(form: NormalizationForm) -> Self {
+        Normalizer { form, segment: [('\0', 0); SEGMENT], len: 0, non_starters: 0 }
+    }
+
+    fn push(&mut self, c: char, emit: &mut impl FnMut(char)) {
+        let class = combining_class(c);
+        if class == 0 {
+            self.non_starters = 0;
+            self.settle();
+            // a starter can still compose with a previous starter, as with Hangul jamo
+            if self.form.is_composed() && self.len == 1 && self.segment[0].1 == 0 {
+                if let Some(composite) = compose(self.segment[0].0, c) {
+                    self.segment[0].0 = composite;
+                    return;
+                }
+            }
+            self.flush(emit);
+        } else {
+            if self.non_starters == MAX_NON_STARTERS {
+                self.settle();
+                self.flush(emit);
+                self.segment[0] = (CGJ, 0);
+                self.len = 1;
+                self.non_starters = 0;
+            }
+            self.non_starters += 1;
+        }
+        self.segment[self.len] = (c, class);
+        self.len += 1;
+    }
+
+    /// Puts the segment in canonical order, then composes it if the form asks for it.
+    fn settle(&mut self) {
+        let segment = &mut self.segment[..self.len];
+        // a stable insertion sort by combining class, the starter (if any) stays first
+        for i in 1..segment.len() {
+            let mut j = i;
+            while j > 0 && segment[j - 1].1 > segment[j].1 {
+                segment.swap(j - 1, j);
+                j -= 1;
+            }
+        }
+        if !self.form.is_composed() || segment.first().is_none_or(|&(_, class)| class != 0) {
+            return;
+        }
+        // the non-starters are in order, so one is blocked from the starter exactly when the last one kept has the same class
+        let (mut kept, mut last_class) = (1, 0);
+        for i in 1..segment.len() {
+            let (c, class) = segment[i];
+            if last_class < class {
+                if let Some(composite) = compose(segment[0].0, c) {
+                    segment[0].0 = composite;
+                    continue;
+                }
+            }
+            segment[kept] = (c, class);
+            kept += 1;
+            last_class = class;
+        }
+        self.len = kept;
+    }
+
+    /// Passes the rest of the output to `emit`, at the end of the text.
+    fn finish(&mut self, emit: &mut impl FnMut(char)) {
+        self.settle();
+        self.flush(emit);
+    }
+
+    fn flush(&mut self, emit: &mut impl FnMut(char)) {
+        for &(c, _) in &self.segment[..self.len] {
+            emit(c);
+        }
+        self.len = 0;
+    }
+}
+
+/// Passes the normalized chars of `s` to `emit`.
+fn normalize(s: &str, form: NormalizationForm, mut emit: impl FnMut(char)) {
+    let mut normalizer = Normalizer::new(form);
+    for c in s.chars() {
+        decompose(c, form.is_compatibility(), &mut |d| normalizer.push(d, &mut emit));
+    }
+    normalizer.finish(&mut emit);
+}
+
+/// The value of a quick check property of UAX #15 for one char
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum QuickCheck {
+    Yes,
+    No,
+    /// The char can compose with the char before it
+    Maybe,
+}
+
+fn quick_check(c: char, form: NormalizationForm) -> QuickCheck {
+    let cp = c as u32;
+    let no = match form {
+        NormalizationForm::Nfc => in_ranges(tables::NFC_NO, c),
+        NormalizationForm::Nfkc => in_ranges(tables::NFKC_NO, c),
+        // a char is in NFD or NFKD unless it decomposes
+        NormalizationForm::Nfd | NormalizationForm::Nfkd => {
+            (S_BASE..S_BASE + S_COUNT).contains(&cp)
+                || tables::DECOMPOSITIONS.binary_search_by_key(&cp, |&(c, ..)| c).is_ok_and(|i| form.is_compatibility() || !tables::DECOMPOSITIONS[i].1)
+        }
+    };
+    if no {
+        QuickCheck::No
+    } else if form.is_composed()
+        && ((V_BASE..V_BASE + V_COUNT).contains(&cp) || (T_BASE + 1..T_BASE + T_COUNT).contains(&cp) || in_ranges(tables::COMPOSES_WITH_PREVIOUS, c))
+    {
+        QuickCheck::Maybe
+    } else {
+        QuickCheck::Yes
+    }
+}
+
+/// Answers whether `s` is in the normalization `form` from the quick check properties and the canonical order of its chars, as in UAX #15.
+///
+/// Also answers `Maybe` for a run of non-starters too long for the stream-safe text format, which `normalize` would split.
+fn quick_check_str(s: &str, form: NormalizationForm) -> QuickCheck {
+    let mut result = QuickCheck::Yes;
+    let (mut last_class, mut non_starters) = (0, 0);
+    for c in s.chars() {
+        if c.is_ascii() {
+            (last_class, non_starters) = (0, 0);
+            continue;
+        }
+        let class = combining_class(c);
+        if class != 0 && last_class > class {
+            return QuickCheck::No;
+        }
+        match quick_check(c, form) {
+            QuickCheck::No => return QuickCheck::No,
+            QuickCheck::Maybe => result = QuickCheck::Maybe,
+            QuickCheck::Yes => {}
+        }
+        if class == 0 {
+            // a composed starter still counts the non-starters it decomposes to
+            non_starters = 0;
+            decompose(c, form.is_compatibility(), &mut |d| non_starters += usize::from(combining_class(d) != 0));
+        } else {
+            non_starters += 1;
+        }
+        if non_starters > MAX_NON_STARTERS {
+            result = QuickCheck::Maybe;
+        }
+        last_class = class;
+    }
+    result
+}
+
+impl<const N: usize, L: LenType> String<N, L> {
+    /// Returns true if the string is already in the normalization `form`.
+    ///
+    /// Most text is answered from the quick check properties of UAX #15 alone, in one pass over the string.
+    /// Only text with chars that might compose with the char before them is normalized on the fly and compared, without a buffer.
+    pub fn is_normalized(&self, form: NormalizationForm) -> bool {
+        match quick_check_str(self, form) {
+            QuickCheck::Yes => return true,
+            QuickCheck::No => return false,
+            QuickCheck::Maybe => {}
+        }
+        let mut chars = self.chars();
+        let mut same = true;
+        normalize(self, form, |c| same &= chars.next() == Some(c));
+        same && chars.next().is_none()
+    }
+
+    /// Converts the string to the normalization `form` in place.
+    ///
+    /// Panics if the normalized text does not fit.
+    pub fn normalize(&mut self, form: NormalizationForm) {
+        self.try_normalize(form).expect("String is full")
+    }
+
+    /// Returns the string in the normalization `form`, failing if it is longer than `M` bytes.
+    ///
+    /// The tables follow the Unicode version given by [`UNICODE_VERSION`](crate::UNICODE_VERSION).
+    /// The text is first made stream-safe as described in UAX #15: in a run of more than 30 non-starters (such as combining accents) in a row,
+    /// which does not occur in real text, a combining grapheme joiner (U+034F) is inserted after every 30 so that the result stays in canonical order.
+    ///
+    /// ```
+    /// use nstr::{NormalizationForm, String};
+    ///
+    /// let composed = String::<8>::from("é");
+    /// let decomposed = composed.normalize_into::<8>(NormalizationForm::Nfd).unwrap();
+    /// assert_eq!(decomposed, "e\u{301}");
+    /// assert_eq!(decomposed.normalize_into::<8>(NormalizationForm::Nfc).unwrap(), composed);
+    /// assert_eq!(String::<8>::from("ﬁ").normalize_into::<8>(NormalizationForm::Nfkc).unwrap(), "fi");
+    /// ```
+    pub fn normalize_into<const M: usize>(&self, form: NormalizationForm) -> Result<String<M, L>, CapacityError> {
+        let mut requested = 0;
+        normalize(self, form, |c| requested += c.len_utf8());
+        if requested > M {
+            return Err(CapacityError::new(requested, M));
+        }
+        let mut s = String::new();
+        normalize(self, form, |c| s.push(c));
+        Ok(s)
+    }
+
+    /// Fallible version of [`String::normalize()`], leaving the string unchanged if the normalized text does not fit.
+    ///
+    /// The text is normalized in place, from the front, so the string must also have room for every intermediate result.
+    /// This can only be larger than the final result when a char that grows comes before text that shrinks, such as `"¼"` before a few `"e\u{301}"` in NFKC.
+    pub fn try_normalize(&mut self, form: NormalizationForm) -> Result<(), CapacityError> {
+        // measure the result, and how far the normalized text gets ahead of the text it comes from
+        let len = self.len();
+        let (mut requested, mut ahead) = (0, 0);
+        let mut normalizer = Normalizer::new(form);
+        let mut measure = |end: usize, requested: &mut usize, c: char| {
+            *requested += c.len_utf8();
+            ahead = ahead.max(requested.saturating_sub(end));
+        };
+        for (i, c) in self.char_indices() {
+            let end = i + c.len_utf8();
+            decompose(c, form.is_compatibility(), &mut |d| normalizer.push(d, &mut |n| measure(end, &mut requested, n)));
+        }
+        normalizer.finish(&mut |n| measure(len, &mut requested, n));
+        if requested > N || ahead > N - len {
+            return Err(CapacityError::new(requested.max(len + ahead), N));
+        }
+
+        // move the text to the end of the buffer, then write the normalized text from the start, which never overtakes the text still to read
+        let start = N - len;
+        self.vec.items.copy_within(..len, start);
+        let (mut read, mut written) = (start, 0);
+        let mut normalizer = Normalizer::new(form);
+        let items = &mut self.vec.items;
+        let mut write = |items: &mut [u8; N], c: char| written += c.encode_utf8(&mut items[written..]).len();
+        while read < N {
+            // Safety: the bytes from `read` are the end of the original text, starting at a char boundary
+            let Some(c) = unsafe { core::str::from_utf8_unchecked(&items[read..]) }.chars().next() else {
+                break;
+            };
+            read += c.len_utf8();
+            decompose(c, form.is_compatibility(), &mut |d| normalizer.push(d, &mut |n| write(items, n)));
+        }
+        normalizer.finish(&mut |n| write(items, n));
+        self.vec.set_len(written);
+        Ok(())
+    }
+}
@@ -0,0 +1,2270 @@
+// Generated by tools/unicode-tables from the Unicode Character Database, version 17.0.0. Do not edit by hand.
+
+/// The canonical combining class of each range of chars `(first, last, class)`, for the chars whose class is not zero
+#[rustfmt::skip]
+pub(super) const COMBINING_CLASSES: &[(u32, u32, u8)] = &[
+    (0x300, 0x314, 230), (0x315, 0x315, 232), (0x316, 0x319, 220), (0x31A, 0x31A, 232), (0x31B, 0x31B, 216), (0x31C, 0x320, 220),
+    (0x321, 0x322, 202), (0x323, 0x326, 220), (0x327, 0x328, 202), (0x329, 0x333, 220), (0x334, 0x338, 1), (0x339, 0x33C, 220),
+    (0x33D, 0x344, 230), (0x345, 0x345, 240), (0x346, 0x346, 230), (0x347, 0x349, 220), (0x34A, 0x34C, 230), (0x34D, 0x34E, 220),
+    (0x350, 0x352, 230), (0x353, 0x356, 220), (0x357, 0x357, 230), (0x358, 0x358, 232), (0x359, 0x35A, 220), (0x35B, 0x35B, 230),
+    (0x35C, 0x35C, 233), (0x35D, 0x35E, 234), (0x35F, 0x35F, 233), (0x360, 0x361, 234), (0x362, 0x362, 233), (0x363, 0x36F, 230),
+    (0x483, 0x487, 230), (0x591, 0x591, 220), (0x592, 0x595, 230), (0x596, 0x596, 220), (0x597, 0x599, 230), (0x59A, 0x59A, 222),
+    (0x59B, 0x59B, 220), (0x59C, 0x5A1, 230), (0x5A2, 0x5A7, 220), (0x5A8, 0x5A9, 230), (0x5AA, 0x5AA, 220), (0x5AB, 0x5AC, 230),
+    (0x5AD, 0x5AD, 222), (0x5AE, 0x5AE, 228), (0x5AF, 0x5AF, 230), (0x5B0, 0x5B0, 10), (0x5B1, 0x5B1, 11), (0x5B2, 0x5B2, 12),
+    (0x5B3, 0x5B3, 13), (0x5B4, 0x5B4, 14), (0x5B5, 0x5B5, 15), (0x5B6, 0x5B6, 16), (0x5B7, 0x5B7, 17), (0x5B8, 0x5B8, 18),
+    (0x5B9, 0x5BA, 19), (0x5BB, 0x5BB, 20), (0x5BC, 0x5BC, 21), (0x5BD, 0x5BD, 22), (0x5BF, 0x5BF, 23), (0x5C1, 0x5C1, 24),
+    (0x5C2, 0x5C2, 25), (0x5C4, 0x5C4, 230), (0x5C5, 0x5C5, 220), (0x5C7, 0x5C7, 18), (0x610, 0x617, 230), (0x618, 0x618, 30),
+    (0x619, 0x619, 31), (0x61A, 0x61A, 32), (0x64B, 0x64B, 27), (0x64C, 0x64C, 28), (0x64D, 0x64D, 29), (0x64E, 0x64E, 30),
+    (0x64F, 0x64F, 31), (0x650, 0x650, 32), (0x651, 0x651, 33), (0x652, 0x652, 34), (0x653, 0x654, 230), (0x655, 0x656, 220),
+    (0x657, 0x65B, 230), (0x65C, 0x65C, 220), (0x65D, 0x65E, 230), (0x65F, 0x65F, 220), (0x670, 0x670, 35), (0x6D6, 0x6DC, 230),
+    (0x6DF, 0x6E2, 230), (0x6E3, 0x6E3, 220), (0x6E4, 0x6E4, 230), (0x6E7, 0x6E8, 230), (0x6EA, 0x6EA, 220), (0x6EB, 0x6EC, 230),
+    (0x6ED, 0x6ED, 220), (0x711, 0x711, 36), (0x730, 0x730, 230), (0x731, 0x731, 220), (0x732, 0x733, 230), (0x734, 0x734, 220),
+    (0x735, 0x736, 230), (0x737, 0x739, 220), (0x73A, 0x73A, 230), (0x73B, 0x73C, 220), (0x73D, 0x73D, 230), (0x73E, 0x73E, 220),
+    (0x73F, 0x741, 230), (0x742, 0x742, 220), (0x743, 0x743, 230), (0x744, 0x744, 220), (0x745, 0x745, 230), (0x746, 0x746, 220),
+    (0x747, 0x747, 230), (0x748, 0x748, 220), (0x749, 0x74A, 230), (0x7EB, 0x7F1, 230), (0x7F2, 0x7F2, 220), (0x7F3, 0x7F3, 230),
+    (0x7FD, 0x7FD, 220), (0x816, 0x819, 230), (0x81B, 0x823, 230), (0x825, 0x827, 230), (0x829, 0x82D, 230), (0x859, 0x85B, 220),
+    (0x897, 0x898, 230), (0x899, 0x89B, 220), (0x89C, 0x89F, 230), (0x8CA, 0x8CE, 230), (0x8CF, 0x8D3, 220), (0x8D4, 0x8E1, 230),
+    (0x8E3, 0x8E3, 220), (0x8E4, 0x8E5, 230), (0x8E6, 0x8E6, 220), (0x8E7, 0x8E8, 230), (0x8E9, 0x8E9, 220), (0x8EA, 0x8EC, 230),
+    (0x8ED, 0x8EF, 220), (0x8F0, 0x8F0, 27), (0x8F1, 0x8F1, 28), (0x8F2, 0x8F2, 29), (0x8F3, 0x8F5, 230), (0x8F6, 0x8F6, 220),
+    (0x8F7, 0x8F8, 230), (0x8F9, 0x8FA, 220), (0x8FB, 0x8FF, 230), (0x93C, 0x93C, 7), (0x94D, 0x94D, 9), (0x951, 0x951, 230),
+    (0x952, 0x952, 220), (0x953, 0x954, 230), (0x9BC, 0x9BC, 7), (0x9CD, 0x9CD, 9), (0x9FE, 0x9FE, 230), (0xA3C, 0xA3C, 7),
+    (0xA4D, 0xA4D, 9), (0xABC, 0xABC, 7), (0xACD, 0xACD, 9), (0xB3C, 0xB3C, 7), (0xB4D, 0xB4D, 9), (0xBCD, 0xBCD, 9),
+    (0xC3C, 0xC3C, 7), (0xC4D, 0xC4D, 9), (0xC55, 0xC55, 84), (0xC56, 0xC56, 91), (0xCBC, 0xCBC, 7), (0xCCD, 0xCCD, 9),
+    (0xD3B, 0xD3C, 9), (0xD4D, 0xD4D, 9), (0xDCA, 0xDCA, 9), (0xE38, 0xE39, 103), (0xE3A, 0xE3A, 9), (0xE48, 0xE4B, 107),
+    (0xEB8, 0xEB9, 118), (0xEBA, 0xEBA, 9), (0xEC8, 0xECB, 122), (0xF18, 0xF19, 220), (0xF35, 0xF35, 220), (0xF37, 0xF37, 220),
+    (0xF39, 0xF39, 216), (0xF71, 0xF71, 129), (0xF72, 0xF72, 130), (0xF74, 0xF74, 132), (0xF7A, 0xF7D, 130), (0xF80, 0xF80, 130),
+    (0xF82, 0xF83, 230), (0xF84, 0xF84, 9), (0xF86, 0xF87, 230), (0xFC6, 0xFC6, 220), (0x1037, 0x1037, 7), (0x1039, 0x103A, 9),
+    (0x108D, 0x108D, 220), (0x135D, 0x135F, 230), (0x1714, 0x1715, 9), (0x1734, 0x1734, 9), (0x17D2, 0x17D2, 9), (0x17DD, 0x17DD, 230),
+    (0x18A9, 0x18A9, 228), (0x1939, 0x1939, 222), (0x193A, 0x193A, 230), (0x193B, 0x193B, 220), (0x1A17, 0x1A17, 230), (0x1A18, 0x1A18, 220),
+    (0x1A60, 0x1A60, 9), (0x1A75, 0x1A7C, 230), (0x1A7F, 0x1A7F, 220), (0x1AB0, 0x1AB4, 230), (0x1AB5, 0x1ABA, 220), (0x1ABB, 0x1ABC, 230),
+    (0x1ABD, 0x1ABD, 220), (0x1ABF, 0x1AC0, 220), (0x1AC1, 0x1AC2, 230), (0x1AC3, 0x1AC4, 220), (0x1AC5, 0x1AC9, 230), (0x1ACA, 0x1ACA, 220),
+    (0x1ACB, 0x1ADC, 230), (0x1ADD, 0x1ADD, 220), (0x1AE0, 0x1AE5, 230), (0x1AE6, 0x1AE6, 220), (0x1AE7, 0x1AEA, 230), (0x1AEB, 0x1AEB, 234),
+    (0x1B34, 0x1B34, 7), (0x1B44, 0x1B44, 9), (0x1B6B, 0x1B6B, 230), (0x1B6C, 0x1B6C, 220), (0x1B6D, 0x1B73, 230), (0x1BAA, 0x1BAB, 9),
+    (0x1BE6, 0x1BE6, 7), (0x1BF2, 0x1BF3, 9), (0x1C37, 0x1C37, 7), (0x1CD0, 0x1CD2, 230), (0x1CD4, 0x1CD4, 1), (0x1CD5, 0x1CD9, 220),
+    (0x1CDA, 0x1CDB, 230), (0x1CDC, 0x1CDF, 220), (0x1CE0, 0x1CE0, 230), (0x1CE2, 0x1CE8, 1), (0x1CED, 0x1CED, 220), (0x1CF4, 0x1CF4, 230),
+    (0x1CF8, 0x1CF9, 230), (0x1DC0, 0x1DC1, 230), (0x1DC2, 0x1DC2, 220), (0x1DC3, 0x1DC9, 230), (0x1DCA, 0x1DCA, 220), (0x1DCB, 0x1DCC, 230),
+    (0x1DCD, 0x1DCD, 234), (0x1DCE, 0x1DCE, 214), (0x1DCF, 0x1DCF, 220), (0x1DD0, 0x1DD0, 202), (0x1DD1, 0x1DF5, 230), (0x1DF6, 0x1DF6, 232),
+    (0x1DF7, 0x1DF8, 228), (0x1DF9, 0x1DF9, 220), (0x1DFA, 0x1DFA, 218), (0x1DFB, 0x1DFB, 230), (0x1DFC, 0x1DFC, 233), (0x1DFD, 0x1DFD, 220),
+    (0x1DFE, 0x1DFE, 230), (0x1DFF, 0x1DFF, 220), (0x20D0, 0x20D1, 230), (0x20D2, 0x20D3, 1), (0x20D4, 0x20D7, 230), (0x20D8, 0x20DA, 1),
+    (0x20DB, 0x20DC, 230), (0x20E1, 0x20E1, 230), (0x20E5, 0x20E6, 1), (0x20E7, 0x20E7, 230), (0x20E8, 0x20E8, 220), (0x20E9, 0x20E9, 230),
+    (0x20EA, 0x20EB, 1), (0x20EC, 0x20EF, 220), (0x20F0, 0x20F0, 230), (0x2CEF, 0x2CF1, 230), (0x2D7F, 0x2D7F, 9), (0x2DE0, 0x2DFF, 230),
+    (0x302A, 0x302A, 218), (0x302B, 0x302B, 228), (0x302C, 0x302C, 232), (0x302D, 0x302D, 222), (0x302E, 0x302F, 224), (0x3099, 0x309A, 8),
+    (0xA66F, 0xA66F, 230), (0xA674, 0xA67D, 230), (0xA69E, 0xA69F, 230), (0xA6F0, 0xA6F1, 230), (0xA806, 0xA806, 9), (0xA82C, 0xA82C, 9),
+    (0xA8C4, 0xA8C4, 9), (0xA8E0, 0xA8F1, 230), (0xA92B, 0xA92D, 220), (0xA953, 0xA953, 9), (0xA9B3, 0xA9B3, 7), (0xA9C0, 0xA9C0, 9),
+    (0xAAB0, 0xAAB0, 230), (0xAAB2, 0xAAB3, 230), (0xAAB4, 0xAAB4, 220), (0xAAB7, 0xAAB8, 230), (0xAABE, 0xAABF, 230), (0xAAC1, 0xAAC1, 230),
+    (0xAAF6, 0xAAF6, 9), (0xABED, 0xABED, 9), (0xFB1E, 0xFB1E, 26), (0xFE20, 0xFE26, 230), (0xFE27, 0xFE2D, 220), (0xFE2E, 0xFE2F, 230),
+    (0x101FD, 0x101FD, 220), (0x102E0, 0x102E0, 220), (0x10376, 0x1037A, 230), (0x10A0D, 0x10A0D, 220), (0x10A0F, 0x10A0F, 230), (0x10A38, 0x10A38, 230),
+    (0x10A39, 0x10A39, 1), (0x10A3A, 0x10A3A, 220), (0x10A3F, 0x10A3F, 9), (0x10AE5, 0x10AE5, 230), (0x10AE6, 0x10AE6, 220), (0x10D24, 0x10D27, 230),
+    (0x10D69, 0x10D6D, 230), (0x10EAB, 0x10EAC, 230), (0x10EFA, 0x10EFB, 220), (0x10EFD, 0x10EFF, 220), (0x10F46, 0x10F47, 220), (0x10F48, 0x10F4A, 230),
+    (0x10F4B, 0x10F4B, 220), (0x10F4C, 0x10F4C, 230), (0x10F4D, 0x10F50, 220), (0x10F82, 0x10F82, 230), (0x10F83, 0x10F83, 220), (0x10F84, 0x10F84, 230),
+    (0x10F85, 0x10F85, 220), (0x11046, 0x11046, 9), (0x11070, 0x11070, 9), (0x1107F, 0x1107F, 9), (0x110B9, 0x110B9, 9), (0x110BA, 0x110BA, 7),
+    (0x11100, 0x11102, 230), (0x11133, 0x11134, 9), (0x11173, 0x11173, 7), (0x111C0, 0x111C0, 9), (0x111CA, 0x111CA, 7), (0x11235, 0x11235, 9),
+    (0x11236, 0x11236, 7), (0x112E9, 0x112E9, 7), (0x112EA, 0x112EA, 9), (0x1133B, 0x1133C, 7), (0x1134D, 0x1134D, 9), (0x11366, 0x1136C, 230),
+    (0x11370, 0x11374, 230), (0x113CE, 0x113D0, 9), (0x11442, 0x11442, 9), (0x11446, 0x11446, 7), (0x1145E, 0x1145E, 230), (0x114C2, 0x114C2, 9),
+    (0x114C3, 0x114C3, 7), (0x115BF, 0x115BF, 9), (0x115C0, 0x115C0, 7), (0x1163F, 0x1163F, 9), (0x116B6, 0x116B6, 9), (0x116B7, 0x116B7, 7),
+    (0x1172B, 0x1172B, 9), (0x11839, 0x11839, 9), (0x1183A, 0x1183A, 7), (0x1193D, 0x1193E, 9), (0x11943, 0x11943, 7), (0x119E0, 0x119E0, 9),
+    (0x11A34, 0x11A34, 9), (0x11A47, 0x11A47, 9), (0x11A99, 0x11A99, 9), (0x11C3F, 0x11C3F, 9), (0x11D42, 0x11D42, 7), (0x11D44, 0x11D45, 9),
+    (0x11D97, 0x11D97, 9), (0x11F41, 0x11F42, 9), (0x1612F, 0x1612F, 9), (0x16AF0, 0x16AF4, 1), (0x16B30, 0x16B36, 230), (0x16FF0, 0x16FF1, 6),
+    (0x1BC9E, 0x1BC9E, 1), (0x1D165, 0x1D166, 216), (0x1D167, 0x1D169, 1), (0x1D16D, 0x1D16D, 226), (0x1D16E, 0x1D172, 216), (0x1D17B, 0x1D182, 220),
+    (0x1D185, 0x1D189, 230), (0x1D18A, 0x1D18B, 220), (0x1D1AA, 0x1D1AD, 230), (0x1D242, 0x1D244, 230), (0x1E000, 0x1E006, 230), (0x1E008, 0x1E018, 230),
+    (0x1E01B, 0x1E021, 230), (0x1E023, 0x1E024, 230), (0x1E026, 0x1E02A, 230), (0x1E08F, 0x1E08F, 230), (0x1E130, 0x1E136, 230), (0x1E2AE, 0x1E2AE, 230),
+    (0x1E2EC, 0x1E2EF, 230), (0x1E4EC, 0x1E4ED, 232), (0x1E4EE, 0x1E4EE, 220), (0x1E4EF, 0x1E4EF, 230), (0x1E5EE, 0x1E5EE, 230), (0x1E5EF, 0x1E5EF, 220),
+    (0x1E6E3, 0x1E6E3, 230), (0x1E6E6, 0x1E6E6, 230), (0x1E6EE, 0x1E6EF, 230), (0x1E6F5, 0x1E6F5, 230), (0x1E8D0, 0x1E8D6, 220), (0x1E944, 0x1E949, 230),
+    (0x1E94A, 0x1E94A, 7),
+];
+
+/// The decomposition mapping of each char `(char, is_compatibility, start, len)`, as a slice of `DECOMPOSITION_DATA`.
+/// Canonical mappings are one level deep: the chars they map to may decompose further. Compatibility mappings are fully decomposed.
+/// Hangul syllables are decomposed algorithmically.
+#[rustfmt::skip]
+pub(super) const DECOMPOSITIONS: &[(u32, bool, u16, u8)] = &[
+    (0xA0, true, 0, 1), (0xA8, true, 1, 2), (0xAA, true, 3, 1), (0xAF, true, 4, 2), (0xB2, true, 6, 1),
+    (0xB3, true, 7, 1), (0xB4, true, 8, 2), (0xB5, true, 10, 1), (0xB8, true, 11, 2), (0xB9, true, 13, 1),
+    (0xBA, true, 14, 1), (0xBC, true, 15, 3), (0xBD, true, 18, 3), (0xBE, true, 21, 3), (0xC0, false, 24, 2),
+    (0xC1, false, 26, 2), (0xC2, false, 28, 2), (0xC3, false, 30, 2), (0xC4, false, 32, 2), (0xC5, false, 34, 2),
+    (0xC7, false, 36, 2), (0xC8, false, 38, 2), (0xC9, false, 40, 2), (0xCA, false, 42, 2), (0xCB, false, 44, 2),
+    (0xCC, false, 46, 2), (0xCD, false, 48, 2), (0xCE, false, 50, 2), (0xCF, false, 52, 2), (0xD1, false, 54, 2),
+    (0xD2, false, 56, 2), (0xD3, false, 58, 2), (0xD4, false, 60, 2), (0xD5, false, 62, 2), (0xD6, false, 64, 2),
+    (0xD9, false, 66, 2), (0xDA, false, 68, 2), (0xDB, false, 70, 2), (0xDC, false, 72, 2), (0xDD, false, 74, 2),
+    (0xE0, false, 76, 2), (0xE1, false, 78, 2), (0xE2, false, 80, 2), (0xE3, false, 82, 2), (0xE4, false, 84, 2),
+    (0xE5, false, 86, 2), (0xE7, false, 88, 2), (0xE8, false, 90, 2), (0xE9, false, 92, 2), (0xEA, false, 94, 2),
+    (0xEB, false, 96, 2), (0xEC, false, 98, 2), (0xED, false, 100, 2), (0xEE, false, 102, 2), (0xEF, false, 104, 2),
+    (0xF1, false, 106, 2), (0xF2, false, 108, 2), (0xF3, false, 110, 2), (0xF4, false, 112, 2), (0xF5, false, 114, 2),
+    (0xF6, false, 116, 2), (0xF9, false, 118, 2), (0xFA, false, 120, 2), (0xFB, false, 122, 2), (0xFC, false, 124, 2),
+    (0xFD, false, 126, 2), (0xFF, false, 128, 2), (0x100, false, 130, 2), (0x101, false, 132, 2), (0x102, false, 134, 2),
+    (0x103, false, 136, 2), (0x104, false, 138, 2), (0x105, false, 140, 2), (0x106, false, 142, 2), (0x107, false, 144, 2),
+    (0x108, false, 146, 2), (0x109, false, 148, 2), (0x10A, false, 150, 2), (0x10B, false, 152, 2), (0x10C, false, 154, 2),
+    (0x10D, false, 156, 2), (0x10E, false, 158, 2), (0x10F, false, 160, 2), (0x112, false, 162, 2), (0x113, false, 164, 2),
+    (0x114, false, 166, 2), (0x115, false, 168, 2), (0x116, false, 170, 2), (0x117, false, 172, 2), (0x118, false, 174, 2),
+    (0x119, false, 176, 2), (0x11A, false, 178, 2), (0x11B, false, 180, 2), (0x11C, false, 182, 2), (0x11D, false, 184, 2),
+    (0x11E, false, 186, 2), (0x11F, false, 188, 2), (0x120, false, 190, 2), (0x121, false, 192, 2), (0x122, false, 194, 2),
+    (0x123, false, 196, 2), (0x124, false, 198, 2), (0x125, false, 200, 2), (0x128, false, 202, 2), (0x129, false, 204, 2),
+    (0x12A, false, 206, 2), (0x12B, false, 208, 2), (0x12C, false, 210, 2), (0x12D, false, 212, 2), (0x12E, false, 214, 2),
+    (0x12F, false, 216, 2), (0x130, false, 218, 2), (0x132, true, 220, 2), (0x133, true, 222, 2), (0x134, false, 224, 2),
+    (0x135, false, 226, 2), (0x136, false, 228, 2), (0x137, false, 230, 2), (0x139, false, 232, 2), (0x13A, false, 234, 2),
+    (0x13B, false, 236, 2), (0x13C, false, 238, 2), (0x13D, false, 240, 2), (0x13E, false, 242, 2), (0x13F, true, 244, 2),
+    (0x140, true, 246, 2), (0x143, false, 248, 2), (0x144, false, 250, 2), (0x145, false, 252, 2), (0x146, false, 254, 2),
+    (0x147, false, 256, 2), (0x148, false, 258, 2), (0x149, true, 260, 2), (0x14C, false, 262, 2), (0x14D, false, 264, 2),
+    (0x14E, false, 266, 2), (0x14F, false, 268, 2), (0x150, false, 270, 2), (0x151, false, 272, 2), (0x154, false, 274, 2),
+    (0x155, false, 276, 2), (0x156, false, 278, 2), (0x157, false, 280, 2), (0x158, false, 282, 2), (0x159, false, 284, 2),
+    (0x15A, false, 286, 2), (0x15B, false, 288, 2), (0x15C, false, 290, 2), (0x15D, false, 292, 2), (0x15E, false, 294, 2),
+    (0x15F, false, 296, 2), (0x160, false, 298, 2), (0x161, false, 300, 2), (0x162, false, 302, 2), (0x163, false, 304, 2),
+    (0x164, false, 306, 2), (0x165, false, 308, 2), (0x168, false, 310, 2), (0x169, false, 312, 2), (0x16A, false, 314, 2),
+    (0x16B, false, 316, 2), (0x16C, false, 318, 2), (0x16D, false, 320, 2), (0x16E, false, 322, 2), (0x16F, false, 324, 2),
+    (0x170, false, 326, 2), (0x171, false, 328, 2), (0x172, false, 330, 2), (0x173, false, 332, 2), (0x174, false, 334, 2),
+    (0x175, false, 336, 2), (0x176, false, 338, 2), (0x177, false, 340, 2), (0x178, false, 342, 2), (0x179, false, 344, 2),
+    (0x17A, false, 346, 2), (0x17B, false, 348, 2), (0x17C, false, 350, 2), (0x17D, false, 352, 2), (0x17E, false, 354, 2),
+    (0x17F, true, 356, 1), (0x1A0, false, 357, 2), (0x1A1, false, 359, 2), (0x1AF, false, 361, 2), (0x1B0, false, 363, 2),
+    (0x1C4, true, 365, 3), (0x1C5, true, 368, 3), (0x1C6, true, 371, 3), (0x1C7, true, 374, 2), (0x1C8, true, 376, 2),
+    (0x1C9, true, 378, 2), (0x1CA, true, 380, 2), (0x1CB, true, 382, 2), (0x1CC, true, 384, 2), (0x1CD, false, 386, 2),
+    (0x1CE, false, 388, 2), (0x1CF, false, 390, 2), (0x1D0, false, 392, 2), (0x1D1, false, 394, 2), (0x1D2, false, 396, 2),
+    (0x1D3, false, 398, 2), (0x1D4, false, 400, 2), (0x1D5, false, 402, 2), (0x1D6, false, 404, 2), (0x1D7, false, 406, 2),
+    (0x1D8, false, 408, 2), (0x1D9, false, 410, 2), (0x1DA, false, 412, 2), (0x1DB, false, 414, 2), (0x1DC, false, 416, 2),
+    (0x1DE, false, 418, 2), (0x1DF, false, 420, 2), (0x1E0, false, 422, 2), (0x1E1, false, 424, 2), (0x1E2, false, 426, 2),
+    (0x1E3, false, 428, 2), (0x1E6, false, 430, 2), (0x1E7, false, 432, 2), (0x1E8, false, 434, 2), (0x1E9, false, 436, 2),
+    (0x1EA, false, 438, 2), (0x1EB, false, 440, 2), (0x1EC, false, 442, 2), (0x1ED, false, 444, 2), (0x1EE, false, 446, 2),
+    (0x1EF, false, 448, 2), (0x1F0, false, 450, 2), (0x1F1, true, 452, 2), (0x1F2, true, 454, 2), (0x1F3, true, 456, 2),
+    (0x1F4, false, 458, 2), (0x1F5, false, 460, 2), (0x1F8, false, 462, 2), (0x1F9, false, 464, 2), (0x1FA, false, 466, 2),
+    (0x1FB, false, 468, 2), (0x1FC, false, 470, 2), (0x1FD, false, 472, 2), (0x1FE, false, 474, 2), (0x1FF, false, 476, 2),
+    (0x200, false, 478, 2), (0x201, false, 480, 2), (0x202, false, 482, 2), (0x203, false, 484, 2), (0x204, false, 486, 2),
+    (0x205, false, 488, 2), (0x206, false, 490, 2), (0x207, false, 492, 2), (0x208, false, 494, 2), (0x209, false, 496, 2),
+    (0x20A, false, 498, 2), (0x20B, false, 500, 2), (0x20C, false, 502, 2), (0x20D, false, 504, 2), (0x20E, false, 506, 2),
+    (0x20F, false, 508, 2), (0x210, false, 510, 2), (0x211, false, 512, 2), (0x212, false, 514, 2), (0x213, false, 516, 2),
+    (0x214, false, 518, 2), (0x215, false, 520, 2), (0x216, false, 522, 2), (0x217, false, 524, 2), (0x218, false, 526, 2),
+    (0x219, false, 528, 2), (0x21A, false, 530, 2), (0x21B, false, 532, 2), (0x21E, false, 534, 2), (0x21F, false, 536, 2),
+    (0x226, false, 538, 2), (0x227, false, 540, 2), (0x228, false, 542, 2), (0x229, false, 544, 2), (0x22A, false, 546, 2),
+    (0x22B, false, 548, 2), (0x22C, false, 550, 2), (0x22D, false, 552, 2), (0x22E, false, 554, 2), (0x22F, false, 556, 2),
+    (0x230, false, 558, 2), (0x231, false, 560, 2), (0x232, false, 562, 2), (0x233, false, 564, 2), (0x2B0, true, 566, 1),
+    (0x2B1, true, 567, 1), (0x2B2, true, 568, 1), (0x2B3, true, 569, 1), (0x2B4, true, 570, 1), (0x2B5, true, 571, 1),
+    (0x2B6, true, 572, 1), (0x2B7, true, 573, 1), (0x2B8, true, 574, 1), (0x2D8, true, 575, 2), (0x2D9, true, 577, 2),
+    (0x2DA, true, 579, 2), (0x2DB, true, 581, 2), (0x2DC, true, 583, 2), (0x2DD, true, 585, 2), (0x2E0, true, 587, 1),
+    (0x2E1, true, 588, 1), (0x2E2, true, 589, 1), (0x2E3, true, 590, 1), (0x2E4, true, 591, 1), (0x340, false, 592, 1),
+    (0x341, false, 593, 1), (0x343, false, 594, 1), (0x344, false, 595, 2), (0x374, false, 597, 1), (0x37A, true, 598, 2),
+    (0x37E, false, 600, 1), (0x384, true, 601, 2), (0x385, false, 603, 2), (0x386, false, 605, 2), (0x387, false, 607, 1),
+    (0x388, false, 608, 2), (0x389, false, 610, 2), (0x38A, false, 612, 2), (0x38C, false, 614, 2), (0x38E, false, 616, 2),
+    (0x38F, false, 618, 2), (0x390, false, 620, 2), (0x3AA, false, 622, 2), (0x3AB, false, 624, 2), (0x3AC, false, 626, 2),
+    (0x3AD, false, 628, 2), (0x3AE, false, 630, 2), (0x3AF, false, 632, 2), (0x3B0, false, 634, 2), (0x3CA, false, 636, 2),
+    (0x3CB, false, 638, 2), (0x3CC, false, 640, 2), (0x3CD, false, 642, 2), (0x3CE, false, 644, 2), (0x3D0, true, 646, 1),
+    (0x3D1, true, 647, 1), (0x3D2, true, 648, 1), (0x3D3, false, 649, 2), (0x3D4, false, 651, 2), (0x3D5, true, 653, 1),
+    (0x3D6, true, 654, 1), (0x3F0, true, 655, 1), (0x3F1, true, 656, 1), (0x3F2, true, 657, 1), (0x3F4, true, 658, 1),
+    (0x3F5, true, 659, 1), (0x3F9, true, 660, 1), (0x400, false, 661, 2), (0x401, false, 663, 2), (0x403, false, 665, 2),
+    (0x407, false, 667, 2), (0x40C, false, 669, 2), (0x40D, false, 671, 2), (0x40E, false, 673, 2), (0x419, false, 675, 2),
+    (0x439, false, 677, 2), (0x450, false, 679, 2), (0x451, false, 681, 2), (0x453, false, 683, 2), (0x457, false, 685, 2),
+    (0x45C, false, 687, 2), (0x45D, false, 689, 2), (0x45E, false, 691, 2), (0x476, false, 693, 2), (0x477, false, 695, 2),
+    (0x4C1, false, 697, 2), (0x4C2, false, 699, 2), (0x4D0, false, 701, 2), (0x4D1, false, 703, 2), (0x4D2, false, 705, 2),
+    (0x4D3, false, 707, 2), (0x4D6, false, 709, 2), (0x4D7, false, 711, 2), (0x4DA, false, 713, 2), (0x4DB, false, 715, 2),
+    (0x4DC, false, 717, 2), (0x4DD, false, 719, 2), (0x4DE, false, 721, 2), (0x4DF, false, 723, 2), (0x4E2, false, 725, 2),
+    (0x4E3, false, 727, 2), (0x4E4, false, 729, 2), (0x4E5, false, 731, 2), (0x4E6, false, 733, 2), (0x4E7, false, 735, 2),
+    (0x4EA, false, 737, 2), (0x4EB, false, 739, 2), (0x4EC, false, 741, 2), (0x4ED, false, 743, 2), (0x4EE, false, 745, 2),
+    (0x4EF, false, 747, 2), (0x4F0, false, 749, 2), (0x4F1, false, 751, 2), (0x4F2, false, 753, 2), (0x4F3, false, 755, 2),
+    (0x4F4, false, 757, 2), (0x4F5, false, 759, 2), (0x4F8, false, 761, 2), (0x4F9, false, 763, 2), (0x587, true, 765, 2),
+    (0x622, false, 767, 2), (0x623, false, 769, 2), (0x624, false, 771, 2), (0x625, false, 773, 2), (0x626, false, 775, 2),
+    (0x675, true, 777, 2), (0x676, true, 779, 2), (0x677, true, 781, 2), (0x678, true, 783, 2), (0x6C0, false, 785, 2),
+    (0x6C2, false, 787, 2), (0x6D3, false, 789, 2), (0x929, false, 791, 2), (0x931, false, 793, 2), (0x934, false, 795, 2),
+    (0x958, false, 797, 2), (0x959, false, 799, 2), (0x95A, false, 801, 2), (0x95B, false, 803, 2), (0x95C, false, 805, 2),
+    (0x95D, false, 807, 2), (0x95E, false, 809, 2), (0x95F, false, 811, 2), (0x9CB, false, 813, 2), (0x9CC, false, 815, 2),
+    (0x9DC, false, 817, 2), (0x9DD, false, 819, 2), (0x9DF, false, 821, 2), (0xA33, false, 823, 2), (0xA36, false, 825, 2),
+    (0xA59, false, 827, 2), (0xA5A, false, 829, 2), (0xA5B, false, 831, 2), (0xA5E, false, 833, 2), (0xB48, false, 835, 2),
+    (0xB4B, false, 837, 2), (0xB4C, false, 839, 2), (0xB5C, false, 841, 2), (0xB5D, false, 843, 2), (0xB94, false, 845, 2),
+    (0xBCA, false, 847, 2), (0xBCB, false, 849, 2), (0xBCC, false, 851, 2), (0xC48, false, 853, 2), (0xCC0, false, 855, 2),
+    (0xCC7, false, 857, 2), (0xCC8, false, 859, 2), (0xCCA, false, 861, 2), (0xCCB, false, 863, 2), (0xD4A, false, 865, 2),
+    (0xD4B, false, 867, 2), (0xD4C, false, 869, 2), (0xDDA, false, 871, 2), (0xDDC, false, 873, 2), (0xDDD, false, 875, 2),
+    (0xDDE, false, 877, 2), (0xE33, true, 879, 2), (0xEB3, true, 881, 2), (0xEDC, true, 883, 2), (0xEDD, true, 885, 2),
+    (0xF0C, true, 887, 1), (0xF43, false, 888, 2), (0xF4D, false, 890, 2), (0xF52, false, 892, 2), (0xF57, false, 894, 2),
+    (0xF5C, false, 896, 2), (0xF69, false, 898, 2), (0xF73, false, 900, 2), (0xF75, false, 902, 2), (0xF76, false, 904, 2),
+    (0xF77, true, 906, 3), (0xF78, false, 909, 2), (0xF79, true, 911, 3), (0xF81, false, 914, 2), (0xF93, false, 916, 2),
+    (0xF9D, false, 918, 2), (0xFA2, false, 920, 2), (0xFA7, false, 922, 2), (0xFAC, false, 924, 2), (0xFB9, false, 926, 2),
+    (0x1026, false, 928, 2), (0x10FC, true, 930, 1), (0x1B06, false, 931, 2), (0x1B08, false, 933, 2), (0x1B0A, false, 935, 2),
+    (0x1B0C, false, 937, 2), (0x1B0E, false, 939, 2), (0x1B12, false, 941, 2), (0x1B3B, false, 943, 2), (0x1B3D, false, 945, 2),
+    (0x1B40, false, 947, 2), (0x1B41, false, 949, 2), (0x1B43, false, 951, 2), (0x1D2C, true, 953, 1), (0x1D2D, true, 954, 1),
+    (0x1D2E, true, 955, 1), (0x1D30, true, 956, 1), (0x1D31, true, 957, 1), (0x1D32, true, 958, 1), (0x1D33, true, 959, 1),
+    (0x1D34, true, 960, 1), (0x1D35, true, 961, 1), (0x1D36, true, 962, 1), (0x1D37, true, 963, 1), (0x1D38, true, 964, 1),
+    (0x1D39, true, 965, 1), (0x1D3A, true, 966, 1), (0x1D3C, true, 967, 1), (0x1D3D, true, 968, 1), (0x1D3E, true, 969, 1),
+    (0x1D3F, true, 970, 1), (0x1D40, true, 971, 1), (0x1D41, true, 972, 1), (0x1D42, true, 973, 1), (0x1D43, true, 974, 1),
+    (0x1D44, true, 975, 1), (0x1D45, true, 976, 1), (0x1D46, true, 977, 1), (0x1D47, true, 978, 1), (0x1D48, true, 979, 1),
+    (0x1D49, true, 980, 1), (0x1D4A, true, 981, 1), (0x1D4B, true, 982, 1), (0x1D4C, true, 983, 1), (0x1D4D, true, 984, 1),
+    (0x1D4F, true, 985, 1), (0x1D50, true, 986, 1), (0x1D51, true, 987, 1), (0x1D52, true, 988, 1), (0x1D53, true, 989, 1),
+    (0x1D54, true, 990, 1), (0x1D55, true, 991, 1), (0x1D56, true, 992, 1), (0x1D57, true, 993, 1), (0x1D58, true, 994, 1),
+    (0x1D59, true, 995, 1), (0x1D5A, true, 996, 1), (0x1D5B, true, 997, 1), (0x1D5C, true, 998, 1), (0x1D5D, true, 999, 1),
+    (0x1D5E, true, 1000, 1), (0x1D5F, true, 1001, 1), (0x1D60, true, 1002, 1), (0x1D61, true, 1003, 1), (0x1D62, true, 1004, 1),
+    (0x1D63, true, 1005, 1), (0x1D64, true, 1006, 1), (0x1D65, true, 1007, 1), (0x1D66, true, 1008, 1), (0x1D67, true, 1009, 1),
+    (0x1D68, true, 1010, 1), (0x1D69, true, 1011, 1), (0x1D6A, true, 1012, 1), (0x1D78, true, 1013, 1), (0x1D9B, true, 1014, 1),
+    (0x1D9C, true, 1015, 1), (0x1D9D, true, 1016, 1), (0x1D9E, true, 1017, 1), (0x1D9F, true, 1018, 1), (0x1DA0, true, 1019, 1),
+    (0x1DA1, true, 1020, 1), (0x1DA2, true, 1021, 1), (0x1DA3, true, 1022, 1), (0x1DA4, true, 1023, 1), (0x1DA5, true, 1024, 1),
+    (0x1DA6, true, 1025, 1), (0x1DA7, true, 1026, 1), (0x1DA8, true, 1027, 1), (0x1DA9, true, 1028, 1), (0x1DAA, true, 1029, 1),
+    (0x1DAB, true, 1030, 1), (0x1DAC, true, 1031, 1), (0x1DAD, true, 1032, 1), (0x1DAE, true, 1033, 1), (0x1DAF, true, 1034, 1),
+    (0x1DB0, true, 1035, 1), (0x1DB1, true, 1036, 1), (0x1DB2, true, 1037, 1), (0x1DB3, true, 1038, 1), (0x1DB4, true, 1039, 1),
+    (0x1DB5, true, 1040, 1), (0x1DB6, true, 1041, 1), (0x1DB7, true, 1042, 1), (0x1DB8, true, 1043, 1), (0x1DB9, true, 1044, 1),
+    (0x1DBA, true, 1045, 1), (0x1DBB, true, 1046, 1), (0x1DBC, true, 1047, 1), (0x1DBD, true, 1048, 1), (0x1DBE, true, 1049, 1),
+    (0x1DBF, true, 1050, 1), (0x1E00, false, 1051, 2), (0x1E01, false, 1053, 2), (0x1E02, false, 1055, 2), (0x1E03, false, 1057, 2),
+    (0x1E04, false, 1059, 2), (0x1E05, false, 1061, 2), (0x1E06, false, 1063, 2), (0x1E07, false, 1065, 2), (0x1E08, false, 1067, 2),
+    (0x1E09, false, 1069, 2), (0x1E0A, false, 1071, 2), (0x1E0B, false, 1073, 2), (0x1E0C, false, 1075, 2), (0x1E0D, false, 1077, 2),
+    (0x1E0E, false, 1079, 2), (0x1E0F, false, 1081, 2), (0x1E10, false, 1083, 2), (0x1E11, false, 1085, 2), (0x1E12, false, 1087, 2),
+    (0x1E13, false, 1089, 2), (0x1E14, false, 1091, 2), (0x1E15, false, 1093, 2), (0x1E16, false, 1095, 2), (0x1E17, false, 1097, 2),
+    (0x1E18, false, 1099, 2), (0x1E19, false, 1101, 2), (0x1E1A, false, 1103, 2), (0x1E1B, false, 1105, 2), (0x1E1C, false, 1107, 2),
+    (0x1E1D, false, 1109, 2), (0x1E1E, false, 1111, 2), (0x1E1F, false, 1113, 2), (0x1E20, false, 1115, 2), (0x1E21, false, 1117, 2),
+    (0x1E22, false, 1119, 2), (0x1E23, false, 1121, 2), (0x1E24, false, 1123, 2), (0x1E25, false, 1125, 2), (0x1E26, false, 1127, 2),
+    (0x1E27, false, 1129, 2), (0x1E28, false, 1131, 2), (0x1E29, false, 1133, 2), (0x1E2A, false, 1135, 2), (0x1E2B, false, 1137, 2),
+    (0x1E2C, false, 1139, 2), (0x1E2D, false, 1141, 2), (0x1E2E, false, 1143, 2), (0x1E2F, false, 1145, 2), (0x1E30, false, 1147, 2),
+    (0x1E31, false, 1149, 2), (0x1E32, false, 1151, 2), (0x1E33, false, 1153, 2), (0x1E34, false, 1155, 2), (0x1E35, false, 1157, 2),
+    (0x1E36, false, 1159, 2), (0x1E37, false, 1161, 2), (0x1E38, false, 1163, 2), (0x1E39, false, 1165, 2), (0x1E3A, false, 1167, 2),
+    (0x1E3B, false, 1169, 2), (0x1E3C, false, 1171, 2), (0x1E3D, false, 1173, 2), (0x1E3E, false, 1175, 2), (0x1E3F, false, 1177, 2),
+    (0x1E40, false, 1179, 2), (0x1E41, false, 1181, 2), (0x1E42, false, 1183, 2), (0x1E43, false, 1185, 2), (0x1E44, false, 1187, 2),
+    (0x1E45, false, 1189, 2), (0x1E46, false, 1191, 2), (0x1E47, false, 1193, 2), (0x1E48, false, 1195, 2), (0x1E49, false, 1197, 2),
+    (0x1E4A, false, 1199, 2), (0x1E4B, false, 1201, 2), (0x1E4C, false, 1203, 2), (0x1E4D, false, 1205, 2), (0x1E4E, false, 1207, 2),
+    (0x1E4F, false, 1209, 2), (0x1E50, false, 1211, 2), (0x1E51, false, 1213, 2), (0x1E52, false, 1215, 2), (0x1E53, false, 1217, 2),
+    (0x1E54, false, 1219, 2), (0x1E55, false, 1221, 2), (0x1E56, false, 1223, 2), (0x1E57, false, 1225, 2), (0x1E58, false, 1227, 2),
+    (0x1E59, false, 1229, 2), (0x1E5A, false, 1231, 2), (0x1E5B, false, 1233, 2), (0x1E5C, false, 1235, 2), (0x1E5D, false, 1237, 2),
+    (0x1E5E, false, 1239, 2), (0x1E5F, false, 1241, 2), (0x1E60, false, 1243, 2), (0x1E61, false, 1245, 2), (0x1E62, false, 1247, 2),
+    (0x1E63, false, 1249, 2), (0x1E64, false, 1251, 2), (0x1E65, false, 1253, 2), (0x1E66, false, 1255, 2), (0x1E67, false, 1257, 2),
+    (0x1E68, false, 1259, 2), (0x1E69, false, 1261, 2), (0x1E6A, false, 1263, 2), (0x1E6B, false, 1265, 2), (0x1E6C, false, 1267, 2),
+    (0x1E6D, false, 1269, 2), (0x1E6E, false, 1271, 2), (0x1E6F, false, 1273, 2), (0x1E70, false, 1275, 2), (0x1E71, false, 1277, 2),
+    (0x1E72, false, 1279, 2), (0x1E73, false, 1281, 2), (0x1E74, false, 1283, 2), (0x1E75, false, 1285, 2), (0x1E76, false, 1287, 2),
+    (0x1E77, false, 1289, 2), (0x1E78, false, 1291, 2), (0x1E79, false, 1293, 2), (0x1E7A, false, 1295, 2), (0x1E7B, false, 1297, 2),
+    (0x1E7C, false, 1299, 2), (0x1E7D, false, 1301, 2), (0x1E7E, false, 1303, 2), (0x1E7F, false, 1305, 2), (0x1E80, false, 1307, 2),
+    (0x1E81, false, 1309, 2), (0x1E82, false, 1311, 2), (0x1E83, false, 1313, 2), (0x1E84, false, 1315, 2), (0x1E85, false, 1317, 2),
+    (0x1E86, false, 1319, 2), (0x1E87, false, 1321, 2), (0x1E88, false, 1323, 2), (0x1E89, false, 1325, 2), (0x1E8A, false, 1327, 2),
+    (0x1E8B, false, 1329, 2), (0x1E8C, false, 1331, 2), (0x1E8D, false, 1333, 2), (0x1E8E, false, 1335, 2), (0x1E8F, false, 1337, 2),
+    (0x1E90, false, 1339, 2), (0x1E91, false, 1341, 2), (0x1E92, false, 1343, 2), (0x1E93, false, 1345, 2), (0x1E94, false, 1347, 2),
+    (0x1E95, false, 1349, 2), (0x1E96, false, 1351, 2), (0x1E97, false, 1353, 2), (0x1E98, false, 1355, 2), (0x1E99, false, 1357, 2),
+    (0x1E9A, true, 1359, 2), (0x1E9B, false, 1361, 2), (0x1EA0, false, 1363, 2), (0x1EA1, false, 1365, 2), (0x1EA2, false, 1367, 2),
+    (0x1EA3, false, 1369, 2), (0x1EA4, false, 1371, 2), (0x1EA5, false, 1373, 2), (0x1EA6, false, 1375, 2), (0x1EA7, false, 1377, 2),
+    (0x1EA8, false, 1379, 2), (0x1EA9, false, 1381, 2), (0x1EAA, false, 1383, 2), (0x1EAB, false, 1385, 2), (0x1EAC, false, 1387, 2),
+    (0x1EAD, false, 1389, 2), (0x1EAE, false, 1391, 2), (0x1EAF, false, 1393, 2), (0x1EB0, false, 1395, 2), (0x1EB1, false, 1397, 2),
+    (0x1EB2, false, 1399, 2), (0x1EB3, false, 1401, 2), (0x1EB4, false, 1403, 2), (0x1EB5, false, 1405, 2), (0x1EB6, false, 1407, 2),
+    (0x1EB7, false, 1409, 2), (0x1EB8, false, 1411, 2), (0x1EB9, false, 1413, 2), (0x1EBA, false, 1415, 2), (0x1EBB, false, 1417, 2),
+    (0x1EBC, false, 1419, 2), (0x1EBD, false, 1421, 2), (0x1EBE, false, 1423, 2), (0x1EBF, false, 1425, 2), (0x1EC0, false, 1427, 2),
+    (0x1EC1, false, 1429, 2), (0x1EC2, false, 1431, 2), (0x1EC3, false, 1433, 2), (0x1EC4, false, 1435, 2), (0x1EC5, false, 1437, 2),
+    (0x1EC6, false, 1439, 2), (0x1EC7, false, 1441, 2), (0x1EC8, false, 1443, 2), (0x1EC9, false, 1445, 2), (0x1ECA, false, 1447, 2),
+    (0x1ECB, false, 1449, 2), (0x1ECC, false, 1451, 2), (0x1ECD, false, 1453, 2), (0x1ECE, false, 1455, 2), (0x1ECF, false, 1457, 2),
+    (0x1ED0, false, 1459, 2), (0x1ED1, false, 1461, 2), (0x1ED2, false, 1463, 2), (0x1ED3, false, 1465, 2), (0x1ED4, false, 1467, 2),
+    (0x1ED5, false, 1469, 2), (0x1ED6, false, 1471, 2), (0x1ED7, false, 1473, 2), (0x1ED8, false, 1475, 2), (0x1ED9, false, 1477, 2),
+    (0x1EDA, false, 1479, 2), (0x1EDB, false, 1481, 2), (0x1EDC, false, 1483, 2), (0x1EDD, false, 1485, 2), (0x1EDE, false, 1487, 2),
+    (0x1EDF, false, 1489, 2), (0x1EE0, false, 1491, 2), (0x1EE1, false, 1493, 2), (0x1EE2, false, 1495, 2), (0x1EE3, false, 1497, 2),
+    (0x1EE4, false, 1499, 2), (0x1EE5, false, 1501, 2), (0x1EE6, false, 1503, 2), (0x1EE7, false, 1505, 2), (0x1EE8, false, 1507, 2),
+    (0x1EE9, false, 1509, 2), (0x1EEA, false, 1511, 2), (0x1EEB, false, 1513, 2), (0x1EEC, false, 1515, 2), (0x1EED, false, 1517, 2),
+    (0x1EEE, false, 1519, 2), (0x1EEF, false, 1521, 2), (0x1EF0, false, 1523, 2), (0x1EF1, false, 1525, 2), (0x1EF2, false, 1527, 2),
+    (0x1EF3, false, 1529, 2), (0x1EF4, false, 1531, 2), (0x1EF5, false, 1533, 2), (0x1EF6, false, 1535, 2), (0x1EF7, false, 1537, 2),
+    (0x1EF8, false, 1539, 2), (0x1EF9, false, 1541, 2), (0x1F00, false, 1543, 2), (0x1F01, false, 1545, 2), (0x1F02, false, 1547, 2),
+    (0x1F03, false, 1549, 2), (0x1F04, false, 1551, 2), (0x1F05, false, 1553, 2), (0x1F06, false, 1555, 2), (0x1F07, false, 1557, 2),
+    (0x1F08, false, 1559, 2), (0x1F09, false, 1561, 2), (0x1F0A, false, 1563, 2), (0x1F0B, false, 1565, 2), (0x1F0C, false, 1567, 2),
+    (0x1F0D, false, 1569, 2), (0x1F0E, false, 1571, 2), (0x1F0F, false, 1573, 2), (0x1F10, false, 1575, 2), (0x1F11, false, 1577, 2),
+    (0x1F12, false, 1579, 2), (0x1F13, false, 1581, 2), (0x1F14, false, 1583, 2), (0x1F15, false, 1585, 2), (0x1F18, false, 1587, 2),
+    (0x1F19, false, 1589, 2), (0x1F1A, false, 1591, 2), (0x1F1B, false, 1593, 2), (0x1F1C, false, 1595, 2), (0x1F1D, false, 1597, 2),
+    (0x1F20, false, 1599, 2), (0x1F21, false, 1601, 2), (0x1F22, false, 1603, 2), (0x1F23, false, 1605, 2), (0x1F24, false, 1607, 2),
+    (0x1F25, false, 1609, 2), (0x1F26, false, 1611, 2), (0x1F27, false, 1613, 2), (0x1F28, false, 1615, 2), (0x1F29, false, 1617, 2),
+    (0x1F2A, false, 1619, 2), (0x1F2B, false, 1621, 2), (0x1F2C, false, 1623, 2), (0x1F2D, false, 1625, 2), (0x1F2E, false, 1627, 2),
+    (0x1F2F, false, 1629, 2), (0x1F30, false, 1631, 2), (0x1F31, false, 1633, 2), (0x1F32, false, 1635, 2), (0x1F33, false, 1637, 2),
+    (0x1F34, false, 1639, 2), (0x1F35, false, 1641, 2), (0x1F36, false, 1643, 2), (0x1F37, false, 1645, 2), (0x1F38, false, 1647, 2),
+    (0x1F39, false, 1649, 2), (0x1F3A, false, 1651, 2), (0x1F3B, false, 1653, 2), (0x1F3C, false, 1655, 2), (0x1F3D, false, 1657, 2),
+    (0x1F3E, false, 1659, 2), (0x1F3F, false, 1661, 2), (0x1F40, false, 1663, 2), (0x1F41, false, 1665, 2), (0x1F42, false, 1667, 2),
+    (0x1F43, false, 1669, 2), (0x1F44, false, 1671, 2), (0x1F45, false, 1673, 2), (0x1F48, false, 1675, 2), (0x1F49, false, 1677, 2),
+    (0x1F4A, false, 1679, 2), (0x1F4B, false, 1681, 2), (0x1F4C, false, 1683, 2), (0x1F4D, false, 1685, 2), (0x1F50, false, 1687, 2),
+    (0x1F51, false, 1689, 2), (0x1F52, false, 1691, 2), (0x1F53, false, 1693, 2), (0x1F54, false, 1695, 2), (0x1F55, false, 1697, 2),
+    (0x1F56, false, 1699, 2), (0x1F57, false, 1701, 2), (0x1F59, false, 1703, 2), (0x1F5B, false, 1705, 2), (0x1F5D, false, 1707, 2),
+    (0x1F5F, false, 1709, 2), (0x1F60, false, 1711, 2), (0x1F61, false, 1713, 2), (0x1F62, false, 1715, 2), (0x1F63, false, 1717, 2),
+    (0x1F64, false, 1719, 2), (0x1F65, false, 1721, 2), (0x1F66, false, 1723, 2), (0x1F67, false, 1725, 2), (0x1F68, false, 1727, 2),
+    (0x1F69, false, 1729, 2), (0x1F6A, false, 1731, 2), (0x1F6B, false, 1733, 2), (0x1F6C, false, 1735, 2), (0x1F6D, false, 1737, 2),
+    (0x1F6E, false, 1739, 2), (0x1F6F, false, 1741, 2), (0x1F70, false, 1743, 2), (0x1F71, false, 1745, 1), (0x1F72, false, 1746, 2),
+    (0x1F73, false, 1748, 1), (0x1F74, false, 1749, 2), (0x1F75, false, 1751, 1), (0x1F76, false, 1752, 2), (0x1F77, false, 1754, 1),
+    (0x1F78, false, 1755, 2), (0x1F79, false, 1757, 1), (0x1F7A, false, 1758, 2), (0x1F7B, false, 1760, 1), (0x1F7C, false, 1761, 2),
+    (0x1F7D, false, 1763, 1), (0x1F80, false, 1764, 2), (0x1F81, false, 1766, 2), (0x1F82, false, 1768, 2), (0x1F83, false, 1770, 2),
+    (0x1F84, false, 1772, 2), (0x1F85, false, 1774, 2), (0x1F86, false, 1776, 2), (0x1F87, false, 1778, 2), (0x1F88, false, 1780, 2),
+    (0x1F89, false, 1782, 2), (0x1F8A, false, 1784, 2), (0x1F8B, false, 1786, 2), (0x1F8C, false, 1788, 2), (0x1F8D, false, 1790, 2),
+    (0x1F8E, false, 1792, 2), (0x1F8F, false, 1794, 2), (0x1F90, false, 1796, 2), (0x1F91, false, 1798, 2), (0x1F92, false, 1800, 2),
+    (0x1F93, false, 1802, 2), (0x1F94, false, 1804, 2), (0x1F95, false, 1806, 2), (0x1F96, false, 1808, 2), (0x1F97, false, 1810, 2),
+    (0x1F98, false, 1812, 2), (0x1F99, false, 1814, 2), (0x1F9A, false, 1816, 2), (0x1F9B, false, 1818, 2), (0x1F9C, false, 1820, 2),
+    (0x1F9D, false, 1822, 2), (0x1F9E, false, 1824, 2), (0x1F9F, false, 1826, 2), (0x1FA0, false, 1828, 2), (0x1FA1, false, 1830, 2),
+    (0x1FA2, false, 1832, 2), (0x1FA3, false, 1834, 2), (0x1FA4, false, 1836, 2), (0x1FA5, false, 1838, 2), (0x1FA6, false, 1840, 2),
+    (0x1FA7, false, 1842, 2), (0x1FA8, false, 1844, 2), (0x1FA9, false, 1846, 2), (0x1FAA, false, 1848, 2), (0x1FAB, false, 1850, 2),
+    (0x1FAC, false, 1852, 2), (0x1FAD, false, 1854, 2), (0x1FAE, false, 1856, 2), (0x1FAF, false, 1858, 2), (0x1FB0, false, 1860, 2),
+    (0x1FB1, false, 1862, 2), (0x1FB2, false, 1864, 2), (0x1FB3, false, 1866, 2), (0x1FB4, false, 1868, 2), (0x1FB6, false, 1870, 2),
+    (0x1FB7, false, 1872, 2), (0x1FB8, false, 1874, 2), (0x1FB9, false, 1876, 2), (0x1FBA, false, 1878, 2), (0x1FBB, false, 1880, 1),
+    (0x1FBC, false, 1881, 2), (0x1FBD, true, 1883, 2), (0x1FBE, false, 1885, 1), (0x1FBF, true, 1886, 2), (0x1FC0, true, 1888, 2),
+    (0x1FC1, false, 1890, 2), (0x1FC2, false, 1892, 2), (0x1FC3, false, 1894, 2), (0x1FC4, false, 1896, 2), (0x1FC6, false, 1898, 2),
+    (0x1FC7, false, 1900, 2), (0x1FC8, false, 1902, 2), (0x1FC9, false, 1904, 1), (0x1FCA, false, 1905, 2), (0x1FCB, false, 1907, 1),
+    (0x1FCC, false, 1908, 2), (0x1FCD, false, 1910, 2), (0x1FCE, false, 1912, 2), (0x1FCF, false, 1914, 2), (0x1FD0, false, 1916, 2),
+    (0x1FD1, false, 1918, 2), (0x1FD2, false, 1920, 2), (0x1FD3, false, 1922, 1), (0x1FD6, false, 1923, 2), (0x1FD7, false, 1925, 2),
+    (0x1FD8, false, 1927, 2), (0x1FD9, false, 1929, 2), (0x1FDA, false, 1931, 2), (0x1FDB, false, 1933, 1), (0x1FDD, false, 1934, 2),
+    (0x1FDE, false, 1936, 2), (0x1FDF, false, 1938, 2), (0x1FE0, false, 1940, 2), (0x1FE1, false, 1942, 2), (0x1FE2, false, 1944, 2),
+    (0x1FE3, false, 1946, 1), (0x1FE4, false, 1947, 2), (0x1FE5, false, 1949, 2), (0x1FE6, false, 1951, 2), (0x1FE7, false, 1953, 2),
+    (0x1FE8, false, 1955, 2), (0x1FE9, false, 1957, 2), (0x1FEA, false, 1959, 2), (0x1FEB, false, 1961, 1), (0x1FEC, false, 1962, 2),
+    (0x1FED, false, 1964, 2), (0x1FEE, false, 1966, 1), (0x1FEF, false, 1967, 1), (0x1FF2, false, 1968, 2), (0x1FF3, false, 1970, 2),
+    (0x1FF4, false, 1972, 2), (0x1FF6, false, 1974, 2), (0x1FF7, false, 1976, 2), (0x1FF8, false, 1978, 2), (0x1FF9, false, 1980, 1),
+    (0x1FFA, false, 1981, 2), (0x1FFB, false, 1983, 1), (0x1FFC, false, 1984, 2), (0x1FFD, false, 1986, 1), (0x1FFE, true, 1987, 2),
+    (0x2000, false, 1989, 1), (0x2001, false, 1990, 1), (0x2002, true, 1991, 1), (0x2003, true, 1992, 1), (0x2004, true, 1993, 1),
+    (0x2005, true, 1994, 1), (0x2006, true, 1995, 1), (0x2007, true, 1996, 1), (0x2008, true, 1997, 1), (0x2009, true, 1998, 1),
+    (0x200A, true, 1999, 1), (0x2011, true, 2000, 1), (0x2017, true, 2001, 2), (0x2024, true, 2003, 1), (0x2025, true, 2004, 2),
+    (0x2026, true, 2006, 3), (0x202F, true, 2009, 1), (0x2033, true, 2010, 2), (0x2034, true, 2012, 3), (0x2036, true, 2015, 2),
+    (0x2037, true, 2017, 3), (0x203C, true, 2020, 2), (0x203E, true, 2022, 2), (0x2047, true, 2024, 2), (0x2048, true, 2026, 2),
+    (0x2049, true, 2028, 2), (0x2057, true, 2030, 4), (0x205F, true, 2034, 1), (0x2070, true, 2035, 1), (0x2071, true, 2036, 1),
+    (0x2074, true, 2037, 1), (0x2075, true, 2038, 1), (0x2076, true, 2039, 1), (0x2077, true, 2040, 1), (0x2078, true, 2041, 1),
+    (0x2079, true, 2042, 1), (0x207A, true, 2043, 1), (0x207B, true, 2044, 1), (0x207C, true, 2045, 1), (0x207D, true, 2046, 1),
+    (0x207E, true, 2047, 1), (0x207F, true, 2048, 1), (0x2080, true, 2049, 1), (0x2081, true, 2050, 1), (0x2082, true, 2051, 1),
+    (0x2083, true, 2052, 1), (0x2084, true, 2053, 1), (0x2085, true, 2054, 1), (0x2086, true, 2055, 1), (0x2087, true, 2056, 1),
+    (0x2088, true, 2057, 1), (0x2089, true, 2058, 1), (0x208A, true, 2059, 1), (0x208B, true, 2060, 1), (0x208C, true, 2061, 1),
+    (0x208D, true, 2062, 1), (0x208E, true, 2063, 1), (0x2090, true, 2064, 1), (0x2091, true, 2065, 1), (0x2092, true, 2066, 1),
+    (0x2093, true, 2067, 1), (0x2094, true, 2068, 1), (0x2095, true, 2069, 1), (0x2096, true, 2070, 1), (0x2097, true, 2071, 1),
+    (0x2098, true, 2072, 1), (0x2099, true, 2073, 1), (0x209A, true, 2074, 1), (0x209B, true, 2075, 1), (0x209C, true, 2076, 1),
+    (0x20A8, true, 2077, 2), (0x2100, true, 2079, 3), (0x2101, true, 2082, 3), (0x2102, true, 2085, 1), (0x2103, true, 2086, 2),
+    (0x2105, true, 2088, 3), (0x2106, true, 2091, 3), (0x2107, true, 2094, 1), (0x2109, true, 2095, 2), (0x210A, true, 2097, 1),
+    (0x210B, true, 2098, 1), (0x210C, true, 2099, 1), (0x210D, true, 2100, 1), (0x210E, true, 2101, 1), (0x210F, true, 2102, 1),
+    (0x2110, true, 2103, 1), (0x2111, true, 2104, 1), (0x2112, true, 2105, 1), (0x2113, true, 2106, 1), (0x2115, true, 2107, 1),
+    (0x2116, true, 2108, 2), (0x2119, true, 2110, 1), (0x211A, true, 2111, 1), (0x211B, true, 2112, 1), (0x211C, true, 2113, 1),
+    (0x211D, true, 2114, 1), (0x2120, true, 2115, 2), (0x2121, true, 2117, 3), (0x2122, true, 2120, 2), (0x2124, true, 2122, 1),
+    (0x2126, false, 2123, 1), (0x2128, true, 2124, 1), (0x212A, false, 2125, 1), (0x212B, false, 2126, 1), (0x212C, true, 2127, 1),
+    (0x212D, true, 2128, 1), (0x212F, true, 2129, 1), (0x2130, true, 2130, 1), (0x2131, true, 2131, 1), (0x2133, true, 2132, 1),
+    (0x2134, true, 2133, 1), (0x2135, true, 2134, 1), (0x2136, true, 2135, 1), (0x2137, true, 2136, 1), (0x2138, true, 2137, 1),
+    (0x2139, true, 2138, 1), (0x213B, true, 2139, 3), (0x213C, true, 2142, 1), (0x213D, true, 2143, 1), (0x213E, true, 2144, 1),
+    (0x213F, true, 2145, 1), (0x2140, true, 2146, 1), (0x2145, true, 2147, 1), (0x2146, true, 2148, 1), (0x2147, true, 2149, 1),
+    (0x2148, true, 2150, 1), (0x2149, true, 2151, 1), (0x2150, true, 2152, 3), (0x2151, true, 2155, 3), (0x2152, true, 2158, 4),
+    (0x2153, true, 2162, 3), (0x2154, true, 2165, 3), (0x2155, true, 2168, 3), (0x2156, true, 2171, 3), (0x2157, true, 2174, 3),
+    (0x2158, true, 2177, 3), (0x2159, true, 2180, 3), (0x215A, true, 2183, 3), (0x215B, true, 2186, 3), (0x215C, true, 2189, 3),
+    (0x215D, true, 2192, 3), (0x215E, true, 2195, 3), (0x215F, true, 2198, 2), (0x2160, true, 2200, 1), (0x2161, true, 2201, 2),
+    (0x2162, true, 2203, 3), (0x2163, true, 2206, 2), (0x2164, true, 2208, 1), (0x2165, true, 2209, 2), (0x2166, true, 2211, 3),
+    (0x2167, true, 2214, 4), (0x2168, true, 2218, 2), (0x2169, true, 2220, 1), (0x216A, true, 2221, 2), (0x216B, true, 2223, 3),
+    (0x216C, true, 2226, 1), (0x216D, true, 2227, 1), (0x216E, true, 2228, 1), (0x216F, true, 2229, 1), (0x2170, true, 2230, 1),
+    (0x2171, true, 2231, 2), (0x2172, true, 2233, 3), (0x2173, true, 2236, 2), (0x2174, true, 2238, 1), (0x2175, true, 2239, 2),
+    (0x2176, true, 2241, 3), (0x2177, true, 2244, 4), (0x2178, true, 2248, 2), (0x2179, true, 2250, 1), (0x217A, true, 2251, 2),
+    (0x217B, true, 2253, 3), (0x217C, true, 2256, 1), (0x217D, true, 2257, 1), (0x217E, true, 2258, 1), (0x217F, true, 2259, 1),
+    (0x2189, true, 2260, 3), (0x219A, false, 2263, 2), (0x219B, false, 2265, 2), (0x21AE, false, 2267, 2), (0x21CD, false, 2269, 2),
+    (0x21CE, false, 2271, 2), (0x21CF, false, 2273, 2), (0x2204, false, 2275, 2), (0x2209, false, 2277, 2), (0x220C, false, 2279, 2),
+    (0x2224, false, 2281, 2), (0x2226, false, 2283, 2), (0x222C, true, 2285, 2), (0x222D, true, 2287, 3), (0x222F, true, 2290, 2),
+    (0x2230, true, 2292, 3), (0x2241, false, 2295, 2), (0x2244, false, 2297, 2), (0x2247, false, 2299, 2), (0x2249, false, 2301, 2),
+    (0x2260, false, 2303, 2), (0x2262, false, 2305, 2), (0x226D, false, 2307, 2), (0x226E, false, 2309, 2), (0x226F, false, 2311, 2),
+    (0x2270, false, 2313, 2), (0x2271, false, 2315, 2), (0x2274, false, 2317, 2), (0x2275, false, 2319, 2), (0x2278, false, 2321, 2),
+    (0x2279, false, 2323, 2), (0x2280, false, 2325, 2), (0x2281, false, 2327, 2), (0x2284, false, 2329, 2), (0x2285, false, 2331, 2),
+    (0x2288, false, 2333, 2), (0x2289, false, 2335, 2), (0x22AC, false, 2337, 2), (0x22AD, false, 2339, 2), (0x22AE, false, 2341, 2),
+    (0x22AF, false, 2343, 2), (0x22E0, false, 2345, 2), (0x22E1, false, 2347, 2), (0x22E2, false, 2349, 2), (0x22E3, false, 2351, 2),
+    (0x22EA, false, 2353, 2), (0x22EB, false, 2355, 2), (0x22EC, false, 2357, 2), (0x22ED, false, 2359, 2), (0x2329, false, 2361, 1),
+    (0x232A, false, 2362, 1), (0x2460, true, 2363, 1), (0x2461, true, 2364, 1), (0x2462, true, 2365, 1), (0x2463, true, 2366, 1),
+    (0x2464, true, 2367, 1), (0x2465, true, 2368, 1), (0x2466, true, 2369, 1), (0x2467, true, 2370, 1), (0x2468, true, 2371, 1),
+    (0x2469, true, 2372, 2), (0x246A, true, 2374, 2), (0x246B, true, 2376, 2), (0x246C, true, 2378, 2), (0x246D, true, 2380, 2),
+    (0x246E, true, 2382, 2), (0x246F, true, 2384, 2), (0x2470, true, 2386, 2), (0x2471, true, 2388, 2), (0x2472, true, 2390, 2),
+    (0x2473, true, 2392, 2), (0x2474, true, 2394, 3), (0x2475, true, 2397, 3), (0x2476, true, 2400, 3), (0x2477, true, 2403, 3),
+    (0x2478, true, 2406, 3), (0x2479, true, 2409, 3), (0x247A, true, 2412, 3), (0x247B, true, 2415, 3), (0x247C, true, 2418, 3),
+    (0x247D, true, 2421, 4), (0x247E, true, 2425, 4), (0x247F, true, 2429, 4), (0x2480, true, 2433, 4), (0x2481, true, 2437, 4),
+    (0x2482, true, 2441, 4), (0x2483, true, 2445, 4), (0x2484, true, 2449, 4), (0x2485, true, 2453, 4), (0x2486, true, 2457, 4),
+    (0x2487, true, 2461, 4), (0x2488, true, 2465, 2), (0x2489, true, 2467, 2), (0x248A, true, 2469, 2), (0x248B, true, 2471, 2),
+    (0x248C, true, 2473, 2), (0x248D, true, 2475, 2), (0x248E, true, 2477, 2), (0x248F, true, 2479, 2), (0x2490, true, 2481, 2),
+    (0x2491, true, 2483, 3), (0x2492, true, 2486, 3), (0x2493, true, 2489, 3), (0x2494, true, 2492, 3), (0x2495, true, 2495, 3),
+    (0x2496, true, 2498, 3), (0x2497, true, 2501, 3), (0x2498, true, 2504, 3), (0x2499, true, 2507, 3), (0x249A, true, 2510, 3),
+    (0x249B, true, 2513, 3), (0x249C, true, 2516, 3), (0x249D, true, 2519, 3), (0x249E, true, 2522, 3), (0x249F, true, 2525, 3),
+    (0x24A0, true, 2528, 3), (0x24A1, true, 2531, 3), (0x24A2, true, 2534, 3), (0x24A3, true, 2537, 3), (0x24A4, true, 2540, 3),
+    (0x24A5, true, 2543, 3), (0x24A6, true, 2546, 3), (0x24A7, true, 2549, 3), (0x24A8, true, 2552, 3), (0x24A9, true, 2555, 3),
+    (0x24AA, true, 2558, 3), (0x24AB, true, 2561, 3), (0x24AC, true, 2564, 3), (0x24AD, true, 2567, 3), (0x24AE, true, 2570, 3),
+    (0x24AF, true, 2573, 3), (0x24B0, true, 2576, 3), (0x24B1, true, 2579, 3), (0x24B2, true, 2582, 3), (0x24B3, true, 2585, 3),
+    (0x24B4, true, 2588, 3), (0x24B5, true, 2591, 3), (0x24B6, true, 2594, 1), (0x24B7, true, 2595, 1), (0x24B8, true, 2596, 1),
+    (0x24B9, true, 2597, 1), (0x24BA, true, 2598, 1), (0x24BB, true, 2599, 1), (0x24BC, true, 2600, 1), (0x24BD, true, 2601, 1),
+    (0x24BE, true, 2602, 1), (0x24BF, true, 2603, 1), (0x24C0, true, 2604, 1), (0x24C1, true, 2605, 1), (0x24C2, true, 2606, 1),
+    (0x24C3, true, 2607, 1), (0x24C4, true, 2608, 1), (0x24C5, true, 2609, 1), (0x24C6, true, 2610, 1), (0x24C7, true, 2611, 1),
+    (0x24C8, true, 2612, 1), (0x24C9, true, 2613, 1), (0x24CA, true, 2614, 1), (0x24CB, true, 2615, 1), (0x24CC, true, 2616, 1),
+    (0x24CD, true, 2617, 1), (0x24CE, true, 2618, 1), (0x24CF, true, 2619, 1), (0x24D0, true, 2620, 1), (0x24D1, true, 2621, 1),
+    (0x24D2, true, 2622, 1), (0x24D3, true, 2623, 1), (0x24D4, true, 2624, 1), (0x24D5, true, 2625, 1), (0x24D6, true, 2626, 1),
+    (0x24D7, true, 2627, 1), (0x24D8, true, 2628, 1), (0x24D9, true, 2629, 1), (0x24DA, true, 2630, 1), (0x24DB, true, 2631, 1),
+    (0x24DC, true, 2632, 1), (0x24DD, true, 2633, 1), (0x24DE, true, 2634, 1), (0x24DF, true, 2635, 1), (0x24E0, true, 2636, 1),
+    (0x24E1, true, 2637, 1), (0x24E2, true, 2638, 1), (0x24E3, true, 2639, 1), (0x24E4, true, 2640, 1), (0x24E5, true, 2641, 1),
+    (0x24E6, true, 2642, 1), (0x24E7, true, 2643, 1), (0x24E8, true, 2644, 1), (0x24E9, true, 2645, 1), (0x24EA, true, 2646, 1),
+    (0x2A0C, true, 2647, 4), (0x2A74, true, 2651, 3), (0x2A75, true, 2654, 2), (0x2A76, true, 2656, 3), (0x2ADC, false, 2659, 2),
+    (0x2C7C, true, 2661, 1), (0x2C7D, true, 2662, 1), (0x2D6F, true, 2663, 1), (0x2E9F, true, 2664, 1), (0x2EF3, true, 2665, 1),
+    (0x2F00, true, 2666, 1), (0x2F01, true, 2667, 1), (0x2F02, true, 2668, 1), (0x2F03, true, 2669, 1), (0x2F04, true, 2670, 1),
+    (0x2F05, true, 2671, 1), (0x2F06, true, 2672, 1), (0x2F07, true, 2673, 1), (0x2F08, true, 2674, 1), (0x2F09, true, 2675, 1),
+    (0x2F0A, true, 2676, 1), (0x2F0B, true, 2677, 1), (0x2F0C, true, 2678, 1), (0x2F0D, true, 2679, 1), (0x2F0E, true, 2680, 1),
+    (0x2F0F, true, 2681, 1), (0x2F10, true, 2682, 1), (0x2F11, true, 2683, 1), (0x2F12, true, 2684, 1), (0x2F13, true, 2685, 1),
+    (0x2F14, true, 2686, 1), (0x2F15, true, 2687, 1), (0x2F16, true, 2688, 1), (0x2F17, true, 2689, 1), (0x2F18, true, 2690, 1),
+    (0x2F19, true, 2691, 1), (0x2F1A, true, 2692, 1), (0x2F1B, true, 2693, 1), (0x2F1C, true, 2694, 1), (0x2F1D, true, 2695, 1),
+    (0x2F1E, true, 2696, 1), (0x2F1F, true, 2697, 1), (0x2F20, true, 2698, 1), (0x2F21, true, 2699, 1), (0x2F22, true, 2700, 1),
+    (0x2F23, true, 2701, 1), (0x2F24, true, 2702, 1), (0x2F25, true, 2703, 1), (0x2F26, true, 2704, 1), (0x2F27, true, 2705, 1),
+    (0x2F28, true, 2706, 1), (0x2F29, true, 2707, 1), (0x2F2A, true, 2708, 1), (0x2F2B, true, 2709, 1), (0x2F2C, true, 2710, 1),
+    (0x2F2D, true, 2711, 1), (0x2F2E, true, 2712, 1), (0x2F2F, true, 2713, 1), (0x2F30, true, 2714, 1), (0x2F31, true, 2715, 1),
+    (0x2F32, true, 2716, 1), (0x2F33, true, 2717, 1), (0x2F34, true, 2718, 1), (0x2F35, true, 2719, 1), (0x2F36, true, 2720, 1),
+    (0x2F37, true, 2721, 1), (0x2F38, true, 2722, 1), (0x2F39, true, 2723, 1), (0x2F3A, true, 2724, 1), (0x2F3B, true, 2725, 1),
+    (0x2F3C, true, 2726, 1), (0x2F3D, true, 2727, 1), (0x2F3E, true, 2728, 1), (0x2F3F, true, 2729, 1), (0x2F40, true, 2730, 1),
+    (0x2F41, true, 2731, 1), (0x2F42, true, 2732, 1), (0x2F43, true, 2733, 1), (0x2F44, true, 2734, 1), (0x2F45, true, 2735, 1),
+    (0x2F46, true, 2736, 1), (0x2F47, true, 2737, 1), (0x2F48, true, 2738, 1), (0x2F49, true, 2739, 1), (0x2F4A, true, 2740, 1),
+    (0x2F4B, true, 2741, 1), (0x2F4C, true, 2742, 1), (0x2F4D, true, 2743, 1), (0x2F4E, true, 2744, 1), (0x2F4F, true, 2745, 1),
+    (0x2F50, true, 2746, 1), (0x2F51, true, 2747, 1), (0x2F52, true, 2748, 1), (0x2F53, true, 2749, 1), (0x2F54, true, 2750, 1),
+    (0x2F55, true, 2751, 1), (0x2F56, true, 2752, 1), (0x2F57, true, 2753, 1), (0x2F58, true, 2754, 1), (0x2F59, true, 2755, 1),
+    (0x2F5A, true, 2756, 1), (0x2F5B, true, 2757, 1), (0x2F5C, true, 2758, 1), (0x2F5D, true, 2759, 1), (0x2F5E, true, 2760, 1),
+    (0x2F5F, true, 2761, 1), (0x2F60, true, 2762, 1), (0x2F61, true, 2763, 1), (0x2F62, true, 2764, 1), (0x2F63, true, 2765, 1),
+    (0x2F64, true, 2766, 1), (0x2F65, true, 2767, 1), (0x2F66, true, 2768, 1), (0x2F67, true, 2769, 1), (0x2F68, true, 2770, 1),
+    (0x2F69, true, 2771, 1), (0x2F6A, true, 2772, 1), (0x2F6B, true, 2773, 1), (0x2F6C, true, 2774, 1), (0x2F6D, true, 2775, 1),
+    (0x2F6E, true, 2776, 1), (0x2F6F, true, 2777, 1), (0x2F70, true, 2778, 1), (0x2F71, true, 2779, 1), (0x2F72, true, 2780, 1),
+    (0x2F73, true, 2781, 1), (0x2F74, true, 2782, 1), (0x2F75, true, 2783, 1), (0x2F76, true, 2784, 1), (0x2F77, true, 2785, 1),
+    (0x2F78, true, 2786, 1), (0x2F79, true, 2787, 1), (0x2F7A, true, 2788, 1), (0x2F7B, true, 2789, 1), (0x2F7C, true, 2790, 1),
+    (0x2F7D, true, 2791, 1), (0x2F7E, true, 2792, 1), (0x2F7F, true, 2793, 1), (0x2F80, true, 2794, 1), (0x2F81, true, 2795, 1),
+    (0x2F82, true, 2796, 1), (0x2F83, true, 2797, 1), (0x2F84, true, 2798, 1), (0x2F85, true, 2799, 1), (0x2F86, true, 2800, 1),
+    (0x2F87, true, 2801, 1), (0x2F88, true, 2802, 1), (0x2F89, true, 2803, 1), (0x2F8A, true, 2804, 1), (0x2F8B, true, 2805, 1),
+    (0x2F8C, true, 2806, 1), (0x2F8D, true, 2807, 1), (0x2F8E, true, 2808, 1), (0x2F8F, true, 2809, 1), (0x2F90, true, 2810, 1),
+    (0x2F91, true, 2811, 1), (0x2F92, true, 2812, 1), (0x2F93, true, 2813, 1), (0x2F94, true, 2814, 1), (0x2F95, true, 2815, 1),
+    (0x2F96, true, 2816, 1), (0x2F97, true, 2817, 1), (0x2F98, true, 2818, 1), (0x2F99, true, 2819, 1), (0x2F9A, true, 2820, 1),
+    (0x2F9B, true, 2821, 1), (0x2F9C, true, 2822, 1), (0x2F9D, true, 2823, 1), (0x2F9E, true, 2824, 1), (0x2F9F, true, 2825, 1),
+    (0x2FA0, true, 2826, 1), (0x2FA1, true, 2827, 1), (0x2FA2, true, 2828, 1), (0x2FA3, true, 2829, 1), (0x2FA4, true, 2830, 1),
+    (0x2FA5, true, 2831, 1), (0x2FA6, true, 2832, 1), (0x2FA7, true, 2833, 1), (0x2FA8, true, 2834, 1), (0x2FA9, true, 2835, 1),
+    (0x2FAA, true, 2836, 1), (0x2FAB, true, 2837, 1), (0x2FAC, true, 2838, 1), (0x2FAD, true, 2839, 1), (0x2FAE, true, 2840, 1),
+    (0x2FAF, true, 2841, 1), (0x2FB0, true, 2842, 1), (0x2FB1, true, 2843, 1), (0x2FB2, true, 2844, 1), (0x2FB3, true, 2845, 1),
+    (0x2FB4, true, 2846, 1), (0x2FB5, true, 2847, 1), (0x2FB6, true, 2848, 1), (0x2FB7, true, 2849, 1), (0x2FB8, true, 2850, 1),
+    (0x2FB9, true, 2851, 1), (0x2FBA, true, 2852, 1), (0x2FBB, true, 2853, 1), (0x2FBC, true, 2854, 1), (0x2FBD, true, 2855, 1),
+    (0x2FBE, true, 2856, 1), (0x2FBF, true, 2857, 1), (0x2FC0, true, 2858, 1), (0x2FC1, true, 2859, 1), (0x2FC2, true, 2860, 1),
+    (0x2FC3, true, 2861, 1), (0x2FC4, true, 2862, 1), (0x2FC5, true, 2863, 1), (0x2FC6, true, 2864, 1), (0x2FC7, true, 2865, 1),
+    (0x2FC8, true, 2866, 1), (0x2FC9, true, 2867, 1), (0x2FCA, true, 2868, 1), (0x2FCB, true, 2869, 1), (0x2FCC, true, 2870, 1),
+    (0x2FCD, true, 2871, 1), (0x2FCE, true, 2872, 1), (0x2FCF, true, 2873, 1), (0x2FD0, true, 2874, 1), (0x2FD1, true, 2875, 1),
+    (0x2FD2, true, 2876, 1), (0x2FD3, true, 2877, 1), (0x2FD4, true, 2878, 1), (0x2FD5, true, 2879, 1), (0x3000, true, 2880, 1),
+    (0x3036, true, 2881, 1), (0x3038, true, 2882, 1), (0x3039, true, 2883, 1), (0x303A, true, 2884, 1), (0x304C, false, 2885, 2),
+    (0x304E, false, 2887, 2), (0x3050, false, 2889, 2), (0x3052, false, 2891, 2), (0x3054, false, 2893, 2), (0x3056, false, 2895, 2),
+    (0x3058, false, 2897, 2), (0x305A, false, 2899, 2), (0x305C, false, 2901, 2), (0x305E, false, 2903, 2), (0x3060, false, 2905, 2),
+    (0x3062, false, 2907, 2), (0x3065, false, 2909, 2), (0x3067, false, 2911, 2), (0x3069, false, 2913, 2), (0x3070, false, 2915, 2),
+    (0x3071, false, 2917, 2), (0x3073, false, 2919, 2), (0x3074, false, 2921, 2), (0x3076, false, 2923, 2), (0x3077, false, 2925, 2),
+    (0x3079, false, 2927, 2), (0x307A, false, 2929, 2), (0x307C, false, 2931, 2), (0x307D, false, 2933, 2), (0x3094, false, 2935, 2),
+    (0x309B, true, 2937, 2), (0x309C, true, 2939, 2), (0x309E, false, 2941, 2), (0x309F, true, 2943, 2), (0x30AC, false, 2945, 2),
+    (0x30AE, false, 2947, 2), (0x30B0, false, 2949, 2), (0x30B2, false, 2951, 2), (0x30B4, false, 2953, 2), (0x30B6, false, 2955, 2),
+    (0x30B8, false, 2957, 2), (0x30BA, false, 2959, 2), (0x30BC, false, 2961, 2), (0x30BE, false, 2963, 2), (0x30C0, false, 2965, 2),
+    (0x30C2, false, 2967, 2), (0x30C5, false, 2969, 2), (0x30C7, false, 2971, 2), (0x30C9, false, 2973, 2), (0x30D0, false, 2975, 2),
+    (0x30D1, false, 2977, 2), (0x30D3, false, 2979, 2), (0x30D4, false, 2981, 2), (0x30D6, false, 2983, 2), (0x30D7, false, 2985, 2),
+    (0x30D9, false, 2987, 2), (0x30DA, false, 2989, 2), (0x30DC, false, 2991, 2), (0x30DD, false, 2993, 2), (0x30F4, false, 2995, 2),
+    (0x30F7, false, 2997, 2), (0x30F8, false, 2999, 2), (0x30F9, false, 3001, 2), (0x30FA, false, 3003, 2), (0x30FE, false, 3005, 2),
+    (0x30FF, true, 3007, 2), (0x3131, true, 3009, 1), (0x3132, true, 3010, 1), (0x3133, true, 3011, 1), (0x3134, true, 3012, 1),
+    (0x3135, true, 3013, 1), (0x3136, true, 3014, 1), (0x3137, true, 3015, 1), (0x3138, true, 3016, 1), (0x3139, true, 3017, 1),
+    (0x313A, true, 3018, 1), (0x313B, true, 3019, 1), (0x313C, true, 3020, 1), (0x313D, true, 3021, 1), (0x313E, true, 3022, 1),
+    (0x313F, true, 3023, 1), (0x3140, true, 3024, 1), (0x3141, true, 3025, 1), (0x3142, true, 3026, 1), (0x3143, true, 3027, 1),
+    (0x3144, true, 3028, 1), (0x3145, true, 3029, 1), (0x3146, true, 3030, 1), (0x3147, true, 3031, 1), (0x3148, true, 3032, 1),
+    (0x3149, true, 3033, 1), (0x314A, true, 3034, 1), (0x314B, true, 3035, 1), (0x314C, true, 3036, 1), (0x314D, true, 3037, 1),
+    (0x314E, true, 3038, 1), (0x314F, true, 3039, 1), (0x3150, true, 3040, 1), (0x3151, true, 3041, 1), (0x3152, true, 3042, 1),
+    (0x3153, true, 3043, 1), (0x3154, true, 3044, 1), (0x3155, true, 3045, 1), (0x3156, true, 3046, 1), (0x3157, true, 3047, 1),
+    (0x3158, true, 3048, 1), (0x3159, true, 3049, 1), (0x315A, true, 3050, 1), (0x315B, true, 3051, 1), (0x315C, true, 3052, 1),
+    (0x315D, true, 3053, 1), (0x315E, true, 3054, 1), (0x315F, true, 3055, 1), (0x3160, true, 3056, 1), (0x3161, true, 3057, 1),
+    (0x3162, true, 3058, 1), (0x3163, true, 3059, 1), (0x3164, true, 3060, 1), (0x3165, true, 3061, 1), (0x3166, true, 3062, 1),
+    (0x3167, true, 3063, 1), (0x3168, true, 3064, 1), (0x3169, true, 3065, 1), (0x316A, true, 3066, 1), (0x316B, true, 3067, 1),
+    (0x316C, true, 3068, 1), (0x316D, true, 3069, 1), (0x316E, true, 3070, 1), (0x316F, true, 3071, 1), (0x3170, true, 3072, 1),
+    (0x3171, true, 3073, 1), (0x3172, true, 3074, 1), (0x3173, true, 3075, 1), (0x3174, true, 3076, 1), (0x3175, true, 3077, 1),
+    (0x3176, true, 3078, 1), (0x3177, true, 3079, 1), (0x3178, true, 3080, 1), (0x3179, true, 3081, 1), (0x317A, true, 3082, 1),
+    (0x317B, true, 3083, 1), (0x317C, true, 3084, 1), (0x317D, true, 3085, 1), (0x317E, true, 3086, 1), (0x317F, true, 3087, 1),
+    (0x3180, true, 3088, 1), (0x3181, true, 3089, 1), (0x3182, true, 3090, 1), (0x3183, true, 3091, 1), (0x3184, true, 3092, 1),
+    (0x3185, true, 3093, 1), (0x3186, true, 3094, 1), (0x3187, true, 3095, 1), (0x3188, true, 3096, 1), (0x3189, true, 3097, 1),
+    (0x318A, true, 3098, 1), (0x318B, true, 3099, 1), (0x318C, true, 3100, 1), (0x318D, true, 3101, 1), (0x318E, true, 3102, 1),
+    (0x3192, true, 3103, 1), (0x3193, true, 3104, 1), (0x3194, true, 3105, 1), (0x3195, true, 3106, 1), (0x3196, true, 3107, 1),
+    (0x3197, true, 3108, 1), (0x3198, true, 3109, 1), (0x3199, true, 3110, 1), (0x319A, true, 3111, 1), (0x319B, true, 3112, 1),
+    (0x319C, true, 3113, 1), (0x319D, true, 3114, 1), (0x319E, true, 3115, 1), (0x319F, true, 3116, 1), (0x3200, true, 3117, 3),
+    (0x3201, true, 3120, 3), (0x3202, true, 3123, 3), (0x3203, true, 3126, 3), (0x3204, true, 3129, 3), (0x3205, true, 3132, 3),
+    (0x3206, true, 3135, 3), (0x3207, true, 3138, 3), (0x3208, true, 3141, 3), (0x3209, true, 3144, 3), (0x320A, true, 3147, 3),
+    (0x320B, true, 3150, 3), (0x320C, true, 3153, 3), (0x320D, true, 3156, 3), (0x320E, true, 3159, 4), (0x320F, true, 3163, 4),
+    (0x3210, true, 3167, 4), (0x3211, true, 3171, 4), (0x3212, true, 3175, 4), (0x3213, true, 3179, 4), (0x3214, true, 3183, 4),
+    (0x3215, true, 3187, 4), (0x3216, true, 3191, 4), (0x3217, true, 3195, 4), (0x3218, true, 3199, 4), (0x3219, true, 3203, 4),
+    (0x321A, true, 3207, 4), (0x321B, true, 3211, 4), (0x321C, true, 3215, 4), (0x321D, true, 3219, 7), (0x321E, true, 3226, 6),
+    (0x3220, true, 3232, 3), (0x3221, true, 3235, 3), (0x3222, true, 3238, 3), (0x3223, true, 3241, 3), (0x3224, true, 3244, 3),
+    (0x3225, true, 3247, 3), (0x3226, true, 3250, 3), (0x3227, true, 3253, 3), (0x3228, true, 3256, 3), (0x3229, true, 3259, 3),
+    (0x322A, true, 3262, 3), (0x322B, true, 3265, 3), (0x322C, true, 3268, 3), (0x322D, true, 3271, 3), (0x322E, true, 3274, 3),
+    (0x322F, true, 3277, 3), (0x3230, true, 3280, 3), (0x3231, true, 3283, 3), (0x3232, true, 3286, 3), (0x3233, true, 3289, 3),
+    (0x3234, true, 3292, 3), (0x3235, true, 3295, 3), (0x3236, true, 3298, 3), (0x3237, true, 3301, 3), (0x3238, true, 3304, 3),
+    (0x3239, true, 3307, 3), (0x323A, true, 3310, 3), (0x323B, true, 3313, 3), (0x323C, true, 3316, 3), (0x323D, true, 3319, 3),
+    (0x323E, true, 3322, 3), (0x323F, true, 3325, 3), (0x3240, true, 3328, 3), (0x3241, true, 3331, 3), (0x3242, true, 3334, 3),
+    (0x3243, true, 3337, 3), (0x3244, true, 3340, 1), (0x3245, true, 3341, 1), (0x3246, true, 3342, 1), (0x3247, true, 3343, 1),
+    (0x3250, true, 3344, 3), (0x3251, true, 3347, 2), (0x3252, true, 3349, 2), (0x3253, true, 3351, 2), (0x3254, true, 3353, 2),
+    (0x3255, true, 3355, 2), (0x3256, true, 3357, 2), (0x3257, true, 3359, 2), (0x3258, true, 3361, 2), (0x3259, true, 3363, 2),
+    (0x325A, true, 3365, 2), (0x325B, true, 3367, 2), (0x325C, true, 3369, 2), (0x325D, true, 3371, 2), (0x325E, true, 3373, 2),
+    (0x325F, true, 3375, 2), (0x3260, true, 3377, 1), (0x3261, true, 3378, 1), (0x3262, true, 3379, 1), (0x3263, true, 3380, 1),
+    (0x3264, true, 3381, 1), (0x3265, true, 3382, 1), (0x3266, true, 3383, 1), (0x3267, true, 3384, 1), (0x3268, true, 3385, 1),
+    (0x3269, true, 3386, 1), (0x326A, true, 3387, 1), (0x326B, true, 3388, 1), (0x326C, true, 3389, 1), (0x326D, true, 3390, 1),
+    (0x326E, true, 3391, 2), (0x326F, true, 3393, 2), (0x3270, true, 3395, 2), (0x3271, true, 3397, 2), (0x3272, true, 3399, 2),
+    (0x3273, true, 3401, 2), (0x3274, true, 3403, 2), (0x3275, true, 3405, 2), (0x3276, true, 3407, 2), (0x3277, true, 3409, 2),
+    (0x3278, true, 3411, 2), (0x3279, true, 3413, 2), (0x327A, true, 3415, 2), (0x327B, true, 3417, 2), (0x327C, true, 3419, 5),
+    (0x327D, true, 3424, 4), (0x327E, true, 3428, 2), (0x3280, true, 3430, 1), (0x3281, true, 3431, 1), (0x3282, true, 3432, 1),
+    (0x3283, true, 3433, 1), (0x3284, true, 3434, 1), (0x3285, true, 3435, 1), (0x3286, true, 3436, 1), (0x3287, true, 3437, 1),
+    (0x3288, true, 3438, 1), (0x3289, true, 3439, 1), (0x328A, true, 3440, 1), (0x328B, true, 3441, 1), (0x328C, true, 3442, 1),
+    (0x328D, true, 3443, 1), (0x328E, true, 3444, 1), (0x328F, true, 3445, 1), (0x3290, true, 3446, 1), (0x3291, true, 3447, 1),
+    (0x3292, true, 3448, 1), (0x3293, true, 3449, 1), (0x3294, true, 3450, 1), (0x3295, true, 3451, 1), (0x3296, true, 3452, 1),
+    (0x3297, true, 3453, 1), (0x3298, true, 3454, 1), (0x3299, true, 3455, 1), (0x329A, true, 3456, 1), (0x329B, true, 3457, 1),
+    (0x329C, true, 3458, 1), (0x329D, true, 3459, 1), (0x329E, true, 3460, 1), (0x329F, true, 3461, 1), (0x32A0, true, 3462, 1),
+    (0x32A1, true, 3463, 1), (0x32A2, true, 3464, 1), (0x32A3, true, 3465, 1), (0x32A4, true, 3466, 1), (0x32A5, true, 3467, 1),
+    (0x32A6, true, 3468, 1), (0x32A7, true, 3469, 1), (0x32A8, true, 3470, 1), (0x32A9, true, 3471, 1), (0x32AA, true, 3472, 1),
+    (0x32AB, true, 3473, 1), (0x32AC, true, 3474, 1), (0x32AD, true, 3475, 1), (0x32AE, true, 3476, 1), (0x32AF, true, 3477, 1),
+    (0x32B0, true, 3478, 1), (0x32B1, true, 3479, 2), (0x32B2, true, 3481, 2), (0x32B3, true, 3483, 2), (0x32B4, true, 3485, 2),
+    (0x32B5, true, 3487, 2), (0x32B6, true, 3489, 2), (0x32B7, true, 3491, 2), (0x32B8, true, 3493, 2), (0x32B9, true, 3495, 2),
+    (0x32BA, true, 3497, 2), (0x32BB, true, 3499, 2), (0x32BC, true, 3501, 2), (0x32BD, true, 3503, 2), (0x32BE, true, 3505, 2),
+    (0x32BF, true, 3507, 2), (0x32C0, true, 3509, 2), (0x32C1, true, 3511, 2), (0x32C2, true, 3513, 2), (0x32C3, true, 3515, 2),
+    (0x32C4, true, 3517, 2), (0x32C5, true, 3519, 2), (0x32C6, true, 3521, 2), (0x32C7, true, 3523, 2), (0x32C8, true, 3525, 2),
+    (0x32C9, true, 3527, 3), (0x32CA, true, 3530, 3), (0x32CB, true, 3533, 3), (0x32CC, true, 3536, 2), (0x32CD, true, 3538, 3),
+    (0x32CE, true, 3541, 2), (0x32CF, true, 3543, 3), (0x32D0, true, 3546, 1), (0x32D1, true, 3547, 1), (0x32D2, true, 3548, 1),
+    (0x32D3, true, 3549, 1), (0x32D4, true, 3550, 1), (0x32D5, true, 3551, 1), (0x32D6, true, 3552, 1), (0x32D7, true, 3553, 1),
+    (0x32D8, true, 3554, 1), (0x32D9, true, 3555, 1), (0x32DA, true, 3556, 1), (0x32DB, true, 3557, 1), (0x32DC, true, 3558, 1),
+    (0x32DD, true, 3559, 1), (0x32DE, true, 3560, 1), (0x32DF, true, 3561, 1), (0x32E0, true, 3562, 1), (0x32E1, true, 3563, 1),
+    (0x32E2, true, 3564, 1), (0x32E3, true, 3565, 1), (0x32E4, true, 3566, 1), (0x32E5, true, 3567, 1), (0x32E6, true, 3568, 1),
+    (0x32E7, true, 3569, 1), (0x32E8, true, 3570, 1), (0x32E9, true, 3571, 1), (0x32EA, true, 3572, 1), (0x32EB, true, 3573, 1),
+    (0x32EC, true, 3574, 1), (0x32ED, true, 3575, 1), (0x32EE, true, 3576, 1), (0x32EF, true, 3577, 1), (0x32F0, true, 3578, 1),
+    (0x32F1, true, 3579, 1), (0x32F2, true, 3580, 1), (0x32F3, true, 3581, 1), (0x32F4, true, 3582, 1), (0x32F5, true, 3583, 1),
+    (0x32F6, true, 3584, 1), (0x32F7, true, 3585, 1), (0x32F8, true, 3586, 1), (0x32F9, true, 3587, 1), (0x32FA, true, 3588, 1),
+    (0x32FB, true, 3589, 1), (0x32FC, true, 3590, 1), (0x32FD, true, 3591, 1), (0x32FE, true, 3592, 1), (0x32FF, true, 3593, 2),
+    (0x3300, true, 3595, 5), (0x3301, true, 3600, 4), (0x3302, true, 3604, 5), (0x3303, true, 3609, 3), (0x3304, true, 3612, 5),
+    (0x3305, true, 3617, 3), (0x3306, true, 3620, 3), (0x3307, true, 3623, 6), (0x3308, true, 3629, 4), (0x3309, true, 3633, 3),
+    (0x330A, true, 3636, 3), (0x330B, true, 3639, 3), (0x330C, true, 3642, 4), (0x330D, true, 3646, 4), (0x330E, true, 3650, 4),
+    (0x330F, true, 3654, 4), (0x3310, true, 3658, 4), (0x3311, true, 3662, 4), (0x3312, true, 3666, 4), (0x3313, true, 3670, 6),
+    (0x3314, true, 3676, 2), (0x3315, true, 3678, 6), (0x3316, true, 3684, 6), (0x3317, true, 3690, 5), (0x3318, true, 3695, 4),
+    (0x3319, true, 3699, 6), (0x331A, true, 3705, 6), (0x331B, true, 3711, 4), (0x331C, true, 3715, 3), (0x331D, true, 3718, 3),
+    (0x331E, true, 3721, 4), (0x331F, true, 3725, 4), (0x3320, true, 3729, 5), (0x3321, true, 3734, 5), (0x3322, true, 3739, 3),
+    (0x3323, true, 3742, 3), (0x3324, true, 3745, 4), (0x3325, true, 3749, 3), (0x3326, true, 3752, 3), (0x3327, true, 3755, 2),
+    (0x3328, true, 3757, 2), (0x3329, true, 3759, 3), (0x332A, true, 3762, 3), (0x332B, true, 3765, 6), (0x332C, true, 3771, 4),
+    (0x332D, true, 3775, 5), (0x332E, true, 3780, 6), (0x332F, true, 3786, 4), (0x3330, true, 3790, 3), (0x3331, true, 3793, 3),
+    (0x3332, true, 3796, 6), (0x3333, true, 3802, 4), (0x3334, true, 3806, 6), (0x3335, true, 3812, 3), (0x3336, true, 3815, 5),
+    (0x3337, true, 3820, 3), (0x3338, true, 3823, 4), (0x3339, true, 3827, 3), (0x333A, true, 3830, 4), (0x333B, true, 3834, 5),
+    (0x333C, true, 3839, 4), (0x333D, true, 3843, 5), (0x333E, true, 3848, 4), (0x333F, true, 3852, 2), (0x3340, true, 3854, 5),
+    (0x3341, true, 3859, 3), (0x3342, true, 3862, 3), (0x3343, true, 3865, 4), (0x3344, true, 3869, 3), (0x3345, true, 3872, 3),
+    (0x3346, true, 3875, 3), (0x3347, true, 3878, 5), (0x3348, true, 3883, 4), (0x3349, true, 3887, 2), (0x334A, true, 3889, 6),
+    (0x334B, true, 3895, 3), (0x334C, true, 3898, 5), (0x334D, true, 3903, 4), (0x334E, true, 3907, 4), (0x334F, true, 3911, 3),
+    (0x3350, true, 3914, 3), (0x3351, true, 3917, 4), (0x3352, true, 3921, 2), (0x3353, true, 3923, 4), (0x3354, true, 3927, 5),
+    (0x3355, true, 3932, 2), (0x3356, true, 3934, 6), (0x3357, true, 3940, 3), (0x3358, true, 3943, 2), (0x3359, true, 3945, 2),
+    (0x335A, true, 3947, 2), (0x335B, true, 3949, 2), (0x335C, true, 3951, 2), (0x335D, true, 3953, 2), (0x335E, true, 3955, 2),
+    (0x335F, true, 3957, 2), (0x3360, true, 3959, 2), (0x3361, true, 3961, 2), (0x3362, true, 3963, 3), (0x3363, true, 3966, 3),
+    (0x3364, true, 3969, 3), (0x3365, true, 3972, 3), (0x3366, true, 3975, 3), (0x3367, true, 3978, 3), (0x3368, true, 3981, 3),
+    (0x3369, true, 3984, 3), (0x336A, true, 3987, 3), (0x336B, true, 3990, 3), (0x336C, true, 3993, 3), (0x336D, true, 3996, 3),
+    (0x336E, true, 3999, 3), (0x336F, true, 4002, 3), (0x3370, true, 4005, 3), (0x3371, true, 4008, 3), (0x3372, true, 4011, 2),
+    (0x3373, true, 4013, 2), (0x3374, true, 4015, 3), (0x3375, true, 4018, 2), (0x3376, true, 4020, 2), (0x3377, true, 4022, 2),
+    (0x3378, true, 4024, 3), (0x3379, true, 4027, 3), (0x337A, true, 4030, 2), (0x337B, true, 4032, 2), (0x337C, true, 4034, 2),
+    (0x337D, true, 4036, 2), (0x337E, true, 4038, 2), (0x337F, true, 4040, 4), (0x3380, true, 4044, 2), (0x3381, true, 4046, 2),
+    (0x3382, true, 4048, 2), (0x3383, true, 4050, 2), (0x3384, true, 4052, 2), (0x3385, true, 4054, 2), (0x3386, true, 4056, 2),
+    (0x3387, true, 4058, 2), (0x3388, true, 4060, 3), (0x3389, true, 4063, 4), (0x338A, true, 4067, 2), (0x338B, true, 4069, 2),
+    (0x338C, true, 4071, 2), (0x338D, true, 4073, 2), (0x338E, true, 4075, 2), (0x338F, true, 4077, 2), (0x3390, true, 4079, 2),
+    (0x3391, true, 4081, 3), (0x3392, true, 4084, 3), (0x3393, true, 4087, 3), (0x3394, true, 4090, 3), (0x3395, true, 4093, 2),
+    (0x3396, true, 4095, 2), (0x3397, true, 4097, 2), (0x3398, true, 4099, 2), (0x3399, true, 4101, 2), (0x339A, true, 4103, 2),
+    (0x339B, true, 4105, 2), (0x339C, true, 4107, 2), (0x339D, true, 4109, 2), (0x339E, true, 4111, 2), (0x339F, true, 4113, 3),
+    (0x33A0, true, 4116, 3), (0x33A1, true, 4119, 2), (0x33A2, true, 4121, 3), (0x33A3, true, 4124, 3), (0x33A4, true, 4127, 3),
+    (0x33A5, true, 4130, 2), (0x33A6, true, 4132, 3), (0x33A7, true, 4135, 3), (0x33A8, true, 4138, 4), (0x33A9, true, 4142, 2),
+    (0x33AA, true, 4144, 3), (0x33AB, true, 4147, 3), (0x33AC, true, 4150, 3), (0x33AD, true, 4153, 3), (0x33AE, true, 4156, 5),
+    (0x33AF, true, 4161, 6), (0x33B0, true, 4167, 2), (0x33B1, true, 4169, 2), (0x33B2, true, 4171, 2), (0x33B3, true, 4173, 2),
+    (0x33B4, true, 4175, 2), (0x33B5, true, 4177, 2), (0x33B6, true, 4179, 2), (0x33B7, true, 4181, 2), (0x33B8, true, 4183, 2),
+    (0x33B9, true, 4185, 2), (0x33BA, true, 4187, 2), (0x33BB, true, 4189, 2), (0x33BC, true, 4191, 2), (0x33BD, true, 4193, 2),
+    (0x33BE, true, 4195, 2), (0x33BF, true, 4197, 2), (0x33C0, true, 4199, 2), (0x33C1, true, 4201, 2), (0x33C2, true, 4203, 4),
+    (0x33C3, true, 4207, 2), (0x33C4, true, 4209, 2), (0x33C5, true, 4211, 2), (0x33C6, true, 4213, 4), (0x33C7, true, 4217, 3),
+    (0x33C8, true, 4220, 2), (0x33C9, true, 4222, 2), (0x33CA, true, 4224, 2), (0x33CB, true, 4226, 2), (0x33CC, true, 4228, 2),
+    (0x33CD, true, 4230, 2), (0x33CE, true, 4232, 2), (0x33CF, true, 4234, 2), (0x33D0, true, 4236, 2), (0x33D1, true, 4238, 2),
+    (0x33D2, true, 4240, 3), (0x33D3, true, 4243, 2), (0x33D4, true, 4245, 2), (0x33D5, true, 4247, 3), (0x33D6, true, 4250, 3),
+    (0x33D7, true, 4253, 2), (0x33D8, true, 4255, 4), (0x33D9, true, 4259, 3), (0x33DA, true, 4262, 2), (0x33DB, true, 4264, 2),
+    (0x33DC, true, 4266, 2), (0x33DD, true, 4268, 2), (0x33DE, true, 4270, 3), (0x33DF, true, 4273, 3), (0x33E0, true, 4276, 2),
+    (0x33E1, true, 4278, 2), (0x33E2, true, 4280, 2), (0x33E3, true, 4282, 2), (0x33E4, true, 4284, 2), (0x33E5, true, 4286, 2),
+    (0x33E6, true, 4288, 2), (0x33E7, true, 4290, 2), (0x33E8, true, 4292, 2), (0x33E9, true, 4294, 3), (0x33EA, true, 4297, 3),
+    (0x33EB, true, 4300, 3), (0x33EC, true, 4303, 3), (0x33ED, true, 4306, 3), (0x33EE, true, 4309, 3), (0x33EF, true, 4312, 3),
+    (0x33F0, true, 4315, 3), (0x33F1, true, 4318, 3), (0x33F2, true, 4321, 3), (0x33F3, true, 4324, 3), (0x33F4, true, 4327, 3),
+    (0x33F5, true, 4330, 3), (0x33F6, true, 4333, 3), (0x33F7, true, 4336, 3), (0x33F8, true, 4339, 3), (0x33F9, true, 4342, 3),
+    (0x33FA, true, 4345, 3), (0x33FB, true, 4348, 3), (0x33FC, true, 4351, 3), (0x33FD, true, 4354, 3), (0x33FE, true, 4357, 3),
+    (0x33FF, true, 4360, 3), (0xA69C, true, 4363, 1), (0xA69D, true, 4364, 1), (0xA770, true, 4365, 1), (0xA7F1, true, 4366, 1),
+    (0xA7F2, true, 4367, 1), (0xA7F3, true, 4368, 1), (0xA7F4, true, 4369, 1), (0xA7F8, true, 4370, 1), (0xA7F9, true, 4371, 1),
+    (0xAB5C, true, 4372, 1), (0xAB5D, true, 4373, 1), (0xAB5E, true, 4374, 1), (0xAB5F, true, 4375, 1), (0xAB69, true, 4376, 1),
+    (0xF900, false, 4377, 1), (0xF901, false, 4378, 1), (0xF902, false, 4379, 1), (0xF903, false, 4380, 1), (0xF904, false, 4381, 1),
+    (0xF905, false, 4382, 1), (0xF906, false, 4383, 1), (0xF907, false, 4384, 1), (0xF908, false, 4385, 1), (0xF909, false, 4386, 1),
+    (0xF90A, false, 4387, 1), (0xF90B, false, 4388, 1), (0xF90C, false, 4389, 1), (0xF90D, false, 4390, 1), (0xF90E, false, 4391, 1),
+    (0xF90F, false, 4392, 1), (0xF910, false, 4393, 1), (0xF911, false, 4394, 1), (0xF912, false, 4395, 1), (0xF913, false, 4396, 1),
+    (0xF914, false, 4397, 1), (0xF915, false, 4398, 1), (0xF916, false, 4399, 1), (0xF917, false, 4400, 1), (0xF918, false, 4401, 1),
+    (0xF919, false, 4402, 1), (0xF91A, false, 4403, 1), (0xF91B, false, 4404, 1), (0xF91C, false, 4405, 1), (0xF91D, false, 4406, 1),
+    (0xF91E, false, 4407, 1), (0xF91F, false, 4408, 1), (0xF920, false, 4409, 1), (0xF921, false, 4410, 1), (0xF922, false, 4411, 1),
+    (0xF923, false, 4412, 1), (0xF924, false, 4413, 1), (0xF925, false, 4414, 1), (0xF926, false, 4415, 1), (0xF927, false, 4416, 1),
+    (0xF928, false, 4417, 1), (0xF929, false, 4418, 1), (0xF92A, false, 4419, 1), (0xF92B, false, 4420, 1), (0xF92C, false, 4421, 1),
+    (0xF92D, false, 4422, 1), (0xF92E, false, 4423, 1), (0xF92F, false, 4424, 1), (0xF930, false, 4425, 1), (0xF931, false, 4426, 1),
+    (0xF932, false, 4427, 1), (0xF933, false, 4428, 1), (0xF934, false, 4429, 1), (0xF935, false, 4430, 1), (0xF936, false, 4431, 1),
+    (0xF937, false, 4432, 1), (0xF938, false, 4433, 1), (0xF939, false, 4434, 1), (0xF93A, false, 4435, 1), (0xF93B, false, 4436, 1),
+    (0xF93C, false, 4437, 1), (0xF93D, false, 4438, 1), (0xF93E, false, 4439, 1), (0xF93F, false, 4440, 1), (0xF940, false, 4441, 1),
+    (0xF941, false, 4442, 1), (0xF942, false, 4443, 1), (0xF943, false, 4444, 1), (0xF944, false, 4445, 1), (0xF945, false, 4446, 1),
+    (0xF946, false, 4447, 1), (0xF947, false, 4448, 1), (0xF948, false, 4449, 1), (0xF949, false, 4450, 1), (0xF94A, false, 4451, 1),
+    (0xF94B, false, 4452, 1), (0xF94C, false, 4453, 1), (0xF94D, false, 4454, 1), (0xF94E, false, 4455, 1), (0xF94F, false, 4456, 1),
+    (0xF950, false, 4457, 1), (0xF951, false, 4458, 1), (0xF952, false, 4459, 1), (0xF953, false, 4460, 1), (0xF954, false, 4461, 1),
+    (0xF955, false, 4462, 1), (0xF956, false, 4463, 1), (0xF957, false, 4464, 1), (0xF958, false, 4465, 1), (0xF959, false, 4466, 1),
+    (0xF95A, false, 4467, 1), (0xF95B, false, 4468, 1), (0xF95C, false, 4469, 1), (0xF95D, false, 4470, 1), (0xF95E, false, 4471, 1),
+    (0xF95F, false, 4472, 1), (0xF960, false, 4473, 1), (0xF961, false, 4474, 1), (0xF962, false, 4475, 1), (0xF963, false, 4476, 1),
+    (0xF964, false, 4477, 1), (0xF965, false, 4478, 1), (0xF966, false, 4479, 1), (0xF967, false, 4480, 1), (0xF968, false, 4481, 1),
+    (0xF969, false, 4482, 1), (0xF96A, false, 4483, 1), (0xF96B, false, 4484, 1), (0xF96C, false, 4485, 1), (0xF96D, false, 4486, 1),
+    (0xF96E, false, 4487, 1), (0xF96F, false, 4488, 1), (0xF970, false, 4489, 1), (0xF971, false, 4490, 1), (0xF972, false, 4491, 1),
+    (0xF973, false, 4492, 1), (0xF974, false, 4493, 1), (0xF975, false, 4494, 1), (0xF976, false, 4495, 1), (0xF977, false, 4496, 1),
+    (0xF978, false, 4497, 1), (0xF979, false, 4498, 1), (0xF97A, false, 4499, 1), (0xF97B, false, 4500, 1), (0xF97C, false, 4501, 1),
+    (0xF97D, false, 4502, 1), (0xF97E, false, 4503, 1), (0xF97F, false, 4504, 1), (0xF980, false, 4505, 1), (0xF981, false, 4506, 1),
+    (0xF982, false, 4507, 1), (0xF983, false, 4508, 1), (0xF984, false, 4509, 1), (0xF985, false, 4510, 1), (0xF986, false, 4511, 1),
+    (0xF987, false, 4512, 1), (0xF988, false, 4513, 1), (0xF989, false, 4514, 1), (0xF98A, false, 4515, 1), (0xF98B, false, 4516, 1),
+    (0xF98C, false, 4517, 1), (0xF98D, false, 4518, 1), (0xF98E, false, 4519, 1), (0xF98F, false, 4520, 1), (0xF990, false, 4521, 1),
+    (0xF991, false, 4522, 1), (0xF992, false, 4523, 1), (0xF993, false, 4524, 1), (0xF994, false, 4525, 1), (0xF995, false, 4526, 1),
+    (0xF996, false, 4527, 1), (0xF997, false, 4528, 1), (0xF998, false, 4529, 1), (0xF999, false, 4530, 1), (0xF99A, false, 4531, 1),
+    (0xF99B, false, 4532, 1), (0xF99C, false, 4533, 1), (0xF99D, false, 4534, 1), (0xF99E, false, 4535, 1), (0xF99F, false, 4536, 1),
+    (0xF9A0, false, 4537, 1), (0xF9A1, false, 4538, 1), (0xF9A2, false, 4539, 1), (0xF9A3, false, 4540, 1), (0xF9A4, false, 4541, 1),
+    (0xF9A5, false, 4542, 1), (0xF9A6, false, 4543, 1), (0xF9A7, false, 4544, 1), (0xF9A8, false, 4545, 1), (0xF9A9, false, 4546, 1),
+    (0xF9AA, false, 4547, 1), (0xF9AB, false, 4548, 1), (0xF9AC, false, 4549, 1), (0xF9AD, false, 4550, 1), (0xF9AE, false, 4551, 1),
+    (0xF9AF, false, 4552, 1), (0xF9B0, false, 4553, 1), (0xF9B1, false, 4554, 1), (0xF9B2, false, 4555, 1), (0xF9B3, false, 4556, 1),
+    (0xF9B4, false, 4557, 1), (0xF9B5, false, 4558, 1), (0xF9B6, false, 4559, 1), (0xF9B7, false, 4560, 1), (0xF9B8, false, 4561, 1),
+    (0xF9B9, false, 4562, 1), (0xF9BA, false, 4563, 1), (0xF9BB, false, 4564, 1), (0xF9BC, false, 4565, 1), (0xF9BD, false, 4566, 1),
+    (0xF9BE, false, 4567, 1), (0xF9BF, false, 4568, 1), (0xF9C0, false, 4569, 1), (0xF9C1, false, 4570, 1), (0xF9C2, false, 4571, 1),
+    (0xF9C3, false, 4572, 1), (0xF9C4, false, 4573, 1), (0xF9C5, false, 4574, 1), (0xF9C6, false, 4575, 1), (0xF9C7, false, 4576, 1),
+    (0xF9C8, false, 4577, 1), (0xF9C9, false, 4578, 1), (0xF9CA, false, 4579, 1), (0xF9CB, false, 4580, 1), (0xF9CC, false, 4581, 1),
+    (0xF9CD, false, 4582, 1), (0xF9CE, false, 4583, 1), (0xF9CF, false, 4584, 1), (0xF9D0, false, 4585, 1), (0xF9D1, false, 4586, 1),
+    (0xF9D2, false, 4587, 1), (0xF9D3, false, 4588, 1), (0xF9D4, false, 4589, 1), (0xF9D5, false, 4590, 1), (0xF9D6, false, 4591, 1),
+    (0xF9D7, false, 4592, 1), (0xF9D8, false, 4593, 1), (0xF9D9, false, 4594, 1), (0xF9DA, false, 4595, 1), (0xF9DB, false, 4596, 1),
+    (0xF9DC, false, 4597, 1), (0xF9DD, false, 4598, 1), (0xF9DE, false, 4599, 1), (0xF9DF, false, 4600, 1), (0xF9E0, false, 4601, 1),
+    (0xF9E1, false, 4602, 1), (0xF9E2, false, 4603, 1), (0xF9E3, false, 4604, 1), (0xF9E4, false, 4605, 1), (0xF9E5, false, 4606, 1),
+    (0xF9E6, false, 4607, 1), (0xF9E7, false, 4608, 1), (0xF9E8, false, 4609, 1), (0xF9E9, false, 4610, 1), (0xF9EA, false, 4611, 1),
+    (0xF9EB, false, 4612, 1), (0xF9EC, false, 4613, 1), (0xF9ED, false, 4614, 1), (0xF9EE, false, 4615, 1), (0xF9EF, false, 4616, 1),
+    (0xF9F0, false, 4617, 1), (0xF9F1, false, 4618, 1), (0xF9F2, false, 4619, 1), (0xF9F3, false, 4620, 1), (0xF9F4, false, 4621, 1),
+    (0xF9F5, false, 4622, 1), (0xF9F6, false, 4623, 1), (0xF9F7, false, 4624, 1), (0xF9F8, false, 4625, 1), (0xF9F9, false, 4626, 1),
+    (0xF9FA, false, 4627, 1), (0xF9FB, false, 4628, 1), (0xF9FC, false, 4629, 1), (0xF9FD, false, 4630, 1), (0xF9FE, false, 4631, 1),
+    (0xF9FF, false, 4632, 1), (0xFA00, false, 4633, 1), (0xFA01, false, 4634, 1), (0xFA02, false, 4635, 1), (0xFA03, false, 4636, 1),
+    (0xFA04, false, 4637, 1), (0xFA05, false, 4638, 1), (0xFA06, false, 4639, 1), (0xFA07, false, 4640, 1), (0xFA08, false, 4641, 1),
+    (0xFA09, false, 4642, 1), (0xFA0A, false, 4643, 1), (0xFA0B, false, 4644, 1), (0xFA0C, false, 4645, 1), (0xFA0D, false, 4646, 1),
+    (0xFA10, false, 4647, 1), (0xFA12, false, 4648, 1), (0xFA15, false, 4649, 1), (0xFA16, false, 4650, 1), (0xFA17, false, 4651, 1),
+    (0xFA18, false, 4652, 1), (0xFA19, false, 4653, 1), (0xFA1A, false, 4654, 1), (0xFA1B, false, 4655, 1), (0xFA1C, false, 4656, 1),
+    (0xFA1D, false, 4657, 1), (0xFA1E, false, 4658, 1), (0xFA20, false, 4659, 1), (0xFA22, false, 4660, 1), (0xFA25, false, 4661, 1),
+    (0xFA26, false, 4662, 1), (0xFA2A, false, 4663, 1), (0xFA2B, false, 4664, 1), (0xFA2C, false, 4665, 1), (0xFA2D, false, 4666, 1),
+    (0xFA2E, false, 4667, 1), (0xFA2F, false, 4668, 1), (0xFA30, false, 4669, 1), (0xFA31, false, 4670, 1), (0xFA32, false, 4671, 1),
+    (0xFA33, false, 4672, 1), (0xFA34, false, 4673, 1), (0xFA35, false, 4674, 1), (0xFA36, false, 4675, 1), (0xFA37, false, 4676, 1),
+    (0xFA38, false, 4677, 1), (0xFA39, false, 4678, 1), (0xFA3A, false, 4679, 1), (0xFA3B, false, 4680, 1), (0xFA3C, false, 4681, 1),
+    (0xFA3D, false, 4682, 1), (0xFA3E, false, 4683, 1), (0xFA3F, false, 4684, 1), (0xFA40, false, 4685, 1), (0xFA41, false, 4686, 1),
+    (0xFA42, false, 4687, 1), (0xFA43, false, 4688, 1), (0xFA44, false, 4689, 1), (0xFA45, false, 4690, 1), (0xFA46, false, 4691, 1),
+    (0xFA47, false, 4692, 1), (0xFA48, false, 4693, 1), (0xFA49, false, 4694, 1), (0xFA4A, false, 4695, 1), (0xFA4B, false, 4696, 1),
+    (0xFA4C, false, 4697, 1), (0xFA4D, false, 4698, 1), (0xFA4E, false, 4699, 1), (0xFA4F, false, 4700, 1), (0xFA50, false, 4701, 1),
+    (0xFA51, false, 4702, 1), (0xFA52, false, 4703, 1), (0xFA53, false, 4704, 1), (0xFA54, false, 4705, 1), (0xFA55, false, 4706, 1),
+    (0xFA56, false, 4707, 1), (0xFA57, false, 4708, 1), (0xFA58, false, 4709, 1), (0xFA59, false, 4710, 1), (0xFA5A, false, 4711, 1),
+    (0xFA5B, false, 4712, 1), (0xFA5C, false, 4713, 1), (0xFA5D, false, 4714, 1), (0xFA5E, false, 4715, 1), (0xFA5F, false, 4716, 1),
+    (0xFA60, false, 4717, 1), (0xFA61, false, 4718, 1), (0xFA62, false, 4719, 1), (0xFA63, false, 4720, 1), (0xFA64, false, 4721, 1),
+    (0xFA65, false, 4722, 1), (0xFA66, false, 4723, 1), (0xFA67, false, 4724, 1), (0xFA68, false, 4725, 1), (0xFA69, false, 4726, 1),
+    (0xFA6A, false, 4727, 1), (0xFA6B, false, 4728, 1), (0xFA6C, false, 4729, 1), (0xFA6D, false, 4730, 1), (0xFA70, false, 4731, 1),
+    (0xFA71, false, 4732, 1), (0xFA72, false, 4733, 1), (0xFA73, false, 4734, 1), (0xFA74, false, 4735, 1), (0xFA75, false, 4736, 1),
+    (0xFA76, false, 4737, 1), (0xFA77, false, 4738, 1), (0xFA78, false, 4739, 1), (0xFA79, false, 4740, 1), (0xFA7A, false, 4741, 1),
+    (0xFA7B, false, 4742, 1), (0xFA7C, false, 4743, 1), (0xFA7D, false, 4744, 1), (0xFA7E, false, 4745, 1), (0xFA7F, false, 4746, 1),
+    (0xFA80, false, 4747, 1), (0xFA81, false, 4748, 1), (0xFA82, false, 4749, 1), (0xFA83, false, 4750, 1), (0xFA84, false, 4751, 1),
+    (0xFA85, false, 4752, 1), (0xFA86, false, 4753, 1), (0xFA87, false, 4754, 1), (0xFA88, false, 4755, 1), (0xFA89, false, 4756, 1),
+    (0xFA8A, false, 4757, 1), (0xFA8B, false, 4758, 1), (0xFA8C, false, 4759, 1), (0xFA8D, false, 4760, 1), (0xFA8E, false, 4761, 1),
+    (0xFA8F, false, 4762, 1), (0xFA90, false, 4763, 1), (0xFA91, false, 4764, 1), (0xFA92, false, 4765, 1), (0xFA93, false, 4766, 1),
+    (0xFA94, false, 4767, 1), (0xFA95, false, 4768, 1), (0xFA96, false, 4769, 1), (0xFA97, false, 4770, 1), (0xFA98, false, 4771, 1),
+    (0xFA99, false, 4772, 1), (0xFA9A, false, 4773, 1), (0xFA9B, false, 4774, 1), (0xFA9C, false, 4775, 1), (0xFA9D, false, 4776, 1),
+    (0xFA9E, false, 4777, 1), (0xFA9F, false, 4778, 1), (0xFAA0, false, 4779, 1), (0xFAA1, false, 4780, 1), (0xFAA2, false, 4781, 1),
+    (0xFAA3, false, 4782, 1), (0xFAA4, false, 4783, 1), (0xFAA5, false, 4784, 1), (0xFAA6, false, 4785, 1), (0xFAA7, false, 4786, 1),
+    (0xFAA8, false, 4787, 1), (0xFAA9, false, 4788, 1), (0xFAAA, false, 4789, 1), (0xFAAB, false, 4790, 1), (0xFAAC, false, 4791, 1),
+    (0xFAAD, false, 4792, 1), (0xFAAE, false, 4793, 1), (0xFAAF, false, 4794, 1), (0xFAB0, false, 4795, 1), (0xFAB1, false, 4796, 1),
+    (0xFAB2, false, 4797, 1), (0xFAB3, false, 4798, 1), (0xFAB4, false, 4799, 1), (0xFAB5, false, 4800, 1), (0xFAB6, false, 4801, 1),
+    (0xFAB7, false, 4802, 1), (0xFAB8, false, 4803, 1), (0xFAB9, false, 4804, 1), (0xFABA, false, 4805, 1), (0xFABB, false, 4806, 1),
+    (0xFABC, false, 4807, 1), (0xFABD, false, 4808, 1), (0xFABE, false, 4809, 1), (0xFABF, false, 4810, 1), (0xFAC0, false, 4811, 1),
+    (0xFAC1, false, 4812, 1), (0xFAC2, false, 4813, 1), (0xFAC3, false, 4814, 1), (0xFAC4, false, 4815, 1), (0xFAC5, false, 4816, 1),
+    (0xFAC6, false, 4817, 1), (0xFAC7, false, 4818, 1), (0xFAC8, false, 4819, 1), (0xFAC9, false, 4820, 1), (0xFACA, false, 4821, 1),
+    (0xFACB, false, 4822, 1), (0xFACC, false, 4823, 1), (0xFACD, false, 4824, 1), (0xFACE, false, 4825, 1), (0xFACF, false, 4826, 1),
+    (0xFAD0, false, 4827, 1), (0xFAD1, false, 4828, 1), (0xFAD2, false, 4829, 1), (0xFAD3, false, 4830, 1), (0xFAD4, false, 4831, 1),
+    (0xFAD5, false, 4832, 1), (0xFAD6, false, 4833, 1), (0xFAD7, false, 4834, 1), (0xFAD8, false, 4835, 1), (0xFAD9, false, 4836, 1),
+    (0xFB00, true, 4837, 2), (0xFB01, true, 4839, 2), (0xFB02, true, 4841, 2), (0xFB03, true, 4843, 3), (0xFB04, true, 4846, 3),
+    (0xFB05, true, 4849, 2), (0xFB06, true, 4851, 2), (0xFB13, true, 4853, 2), (0xFB14, true, 4855, 2), (0xFB15, true, 4857, 2),
+    (0xFB16, true, 4859, 2), (0xFB17, true, 4861, 2), (0xFB1D, false, 4863, 2), (0xFB1F, false, 4865, 2), (0xFB20, true, 4867, 1),
+    (0xFB21, true, 4868, 1), (0xFB22, true, 4869, 1), (0xFB23, true, 4870, 1), (0xFB24, true, 4871, 1), (0xFB25, true, 4872, 1),
+    (0xFB26, true, 4873, 1), (0xFB27, true, 4874, 1), (0xFB28, true, 4875, 1), (0xFB29, true, 4876, 1), (0xFB2A, false, 4877, 2),
+    (0xFB2B, false, 4879, 2), (0xFB2C, false, 4881, 2), (0xFB2D, false, 4883, 2), (0xFB2E, false, 4885, 2), (0xFB2F, false, 4887, 2),
+    (0xFB30, false, 4889, 2), (0xFB31, false, 4891, 2), (0xFB32, false, 4893, 2), (0xFB33, false, 4895, 2), (0xFB34, false, 4897, 2),
+    (0xFB35, false, 4899, 2), (0xFB36, false, 4901, 2), (0xFB38, false, 4903, 2), (0xFB39, false, 4905, 2), (0xFB3A, false, 4907, 2),
+    (0xFB3B, false, 4909, 2), (0xFB3C, false, 4911, 2), (0xFB3E, false, 4913, 2), (0xFB40, false, 4915, 2), (0xFB41, false, 4917, 2),
+    (0xFB43, false, 4919, 2), (0xFB44, false, 4921, 2), (0xFB46, false, 4923, 2), (0xFB47, false, 4925, 2), (0xFB48, false, 4927, 2),
+    (0xFB49, false, 4929, 2), (0xFB4A, false, 4931, 2), (0xFB4B, false, 4933, 2), (0xFB4C, false, 4935, 2), (0xFB4D, false, 4937, 2),
+    (0xFB4E, false, 4939, 2), (0xFB4F, true, 4941, 2), (0xFB50, true, 4943, 1), (0xFB51, true, 4944, 1), (0xFB52, true, 4945, 1),
+    (0xFB53, true, 4946, 1), (0xFB54, true, 4947, 1), (0xFB55, true, 4948, 1), (0xFB56, true, 4949, 1), (0xFB57, true, 4950, 1),
+    (0xFB58, true, 4951, 1), (0xFB59, true, 4952, 1), (0xFB5A, true, 4953, 1), (0xFB5B, true, 4954, 1), (0xFB5C, true, 4955, 1),
+    (0xFB5D, true, 4956, 1), (0xFB5E, true, 4957, 1), (0xFB5F, true, 4958, 1), (0xFB60, true, 4959, 1), (0xFB61, true, 4960, 1),
+    (0xFB62, true, 4961, 1), (0xFB63, true, 4962, 1), (0xFB64, true, 4963, 1), (0xFB65, true, 4964, 1), (0xFB66, true, 4965, 1),
+    (0xFB67, true, 4966, 1), (0xFB68, true, 4967, 1), (0xFB69, true, 4968, 1), (0xFB6A, true, 4969, 1), (0xFB6B, true, 4970, 1),
+    (0xFB6C, true, 4971, 1), (0xFB6D, true, 4972, 1), (0xFB6E, true, 4973, 1), (0xFB6F, true, 4974, 1), (0xFB70, true, 4975, 1),
+    (0xFB71, true, 4976, 1), (0xFB72, true, 4977, 1), (0xFB73, true, 4978, 1), (0xFB74, true, 4979, 1), (0xFB75, true, 4980, 1),
+    (0xFB76, true, 4981, 1), (0xFB77, true, 4982, 1), (0xFB78, true, 4983, 1), (0xFB79, true, 4984, 1), (0xFB7A, true, 4985, 1),
+    (0xFB7B, true, 4986, 1), (0xFB7C, true, 4987, 1), (0xFB7D, true, 4988, 1), (0xFB7E, true, 4989, 1), (0xFB7F, true, 4990, 1),
+    (0xFB80, true, 4991, 1), (0xFB81, true, 4992, 1), (0xFB82, true, 4993, 1), (0xFB83, true, 4994, 1), (0xFB84, true, 4995, 1),
+    (0xFB85, true, 4996, 1), (0xFB86, true, 4997, 1), (0xFB87, true, 4998, 1), (0xFB88, true, 4999, 1), (0xFB89, true, 5000, 1),
+    (0xFB8A, true, 5001, 1), (0xFB8B, true, 5002, 1), (0xFB8C, true, 5003, 1), (0xFB8D, true, 5004, 1), (0xFB8E, true, 5005, 1),
+    (0xFB8F, true, 5006, 1), (0xFB90, true, 5007, 1), (0xFB91, true, 5008, 1), (0xFB92, true, 5009, 1), (0xFB93, true, 5010, 1),
+    (0xFB94, true, 5011, 1), (0xFB95, true, 5012, 1), (0xFB96, true, 5013, 1), (0xFB97, true, 5014, 1), (0xFB98, true, 5015, 1),
+    (0xFB99, true, 5016, 1), (0xFB9A, true, 5017, 1), (0xFB9B, true, 5018, 1), (0xFB9C, true, 5019, 1), (0xFB9D, true, 5020, 1),
+    (0xFB9E, true, 5021, 1), (0xFB9F, true, 5022, 1), (0xFBA0, true, 5023, 1), (0xFBA1, true, 5024, 1), (0xFBA2, true, 5025, 1),
+    (0xFBA3, true, 5026, 1), (0xFBA4, true, 5027, 2), (0xFBA5, true, 5029, 2), (0xFBA6, true, 5031, 1), (0xFBA7, true, 5032, 1),
+    (0xFBA8, true, 5033, 1), (0xFBA9, true, 5034, 1), (0xFBAA, true, 5035, 1), (0xFBAB, true, 5036, 1), (0xFBAC, true, 5037, 1),
+    (0xFBAD, true, 5038, 1), (0xFBAE, true, 5039, 1), (0xFBAF, true, 5040, 1), (0xFBB0, true, 5041, 2), (0xFBB1, true, 5043, 2),
+    (0xFBD3, true, 5045, 1), (0xFBD4, true, 5046, 1), (0xFBD5, true, 5047, 1), (0xFBD6, true, 5048, 1), (0xFBD7, true, 5049, 1),
+    (0xFBD8, true, 5050, 1), (0xFBD9, true, 5051, 1), (0xFBDA, true, 5052, 1), (0xFBDB, true, 5053, 1), (0xFBDC, true, 5054, 1),
+    (0xFBDD, true, 5055, 2), (0xFBDE, true, 5057, 1), (0xFBDF, true, 5058, 1), (0xFBE0, true, 5059, 1), (0xFBE1, true, 5060, 1),
+    (0xFBE2, true, 5061, 1), (0xFBE3, true, 5062, 1), (0xFBE4, true, 5063, 1), (0xFBE5, true, 5064, 1), (0xFBE6, true, 5065, 1),
+    (0xFBE7, true, 5066, 1), (0xFBE8, true, 5067, 1), (0xFBE9, true, 5068, 1), (0xFBEA, true, 5069, 3), (0xFBEB, true, 5072, 3),
+    (0xFBEC, true, 5075, 3), (0xFBED, true, 5078, 3), (0xFBEE, true, 5081, 3), (0xFBEF, true, 5084, 3), (0xFBF0, true, 5087, 3),
+    (0xFBF1, true, 5090, 3), (0xFBF2, true, 5093, 3), (0xFBF3, true, 5096, 3), (0xFBF4, true, 5099, 3), (0xFBF5, true, 5102, 3),
+    (0xFBF6, true, 5105, 3), (0xFBF7, true, 5108, 3), (0xFBF8, true, 5111, 3), (0xFBF9, true, 5114, 3), (0xFBFA, true, 5117, 3),
+    (0xFBFB, true, 5120, 3), (0xFBFC, true, 5123, 1), (0xFBFD, true, 5124, 1), (0xFBFE, true, 5125, 1), (0xFBFF, true, 5126, 1),
+    (0xFC00, true, 5127, 3), (0xFC01, true, 5130, 3), (0xFC02, true, 5133, 3), (0xFC03, true, 5136, 3), (0xFC04, true, 5139, 3),
+    (0xFC05, true, 5142, 2), (0xFC06, true, 5144, 2), (0xFC07, true, 5146, 2), (0xFC08, true, 5148, 2), (0xFC09, true, 5150, 2),
+    (0xFC0A, true, 5152, 2), (0xFC0B, true, 5154, 2), (0xFC0C, true, 5156, 2), (0xFC0D, true, 5158, 2), (0xFC0E, true, 5160, 2),
+    (0xFC0F, true, 5162, 2), (0xFC10, true, 5164, 2), (0xFC11, true, 5166, 2), (0xFC12, true, 5168, 2), (0xFC13, true, 5170, 2),
+    (0xFC14, true, 5172, 2), (0xFC15, true, 5174, 2), (0xFC16, true, 5176, 2), (0xFC17, true, 5178, 2), (0xFC18, true, 5180, 2),
+    (0xFC19, true, 5182, 2), (0xFC1A, true, 5184, 2), (0xFC1B, true, 5186, 2), (0xFC1C, true, 5188, 2), (0xFC1D, true, 5190, 2),
+    (0xFC1E, true, 5192, 2), (0xFC1F, true, 5194, 2), (0xFC20, true, 5196, 2), (0xFC21, true, 5198, 2), (0xFC22, true, 5200, 2),
+    (0xFC23, true, 5202, 2), (0xFC24, true, 5204, 2), (0xFC25, true, 5206, 2), (0xFC26, true, 5208, 2), (0xFC27, true, 5210, 2),
+    (0xFC28, true, 5212, 2), (0xFC29, true, 5214, 2), (0xFC2A, true, 5216, 2), (0xFC2B, true, 5218, 2), (0xFC2C, true, 5220, 2),
+    (0xFC2D, true, 5222, 2), (0xFC2E, true, 5224, 2), (0xFC2F, true, 5226, 2), (0xFC30, true, 5228, 2), (0xFC31, true, 5230, 2),
+    (0xFC32, true, 5232, 2), (0xFC33, true, 5234, 2), (0xFC34, true, 5236, 2), (0xFC35, true, 5238, 2), (0xFC36, true, 5240, 2),
+    (0xFC37, true, 5242, 2), (0xFC38, true, 5244, 2), (0xFC39, true, 5246, 2), (0xFC3A, true, 5248, 2), (0xFC3B, true, 5250, 2),
+    (0xFC3C, true, 5252, 2), (0xFC3D, true, 5254, 2), (0xFC3E, true, 5256, 2), (0xFC3F, true, 5258, 2), (0xFC40, true, 5260, 2),
+    (0xFC41, true, 5262, 2), (0xFC42, true, 5264, 2), (0xFC43, true, 5266, 2), (0xFC44, true, 5268, 2), (0xFC45, true, 5270, 2),
+    (0xFC46, true, 5272, 2), (0xFC47, true, 5274, 2), (0xFC48, true, 5276, 2), (0xFC49, true, 5278, 2), (0xFC4A, true, 5280, 2),
+    (0xFC4B, true, 5282, 2), (0xFC4C, true, 5284, 2), (0xFC4D, true, 5286, 2), (0xFC4E, true, 5288, 2), (0xFC4F, true, 5290, 2),
+    (0xFC50, true, 5292, 2), (0xFC51, true, 5294, 2), (0xFC52, true, 5296, 2), (0xFC53, true, 5298, 2), (0xFC54, true, 5300, 2),
+    (0xFC55, true, 5302, 2), (0xFC56, true, 5304, 2), (0xFC57, true, 5306, 2), (0xFC58, true, 5308, 2), (0xFC59, true, 5310, 2),
+    (0xFC5A, true, 5312, 2), (0xFC5B, true, 5314, 2), (0xFC5C, true, 5316, 2), (0xFC5D, true, 5318, 2), (0xFC5E, true, 5320, 3),
+    (0xFC5F, true, 5323, 3), (0xFC60, true, 5326, 3), (0xFC61, true, 5329, 3), (0xFC62, true, 5332, 3), (0xFC63, true, 5335, 3),
+    (0xFC64, true, 5338, 3), (0xFC65, true, 5341, 3), (0xFC66, true, 5344, 3), (0xFC67, true, 5347, 3), (0xFC68, true, 5350, 3),
+    (0xFC69, true, 5353, 3), (0xFC6A, true, 5356, 2), (0xFC6B, true, 5358, 2), (0xFC6C, true, 5360, 2), (0xFC6D, true, 5362, 2),
+    (0xFC6E, true, 5364, 2), (0xFC6F, true, 5366, 2), (0xFC70, true, 5368, 2), (0xFC71, true, 5370, 2), (0xFC72, true, 5372, 2),
+    (0xFC73, true, 5374, 2), (0xFC74, true, 5376, 2), (0xFC75, true, 5378, 2), (0xFC76, true, 5380, 2), (0xFC77, true, 5382, 2),
+    (0xFC78, true, 5384, 2), (0xFC79, true, 5386, 2), (0xFC7A, true, 5388, 2), (0xFC7B, true, 5390, 2), (0xFC7C, true, 5392, 2),
+    (0xFC7D, true, 5394, 2), (0xFC7E, true, 5396, 2), (0xFC7F, true, 5398, 2), (0xFC80, true, 5400, 2), (0xFC81, true, 5402, 2),
+    (0xFC82, true, 5404, 2), (0xFC83, true, 5406, 2), (0xFC84, true, 5408, 2), (0xFC85, true, 5410, 2), (0xFC86, true, 5412, 2),
+    (0xFC87, true, 5414, 2), (0xFC88, true, 5416, 2), (0xFC89, true, 5418, 2), (0xFC8A, true, 5420, 2), (0xFC8B, true, 5422, 2),
+    (0xFC8C, true, 5424, 2), (0xFC8D, true, 5426, 2), (0xFC8E, true, 5428, 2), (0xFC8F, true, 5430, 2), (0xFC90, true, 5432, 2),
+    (0xFC91, true, 5434, 2), (0xFC92, true, 5436, 2), (0xFC93, true, 5438, 2), (0xFC94, true, 5440, 2), (0xFC95, true, 5442, 2),
+    (0xFC96, true, 5444, 2), (0xFC97, true, 5446, 3), (0xFC98, true, 5449, 3), (0xFC99, true, 5452, 3), (0xFC9A, true, 5455, 3),
+    (0xFC9B, true, 5458, 3), (0xFC9C, true, 5461, 2), (0xFC9D, true, 5463, 2), (0xFC9E, true, 5465, 2), (0xFC9F, true, 5467, 2),
+    (0xFCA0, true, 5469, 2), (0xFCA1, true, 5471, 2), (0xFCA2, true, 5473, 2), (0xFCA3, true, 5475, 2), (0xFCA4, true, 5477, 2),
+    (0xFCA5, true, 5479, 2), (0xFCA6, true, 5481, 2), (0xFCA7, true, 5483, 2), (0xFCA8, true, 5485, 2), (0xFCA9, true, 5487, 2),
+    (0xFCAA, true, 5489, 2), (0xFCAB, true, 5491, 2), (0xFCAC, true, 5493, 2), (0xFCAD, true, 5495, 2), (0xFCAE, true, 5497, 2),
+    (0xFCAF, true, 5499, 2), (0xFCB0, true, 5501, 2), (0xFCB1, true, 5503, 2), (0xFCB2, true, 5505, 2), (0xFCB3, true, 5507, 2),
+    (0xFCB4, true, 5509, 2), (0xFCB5, true, 5511, 2), (0xFCB6, true, 5513, 2), (0xFCB7, true, 5515, 2), (0xFCB8, true, 5517, 2),
+    (0xFCB9, true, 5519, 2), (0xFCBA, true, 5521, 2), (0xFCBB, true, 5523, 2), (0xFCBC, true, 5525, 2), (0xFCBD, true, 5527, 2),
+    (0xFCBE, true, 5529, 2), (0xFCBF, true, 5531, 2), (0xFCC0, true, 5533, 2), (0xFCC1, true, 5535, 2), (0xFCC2, true, 5537, 2),
+    (0xFCC3, true, 5539, 2), (0xFCC4, true, 5541, 2), (0xFCC5, true, 5543, 2), (0xFCC6, true, 5545, 2), (0xFCC7, true, 5547, 2),
+    (0xFCC8, true, 5549, 2), (0xFCC9, true, 5551, 2), (0xFCCA, true, 5553, 2), (0xFCCB, true, 5555, 2), (0xFCCC, true, 5557, 2),
+    (0xFCCD, true, 5559, 2), (0xFCCE, true, 5561, 2), (0xFCCF, true, 5563, 2), (0xFCD0, true, 5565, 2), (0xFCD1, true, 5567, 2),
+    (0xFCD2, true, 5569, 2), (0xFCD3, true, 5571, 2), (0xFCD4, true, 5573, 2), (0xFCD5, true, 5575, 2), (0xFCD6, true, 5577, 2),
+    (0xFCD7, true, 5579, 2), (0xFCD8, true, 5581, 2), (0xFCD9, true, 5583, 2), (0xFCDA, true, 5585, 2), (0xFCDB, true, 5587, 2),
+    (0xFCDC, true, 5589, 2), (0xFCDD, true, 5591, 2), (0xFCDE, true, 5593, 2), (0xFCDF, true, 5595, 3), (0xFCE0, true, 5598, 3),
+    (0xFCE1, true, 5601, 2), (0xFCE2, true, 5603, 2), (0xFCE3, true, 5605, 2), (0xFCE4, true, 5607, 2), (0xFCE5, true, 5609, 2),
+    (0xFCE6, true, 5611, 2), (0xFCE7, true, 5613, 2), (0xFCE8, true, 5615, 2), (0xFCE9, true, 5617, 2), (0xFCEA, true, 5619, 2),
+    (0xFCEB, true, 5621, 2), (0xFCEC, true, 5623, 2), (0xFCED, true, 5625, 2), (0xFCEE, true, 5627, 2), (0xFCEF, true, 5629, 2),
+    (0xFCF0, true, 5631, 2), (0xFCF1, true, 5633, 2), (0xFCF2, true, 5635, 3), (0xFCF3, true, 5638, 3), (0xFCF4, true, 5641, 3),
+    (0xFCF5, true, 5644, 2), (0xFCF6, true, 5646, 2), (0xFCF7, true, 5648, 2), (0xFCF8, true, 5650, 2), (0xFCF9, true, 5652, 2),
+    (0xFCFA, true, 5654, 2), (0xFCFB, true, 5656, 2), (0xFCFC, true, 5658, 2), (0xFCFD, true, 5660, 2), (0xFCFE, true, 5662, 2),
+    (0xFCFF, true, 5664, 2), (0xFD00, true, 5666, 2), (0xFD01, true, 5668, 2), (0xFD02, true, 5670, 2), (0xFD03, true, 5672, 2),
+    (0xFD04, true, 5674, 2), (0xFD05, true, 5676, 2), (0xFD06, true, 5678, 2), (0xFD07, true, 5680, 2), (0xFD08, true, 5682, 2),
+    (0xFD09, true, 5684, 2), (0xFD0A, true, 5686, 2), (0xFD0B, true, 5688, 2), (0xFD0C, true, 5690, 2), (0xFD0D, true, 5692, 2),
+    (0xFD0E, true, 5694, 2), (0xFD0F, true, 5696, 2), (0xFD10, true, 5698, 2), (0xFD11, true, 5700, 2), (0xFD12, true, 5702, 2),
+    (0xFD13, true, 5704, 2), (0xFD14, true, 5706, 2), (0xFD15, true, 5708, 2), (0xFD16, true, 5710, 2), (0xFD17, true, 5712, 2),
+    (0xFD18, true, 5714, 2), (0xFD19, true, 5716, 2), (0xFD1A, true, 5718, 2), (0xFD1B, true, 5720, 2), (0xFD1C, true, 5722, 2),
+    (0xFD1D, true, 5724, 2), (0xFD1E, true, 5726, 2), (0xFD1F, true, 5728, 2), (0xFD20, true, 5730, 2), (0xFD21, true, 5732, 2),
+    (0xFD22, true, 5734, 2), (0xFD23, true, 5736, 2), (0xFD24, true, 5738, 2), (0xFD25, true, 5740, 2), (0xFD26, true, 5742, 2),
+    (0xFD27, true, 5744, 2), (0xFD28, true, 5746, 2), (0xFD29, true, 5748, 2), (0xFD2A, true, 5750, 2), (0xFD2B, true, 5752, 2),
+    (0xFD2C, true, 5754, 2), (0xFD2D, true, 5756, 2), (0xFD2E, true, 5758, 2), (0xFD2F, true, 5760, 2), (0xFD30, true, 5762, 2),
+    (0xFD31, true, 5764, 2), (0xFD32, true, 5766, 2), (0xFD33, true, 5768, 2), (0xFD34, true, 5770, 2), (0xFD35, true, 5772, 2),
+    (0xFD36, true, 5774, 2), (0xFD37, true, 5776, 2), (0xFD38, true, 5778, 2), (0xFD39, true, 5780, 2), (0xFD3A, true, 5782, 2),
+    (0xFD3B, true, 5784, 2), (0xFD3C, true, 5786, 2), (0xFD3D, true, 5788, 2), (0xFD50, true, 5790, 3), (0xFD51, true, 5793, 3),
+    (0xFD52, true, 5796, 3), (0xFD53, true, 5799, 3), (0xFD54, true, 5802, 3), (0xFD55, true, 5805, 3), (0xFD56, true, 5808, 3),
+    (0xFD57, true, 5811, 3), (0xFD58, true, 5814, 3), (0xFD59, true, 5817, 3), (0xFD5A, true, 5820, 3), (0xFD5B, true, 5823, 3),
+    (0xFD5C, true, 5826, 3), (0xFD5D, true, 5829, 3), (0xFD5E, true, 5832, 3), (0xFD5F, true, 5835, 3), (0xFD60, true, 5838, 3),
+    (0xFD61, true, 5841, 3), (0xFD62, true, 5844, 3), (0xFD63, true, 5847, 3), (0xFD64, true, 5850, 3), (0xFD65, true, 5853, 3),
+    (0xFD66, true, 5856, 3), (0xFD67, true, 5859, 3), (0xFD68, true, 5862, 3), (0xFD69, true, 5865, 3), (0xFD6A, true, 5868, 3),
+    (0xFD6B, true, 5871, 3), (0xFD6C, true, 5874, 3), (0xFD6D, true, 5877, 3), (0xFD6E, true, 5880, 3), (0xFD6F, true, 5883, 3),
+    (0xFD70, true, 5886, 3), (0xFD71, true, 5889, 3), (0xFD72, true, 5892, 3), (0xFD73, true, 5895, 3), (0xFD74, true, 5898, 3),
+    (0xFD75, true, 5901, 3), (0xFD76, true, 5904, 3), (0xFD77, true, 5907, 3), (0xFD78, true, 5910, 3), (0xFD79, true, 5913, 3),
+    (0xFD7A, true, 5916, 3), (0xFD7B, true, 5919, 3), (0xFD7C, true, 5922, 3), (0xFD7D, true, 5925, 3), (0xFD7E, true, 5928, 3),
+    (0xFD7F, true, 5931, 3), (0xFD80, true, 5934, 3), (0xFD81, true, 5937, 3), (0xFD82, true, 5940, 3), (0xFD83, true, 5943, 3),
+    (0xFD84, true, 5946, 3), (0xFD85, true, 5949, 3), (0xFD86, true, 5952, 3), (0xFD87, true, 5955, 3), (0xFD88, true, 5958, 3),
+    (0xFD89, true, 5961, 3), (0xFD8A, true, 5964, 3), (0xFD8B, true, 5967, 3), (0xFD8C, true, 5970, 3), (0xFD8D, true, 5973, 3),
+    (0xFD8E, true, 5976, 3), (0xFD8F, true, 5979, 3), (0xFD92, true, 5982, 3), (0xFD93, true, 5985, 3), (0xFD94, true, 5988, 3),
+    (0xFD95, true, 5991, 3), (0xFD96, true, 5994, 3), (0xFD97, true, 5997, 3), (0xFD98, true, 6000, 3), (0xFD99, true, 6003, 3),
+    (0xFD9A, true, 6006, 3), (0xFD9B, true, 6009, 3), (0xFD9C, true, 6012, 3), (0xFD9D, true, 6015, 3), (0xFD9E, true, 6018, 3),
+    (0xFD9F, true, 6021, 3), (0xFDA0, true, 6024, 3), (0xFDA1, true, 6027, 3), (0xFDA2, true, 6030, 3), (0xFDA3, true, 6033, 3),
+    (0xFDA4, true, 6036, 3), (0xFDA5, true, 6039, 3), (0xFDA6, true, 6042, 3), (0xFDA7, true, 6045, 3), (0xFDA8, true, 6048, 3),
+    (0xFDA9, true, 6051, 3), (0xFDAA, true, 6054, 3), (0xFDAB, true, 6057, 3), (0xFDAC, true, 6060, 3), (0xFDAD, true, 6063, 3),
+    (0xFDAE, true, 6066, 3), (0xFDAF, true, 6069, 3), (0xFDB0, true, 6072, 3), (0xFDB1, true, 6075, 3), (0xFDB2, true, 6078, 3),
+    (0xFDB3, true, 6081, 3), (0xFDB4, true, 6084, 3), (0xFDB5, true, 6087, 3), (0xFDB6, true, 6090, 3), (0xFDB7, true, 6093, 3),
+    (0xFDB8, true, 6096, 3), (0xFDB9, true, 6099, 3), (0xFDBA, true, 6102, 3), (0xFDBB, true, 6105, 3), (0xFDBC, true, 6108, 3),
+    (0xFDBD, true, 6111, 3), (0xFDBE, true, 6114, 3), (0xFDBF, true, 6117, 3), (0xFDC0, true, 6120, 3), (0xFDC1, true, 6123, 3),
+    (0xFDC2, true, 6126, 3), (0xFDC3, true, 6129, 3), (0xFDC4, true, 6132, 3), (0xFDC5, true, 6135, 3), (0xFDC6, true, 6138, 3),
+    (0xFDC7, true, 6141, 3), (0xFDF0, true, 6144, 3), (0xFDF1, true, 6147, 3), (0xFDF2, true, 6150, 4), (0xFDF3, true, 6154, 4),
+    (0xFDF4, true, 6158, 4), (0xFDF5, true, 6162, 4), (0xFDF6, true, 6166, 4), (0xFDF7, true, 6170, 4), (0xFDF8, true, 6174, 4),
+    (0xFDF9, true, 6178, 3), (0xFDFA, true, 6181, 18), (0xFDFB, true, 6199, 8), (0xFDFC, true, 6207, 4), (0xFE10, true, 6211, 1),
+    (0xFE11, true, 6212, 1), (0xFE12, true, 6213, 1), (0xFE13, true, 6214, 1), (0xFE14, true, 6215, 1), (0xFE15, true, 6216, 1),
+    (0xFE16, true, 6217, 1), (0xFE17, true, 6218, 1), (0xFE18, true, 6219, 1), (0xFE19, true, 6220, 3), (0xFE30, true, 6223, 2),
+    (0xFE31, true, 6225, 1), (0xFE32, true, 6226, 1), (0xFE33, true, 6227, 1), (0xFE34, true, 6228, 1), (0xFE35, true, 6229, 1),
+    (0xFE36, true, 6230, 1), (0xFE37, true, 6231, 1), (0xFE38, true, 6232, 1), (0xFE39, true, 6233, 1), (0xFE3A, true, 6234, 1),
+    (0xFE3B, true, 6235, 1), (0xFE3C, true, 6236, 1), (0xFE3D, true, 6237, 1), (0xFE3E, true, 6238, 1), (0xFE3F, true, 6239, 1),
+    (0xFE40, true, 6240, 1), (0xFE41, true, 6241, 1), (0xFE42, true, 6242, 1), (0xFE43, true, 6243, 1), (0xFE44, true, 6244, 1),
+    (0xFE47, true, 6245, 1), (0xFE48, true, 6246, 1), (0xFE49, true, 6247, 2), (0xFE4A, true, 6249, 2), (0xFE4B, true, 6251, 2),
+    (0xFE4C, true, 6253, 2), (0xFE4D, true, 6255, 1), (0xFE4E, true, 6256, 1), (0xFE4F, true, 6257, 1), (0xFE50, true, 6258, 1),
+    (0xFE51, true, 6259, 1), (0xFE52, true, 6260, 1), (0xFE54, true, 6261, 1), (0xFE55, true, 6262, 1), (0xFE56, true, 6263, 1),
+    (0xFE57, true, 6264, 1), (0xFE58, true, 6265, 1), (0xFE59, true, 6266, 1), (0xFE5A, true, 6267, 1), (0xFE5B, true, 6268, 1),
+    (0xFE5C, true, 6269, 1), (0xFE5D, true, 6270, 1), (0xFE5E, true, 6271, 1), (0xFE5F, true, 6272, 1), (0xFE60, true, 6273, 1),
+    (0xFE61, true, 6274, 1), (0xFE62, true, 6275, 1), (0xFE63, true, 6276, 1), (0xFE64, true, 6277, 1), (0xFE65, true, 6278, 1),
+    (0xFE66, true, 6279, 1), (0xFE68, true, 6280, 1), (0xFE69, true, 6281, 1), (0xFE6A, true, 6282, 1), (0xFE6B, true, 6283, 1),
+    (0xFE70, true, 6284, 2), (0xFE71, true, 6286, 2), (0xFE72, true, 6288, 2), (0xFE74, true, 6290, 2), (0xFE76, true, 6292, 2),
+    (0xFE77, true, 6294, 2), (0xFE78, true, 6296, 2), (0xFE79, true, 6298, 2), (0xFE7A, true, 6300, 2), (0xFE7B, true, 6302, 2),
+    (0xFE7C, true, 6304, 2), (0xFE7D, true, 6306, 2), (0xFE7E, true, 6308, 2), (0xFE7F, true, 6310, 2), (0xFE80, true, 6312, 1),
+    (0xFE81, true, 6313, 2), (0xFE82, true, 6315, 2), (0xFE83, true, 6317, 2), (0xFE84, true, 6319, 2), (0xFE85, true, 6321, 2),
+    (0xFE86, true, 6323, 2), (0xFE87, true, 6325, 2), (0xFE88, true, 6327, 2), (0xFE89, true, 6329, 2), (0xFE8A, true, 6331, 2),
+    (0xFE8B, true, 6333, 2), (0xFE8C, true, 6335, 2), (0xFE8D, true, 6337, 1), (0xFE8E, true, 6338, 1), (0xFE8F, true, 6339, 1),
+    (0xFE90, true, 6340, 1), (0xFE91, true, 6341, 1), (0xFE92, true, 6342, 1), (0xFE93, true, 6343, 1), (0xFE94, true, 6344, 1),
+    (0xFE95, true, 6345, 1), (0xFE96, true, 6346, 1), (0xFE97, true, 6347, 1), (0xFE98, true, 6348, 1), (0xFE99, true, 6349, 1),
+    (0xFE9A, true, 6350, 1), (0xFE9B, true, 6351, 1), (0xFE9C, true, 6352, 1), (0xFE9D, true, 6353, 1), (0xFE9E, true, 6354, 1),
+    (0xFE9F, true, 6355, 1), (0xFEA0, true, 6356, 1), (0xFEA1, true, 6357, 1), (0xFEA2, true, 6358, 1), (0xFEA3, true, 6359, 1),
+    (0xFEA4, true, 6360, 1), (0xFEA5, true, 6361, 1), (0xFEA6, true, 6362, 1), (0xFEA7, true, 6363, 1), (0xFEA8, true, 6364, 1),
+    (0xFEA9, true, 6365, 1), (0xFEAA, true, 6366, 1), (0xFEAB, true, 6367, 1), (0xFEAC, true, 6368, 1), (0xFEAD, true, 6369, 1),
+    (0xFEAE, true, 6370, 1), (0xFEAF, true, 6371, 1), (0xFEB0, true, 6372, 1), (0xFEB1, true, 6373, 1), (0xFEB2, true, 6374, 1),
+    (0xFEB3, true, 6375, 1), (0xFEB4, true, 6376, 1), (0xFEB5, true, 6377, 1), (0xFEB6, true, 6378, 1), (0xFEB7, true, 6379, 1),
+    (0xFEB8, true, 6380, 1), (0xFEB9, true, 6381, 1), (0xFEBA, true, 6382, 1), (0xFEBB, true, 6383, 1), (0xFEBC, true, 6384, 1),
+    (0xFEBD, true, 6385, 1), (0xFEBE, true, 6386, 1), (0xFEBF, true, 6387, 1), (0xFEC0, true, 6388, 1), (0xFEC1, true, 6389, 1),
+    (0xFEC2, true, 6390, 1), (0xFEC3, true, 6391, 1), (0xFEC4, true, 6392, 1), (0xFEC5, true, 6393, 1), (0xFEC6, true, 6394, 1),
+    (0xFEC7, true, 6395, 1), (0xFEC8, true, 6396, 1), (0xFEC9, true, 6397, 1), (0xFECA, true, 6398, 1), (0xFECB, true, 6399, 1),
+    (0xFECC, true, 6400, 1), (0xFECD, true, 6401, 1), (0xFECE, true, 6402, 1), (0xFECF, true, 6403, 1), (0xFED0, true, 6404, 1),
+    (0xFED1, true, 6405, 1), (0xFED2, true, 6406, 1), (0xFED3, true, 6407, 1), (0xFED4, true, 6408, 1), (0xFED5, true, 6409, 1),
+    (0xFED6, true, 6410, 1), (0xFED7, true, 6411, 1), (0xFED8, true, 6412, 1), (0xFED9, true, 6413, 1), (0xFEDA, true, 6414, 1),
+    (0xFEDB, true, 6415, 1), (0xFEDC, true, 6416, 1), (0xFEDD, true, 6417, 1), (0xFEDE, true, 6418, 1), (0xFEDF, true, 6419, 1),
+    (0xFEE0, true, 6420, 1), (0xFEE1, true, 6421, 1), (0xFEE2, true, 6422, 1), (0xFEE3, true, 6423, 1), (0xFEE4, true, 6424, 1),
+    (0xFEE5, true, 6425, 1), (0xFEE6, true, 6426, 1), (0xFEE7, true, 6427, 1), (0xFEE8, true, 6428, 1), (0xFEE9, true, 6429, 1),
+    (0xFEEA, true, 6430, 1), (0xFEEB, true, 6431, 1), (0xFEEC, true, 6432, 1), (0xFEED, true, 6433, 1), (0xFEEE, true, 6434, 1),
+    (0xFEEF, true, 6435, 1), (0xFEF0, true, 6436, 1), (0xFEF1, true, 6437, 1), (0xFEF2, true, 6438, 1), (0xFEF3, true, 6439, 1),
+    (0xFEF4, true, 6440, 1), (0xFEF5, true, 6441, 3), (0xFEF6, true, 6444, 3), (0xFEF7, true, 6447, 3), (0xFEF8, true, 6450, 3),
+    (0xFEF9, true, 6453, 3), (0xFEFA, true, 6456, 3), (0xFEFB, true, 6459, 2), (0xFEFC, true, 6461, 2), (0xFF01, true, 6463, 1),
+    (0xFF02, true, 6464, 1), (0xFF03, true, 6465, 1), (0xFF04, true, 6466, 1), (0xFF05, true, 6467, 1), (0xFF06, true, 6468, 1),
+    (0xFF07, true, 6469, 1), (0xFF08, true, 6470, 1), (0xFF09, true, 6471, 1), (0xFF0A, true, 6472, 1), (0xFF0B, true, 6473, 1),
+    (0xFF0C, true, 6474, 1), (0xFF0D, true, 6475, 1), (0xFF0E, true, 6476, 1), (0xFF0F, true, 6477, 1), (0xFF10, true, 6478, 1),
+    (0xFF11, true, 6479, 1), (0xFF12, true, 6480, 1), (0xFF13, true, 6481, 1), (0xFF14, true, 6482, 1), (0xFF15, true, 6483, 1),
+    (0xFF16, true, 6484, 1), (0xFF17, true, 6485, 1), (0xFF18, true, 6486, 1), (0xFF19, true, 6487, 1), (0xFF1A, true, 6488, 1),
+    (0xFF1B, true, 6489, 1), (0xFF1C, true, 6490, 1), (0xFF1D, true, 6491, 1), (0xFF1E, true, 6492, 1), (0xFF1F, true, 6493, 1),
+    (0xFF20, true, 6494, 1), (0xFF21, true, 6495, 1), (0xFF22, true, 6496, 1), (0xFF23, true, 6497, 1), (0xFF24, true, 6498, 1),
+    (0xFF25, true, 6499, 1), (0xFF26, true, 6500, 1), (0xFF27, true, 6501, 1), (0xFF28, true, 6502, 1), (0xFF29, true, 6503, 1),
+    (0xFF2A, true, 6504, 1), (0xFF2B, true, 6505, 1), (0xFF2C, true, 6506, 1), (0xFF2D, true, 6507, 1), (0xFF2E, true, 6508, 1),
+    (0xFF2F, true, 6509, 1), (0xFF30, true, 6510, 1), (0xFF31, true, 6511, 1), (0xFF32, true, 6512, 1), (0xFF33, true, 6513, 1),
+    (0xFF34, true, 6514, 1), (0xFF35, true, 6515, 1), (0xFF36, true, 6516, 1), (0xFF37, true, 6517, 1), (0xFF38, true, 6518, 1),
+    (0xFF39, true, 6519, 1), (0xFF3A, true, 6520, 1), (0xFF3B, true, 6521, 1), (0xFF3C, true, 6522, 1), (0xFF3D, true, 6523, 1),
+    (0xFF3E, true, 6524, 1), (0xFF3F, true, 6525, 1), (0xFF40, true, 6526, 1), (0xFF41, true, 6527, 1), (0xFF42, true, 6528, 1),
+    (0xFF43, true, 6529, 1), (0xFF44, true, 6530, 1), (0xFF45, true, 6531, 1), (0xFF46, true, 6532, 1), (0xFF47, true, 6533, 1),
+    (0xFF48, true, 6534, 1), (0xFF49, true, 6535, 1), (0xFF4A, true, 6536, 1), (0xFF4B, true, 6537, 1), (0xFF4C, true, 6538, 1),
+    (0xFF4D, true, 6539, 1), (0xFF4E, true, 6540, 1), (0xFF4F, true, 6541, 1), (0xFF50, true, 6542, 1), (0xFF51, true, 6543, 1),
+    (0xFF52, true, 6544, 1), (0xFF53, true, 6545, 1), (0xFF54, true, 6546, 1), (0xFF55, true, 6547, 1), (0xFF56, true, 6548, 1),
+    (0xFF57, true, 6549, 1), (0xFF58, true, 6550, 1), (0xFF59, true, 6551, 1), (0xFF5A, true, 6552, 1), (0xFF5B, true, 6553, 1),
+    (0xFF5C, true, 6554, 1), (0xFF5D, true, 6555, 1), (0xFF5E, true, 6556, 1), (0xFF5F, true, 6557, 1), (0xFF60, true, 6558, 1),
+    (0xFF61, true, 6559, 1), (0xFF62, true, 6560, 1), (0xFF63, true, 6561, 1), (0xFF64, true, 6562, 1), (0xFF65, true, 6563, 1),
+    (0xFF66, true, 6564, 1), (0xFF67, true, 6565, 1), (0xFF68, true, 6566, 1), (0xFF69, true, 6567, 1), (0xFF6A, true, 6568, 1),
+    (0xFF6B, true, 6569, 1), (0xFF6C, true, 6570, 1), (0xFF6D, true, 6571, 1), (0xFF6E, true, 6572, 1), (0xFF6F, true, 6573, 1),
+    (0xFF70, true, 6574, 1), (0xFF71, true, 6575, 1), (0xFF72, true, 6576, 1), (0xFF73, true, 6577, 1), (0xFF74, true, 6578, 1),
+    (0xFF75, true, 6579, 1), (0xFF76, true, 6580, 1), (0xFF77, true, 6581, 1), (0xFF78, true, 6582, 1), (0xFF79, true, 6583, 1),
+    (0xFF7A, true, 6584, 1), (0xFF7B, true, 6585, 1), (0xFF7C, true, 6586, 1), (0xFF7D, true, 6587, 1), (0xFF7E, true, 6588, 1),
+    (0xFF7F, true, 6589, 1), (0xFF80, true, 6590, 1), (0xFF81, true, 6591, 1), (0xFF82, true, 6592, 1), (0xFF83, true, 6593, 1),
+    (0xFF84, true, 6594, 1), (0xFF85, true, 6595, 1), (0xFF86, true, 6596, 1), (0xFF87, true, 6597, 1), (0xFF88, true, 6598, 1),
+    (0xFF89, true, 6599, 1), (0xFF8A, true, 6600, 1), (0xFF8B, true, 6601, 1), (0xFF8C, true, 6602, 1), (0xFF8D, true, 6603, 1),
+    (0xFF8E, true, 6604, 1), (0xFF8F, true, 6605, 1), (0xFF90, true, 6606, 1), (0xFF91, true, 6607, 1), (0xFF92, true, 6608, 1),
+    (0xFF93, true, 6609, 1), (0xFF94, true, 6610, 1), (0xFF95, true, 6611, 1), (0xFF96, true, 6612, 1), (0xFF97, true, 6613, 1),
+    (0xFF98, true, 6614, 1), (0xFF99, true, 6615, 1), (0xFF9A, true, 6616, 1), (0xFF9B, true, 6617, 1), (0xFF9C, true, 6618, 1),
+    (0xFF9D, true, 6619, 1), (0xFF9E, true, 6620, 1), (0xFF9F, true, 6621, 1), (0xFFA0, true, 6622, 1), (0xFFA1, true, 6623, 1),
+    (0xFFA2, true, 6624, 1), (0xFFA3, true, 6625, 1), (0xFFA4, true, 6626, 1), (0xFFA5, true, 6627, 1), (0xFFA6, true, 6628, 1),
+    (0xFFA7, true, 6629, 1), (0xFFA8, true, 6630, 1), (0xFFA9, true, 6631, 1), (0xFFAA, true, 6632, 1), (0xFFAB, true, 6633, 1),
+    (0xFFAC, true, 6634, 1), (0xFFAD, true, 6635, 1), (0xFFAE, true, 6636, 1), (0xFFAF, true, 6637, 1), (0xFFB0, true, 6638, 1),
+    (0xFFB1, true, 6639, 1), (0xFFB2, true, 6640, 1), (0xFFB3, true, 6641, 1), (0xFFB4, true, 6642, 1), (0xFFB5, true, 6643, 1),
+    (0xFFB6, true, 6644, 1), (0xFFB7, true, 6645, 1), (0xFFB8, true, 6646, 1), (0xFFB9, true, 6647, 1), (0xFFBA, true, 6648, 1),
+    (0xFFBB, true, 6649, 1), (0xFFBC, true, 6650, 1), (0xFFBD, true, 6651, 1), (0xFFBE, true, 6652, 1), (0xFFC2, true, 6653, 1),
+    (0xFFC3, true, 6654, 1), (0xFFC4, true, 6655, 1), (0xFFC5, true, 6656, 1), (0xFFC6, true, 6657, 1), (0xFFC7, true, 6658, 1),
+    (0xFFCA, true, 6659, 1), (0xFFCB, true, 6660, 1), (0xFFCC, true, 6661, 1), (0xFFCD, true, 6662, 1), (0xFFCE, true, 6663, 1),
+    (0xFFCF, true, 6664, 1), (0xFFD2, true, 6665, 1), (0xFFD3, true, 6666, 1), (0xFFD4, true, 6667, 1), (0xFFD5, true, 6668, 1),
+    (0xFFD6, true, 6669, 1), (0xFFD7, true, 6670, 1), (0xFFDA, true, 6671, 1), (0xFFDB, true, 6672, 1), (0xFFDC, true, 6673, 1),
+    (0xFFE0, true, 6674, 1), (0xFFE1, true, 6675, 1), (0xFFE2, true, 6676, 1), (0xFFE3, true, 6677, 2), (0xFFE4, true, 6679, 1),
+    (0xFFE5, true, 6680, 1), (0xFFE6, true, 6681, 1), (0xFFE8, true, 6682, 1), (0xFFE9, true, 6683, 1), (0xFFEA, true, 6684, 1),
+    (0xFFEB, true, 6685, 1), (0xFFEC, true, 6686, 1), (0xFFED, true, 6687, 1), (0xFFEE, true, 6688, 1), (0x105C9, false, 6689, 2),
+    (0x105E4, false, 6691, 2), (0x10781, true, 6693, 1), (0x10782, true, 6694, 1), (0x10783, true, 6695, 1), (0x10784, true, 6696, 1),
+    (0x10785, true, 6697, 1), (0x10787, true, 6698, 1), (0x10788, true, 6699, 1), (0x10789, true, 6700, 1), (0x1078A, true, 6701, 1),
+    (0x1078B, true, 6702, 1), (0x1078C, true, 6703, 1), (0x1078D, true, 6704, 1), (0x1078E, true, 6705, 1), (0x1078F, true, 6706, 1),
+    (0x10790, true, 6707, 1), (0x10791, true, 6708, 1), (0x10792, true, 6709, 1), (0x10793, true, 6710, 1), (0x10794, true, 6711, 1),
+    (0x10795, true, 6712, 1), (0x10796, true, 6713, 1), (0x10797, true, 6714, 1), (0x10798, true, 6715, 1), (0x10799, true, 6716, 1),
+    (0x1079A, true, 6717, 1), (0x1079B, true, 6718, 1), (0x1079C, true, 6719, 1), (0x1079D, true, 6720, 1), (0x1079E, true, 6721, 1),
+    (0x1079F, true, 6722, 1), (0x107A0, true, 6723, 1), (0x107A1, true, 6724, 1), (0x107A2, true, 6725, 1), (0x107A3, true, 6726, 1),
+    (0x107A4, true, 6727, 1), (0x107A5, true, 6728, 1), (0x107A6, true, 6729, 1), (0x107A7, true, 6730, 1), (0x107A8, true, 6731, 1),
+    (0x107A9, true, 6732, 1), (0x107AA, true, 6733, 1), (0x107AB, true, 6734, 1), (0x107AC, true, 6735, 1), (0x107AD, true, 6736, 1),
+    (0x107AE, true, 6737, 1), (0x107AF, true, 6738, 1), (0x107B0, true, 6739, 1), (0x107B2, true, 6740, 1), (0x107B3, true, 6741, 1),
+    (0x107B4, true, 6742, 1), (0x107B5, true, 6743, 1), (0x107B6, true, 6744, 1), (0x107B7, true, 6745, 1), (0x107B8, true, 6746, 1),
+    (0x107B9, true, 6747, 1), (0x107BA, true, 6748, 1), (0x1109A, false, 6749, 2), (0x1109C, false, 6751, 2), (0x110AB, false, 6753, 2),
+    (0x1112E, false, 6755, 2), (0x1112F, false, 6757, 2), (0x1134B, false, 6759, 2), (0x1134C, false, 6761, 2), (0x11383, false, 6763, 2),
+    (0x11385, false, 6765, 2), (0x1138E, false, 6767, 2), (0x11391, false, 6769, 2), (0x113C5, false, 6771, 2), (0x113C7, false, 6773, 2),
+    (0x113C8, false, 6775, 2), (0x114BB, false, 6777, 2), (0x114BC, false, 6779, 2), (0x114BE, false, 6781, 2), (0x115BA, false, 6783, 2),
+    (0x115BB, false, 6785, 2), (0x11938, false, 6787, 2), (0x16121, false, 6789, 2), (0x16122, false, 6791, 2), (0x16123, false, 6793, 2),
+    (0x16124, false, 6795, 2), (0x16125, false, 6797, 2), (0x16126, false, 6799, 2), (0x16127, false, 6801, 2), (0x16128, false, 6803, 2),
+    (0x16D68, false, 6805, 2), (0x16D69, false, 6807, 2), (0x16D6A, false, 6809, 2), (0x1CCD6, true, 6811, 1), (0x1CCD7, true, 6812, 1),
+    (0x1CCD8, true, 6813, 1), (0x1CCD9, true, 6814, 1), (0x1CCDA, true, 6815, 1), (0x1CCDB, true, 6816, 1), (0x1CCDC, true, 6817, 1),
+    (0x1CCDD, true, 6818, 1), (0x1CCDE, true, 6819, 1), (0x1CCDF, true, 6820, 1), (0x1CCE0, true, 6821, 1), (0x1CCE1, true, 6822, 1),
+    (0x1CCE2, true, 6823, 1), (0x1CCE3, true, 6824, 1), (0x1CCE4, true, 6825, 1), (0x1CCE5, true, 6826, 1), (0x1CCE6, true, 6827, 1),
+    (0x1CCE7, true, 6828, 1), (0x1CCE8, true, 6829, 1), (0x1CCE9, true, 6830, 1), (0x1CCEA, true, 6831, 1), (0x1CCEB, true, 6832, 1),
+    (0x1CCEC, true, 6833, 1), (0x1CCED, true, 6834, 1), (0x1CCEE, true, 6835, 1), (0x1CCEF, true, 6836, 1), (0x1CCF0, true, 6837, 1),
+    (0x1CCF1, true, 6838, 1), (0x1CCF2, true, 6839, 1), (0x1CCF3, true, 6840, 1), (0x1CCF4, true, 6841, 1), (0x1CCF5, true, 6842, 1),
+    (0x1CCF6, true, 6843, 1), (0x1CCF7, true, 6844, 1), (0x1CCF8, true, 6845, 1), (0x1CCF9, true, 6846, 1), (0x1D15E, false, 6847, 2),
+    (0x1D15F, false, 6849, 2), (0x1D160, false, 6851, 2), (0x1D161, false, 6853, 2), (0x1D162, false, 6855, 2), (0x1D163, false, 6857, 2),
+    (0x1D164, false, 6859, 2), (0x1D1BB, false, 6861, 2), (0x1D1BC, false, 6863, 2), (0x1D1BD, false, 6865, 2), (0x1D1BE, false, 6867, 2),
+    (0x1D1BF, false, 6869, 2), (0x1D1C0, false, 6871, 2), (0x1D400, true, 6873, 1), (0x1D401, true, 6874, 1), (0x1D402, true, 6875, 1),
+    (0x1D403, true, 6876, 1), (0x1D404, true, 6877, 1), (0x1D405, true, 6878, 1), (0x1D406, true, 6879, 1), (0x1D407, true, 6880, 1),
+    (0x1D408, true, 6881, 1), (0x1D409, true, 6882, 1), (0x1D40A, true, 6883, 1), (0x1D40B, true, 6884, 1), (0x1D40C, true, 6885, 1),
+    (0x1D40D, true, 6886, 1), (0x1D40E, true, 6887, 1), (0x1D40F, true, 6888, 1), (0x1D410, true, 6889, 1), (0x1D411, true, 6890, 1),
+    (0x1D412, true, 6891, 1), (0x1D413, true, 6892, 1), (0x1D414, true, 6893, 1), (0x1D415, true, 6894, 1), (0x1D416, true, 6895, 1),
+    (0x1D417, true, 6896, 1), (0x1D418, true, 6897, 1), (0x1D419, true, 6898, 1), (0x1D41A, true, 6899, 1), (0x1D41B, true, 6900, 1),
+    (0x1D41C, true, 6901, 1), (0x1D41D, true, 6902, 1), (0x1D41E, true, 6903, 1), (0x1D41F, true, 6904, 1), (0x1D420, true, 6905, 1),
+    (0x1D421, true, 6906, 1), (0x1D422, true, 6907, 1), (0x1D423, true, 6908, 1), (0x1D424, true, 6909, 1), (0x1D425, true, 6910, 1),
+    (0x1D426, true, 6911, 1), (0x1D427, true, 6912, 1), (0x1D428, true, 6913, 1), (0x1D429, true, 6914, 1), (0x1D42A, true, 6915, 1),
+    (0x1D42B, true, 6916, 1), (0x1D42C, true, 6917, 1), (0x1D42D, true, 6918, 1), (0x1D42E, true, 6919, 1), (0x1D42F, true, 6920, 1),
+    (0x1D430, true, 6921, 1), (0x1D431, true, 6922, 1), (0x1D432, true, 6923, 1), (0x1D433, true, 6924, 1), (0x1D434, true, 6925, 1),
+    (0x1D435, true, 6926, 1), (0x1D436, true, 6927, 1), (0x1D437, true, 6928, 1), (0x1D438, true, 6929, 1), (0x1D439, true, 6930, 1),
+    (0x1D43A, true, 6931, 1), (0x1D43B, true, 6932, 1), (0x1D43C, true, 6933, 1), (0x1D43D, true, 6934, 1), (0x1D43E, true, 6935, 1),
+    (0x1D43F, true, 6936, 1), (0x1D440, true, 6937, 1), (0x1D441, true, 6938, 1), (0x1D442, true, 6939, 1), (0x1D443, true, 6940, 1),
+    (0x1D444, true, 6941, 1), (0x1D445, true, 6942, 1), (0x1D446, true, 6943, 1), (0x1D447, true, 6944, 1), (0x1D448, true, 6945, 1),
+    (0x1D449, true, 6946, 1), (0x1D44A, true, 6947, 1), (0x1D44B, true, 6948, 1), (0x1D44C, true, 6949, 1), (0x1D44D, true, 6950, 1),
+    (0x1D44E, true, 6951, 1), (0x1D44F, true, 6952, 1), (0x1D450, true, 6953, 1), (0x1D451, true, 6954, 1), (0x1D452, true, 6955, 1),
+    (0x1D453, true, 6956, 1), (0x1D454, true, 6957, 1), (0x1D456, true, 6958, 1), (0x1D457, true, 6959, 1), (0x1D458, true, 6960, 1),
+    (0x1D459, true, 6961, 1), (0x1D45A, true, 6962, 1), (0x1D45B, true, 6963, 1), (0x1D45C, true, 6964, 1), (0x1D45D, true, 6965, 1),
+    (0x1D45E, true, 6966, 1), (0x1D45F, true, 6967, 1), (0x1D460, true, 6968, 1), (0x1D461, true, 6969, 1), (0x1D462, true, 6970, 1),
+    (0x1D463, true, 6971, 1), (0x1D464, true, 6972, 1), (0x1D465, true, 6973, 1), (0x1D466, true, 6974, 1), (0x1D467, true, 6975, 1),
+    (0x1D468, true, 6976, 1), (0x1D469, true, 6977, 1), (0x1D46A, true, 6978, 1), (0x1D46B, true, 6979, 1), (0x1D46C, true, 6980, 1),
+    (0x1D46D, true, 6981, 1), (0x1D46E, true, 6982, 1), (0x1D46F, true, 6983, 1), (0x1D470, true, 6984, 1), (0x1D471, true, 6985, 1),
+    (0x1D472, true, 6986, 1), (0x1D473, true, 6987, 1), (0x1D474, true, 6988, 1), (0x1D475, true, 6989, 1), (0x1D476, true, 6990, 1),
+    (0x1D477, true, 6991, 1), (0x1D478, true, 6992, 1), (0x1D479, true, 6993, 1), (0x1D47A, true, 6994, 1), (0x1D47B, true, 6995, 1),
+    (0x1D47C, true, 6996, 1), (0x1D47D, true, 6997, 1), (0x1D47E, true, 6998, 1), (0x1D47F, true, 6999, 1), (0x1D480, true, 7000, 1),
+    (0x1D481, true, 7001, 1), (0x1D482, true, 7002, 1), (0x1D483, true, 7003, 1), (0x1D484, true, 7004, 1), (0x1D485, true, 7005, 1),
+    (0x1D486, true, 7006, 1), (0x1D487, true, 7007, 1), (0x1D488, true, 7008, 1), (0x1D489, true, 7009, 1), (0x1D48A, true, 7010, 1),
+    (0x1D48B, true, 7011, 1), (0x1D48C, true, 7012, 1), (0x1D48D, true, 7013, 1), (0x1D48E, true, 7014, 1), (0x1D48F, true, 7015, 1),
+    (0x1D490, true, 7016, 1), (0x1D491, true, 7017, 1), (0x1D492, true, 7018, 1), (0x1D493, true, 7019, 1), (0x1D494, true, 7020, 1),
+    (0x1D495, true, 7021, 1), (0x1D496, true, 7022, 1), (0x1D497, true, 7023, 1), (0x1D498, true, 7024, 1), (0x1D499, true, 7025, 1),
+    (0x1D49A, true, 7026, 1), (0x1D49B, true, 7027, 1), (0x1D49C, true, 7028, 1), (0x1D49E, true, 7029, 1), (0x1D49F, true, 7030, 1),
+    (0x1D4A2, true, 7031, 1), (0x1D4A5, true, 7032, 1), (0x1D4A6, true, 7033, 1), (0x1D4A9, true, 7034, 1), (0x1D4AA, true, 7035, 1),
+    (0x1D4AB, true, 7036, 1), (0x1D4AC, true, 7037, 1), (0x1D4AE, true, 7038, 1), (0x1D4AF, true, 7039, 1), (0x1D4B0, true, 7040, 1),
+    (0x1D4B1, true, 7041, 1), (0x1D4B2, true, 7042, 1), (0x1D4B3, true, 7043, 1), (0x1D4B4, true, 7044, 1), (0x1D4B5, true, 7045, 1),
+    (0x1D4B6, true, 7046, 1), (0x1D4B7, true, 7047, 1), (0x1D4B8, true, 7048, 1), (0x1D4B9, true, 7049, 1), (0x1D4BB, true, 7050, 1),
+    (0x1D4BD, true, 7051, 1), (0x1D4BE, true, 7052, 1), (0x1D4BF, true, 7053, 1), (0x1D4C0, true, 7054, 1), (0x1D4C1, true, 7055, 1),
+    (0x1D4C2, true, 7056, 1), (0x1D4C3, true, 7057, 1), (0x1D4C5, true, 7058, 1), (0x1D4C6, true, 7059, 1), (0x1D4C7, true, 7060, 1),
+    (0x1D4C8, true, 7061, 1), (0x1D4C9, true, 7062, 1), (0x1D4CA, true, 7063, 1), (0x1D4CB, true, 7064, 1), (0x1D4CC, true, 7065, 1),
+    (0x1D4CD, true, 7066, 1), (0x1D4CE, true, 7067, 1), (0x1D4CF, true, 7068, 1), (0x1D4D0, true, 7069, 1), (0x1D4D1, true, 7070, 1),
+    (0x1D4D2, true, 7071, 1), (0x1D4D3, true, 7072, 1), (0x1D4D4, true, 7073, 1), (0x1D4D5, true, 7074, 1), (0x1D4D6, true, 7075, 1),
+    (0x1D4D7, true, 7076, 1), (0x1D4D8, true, 7077, 1), (0x1D4D9, true, 7078, 1), (0x1D4DA, true, 7079, 1), (0x1D4DB, true, 7080, 1),
+    (0x1D4DC, true, 7081, 1), (0x1D4DD, true, 7082, 1), (0x1D4DE, true, 7083, 1), (0x1D4DF, true, 7084, 1), (0x1D4E0, true, 7085, 1),
+    (0x1D4E1, true, 7086, 1), (0x1D4E2, true, 7087, 1), (0x1D4E3, true, 7088, 1), (0x1D4E4, true, 7089, 1), (0x1D4E5, true, 7090, 1),
+    (0x1D4E6, true, 7091, 1), (0x1D4E7, true, 7092, 1), (0x1D4E8, true, 7093, 1), (0x1D4E9, true, 7094, 1), (0x1D4EA, true, 7095, 1),
+    (0x1D4EB, true, 7096, 1), (0x1D4EC, true, 7097, 1), (0x1D4ED, true, 7098, 1), (0x1D4EE, true, 7099, 1), (0x1D4EF, true, 7100, 1),
+    (0x1D4F0, true, 7101, 1), (0x1D4F1, true, 7102, 1), (0x1D4F2, true, 7103, 1), (0x1D4F3, true, 7104, 1), (0x1D4F4, true, 7105, 1),
+    (0x1D4F5, true, 7106, 1), (0x1D4F6, true, 7107, 1), (0x1D4F7, true, 7108, 1), (0x1D4F8, true, 7109, 1), (0x1D4F9, true, 7110, 1),
+    (0x1D4FA, true, 7111, 1), (0x1D4FB, true, 7112, 1), (0x1D4FC, true, 7113, 1), (0x1D4FD, true, 7114, 1), (0x1D4FE, true, 7115, 1),
+    (0x1D4FF, true, 7116, 1), (0x1D500, true, 7117, 1), (0x1D501, true, 7118, 1), (0x1D502, true, 7119, 1), (0x1D503, true, 7120, 1),
+    (0x1D504, true, 7121, 1), (0x1D505, true, 7122, 1), (0x1D507, true, 7123, 1), (0x1D508, true, 7124, 1), (0x1D509, true, 7125, 1),
+    (0x1D50A, true, 7126, 1), (0x1D50D, true, 7127, 1), (0x1D50E, true, 7128, 1), (0x1D50F, true, 7129, 1), (0x1D510, true, 7130, 1),
+    (0x1D511, true, 7131, 1), (0x1D512, true, 7132, 1), (0x1D513, true, 7133, 1), (0x1D514, true, 7134, 1), (0x1D516, true, 7135, 1),
+    (0x1D517, true, 7136, 1), (0x1D518, true, 7137, 1), (0x1D519, true, 7138, 1), (0x1D51A, true, 7139, 1), (0x1D51B, true, 7140, 1),
+    (0x1D51C, true, 7141, 1), (0x1D51E, true, 7142, 1), (0x1D51F, true, 7143, 1), (0x1D520, true, 7144, 1), (0x1D521, true, 7145, 1),
+    (0x1D522, true, 7146, 1), (0x1D523, true, 7147, 1), (0x1D524, true, 7148, 1), (0x1D525, true, 7149, 1), (0x1D526, true, 7150, 1),
+    (0x1D527, true, 7151, 1), (0x1D528, true, 7152, 1), (0x1D529, true, 7153, 1), (0x1D52A, true, 7154, 1), (0x1D52B, true, 7155, 1),
+    (0x1D52C, true, 7156, 1), (0x1D52D, true, 7157, 1), (0x1D52E, true, 7158, 1), (0x1D52F, true, 7159, 1), (0x1D530, true, 7160, 1),
+    (0x1D531, true, 7161, 1), (0x1D532, true, 7162, 1), (0x1D533, true, 7163, 1), (0x1D534, true, 7164, 1), (0x1D535, true, 7165, 1),
+    (0x1D536, true, 7166, 1), (0x1D537, true, 7167, 1), (0x1D538, true, 7168, 1), (0x1D539, true, 7169, 1), (0x1D53B, true, 7170, 1),
+    (0x1D53C, true, 7171, 1), (0x1D53D, true, 7172, 1), (0x1D53E, true, 7173, 1), (0x1D540, true, 7174, 1), (0x1D541, true, 7175, 1),
+    (0x1D542, true, 7176, 1), (0x1D543, true, 7177, 1), (0x1D544, true, 7178, 1), (0x1D546, true, 7179, 1), (0x1D54A, true, 7180, 1),
+    (0x1D54B, true, 7181, 1), (0x1D54C, true, 7182, 1), (0x1D54D, true, 7183, 1), (0x1D54E, true, 7184, 1), (0x1D54F, true, 7185, 1),
+    (0x1D550, true, 7186, 1), (0x1D552, true, 7187, 1), (0x1D553, true, 7188, 1), (0x1D554, true, 7189, 1), (0x1D555, true, 7190, 1),
+    (0x1D556, true, 7191, 1), (0x1D557, true, 7192, 1), (0x1D558, true, 7193, 1), (0x1D559, true, 7194, 1), (0x1D55A, true, 7195, 1),
+    (0x1D55B, true, 7196, 1), (0x1D55C, true, 7197, 1), (0x1D55D, true, 7198, 1), (0x1D55E, true, 7199, 1), (0x1D55F, true, 7200, 1),
+    (0x1D560, true, 7201, 1), (0x1D561, true, 7202, 1), (0x1D562, true, 7203, 1), (0x1D563, true, 7204, 1), (0x1D564, true, 7205, 1),
+    (0x1D565, true, 7206, 1), (0x1D566, true, 7207, 1), (0x1D567, true, 7208, 1), (0x1D568, true, 7209, 1), (0x1D569, true, 7210, 1),
+    (0x1D56A, true, 7211, 1), (0x1D56B, true, 7212, 1), (0x1D56C, true, 7213, 1), (0x1D56D, true, 7214, 1), (0x1D56E, true, 7215, 1),
+    (0x1D56F, true, 7216, 1), (0x1D570, true, 7217, 1), (0x1D571, true, 7218, 1), (0x1D572, true, 7219, 1), (0x1D573, true, 7220, 1),
+    (0x1D574, true, 7221, 1), (0x1D575, true, 7222, 1), (0x1D576, true, 7223, 1), (0x1D577, true, 7224, 1), (0x1D578, true, 7225, 1),
+    (0x1D579, true, 7226, 1), (0x1D57A, true, 7227, 1), (0x1D57B, true, 7228, 1), (0x1D57C, true, 7229, 1), (0x1D57D, true, 7230, 1),
+    (0x1D57E, true, 7231, 1), (0x1D57F, true, 7232, 1), (0x1D580, true, 7233, 1), (0x1D581, true, 7234, 1), (0x1D582, true, 7235, 1),
+    (0x1D583, true, 7236, 1), (0x1D584, true, 7237, 1), (0x1D585, true, 7238, 1), (0x1D586, true, 7239, 1), (0x1D587, true, 7240, 1),
+    (0x1D588, true, 7241, 1), (0x1D589, true, 7242, 1), (0x1D58A, true, 7243, 1), (0x1D58B, true, 7244, 1), (0x1D58C, true, 7245, 1),
+    (0x1D58D, true, 7246, 1), (0x1D58E, true, 7247, 1), (0x1D58F, true, 7248, 1), (0x1D590, true, 7249, 1), (0x1D591, true, 7250, 1),
+    (0x1D592, true, 7251, 1), (0x1D593, true, 7252, 1), (0x1D594, true, 7253, 1), (0x1D595, true, 7254, 1), (0x1D596, true, 7255, 1),
+    (0x1D597, true, 7256, 1), (0x1D598, true, 7257, 1), (0x1D599, true, 7258, 1), (0x1D59A, true, 7259, 1), (0x1D59B, true, 7260, 1),
+    (0x1D59C, true, 7261, 1), (0x1D59D, true, 7262, 1), (0x1D59E, true, 7263, 1), (0x1D59F, true, 7264, 1), (0x1D5A0, true, 7265, 1),
+    (0x1D5A1, true, 7266, 1), (0x1D5A2, true, 7267, 1), (0x1D5A3, true, 7268, 1), (0x1D5A4, true, 7269, 1), (0x1D5A5, true, 7270, 1),
+    (0x1D5A6, true, 7271, 1), (0x1D5A7, true, 7272, 1), (0x1D5A8, true, 7273, 1), (0x1D5A9, true, 7274, 1), (0x1D5AA, true, 7275, 1),
+    (0x1D5AB, true, 7276, 1), (0x1D5AC, true, 7277, 1), (0x1D5AD, true, 7278, 1), (0x1D5AE, true, 7279, 1), (0x1D5AF, true, 7280, 1),
+    (0x1D5B0, true, 7281, 1), (0x1D5B1, true, 7282, 1), (0x1D5B2, true, 7283, 1), (0x1D5B3, true, 7284, 1), (0x1D5B4, true, 7285, 1),
+    (0x1D5B5, true, 7286, 1), (0x1D5B6, true, 7287, 1), (0x1D5B7, true, 7288, 1), (0x1D5B8, true, 7289, 1), (0x1D5B9, true, 7290, 1),
+    (0x1D5BA, true, 7291, 1), (0x1D5BB, true, 7292, 1), (0x1D5BC, true, 7293, 1), (0x1D5BD, true, 7294, 1), (0x1D5BE, true, 7295, 1),
+    (0x1D5BF, true, 7296, 1), (0x1D5C0, true, 7297, 1), (0x1D5C1, true, 7298, 1), (0x1D5C2, true, 7299, 1), (0x1D5C3, true, 7300, 1),
+    (0x1D5C4, true, 7301, 1), (0x1D5C5, true, 7302, 1), (0x1D5C6, true, 7303, 1), (0x1D5C7, true, 7304, 1), (0x1D5C8, true, 7305, 1),
+    (0x1D5C9, true, 7306, 1), (0x1D5CA, true, 7307, 1), (0x1D5CB, true, 7308, 1), (0x1D5CC, true, 7309, 1), (0x1D5CD, true, 7310, 1),
+    (0x1D5CE, true, 7311, 1), (0x1D5CF, true, 7312, 1), (0x1D5D0, true, 7313, 1), (0x1D5D1, true, 7314, 1), (0x1D5D2, true, 7315, 1),
+    (0x1D5D3, true, 7316, 1), (0x1D5D4, true, 7317, 1), (0x1D5D5, true, 7318, 1), (0x1D5D6, true, 7319, 1), (0x1D5D7, true, 7320, 1),
+    (0x1D5D8, true, 7321, 1), (0x1D5D9, true, 7322, 1), (0x1D5DA, true, 7323, 1), (0x1D5DB, true, 7324, 1), (0x1D5DC, true, 7325, 1),
+    (0x1D5DD, true, 7326, 1), (0x1D5DE, true, 7327, 1), (0x1D5DF, true, 7328, 1), (0x1D5E0, true, 7329, 1), (0x1D5E1, true, 7330, 1),
+    (0x1D5E2, true, 7331, 1), (0x1D5E3, true, 7332, 1), (0x1D5E4, true, 7333, 1), (0x1D5E5, true, 7334, 1), (0x1D5E6, true, 7335, 1),
+    (0x1D5E7, true, 7336, 1), (0x1D5E8, true, 7337, 1), (0x1D5E9, true, 7338, 1), (0x1D5EA, true, 7339, 1), (0x1D5EB, true, 7340, 1),
+    (0x1D5EC, true, 7341, 1), (0x1D5ED, true, 7342, 1), (0x1D5EE, true, 7343, 1), (0x1D5EF, true, 7344, 1), (0x1D5F0, true, 7345, 1),
+    (0x1D5F1, true, 7346, 1), (0x1D5F2, true, 7347, 1), (0x1D5F3, true, 7348, 1), (0x1D5F4, true, 7349, 1), (0x1D5F5, true, 7350, 1),
+    (0x1D5F6, true, 7351, 1), (0x1D5F7, true, 7352, 1), (0x1D5F8, true, 7353, 1), (0x1D5F9, true, 7354, 1), (0x1D5FA, true, 7355, 1),
+    (0x1D5FB, true, 7356, 1), (0x1D5FC, true, 7357, 1), (0x1D5FD, true, 7358, 1), (0x1D5FE, true, 7359, 1), (0x1D5FF, true, 7360, 1),
+    (0x1D600, true, 7361, 1), (0x1D601, true, 7362, 1), (0x1D602, true, 7363, 1), (0x1D603, true, 7364, 1), (0x1D604, true, 7365, 1),
+    (0x1D605, true, 7366, 1), (0x1D606, true, 7367, 1), (0x1D607, true, 7368, 1), (0x1D608, true, 7369, 1), (0x1D609, true, 7370, 1),
+    (0x1D60A, true, 7371, 1), (0x1D60B, true, 7372, 1), (0x1D60C, true, 7373, 1), (0x1D60D, true, 7374, 1), (0x1D60E, true, 7375, 1),
+    (0x1D60F, true, 7376, 1), (0x1D610, true, 7377, 1), (0x1D611, true, 7378, 1), (0x1D612, true, 7379, 1), (0x1D613, true, 7380, 1),
+    (0x1D614, true, 7381, 1), (0x1D615, true, 7382, 1), (0x1D616, true, 7383, 1), (0x1D617, true, 7384, 1), (0x1D618, true, 7385, 1),
+    (0x1D619, true, 7386, 1), (0x1D61A, true, 7387, 1), (0x1D61B, true, 7388, 1), (0x1D61C, true, 7389, 1), (0x1D61D, true, 7390, 1),
+    (0x1D61E, true, 7391, 1), (0x1D61F, true, 7392, 1), (0x1D620, true, 7393, 1), (0x1D621, true, 7394, 1), (0x1D622, true, 7395, 1),
+    (0x1D623, true, 7396, 1), (0x1D624, true, 7397, 1), (0x1D625, true, 7398, 1), (0x1D626, true, 7399, 1), (0x1D627, true, 7400, 1),
+    (0x1D628, true, 7401, 1), (0x1D629, true, 7402, 1), (0x1D62A, true, 7403, 1), (0x1D62B, true, 7404, 1), (0x1D62C, true, 7405, 1),
+    (0x1D62D, true, 7406, 1), (0x1D62E, true, 7407, 1), (0x1D62F, true, 7408, 1), (0x1D630, true, 7409, 1), (0x1D631, true, 7410, 1),
+    (0x1D632, true, 7411, 1), (0x1D633, true, 7412, 1), (0x1D634, true, 7413, 1), (0x1D635, true, 7414, 1), (0x1D636, true, 7415, 1),
+    (0x1D637, true, 7416, 1), (0x1D638, true, 7417, 1), (0x1D639, true, 7418, 1), (0x1D63A, true, 7419, 1), (0x1D63B, true, 7420, 1),
+    (0x1D63C, true, 7421, 1), (0x1D63D, true, 7422, 1), (0x1D63E, true, 7423, 1), (0x1D63F, true, 7424, 1), (0x1D640, true, 7425, 1),
+    (0x1D641, true, 7426, 1), (0x1D642, true, 7427, 1), (0x1D643, true, 7428, 1), (0x1D644, true, 7429, 1), (0x1D645, true, 7430, 1),
+    (0x1D646, true, 7431, 1), (0x1D647, true, 7432, 1), (0x1D648, true, 7433, 1), (0x1D649, true, 7434, 1), (0x1D64A, true, 7435, 1),
+    (0x1D64B, true, 7436, 1), (0x1D64C, true, 7437, 1), (0x1D64D, true, 7438, 1), (0x1D64E, true, 7439, 1), (0x1D64F, true, 7440, 1),
+    (0x1D650, true, 7441, 1), (0x1D651, true, 7442, 1), (0x1D652, true, 7443, 1), (0x1D653, true, 7444, 1), (0x1D654, true, 7445, 1),
+    (0x1D655, true, 7446, 1), (0x1D656, true, 7447, 1), (0x1D657, true, 7448, 1), (0x1D658, true, 7449, 1), (0x1D659, true, 7450, 1),
+    (0x1D65A, true, 7451, 1), (0x1D65B, true, 7452, 1), (0x1D65C, true, 7453, 1), (0x1D65D, true, 7454, 1), (0x1D65E, true, 7455, 1),
+    (0x1D65F, true, 7456, 1), (0x1D660, true, 7457, 1), (0x1D661, true, 7458, 1), (0x1D662, true, 7459, 1), (0x1D663, true, 7460, 1),
+    (0x1D664, true, 7461, 1), (0x1D665, true, 7462, 1), (0x1D666, true, 7463, 1), (0x1D667, true, 7464, 1), (0x1D668, true, 7465, 1),
+    (0x1D669, true, 7466, 1), (0x1D66A, true, 7467, 1), (0x1D66B, true, 7468, 1), (0x1D66C, true, 7469, 1), (0x1D66D, true, 7470, 1),
+    (0x1D66E, true, 7471, 1), (0x1D66F, true, 7472, 1), (0x1D670, true, 7473, 1), (0x1D671, true, 7474, 1), (0x1D672, true, 7475, 1),
+    (0x1D673, true, 7476, 1), (0x1D674, true, 7477, 1), (0x1D675, true, 7478, 1), (0x1D676, true, 7479, 1), (0x1D677, true, 7480, 1),
+    (0x1D678, true, 7481, 1), (0x1D679, true, 7482, 1), (0x1D67A, true, 7483, 1), (0x1D67B, true, 7484, 1), (0x1D67C, true, 7485, 1),
+    (0x1D67D, true, 7486, 1), (0x1D67E, true, 7487, 1), (0x1D67F, true, 7488, 1), (0x1D680, true, 7489, 1), (0x1D681, true, 7490, 1),
+    (0x1D682, true, 7491, 1), (0x1D683, true, 7492, 1), (0x1D684, true, 7493, 1), (0x1D685, true, 7494, 1), (0x1D686, true, 7495, 1),
+    (0x1D687, true, 7496, 1), (0x1D688, true, 7497, 1), (0x1D689, true, 7498, 1), (0x1D68A, true, 7499, 1), (0x1D68B, true, 7500, 1),
+    (0x1D68C, true, 7501, 1), (0x1D68D, true, 7502, 1), (0x1D68E, true, 7503, 1), (0x1D68F, true, 7504, 1), (0x1D690, true, 7505, 1),
+    (0x1D691, true, 7506, 1), (0x1D692, true, 7507, 1), (0x1D693, true, 7508, 1), (0x1D694, true, 7509, 1), (0x1D695, true, 7510, 1),
+    (0x1D696, true, 7511, 1), (0x1D697, true, 7512, 1), (0x1D698, true, 7513, 1), (0x1D699, true, 7514, 1), (0x1D69A, true, 7515, 1),
+    (0x1D69B, true, 7516, 1), (0x1D69C, true, 7517, 1), (0x1D69D, true, 7518, 1), (0x1D69E, true, 7519, 1), (0x1D69F, true, 7520, 1),
+    (0x1D6A0, true, 7521, 1), (0x1D6A1, true, 7522, 1), (0x1D6A2, true, 7523, 1), (0x1D6A3, true, 7524, 1), (0x1D6A4, true, 7525, 1),
+    (0x1D6A5, true, 7526, 1), (0x1D6A8, true, 7527, 1), (0x1D6A9, true, 7528, 1), (0x1D6AA, true, 7529, 1), (0x1D6AB, true, 7530, 1),
+    (0x1D6AC, true, 7531, 1), (0x1D6AD, true, 7532, 1), (0x1D6AE, true, 7533, 1), (0x1D6AF, true, 7534, 1), (0x1D6B0, true, 7535, 1),
+    (0x1D6B1, true, 7536, 1), (0x1D6B2, true, 7537, 1), (0x1D6B3, true, 7538, 1), (0x1D6B4, true, 7539, 1), (0x1D6B5, true, 7540, 1),
+    (0x1D6B6, true, 7541, 1), (0x1D6B7, true, 7542, 1), (0x1D6B8, true, 7543, 1), (0x1D6B9, true, 7544, 1), (0x1D6BA, true, 7545, 1),
+    (0x1D6BB, true, 7546, 1), (0x1D6BC, true, 7547, 1), (0x1D6BD, true, 7548, 1), (0x1D6BE, true, 7549, 1), (0x1D6BF, true, 7550, 1),
+    (0x1D6C0, true, 7551, 1), (0x1D6C1, true, 7552, 1), (0x1D6C2, true, 7553, 1), (0x1D6C3, true, 7554, 1), (0x1D6C4, true, 7555, 1),
+    (0x1D6C5, true, 7556, 1), (0x1D6C6, true, 7557, 1), (0x1D6C7, true, 7558, 1), (0x1D6C8, true, 7559, 1), (0x1D6C9, true, 7560, 1),
+    (0x1D6CA, true, 7561, 1), (0x1D6CB, true, 7562, 1), (0x1D6CC, true, 7563, 1), (0x1D6CD, true, 7564, 1), (0x1D6CE, true, 7565, 1),
+    (0x1D6CF, true, 7566, 1), (0x1D6D0, true, 7567, 1), (0x1D6D1, true, 7568, 1), (0x1D6D2, true, 7569, 1), (0x1D6D3, true, 7570, 1),
+    (0x1D6D4, true, 7571, 1), (0x1D6D5, true, 7572, 1), (0x1D6D6, true, 7573, 1), (0x1D6D7, true, 7574, 1), (0x1D6D8, true, 7575, 1),
+    (0x1D6D9, true, 7576, 1), (0x1D6DA, true, 7577, 1), (0x1D6DB, true, 7578, 1), (0x1D6DC, true, 7579, 1), (0x1D6DD, true, 7580, 1),
+    (0x1D6DE, true, 7581, 1), (0x1D6DF, true, 7582, 1), (0x1D6E0, true, 7583, 1), (0x1D6E1, true, 7584, 1), (0x1D6E2, true, 7585, 1),
+    (0x1D6E3, true, 7586, 1), (0x1D6E4, true, 7587, 1), (0x1D6E5, true, 7588, 1), (0x1D6E6, true, 7589, 1), (0x1D6E7, true, 7590, 1),
+    (0x1D6E8, true, 7591, 1), (0x1D6E9, true, 7592, 1), (0x1D6EA, true, 7593, 1), (0x1D6EB, true, 7594, 1), (0x1D6EC, true, 7595, 1),
+    (0x1D6ED, true, 7596, 1), (0x1D6EE, true, 7597, 1), (0x1D6EF, true, 7598, 1), (0x1D6F0, true, 7599, 1), (0x1D6F1, true, 7600, 1),
+    (0x1D6F2, true, 7601, 1), (0x1D6F3, true, 7602, 1), (0x1D6F4, true, 7603, 1), (0x1D6F5, true, 7604, 1), (0x1D6F6, true, 7605, 1),
+    (0x1D6F7, true, 7606, 1), (0x1D6F8, true, 7607, 1), (0x1D6F9, true, 7608, 1), (0x1D6FA, true, 7609, 1), (0x1D6FB, true, 7610, 1),
+    (0x1D6FC, true, 7611, 1), (0x1D6FD, true, 7612, 1), (0x1D6FE, true, 7613, 1), (0x1D6FF, true, 7614, 1), (0x1D700, true, 7615, 1),
+    (0x1D701, true, 7616, 1), (0x1D702, true, 7617, 1), (0x1D703, true, 7618, 1), (0x1D704, true, 7619, 1), (0x1D705, true, 7620, 1),
+    (0x1D706, true, 7621, 1), (0x1D707, true, 7622, 1), (0x1D708, true, 7623, 1), (0x1D709, true, 7624, 1), (0x1D70A, true, 7625, 1),
+    (0x1D70B, true, 7626, 1), (0x1D70C, true, 7627, 1), (0x1D70D, true, 7628, 1), (0x1D70E, true, 7629, 1), (0x1D70F, true, 7630, 1),
+    (0x1D710, true, 7631, 1), (0x1D711, true, 7632, 1), (0x1D712, true, 7633, 1), (0x1D713, true, 7634, 1), (0x1D714, true, 7635, 1),
+    (0x1D715, true, 7636, 1), (0x1D716, true, 7637, 1), (0x1D717, true, 7638, 1), (0x1D718, true, 7639, 1), (0x1D719, true, 7640, 1),
+    (0x1D71A, true, 7641, 1), (0x1D71B, true, 7642, 1), (0x1D71C, true, 7643, 1), (0x1D71D, true, 7644, 1), (0x1D71E, true, 7645, 1),
+    (0x1D71F, true, 7646, 1), (0x1D720, true, 7647, 1), (0x1D721, true, 7648, 1), (0x1D722, true, 7649, 1), (0x1D723, true, 7650, 1),
+    (0x1D724, true, 7651, 1), (0x1D725, true, 7652, 1), (0x1D726, true, 7653, 1), (0x1D727, true, 7654, 1), (0x1D728, true, 7655, 1),
+    (0x1D729, true, 7656, 1), (0x1D72A, true, 7657, 1), (0x1D72B, true, 7658, 1), (0x1D72C, true, 7659, 1), (0x1D72D, true, 7660, 1),
+    (0x1D72E, true, 7661, 1), (0x1D72F, true, 7662, 1), (0x1D730, true, 7663, 1), (0x1D731, true, 7664, 1), (0x1D732, true, 7665, 1),
+    (0x1D733, true, 7666, 1), (0x1D734, true, 7667, 1), (0x1D735, true, 7668, 1), (0x1D736, true, 7669, 1), (0x1D737, true, 7670, 1),
+    (0x1D738, true, 7671, 1), (0x1D739, true, 7672, 1), (0x1D73A, true, 7673, 1), (0x1D73B, true, 7674, 1), (0x1D73C, true, 7675, 1),
+    (0x1D73D, true, 7676, 1), (0x1D73E, true, 7677, 1), (0x1D73F, true, 7678, 1), (0x1D740, true, 7679, 1), (0x1D741, true, 7680, 1),
+    (0x1D742, true, 7681, 1), (0x1D743, true, 7682, 1), (0x1D744, true, 7683, 1), (0x1D745, true, 7684, 1), (0x1D746, true, 7685, 1),
+    (0x1D747, true, 7686, 1), (0x1D748, true, 7687, 1), (0x1D749, true, 7688, 1), (0x1D74A, true, 7689, 1), (0x1D74B, true, 7690, 1),
+    (0x1D74C, true, 7691, 1), (0x1D74D, true, 7692, 1), (0x1D74E, true, 7693, 1), (0x1D74F, true, 7694, 1), (0x1D750, true, 7695, 1),
+    (0x1D751, true, 7696, 1), (0x1D752, true, 7697, 1), (0x1D753, true, 7698, 1), (0x1D754, true, 7699, 1), (0x1D755, true, 7700, 1),
+    (0x1D756, true, 7701, 1), (0x1D757, true, 7702, 1), (0x1D758, true, 7703, 1), (0x1D759, true, 7704, 1), (0x1D75A, true, 7705, 1),
+    (0x1D75B, true, 7706, 1), (0x1D75C, true, 7707, 1), (0x1D75D, true, 7708, 1), (0x1D75E, true, 7709, 1), (0x1D75F, true, 7710, 1),
+    (0x1D760, true, 7711, 1), (0x1D761, true, 7712, 1), (0x1D762, true, 7713, 1), (0x1D763, true, 7714, 1), (0x1D764, true, 7715, 1),
+    (0x1D765, true, 7716, 1), (0x1D766, true, 7717, 1), (0x1D767, true, 7718, 1), (0x1D768, true, 7719, 1), (0x1D769, true, 7720, 1),
+    (0x1D76A, true, 7721, 1), (0x1D76B, true, 7722, 1), (0x1D76C, true, 7723, 1), (0x1D76D, true, 7724, 1), (0x1D76E, true, 7725, 1),
+    (0x1D76F, true, 7726, 1), (0x1D770, true, 7727, 1), (0x1D771, true, 7728, 1), (0x1D772, true, 7729, 1), (0x1D773, true, 7730, 1),
+    (0x1D774, true, 7731, 1), (0x1D775, true, 7732, 1), (0x1D776, true, 7733, 1), (0x1D777, true, 7734, 1), (0x1D778, true, 7735, 1),
+    (0x1D779, true, 7736, 1), (0x1D77A, true, 7737, 1), (0x1D77B, true, 7738, 1), (0x1D77C, true, 7739, 1), (0x1D77D, true, 7740, 1),
+    (0x1D77E, true, 7741, 1), (0x1D77F, true, 7742, 1), (0x1D780, true, 7743, 1), (0x1D781, true, 7744, 1), (0x1D782, true, 7745, 1),
+    (0x1D783, true, 7746, 1), (0x1D784, true, 7747, 1), (0x1D785, true, 7748, 1), (0x1D786, true, 7749, 1), (0x1D787, true, 7750, 1),
+    (0x1D788, true, 7751, 1), (0x1D789, true, 7752, 1), (0x1D78A, true, 7753, 1), (0x1D78B, true, 7754, 1), (0x1D78C, true, 7755, 1),
+    (0x1D78D, true, 7756, 1), (0x1D78E, true, 7757, 1), (0x1D78F, true, 7758, 1), (0x1D790, true, 7759, 1), (0x1D791, true, 7760, 1),
+    (0x1D792, true, 7761, 1), (0x1D793, true, 7762, 1), (0x1D794, true, 7763, 1), (0x1D795, true, 7764, 1), (0x1D796, true, 7765, 1),
+    (0x1D797, true, 7766, 1), (0x1D798, true, 7767, 1), (0x1D799, true, 7768, 1), (0x1D79A, true, 7769, 1), (0x1D79B, true, 7770, 1),
+    (0x1D79C, true, 7771, 1), (0x1D79D, true, 7772, 1), (0x1D79E, true, 7773, 1), (0x1D79F, true, 7774, 1), (0x1D7A0, true, 7775, 1),
+    (0x1D7A1, true, 7776, 1), (0x1D7A2, true, 7777, 1), (0x1D7A3, true, 7778, 1), (0x1D7A4, true, 7779, 1), (0x1D7A5, true, 7780, 1),
+    (0x1D7A6, true, 7781, 1), (0x1D7A7, true, 7782, 1), (0x1D7A8, true, 7783, 1), (0x1D7A9, true, 7784, 1), (0x1D7AA, true, 7785, 1),
+    (0x1D7AB, true, 7786, 1), (0x1D7AC, true, 7787, 1), (0x1D7AD, true, 7788, 1), (0x1D7AE, true, 7789, 1), (0x1D7AF, true, 7790, 1),
+    (0x1D7B0, true, 7791, 1), (0x1D7B1, true, 7792, 1), (0x1D7B2, true, 7793, 1), (0x1D7B3, true, 7794, 1), (0x1D7B4, true, 7795, 1),
+    (0x1D7B5, true, 7796, 1), (0x1D7B6, true, 7797, 1), (0x1D7B7, true, 7798, 1), (0x1D7B8, true, 7799, 1), (0x1D7B9, true, 7800, 1),
+    (0x1D7BA, true, 7801, 1), (0x1D7BB, true, 7802, 1), (0x1D7BC, true, 7803, 1), (0x1D7BD, true, 7804, 1), (0x1D7BE, true, 7805, 1),
+    (0x1D7BF, true, 7806, 1), (0x1D7C0, true, 7807, 1), (0x1D7C1, true, 7808, 1), (0x1D7C2, true, 7809, 1), (0x1D7C3, true, 7810, 1),
+    (0x1D7C4, true, 7811, 1), (0x1D7C5, true, 7812, 1), (0x1D7C6, true, 7813, 1), (0x1D7C7, true, 7814, 1), (0x1D7C8, true, 7815, 1),
+    (0x1D7C9, true, 7816, 1), (0x1D7CA, true, 7817, 1), (0x1D7CB, true, 7818, 1), (0x1D7CE, true, 7819, 1), (0x1D7CF, true, 7820, 1),
+    (0x1D7D0, true, 7821, 1), (0x1D7D1, true, 7822, 1), (0x1D7D2, true, 7823, 1), (0x1D7D3, true, 7824, 1), (0x1D7D4, true, 7825, 1),
+    (0x1D7D5, true, 7826, 1), (0x1D7D6, true, 7827, 1), (0x1D7D7, true, 7828, 1), (0x1D7D8, true, 7829, 1), (0x1D7D9, true, 7830, 1),
+    (0x1D7DA, true, 7831, 1), (0x1D7DB, true, 7832, 1), (0x1D7DC, true, 7833, 1), (0x1D7DD, true, 7834, 1), (0x1D7DE, true, 7835, 1),
+    (0x1D7DF, true, 7836, 1), (0x1D7E0, true, 7837, 1), (0x1D7E1, true, 7838, 1), (0x1D7E2, true, 7839, 1), (0x1D7E3, true, 7840, 1),
+    (0x1D7E4, true, 7841, 1), (0x1D7E5, true, 7842, 1), (0x1D7E6, true, 7843, 1), (0x1D7E7, true, 7844, 1), (0x1D7E8, true, 7845, 1),
+    (0x1D7E9, true, 7846, 1), (0x1D7EA, true, 7847, 1), (0x1D7EB, true, 7848, 1), (0x1D7EC, true, 7849, 1), (0x1D7ED, true, 7850, 1),
+    (0x1D7EE, true, 7851, 1), (0x1D7EF, true, 7852, 1), (0x1D7F0, true, 7853, 1), (0x1D7F1, true, 7854, 1), (0x1D7F2, true, 7855, 1),
+    (0x1D7F3, true, 7856, 1), (0x1D7F4, true, 7857, 1), (0x1D7F5, true, 7858, 1), (0x1D7F6, true, 7859, 1), (0x1D7F7, true, 7860, 1),
+    (0x1D7F8, true, 7861, 1), (0x1D7F9, true, 7862, 1), (0x1D7FA, true, 7863, 1), (0x1D7FB, true, 7864, 1), (0x1D7FC, true, 7865, 1),
+    (0x1D7FD, true, 7866, 1), (0x1D7FE, true, 7867, 1), (0x1D7FF, true, 7868, 1), (0x1E030, true, 7869, 1), (0x1E031, true, 7870, 1),
+    (0x1E032, true, 7871, 1), (0x1E033, true, 7872, 1), (0x1E034, true, 7873, 1), (0x1E035, true, 7874, 1), (0x1E036, true, 7875, 1),
+    (0x1E037, true, 7876, 1), (0x1E038, true, 7877, 1), (0x1E039, true, 7878, 1), (0x1E03A, true, 7879, 1), (0x1E03B, true, 7880, 1),
+    (0x1E03C, true, 7881, 1), (0x1E03D, true, 7882, 1), (0x1E03E, true, 7883, 1), (0x1E03F, true, 7884, 1), (0x1E040, true, 7885, 1),
+    (0x1E041, true, 7886, 1), (0x1E042, true, 7887, 1), (0x1E043, true, 7888, 1), (0x1E044, true, 7889, 1), (0x1E045, true, 7890, 1),
+    (0x1E046, true, 7891, 1), (0x1E047, true, 7892, 1), (0x1E048, true, 7893, 1), (0x1E049, true, 7894, 1), (0x1E04A, true, 7895, 1),
+    (0x1E04B, true, 7896, 1), (0x1E04C, true, 7897, 1), (0x1E04D, true, 7898, 1), (0x1E04E, true, 7899, 1), (0x1E04F, true, 7900, 1),
+    (0x1E050, true, 7901, 1), (0x1E051, true, 7902, 1), (0x1E052, true, 7903, 1), (0x1E053, true, 7904, 1), (0x1E054, true, 7905, 1),
+    (0x1E055, true, 7906, 1), (0x1E056, true, 7907, 1), (0x1E057, true, 7908, 1), (0x1E058, true, 7909, 1), (0x1E059, true, 7910, 1),
+    (0x1E05A, true, 7911, 1), (0x1E05B, true, 7912, 1), (0x1E05C, true, 7913, 1), (0x1E05D, true, 7914, 1), (0x1E05E, true, 7915, 1),
+    (0x1E05F, true, 7916, 1), (0x1E060, true, 7917, 1), (0x1E061, true, 7918, 1), (0x1E062, true, 7919, 1), (0x1E063, true, 7920, 1),
+    (0x1E064, true, 7921, 1), (0x1E065, true, 7922, 1), (0x1E066, true, 7923, 1), (0x1E067, true, 7924, 1), (0x1E068, true, 7925, 1),
+    (0x1E069, true, 7926, 1), (0x1E06A, true, 7927, 1), (0x1E06B, true, 7928, 1), (0x1E06C, true, 7929, 1), (0x1E06D, true, 7930, 1),
+    (0x1EE00, true, 7931, 1), (0x1EE01, true, 7932, 1), (0x1EE02, true, 7933, 1), (0x1EE03, true, 7934, 1), (0x1EE05, true, 7935, 1),
+    (0x1EE06, true, 7936, 1), (0x1EE07, true, 7937, 1), (0x1EE08, true, 7938, 1), (0x1EE09, true, 7939, 1), (0x1EE0A, true, 7940, 1),
+    (0x1EE0B, true, 7941, 1), (0x1EE0C, true, 7942, 1), (0x1EE0D, true, 7943, 1), (0x1EE0E, true, 7944, 1), (0x1EE0F, true, 7945, 1),
+    (0x1EE10, true, 7946, 1), (0x1EE11, true, 7947, 1), (0x1EE12, true, 7948, 1), (0x1EE13, true, 7949, 1), (0x1EE14, true, 7950, 1),
+    (0x1EE15, true, 7951, 1), (0x1EE16, true, 7952, 1), (0x1EE17, true, 7953, 1), (0x1EE18, true, 7954, 1), (0x1EE19, true, 7955, 1),
+    (0x1EE1A, true, 7956, 1), (0x1EE1B, true, 7957, 1), (0x1EE1C, true, 7958, 1), (0x1EE1D, true, 7959, 1), (0x1EE1E, true, 7960, 1),
+    (0x1EE1F, true, 7961, 1), (0x1EE21, true, 7962, 1), (0x1EE22, true, 7963, 1), (0x1EE24, true, 7964, 1), (0x1EE27, true, 7965, 1),
+    (0x1EE29, true, 7966, 1), (0x1EE2A, true, 7967, 1), (0x1EE2B, true, 7968, 1), (0x1EE2C, true, 7969, 1), (0x1EE2D, true, 7970, 1),
+    (0x1EE2E, true, 7971, 1), (0x1EE2F, true, 7972, 1), (0x1EE30, true, 7973, 1), (0x1EE31, true, 7974, 1), (0x1EE32, true, 7975, 1),
+    (0x1EE34, true, 7976, 1), (0x1EE35, true, 7977, 1), (0x1EE36, true, 7978, 1), (0x1EE37, true, 7979, 1), (0x1EE39, true, 7980, 1),
+    (0x1EE3B, true, 7981, 1), (0x1EE42, true, 7982, 1), (0x1EE47, true, 7983, 1), (0x1EE49, true, 7984, 1), (0x1EE4B, true, 7985, 1),
+    (0x1EE4D, true, 7986, 1), (0x1EE4E, true, 7987, 1), (0x1EE4F, true, 7988, 1), (0x1EE51, true, 7989, 1), (0x1EE52, true, 7990, 1),
+    (0x1EE54, true, 7991, 1), (0x1EE57, true, 7992, 1), (0x1EE59, true, 7993, 1), (0x1EE5B, true, 7994, 1), (0x1EE5D, true, 7995, 1),
+    (0x1EE5F, true, 7996, 1), (0x1EE61, true, 7997, 1), (0x1EE62, true, 7998, 1), (0x1EE64, true, 7999, 1), (0x1EE67, true, 8000, 1),
+    (0x1EE68, true, 8001, 1), (0x1EE69, true, 8002, 1), (0x1EE6A, true, 8003, 1), (0x1EE6C, true, 8004, 1), (0x1EE6D, true, 8005, 1),
+    (0x1EE6E, true, 8006, 1), (0x1EE6F, true, 8007, 1), (0x1EE70, true, 8008, 1), (0x1EE71, true, 8009, 1), (0x1EE72, true, 8010, 1),
+    (0x1EE74, true, 8011, 1), (0x1EE75, true, 8012, 1), (0x1EE76, true, 8013, 1), (0x1EE77, true, 8014, 1), (0x1EE79, true, 8015, 1),
+    (0x1EE7A, true, 8016, 1), (0x1EE7B, true, 8017, 1), (0x1EE7C, true, 8018, 1), (0x1EE7E, true, 8019, 1), (0x1EE80, true, 8020, 1),
+    (0x1EE81, true, 8021, 1), (0x1EE82, true, 8022, 1), (0x1EE83, true, 8023, 1), (0x1EE84, true, 8024, 1), (0x1EE85, true, 8025, 1),
+    (0x1EE86, true, 8026, 1), (0x1EE87, true, 8027, 1), (0x1EE88, true, 8028, 1), (0x1EE89, true, 8029, 1), (0x1EE8B, true, 8030, 1),
+    (0x1EE8C, true, 8031, 1), (0x1EE8D, true, 8032, 1), (0x1EE8E, true, 8033, 1), (0x1EE8F, true, 8034, 1), (0x1EE90, true, 8035, 1),
+    (0x1EE91, true, 8036, 1), (0x1EE92, true, 8037, 1), (0x1EE93, true, 8038, 1), (0x1EE94, true, 8039, 1), (0x1EE95, true, 8040, 1),
+    (0x1EE96, true, 8041, 1), (0x1EE97, true, 8042, 1), (0x1EE98, true, 8043, 1), (0x1EE99, true, 8044, 1), (0x1EE9A, true, 8045, 1),
+    (0x1EE9B, true, 8046, 1), (0x1EEA1, true, 8047, 1), (0x1EEA2, true, 8048, 1), (0x1EEA3, true, 8049, 1), (0x1EEA5, true, 8050, 1),
+    (0x1EEA6, true, 8051, 1), (0x1EEA7, true, 8052, 1), (0x1EEA8, true, 8053, 1), (0x1EEA9, true, 8054, 1), (0x1EEAB, true, 8055, 1),
+    (0x1EEAC, true, 8056, 1), (0x1EEAD, true, 8057, 1), (0x1EEAE, true, 8058, 1), (0x1EEAF, true, 8059, 1), (0x1EEB0, true, 8060, 1),
+    (0x1EEB1, true, 8061, 1), (0x1EEB2, true, 8062, 1), (0x1EEB3, true, 8063, 1), (0x1EEB4, true, 8064, 1), (0x1EEB5, true, 8065, 1),
+    (0x1EEB6, true, 8066, 1), (0x1EEB7, true, 8067, 1), (0x1EEB8, true, 8068, 1), (0x1EEB9, true, 8069, 1), (0x1EEBA, true, 8070, 1),
+    (0x1EEBB, true, 8071, 1), (0x1F100, true, 8072, 2), (0x1F101, true, 8074, 2), (0x1F102, true, 8076, 2), (0x1F103, true, 8078, 2),
+    (0x1F104, true, 8080, 2), (0x1F105, true, 8082, 2), (0x1F106, true, 8084, 2), (0x1F107, true, 8086, 2), (0x1F108, true, 8088, 2),
+    (0x1F109, true, 8090, 2), (0x1F10A, true, 8092, 2), (0x1F110, true, 8094, 3), (0x1F111, true, 8097, 3), (0x1F112, true, 8100, 3),
+    (0x1F113, true, 8103, 3), (0x1F114, true, 8106, 3), (0x1F115, true, 8109, 3), (0x1F116, true, 8112, 3), (0x1F117, true, 8115, 3),
+    (0x1F118, true, 8118, 3), (0x1F119, true, 8121, 3), (0x1F11A, true, 8124, 3), (0x1F11B, true, 8127, 3), (0x1F11C, true, 8130, 3),
+    (0x1F11D, true, 8133, 3), (0x1F11E, true, 8136, 3), (0x1F11F, true, 8139, 3), (0x1F120, true, 8142, 3), (0x1F121, true, 8145, 3),
+    (0x1F122, true, 8148, 3), (0x1F123, true, 8151, 3), (0x1F124, true, 8154, 3), (0x1F125, true, 8157, 3), (0x1F126, true, 8160, 3),
+    (0x1F127, true, 8163, 3), (0x1F128, true, 8166, 3), (0x1F129, true, 8169, 3), (0x1F12A, true, 8172, 3), (0x1F12B, true, 8175, 1),
+    (0x1F12C, true, 8176, 1), (0x1F12D, true, 8177, 2), (0x1F12E, true, 8179, 2), (0x1F130, true, 8181, 1), (0x1F131, true, 8182, 1),
+    (0x1F132, true, 8183, 1), (0x1F133, true, 8184, 1), (0x1F134, true, 8185, 1), (0x1F135, true, 8186, 1), (0x1F136, true, 8187, 1),
+    (0x1F137, true, 8188, 1), (0x1F138, true, 8189, 1), (0x1F139, true, 8190, 1), (0x1F13A, true, 8191, 1), (0x1F13B, true, 8192, 1),
+    (0x1F13C, true, 8193, 1), (0x1F13D, true, 8194, 1), (0x1F13E, true, 8195, 1), (0x1F13F, true, 8196, 1), (0x1F140, true, 8197, 1),
+    (0x1F141, true, 8198, 1), (0x1F142, true, 8199, 1), (0x1F143, true, 8200, 1), (0x1F144, true, 8201, 1), (0x1F145, true, 8202, 1),
+    (0x1F146, true, 8203, 1), (0x1F147, true, 8204, 1), (0x1F148, true, 8205, 1), (0x1F149, true, 8206, 1), (0x1F14A, true, 8207, 2),
+    (0x1F14B, true, 8209, 2), (0x1F14C, true, 8211, 2), (0x1F14D, true, 8213, 2), (0x1F14E, true, 8215, 3), (0x1F14F, true, 8218, 2),
+    (0x1F16A, true, 8220, 2), (0x1F16B, true, 8222, 2), (0x1F16C, true, 8224, 2), (0x1F190, true, 8226, 2), (0x1F200, true, 8228, 2),
+    (0x1F201, true, 8230, 2), (0x1F202, true, 8232, 1), (0x1F210, true, 8233, 1), (0x1F211, true, 8234, 1), (0x1F212, true, 8235, 1),
+    (0x1F213, true, 8236, 2), (0x1F214, true, 8238, 1), (0x1F215, true, 8239, 1), (0x1F216, true, 8240, 1), (0x1F217, true, 8241, 1),
+    (0x1F218, true, 8242, 1), (0x1F219, true, 8243, 1), (0x1F21A, true, 8244, 1), (0x1F21B, true, 8245, 1), (0x1F21C, true, 8246, 1),
+    (0x1F21D, true, 8247, 1), (0x1F21E, true, 8248, 1), (0x1F21F, true, 8249, 1), (0x1F220, true, 8250, 1), (0x1F221, true, 8251, 1),
+    (0x1F222, true, 8252, 1), (0x1F223, true, 8253, 1), (0x1F224, true, 8254, 1), (0x1F225, true, 8255, 1), (0x1F226, true, 8256, 1),
+    (0x1F227, true, 8257, 1), (0x1F228, true, 8258, 1), (0x1F229, true, 8259, 1), (0x1F22A, true, 8260, 1), (0x1F22B, true, 8261, 1),
+    (0x1F22C, true, 8262, 1), (0x1F22D, true, 8263, 1), (0x1F22E, true, 8264, 1), (0x1F22F, true, 8265, 1), (0x1F230, true, 8266, 1),
+    (0x1F231, true, 8267, 1), (0x1F232, true, 8268, 1), (0x1F233, true, 8269, 1), (0x1F234, true, 8270, 1), (0x1F235, true, 8271, 1),
+    (0x1F236, true, 8272, 1), (0x1F237, true, 8273, 1), (0x1F238, true, 8274, 1), (0x1F239, true, 8275, 1), (0x1F23A, true, 8276, 1),
+    (0x1F23B, true, 8277, 1), (0x1F240, true, 8278, 3), (0x1F241, true, 8281, 3), (0x1F242, true, 8284, 3), (0x1F243, true, 8287, 3),
+    (0x1F244, true, 8290, 3), (0x1F245, true, 8293, 3), (0x1F246, true, 8296, 3), (0x1F247, true, 8299, 3), (0x1F248, true, 8302, 3),
+    (0x1F250, true, 8305, 1), (0x1F251, true, 8306, 1), (0x1FBF0, true, 8307, 1), (0x1FBF1, true, 8308, 1), (0x1FBF2, true, 8309, 1),
+    (0x1FBF3, true, 8310, 1), (0x1FBF4, true, 8311, 1), (0x1FBF5, true, 8312, 1), (0x1FBF6, true, 8313, 1), (0x1FBF7, true, 8314, 1),
+    (0x1FBF8, true, 8315, 1), (0x1FBF9, true, 8316, 1), (0x2F800, false, 8317, 1), (0x2F801, false, 8318, 1), (0x2F802, false, 8319, 1),
+    (0x2F803, false, 8320, 1), (0x2F804, false, 8321, 1), (0x2F805, false, 8322, 1), (0x2F806, false, 8323, 1), (0x2F807, false, 8324, 1),
+    (0x2F808, false, 8325, 1), (0x2F809, false, 8326, 1), (0x2F80A, false, 8327, 1), (0x2F80B, false, 8328, 1), (0x2F80C, false, 8329, 1),
+    (0x2F80D, false, 8330, 1), (0x2F80E, false, 8331, 1), (0x2F80F, false, 8332, 1), (0x2F810, false, 8333, 1), (0x2F811, false, 8334, 1),
+    (0x2F812, false, 8335, 1), (0x2F813, false, 8336, 1), (0x2F814, false, 8337, 1), (0x2F815, false, 8338, 1), (0x2F816, false, 8339, 1),
+    (0x2F817, false, 8340, 1), (0x2F818, false, 8341, 1), (0x2F819, false, 8342, 1), (0x2F81A, false, 8343, 1), (0x2F81B, false, 8344, 1),
+    (0x2F81C, false, 8345, 1), (0x2F81D, false, 8346, 1), (0x2F81E, false, 8347, 1), (0x2F81F, false, 8348, 1), (0x2F820, false, 8349, 1),
+    (0x2F821, false, 8350, 1), (0x2F822, false, 8351, 1), (0x2F823, false, 8352, 1), (0x2F824, false, 8353, 1), (0x2F825, false, 8354, 1),
+    (0x2F826, false, 8355, 1), (0x2F827, false, 8356, 1), (0x2F828, false, 8357, 1), (0x2F829, false, 8358, 1), (0x2F82A, false, 8359, 1),
+    (0x2F82B, false, 8360, 1), (0x2F82C, false, 8361, 1), (0x2F82D, false, 8362, 1), (0x2F82E, false, 8363, 1), (0x2F82F, false, 8364, 1),
+    (0x2F830, false, 8365, 1), (0x2F831, false, 8366, 1), (0x2F832, false, 8367, 1), (0x2F833, false, 8368, 1), (0x2F834, false, 8369, 1),
+    (0x2F835, false, 8370, 1), (0x2F836, false, 8371, 1), (0x2F837, false, 8372, 1), (0x2F838, false, 8373, 1), (0x2F839, false, 8374, 1),
+    (0x2F83A, false, 8375, 1), (0x2F83B, false, 8376, 1), (0x2F83C, false, 8377, 1), (0x2F83D, false, 8378, 1), (0x2F83E, false, 8379, 1),
+    (0x2F83F, false, 8380, 1), (0x2F840, false, 8381, 1), (0x2F841, false, 8382, 1), (0x2F842, false, 8383, 1), (0x2F843, false, 8384, 1),
+    (0x2F844, false, 8385, 1), (0x2F845, false, 8386, 1), (0x2F846, false, 8387, 1), (0x2F847, false, 8388, 1), (0x2F848, false, 8389, 1),
+    (0x2F849, false, 8390, 1), (0x2F84A, false, 8391, 1), (0x2F84B, false, 8392, 1), (0x2F84C, false, 8393, 1), (0x2F84D, false, 8394, 1),
+    (0x2F84E, false, 8395, 1), (0x2F84F, false, 8396, 1), (0x2F850, false, 8397, 1), (0x2F851, false, 8398, 1), (0x2F852, false, 8399, 1),
+    (0x2F853, false, 8400, 1), (0x2F854, false, 8401, 1), (0x2F855, false, 8402, 1), (0x2F856, false, 8403, 1), (0x2F857, false, 8404, 1),
+    (0x2F858, false, 8405, 1), (0x2F859, false, 8406, 1), (0x2F85A, false, 8407, 1), (0x2F85B, false, 8408, 1), (0x2F85C, false, 8409, 1),
+    (0x2F85D, false, 8410, 1), (0x2F85E, false, 8411, 1), (0x2F85F, false, 8412, 1), (0x2F860, false, 8413, 1), (0x2F861, false, 8414, 1),
+    (0x2F862, false, 8415, 1), (0x2F863, false, 8416, 1), (0x2F864, false, 8417, 1), (0x2F865, false, 8418, 1), (0x2F866, false, 8419, 1),
+    (0x2F867, false, 8420, 1), (0x2F868, false, 8421, 1), (0x2F869, false, 8422, 1), (0x2F86A, false, 8423, 1), (0x2F86B, false, 8424, 1),
+    (0x2F86C, false, 8425, 1), (0x2F86D, false, 8426, 1), (0x2F86E, false, 8427, 1), (0x2F86F, false, 8428, 1), (0x2F870, false, 8429, 1),
+    (0x2F871, false, 8430, 1), (0x2F872, false, 8431, 1), (0x2F873, false, 8432, 1), (0x2F874, false, 8433, 1), (0x2F875, false, 8434, 1),
+    (0x2F876, false, 8435, 1), (0x2F877, false, 8436, 1), (0x2F878, false, 8437, 1), (0x2F879, false, 8438, 1), (0x2F87A, false, 8439, 1),
+    (0x2F87B, false, 8440, 1), (0x2F87C, false, 8441, 1), (0x2F87D, false, 8442, 1), (0x2F87E, false, 8443, 1), (0x2F87F, false, 8444, 1),
+    (0x2F880, false, 8445, 1), (0x2F881, false, 8446, 1), (0x2F882, false, 8447, 1), (0x2F883, false, 8448, 1), (0x2F884, false, 8449, 1),
+    (0x2F885, false, 8450, 1), (0x2F886, false, 8451, 1), (0x2F887, false, 8452, 1), (0x2F888, false, 8453, 1), (0x2F889, false, 8454, 1),
+    (0x2F88A, false, 8455, 1), (0x2F88B, false, 8456, 1), (0x2F88C, false, 8457, 1), (0x2F88D, false, 8458, 1), (0x2F88E, false, 8459, 1),
+    (0x2F88F, false, 8460, 1), (0x2F890, false, 8461, 1), (0x2F891, false, 8462, 1), (0x2F892, false, 8463, 1), (0x2F893, false, 8464, 1),
+    (0x2F894, false, 8465, 1), (0x2F895, false, 8466, 1), (0x2F896, false, 8467, 1), (0x2F897, false, 8468, 1), (0x2F898, false, 8469, 1),
+    (0x2F899, false, 8470, 1), (0x2F89A, false, 8471, 1), (0x2F89B, false, 8472, 1), (0x2F89C, false, 8473, 1), (0x2F89D, false, 8474, 1),
+    (0x2F89E, false, 8475, 1), (0x2F89F, false, 8476, 1), (0x2F8A0, false, 8477, 1), (0x2F8A1, false, 8478, 1), (0x2F8A2, false, 8479, 1),
+    (0x2F8A3, false, 8480, 1), (0x2F8A4, false, 8481, 1), (0x2F8A5, false, 8482, 1), (0x2F8A6, false, 8483, 1), (0x2F8A7, false, 8484, 1),
+    (0x2F8A8, false, 8485, 1), (0x2F8A9, false, 8486, 1), (0x2F8AA, false, 8487, 1), (0x2F8AB, false, 8488, 1), (0x2F8AC, false, 8489, 1),
+    (0x2F8AD, false, 8490, 1), (0x2F8AE, false, 8491, 1), (0x2F8AF, false, 8492, 1), (0x2F8B0, false, 8493, 1), (0x2F8B1, false, 8494, 1),
+    (0x2F8B2, false, 8495, 1), (0x2F8B3, false, 8496, 1), (0x2F8B4, false, 8497, 1), (0x2F8B5, false, 8498, 1), (0x2F8B6, false, 8499, 1),
+    (0x2F8B7, false, 8500, 1), (0x2F8B8, false, 8501, 1), (0x2F8B9, false, 8502, 1), (0x2F8BA, false, 8503, 1), (0x2F8BB, false, 8504, 1),
+    (0x2F8BC, false, 8505, 1), (0x2F8BD, false, 8506, 1), (0x2F8BE, false, 8507, 1), (0x2F8BF, false, 8508, 1), (0x2F8C0, false, 8509, 1),
+    (0x2F8C1, false, 8510, 1), (0x2F8C2, false, 8511, 1), (0x2F8C3, false, 8512, 1), (0x2F8C4, false, 8513, 1), (0x2F8C5, false, 8514, 1),
+    (0x2F8C6, false, 8515, 1), (0x2F8C7, false, 8516, 1), (0x2F8C8, false, 8517, 1), (0x2F8C9, false, 8518, 1), (0x2F8CA, false, 8519, 1),
+    (0x2F8CB, false, 8520, 1), (0x2F8CC, false, 8521, 1), (0x2F8CD, false, 8522, 1), (0x2F8CE, false, 8523, 1), (0x2F8CF, false, 8524, 1),
+    (0x2F8D0, false, 8525, 1), (0x2F8D1, false, 8526, 1), (0x2F8D2, false, 8527, 1), (0x2F8D3, false, 8528, 1), (0x2F8D4, false, 8529, 1),
+    (0x2F8D5, false, 8530, 1), (0x2F8D6, false, 8531, 1), (0x2F8D7, false, 8532, 1), (0x2F8D8, false, 8533, 1), (0x2F8D9, false, 8534, 1),
+    (0x2F8DA, false, 8535, 1), (0x2F8DB, false, 8536, 1), (0x2F8DC, false, 8537, 1), (0x2F8DD, false, 8538, 1), (0x2F8DE, false, 8539, 1),
+    (0x2F8DF, false, 8540, 1), (0x2F8E0, false, 8541, 1), (0x2F8E1, false, 8542, 1), (0x2F8E2, false, 8543, 1), (0x2F8E3, false, 8544, 1),
+    (0x2F8E4, false, 8545, 1), (0x2F8E5, false, 8546, 1), (0x2F8E6, false, 8547, 1), (0x2F8E7, false, 8548, 1), (0x2F8E8, false, 8549, 1),
+    (0x2F8E9, false, 8550, 1), (0x2F8EA, false, 8551, 1), (0x2F8EB, false, 8552, 1), (0x2F8EC, false, 8553, 1), (0x2F8ED, false, 8554, 1),
+    (0x2F8EE, false, 8555, 1), (0x2F8EF, false, 8556, 1), (0x2F8F0, false, 8557, 1), (0x2F8F1, false, 8558, 1), (0x2F8F2, false, 8559, 1),
+    (0x2F8F3, false, 8560, 1), (0x2F8F4, false, 8561, 1), (0x2F8F5, false, 8562, 1), (0x2F8F6, false, 8563, 1), (0x2F8F7, false, 8564, 1),
+    (0x2F8F8, false, 8565, 1), (0x2F8F9, false, 8566, 1), (0x2F8FA, false, 8567, 1), (0x2F8FB, false, 8568, 1), (0x2F8FC, false, 8569, 1),
+    (0x2F8FD, false, 8570, 1), (0x2F8FE, false, 8571, 1), (0x2F8FF, false, 8572, 1), (0x2F900, false, 8573, 1), (0x2F901, false, 8574, 1),
+    (0x2F902, false, 8575, 1), (0x2F903, false, 8576, 1), (0x2F904, false, 8577, 1), (0x2F905, false, 8578, 1), (0x2F906, false, 8579, 1),
+    (0x2F907, false, 8580, 1), (0x2F908, false, 8581, 1), (0x2F909, false, 8582, 1), (0x2F90A, false, 8583, 1), (0x2F90B, false, 8584, 1),
+    (0x2F90C, false, 8585, 1), (0x2F90D, false, 8586, 1), (0x2F90E, false, 8587, 1), (0x2F90F, false, 8588, 1), (0x2F910, false, 8589, 1),
+    (0x2F911, false, 8590, 1), (0x2F912, false, 8591, 1), (0x2F913, false, 8592, 1), (0x2F914, false, 8593, 1), (0x2F915, false, 8594, 1),
+    (0x2F916, false, 8595, 1), (0x2F917, false, 8596, 1), (0x2F918, false, 8597, 1), (0x2F919, false, 8598, 1), (0x2F91A, false, 8599, 1),
+    (0x2F91B, false, 8600, 1), (0x2F91C, false, 8601, 1), (0x2F91D, false, 8602, 1), (0x2F91E, false, 8603, 1), (0x2F91F, false, 8604, 1),
+    (0x2F920, false, 8605, 1), (0x2F921, false, 8606, 1), (0x2F922, false, 8607, 1), (0x2F923, false, 8608, 1), (0x2F924, false, 8609, 1),
+    (0x2F925, false, 8610, 1), (0x2F926, false, 8611, 1), (0x2F927, false, 8612, 1), (0x2F928, false, 8613, 1), (0x2F929, false, 8614, 1),
+    (0x2F92A, false, 8615, 1), (0x2F92B, false, 8616, 1), (0x2F92C, false, 8617, 1), (0x2F92D, false, 8618, 1), (0x2F92E, false, 8619, 1),
+    (0x2F92F, false, 8620, 1), (0x2F930, false, 8621, 1), (0x2F931, false, 8622, 1), (0x2F932, false, 8623, 1), (0x2F933, false, 8624, 1),
+    (0x2F934, false, 8625, 1), (0x2F935, false, 8626, 1), (0x2F936, false, 8627, 1), (0x2F937, false, 8628, 1), (0x2F938, false, 8629, 1),
+    (0x2F939, false, 8630, 1), (0x2F93A, false, 8631, 1), (0x2F93B, false, 8632, 1), (0x2F93C, false, 8633, 1), (0x2F93D, false, 8634, 1),
+    (0x2F93E, false, 8635, 1), (0x2F93F, false, 8636, 1), (0x2F940, false, 8637, 1), (0x2F941, false, 8638, 1), (0x2F942, false, 8639, 1),
+    (0x2F943, false, 8640, 1), (0x2F944, false, 8641, 1), (0x2F945, false, 8642, 1), (0x2F946, false, 8643, 1), (0x2F947, false, 8644, 1),
+    (0x2F948, false, 8645, 1), (0x2F949, false, 8646, 1), (0x2F94A, false, 8647, 1), (0x2F94B, false, 8648, 1), (0x2F94C, false, 8649, 1),
+    (0x2F94D, false, 8650, 1), (0x2F94E, false, 8651, 1), (0x2F94F, false, 8652, 1), (0x2F950, false, 8653, 1), (0x2F951, false, 8654, 1),
+    (0x2F952, false, 8655, 1), (0x2F953, false, 8656, 1), (0x2F954, false, 8657, 1), (0x2F955, false, 8658, 1), (0x2F956, false, 8659, 1),
+    (0x2F957, false, 8660, 1), (0x2F958, false, 8661, 1), (0x2F959, false, 8662, 1), (0x2F95A, false, 8663, 1), (0x2F95B, false, 8664, 1),
+    (0x2F95C, false, 8665, 1), (0x2F95D, false, 8666, 1), (0x2F95E, false, 8667, 1), (0x2F95F, false, 8668, 1), (0x2F960, false, 8669, 1),
+    (0x2F961, false, 8670, 1), (0x2F962, false, 8671, 1), (0x2F963, false, 8672, 1), (0x2F964, false, 8673, 1), (0x2F965, false, 8674, 1),
+    (0x2F966, false, 8675, 1), (0x2F967, false, 8676, 1), (0x2F968, false, 8677, 1), (0x2F969, false, 8678, 1), (0x2F96A, false, 8679, 1),
+    (0x2F96B, false, 8680, 1), (0x2F96C, false, 8681, 1), (0x2F96D, false, 8682, 1), (0x2F96E, false, 8683, 1), (0x2F96F, false, 8684, 1),
+    (0x2F970, false, 8685, 1), (0x2F971, false, 8686, 1), (0x2F972, false, 8687, 1), (0x2F973, false, 8688, 1), (0x2F974, false, 8689, 1),
+    (0x2F975, false, 8690, 1), (0x2F976, false, 8691, 1), (0x2F977, false, 8692, 1), (0x2F978, false, 8693, 1), (0x2F979, false, 8694, 1),
+    (0x2F97A, false, 8695, 1), (0x2F97B, false, 8696, 1), (0x2F97C, false, 8697, 1), (0x2F97D, false, 8698, 1), (0x2F97E, false, 8699, 1),
+    (0x2F97F, false, 8700, 1), (0x2F980, false, 8701, 1), (0x2F981, false, 8702, 1), (0x2F982, false, 8703, 1), (0x2F983, false, 8704, 1),
+    (0x2F984, false, 8705, 1), (0x2F985, false, 8706, 1), (0x2F986, false, 8707, 1), (0x2F987, false, 8708, 1), (0x2F988, false, 8709, 1),
+    (0x2F989, false, 8710, 1), (0x2F98A, false, 8711, 1), (0x2F98B, false, 8712, 1), (0x2F98C, false, 8713, 1), (0x2F98D, false, 8714, 1),
+    (0x2F98E, false, 8715, 1), (0x2F98F, false, 8716, 1), (0x2F990, false, 8717, 1), (0x2F991, false, 8718, 1), (0x2F992, false, 8719, 1),
+    (0x2F993, false, 8720, 1), (0x2F994, false, 8721, 1), (0x2F995, false, 8722, 1), (0x2F996, false, 8723, 1), (0x2F997, false, 8724, 1),
+    (0x2F998, false, 8725, 1), (0x2F999, false, 8726, 1), (0x2F99A, false, 8727, 1), (0x2F99B, false, 8728, 1), (0x2F99C, false, 8729, 1),
+    (0x2F99D, false, 8730, 1), (0x2F99E, false, 8731, 1), (0x2F99F, false, 8732, 1), (0x2F9A0, false, 8733, 1), (0x2F9A1, false, 8734, 1),
+    (0x2F9A2, false, 8735, 1), (0x2F9A3, false, 8736, 1), (0x2F9A4, false, 8737, 1), (0x2F9A5, false, 8738, 1), (0x2F9A6, false, 8739, 1),
+    (0x2F9A7, false, 8740, 1), (0x2F9A8, false, 8741, 1), (0x2F9A9, false, 8742, 1), (0x2F9AA, false, 8743, 1), (0x2F9AB, false, 8744, 1),
+    (0x2F9AC, false, 8745, 1), (0x2F9AD, false, 8746, 1), (0x2F9AE, false, 8747, 1), (0x2F9AF, false, 8748, 1), (0x2F9B0, false, 8749, 1),
+    (0x2F9B1, false, 8750, 1), (0x2F9B2, false, 8751, 1), (0x2F9B3, false, 8752, 1), (0x2F9B4, false, 8753, 1), (0x2F9B5, false, 8754, 1),
+    (0x2F9B6, false, 8755, 1), (0x2F9B7, false, 8756, 1), (0x2F9B8, false, 8757, 1), (0x2F9B9, false, 8758, 1), (0x2F9BA, false, 8759, 1),
+    (0x2F9BB, false, 8760, 1), (0x2F9BC, false, 8761, 1), (0x2F9BD, false, 8762, 1), (0x2F9BE, false, 8763, 1), (0x2F9BF, false, 8764, 1),
+    (0x2F9C0, false, 8765, 1), (0x2F9C1, false, 8766, 1), (0x2F9C2, false, 8767, 1), (0x2F9C3, false, 8768, 1), (0x2F9C4, false, 8769, 1),
+    (0x2F9C5, false, 8770, 1), (0x2F9C6, false, 8771, 1), (0x2F9C7, false, 8772, 1), (0x2F9C8, false, 8773, 1), (0x2F9C9, false, 8774, 1),
+    (0x2F9CA, false, 8775, 1), (0x2F9CB, false, 8776, 1), (0x2F9CC, false, 8777, 1), (0x2F9CD, false, 8778, 1), (0x2F9CE, false, 8779, 1),
+    (0x2F9CF, false, 8780, 1), (0x2F9D0, false, 8781, 1), (0x2F9D1, false, 8782, 1), (0x2F9D2, false, 8783, 1), (0x2F9D3, false, 8784, 1),
+    (0x2F9D4, false, 8785, 1), (0x2F9D5, false, 8786, 1), (0x2F9D6, false, 8787, 1), (0x2F9D7, false, 8788, 1), (0x2F9D8, false, 8789, 1),
+    (0x2F9D9, false, 8790, 1), (0x2F9DA, false, 8791, 1), (0x2F9DB, false, 8792, 1), (0x2F9DC, false, 8793, 1), (0x2F9DD, false, 8794, 1),
+    (0x2F9DE, false, 8795, 1), (0x2F9DF, false, 8796, 1), (0x2F9E0, false, 8797, 1), (0x2F9E1, false, 8798, 1), (0x2F9E2, false, 8799, 1),
+    (0x2F9E3, false, 8800, 1), (0x2F9E4, false, 8801, 1), (0x2F9E5, false, 8802, 1), (0x2F9E6, false, 8803, 1), (0x2F9E7, false, 8804, 1),
+    (0x2F9E8, false, 8805, 1), (0x2F9E9, false, 8806, 1), (0x2F9EA, false, 8807, 1), (0x2F9EB, false, 8808, 1), (0x2F9EC, false, 8809, 1),
+    (0x2F9ED, false, 8810, 1), (0x2F9EE, false, 8811, 1), (0x2F9EF, false, 8812, 1), (0x2F9F0, false, 8813, 1), (0x2F9F1, false, 8814, 1),
+    (0x2F9F2, false, 8815, 1), (0x2F9F3, false, 8816, 1), (0x2F9F4, false, 8817, 1), (0x2F9F5, false, 8818, 1), (0x2F9F6, false, 8819, 1),
+    (0x2F9F7, false, 8820, 1), (0x2F9F8, false, 8821, 1), (0x2F9F9, false, 8822, 1), (0x2F9FA, false, 8823, 1), (0x2F9FB, false, 8824, 1),
+    (0x2F9FC, false, 8825, 1), (0x2F9FD, false, 8826, 1), (0x2F9FE, false, 8827, 1), (0x2F9FF, false, 8828, 1), (0x2FA00, false, 8829, 1),
+    (0x2FA01, false, 8830, 1), (0x2FA02, false, 8831, 1), (0x2FA03, false, 8832, 1), (0x2FA04, false, 8833, 1), (0x2FA05, false, 8834, 1),
+    (0x2FA06, false, 8835, 1), (0x2FA07, false, 8836, 1), (0x2FA08, false, 8837, 1), (0x2FA09, false, 8838, 1), (0x2FA0A, false, 8839, 1),
+    (0x2FA0B, false, 8840, 1), (0x2FA0C, false, 8841, 1), (0x2FA0D, false, 8842, 1), (0x2FA0E, false, 8843, 1), (0x2FA0F, false, 8844, 1),
+    (0x2FA10, false, 8845, 1), (0x2FA11, false, 8846, 1), (0x2FA12, false, 8847, 1), (0x2FA13, false, 8848, 1), (0x2FA14, false, 8849, 1),
+    (0x2FA15, false, 8850, 1), (0x2FA16, false, 8851, 1), (0x2FA17, false, 8852, 1), (0x2FA18, false, 8853, 1), (0x2FA19, false, 8854, 1),
+    (0x2FA1A, false, 8855, 1), (0x2FA1B, false, 8856, 1), (0x2FA1C, false, 8857, 1), (0x2FA1D, false, 8858, 1),
+];
+
+/// The chars of all the decomposition mappings
+#[rustfmt::skip]
+pub(super) const DECOMPOSITION_DATA: &[u32] = &[
+    0x20, 0x20, 0x308, 0x61, 0x20, 0x304, 0x32, 0x33, 0x20, 0x301, 0x3BC, 0x20,
+    0x327, 0x31, 0x6F, 0x31, 0x2044, 0x34, 0x31, 0x2044, 0x32, 0x33, 0x2044, 0x34,
+    0x41, 0x300, 0x41, 0x301, 0x41, 0x302, 0x41, 0x303, 0x41, 0x308, 0x41, 0x30A,
+    0x43, 0x327, 0x45, 0x300, 0x45, 0x301, 0x45, 0x302, 0x45, 0x308, 0x49, 0x300,
+    0x49, 0x301, 0x49, 0x302, 0x49, 0x308, 0x4E, 0x303, 0x4F, 0x300, 0x4F, 0x301,
+    0x4F, 0x302, 0x4F, 0x303, 0x4F, 0x308, 0x55, 0x300, 0x55, 0x301, 0x55, 0x302,
+    0x55, 0x308, 0x59, 0x301, 0x61, 0x300, 0x61, 0x301, 0x61, 0x302, 0x61, 0x303,
+    0x61, 0x308, 0x61, 0x30A, 0x63, 0x327, 0x65, 0x300, 0x65, 0x301, 0x65, 0x302,
+    0x65, 0x308, 0x69, 0x300, 0x69, 0x301, 0x69, 0x302, 0x69, 0x308, 0x6E, 0x303,
+    0x6F, 0x300, 0x6F, 0x301, 0x6F, 0x302, 0x6F, 0x303, 0x6F, 0x308, 0x75, 0x300,
+    0x75, 0x301, 0x75, 0x302, 0x75, 0x308, 0x79, 0x301, 0x79, 0x308, 0x41, 0x304,
+    0x61, 0x304, 0x41, 0x306, 0x61, 0x306, 0x41, 0x328, 0x61, 0x328, 0x43, 0x301,
+    0x63, 0x301, 0x43, 0x302, 0x63, 0x302, 0x43, 0x307, 0x63, 0x307, 0x43, 0x30C,
+    0x63, 0x30C, 0x44, 0x30C, 0x64, 0x30C, 0x45, 0x304, 0x65, 0x304, 0x45, 0x306,
+    0x65, 0x306, 0x45, 0x307, 0x65, 0x307, 0x45, 0x328, 0x65, 0x328, 0x45, 0x30C,
+    0x65, 0x30C, 0x47, 0x302, 0x67, 0x302, 0x47, 0x306, 0x67, 0x306, 0x47, 0x307,
+    0x67, 0x307, 0x47, 0x327, 0x67, 0x327, 0x48, 0x302, 0x68, 0x302, 0x49, 0x303,
+    0x69, 0x303, 0x49, 0x304, 0x69, 0x304, 0x49, 0x306, 0x69, 0x306, 0x49, 0x328,
+    0x69, 0x328, 0x49, 0x307, 0x49, 0x4A, 0x69, 0x6A, 0x4A, 0x302, 0x6A, 0x302,
+    0x4B, 0x327, 0x6B, 0x327, 0x4C, 0x301, 0x6C, 0x301, 0x4C, 0x327, 0x6C, 0x327,
+    0x4C, 0x30C, 0x6C, 0x30C, 0x4C, 0xB7, 0x6C, 0xB7, 0x4E, 0x301, 0x6E, 0x301,
+    0x4E, 0x327, 0x6E, 0x327, 0x4E, 0x30C, 0x6E, 0x30C, 0x2BC, 0x6E, 0x4F, 0x304,
+    0x6F, 0x304, 0x4F, 0x306, 0x6F, 0x306, 0x4F, 0x30B, 0x6F, 0x30B, 0x52, 0x301,
+    0x72, 0x301, 0x52, 0x327, 0x72, 0x327, 0x52, 0x30C, 0x72, 0x30C, 0x53, 0x301,
+    0x73, 0x301, 0x53, 0x302, 0x73, 0x302, 0x53, 0x327, 0x73, 0x327, 0x53, 0x30C,
+    0x73, 0x30C, 0x54, 0x327, 0x74, 0x327, 0x54, 0x30C, 0x74, 0x30C, 0x55, 0x303,
+    0x75, 0x303, 0x55, 0x304, 0x75, 0x304, 0x55, 0x306, 0x75, 0x306, 0x55, 0x30A,
+    0x75, 0x30A, 0x55, 0x30B, 0x75, 0x30B, 0x55, 0x328, 0x75, 0x328, 0x57, 0x302,
+    0x77, 0x302, 0x59, 0x302, 0x79, 0x302, 0x59, 0x308, 0x5A, 0x301, 0x7A, 0x301,
+    0x5A, 0x307, 0x7A, 0x307, 0x5A, 0x30C, 0x7A, 0x30C, 0x73, 0x4F, 0x31B, 0x6F,
+    0x31B, 0x55, 0x31B, 0x75, 0x31B, 0x44, 0x5A, 0x30C, 0x44, 0x7A, 0x30C, 0x64,
+    0x7A, 0x30C, 0x4C, 0x4A, 0x4C, 0x6A, 0x6C, 0x6A, 0x4E, 0x4A, 0x4E, 0x6A,
+    0x6E, 0x6A, 0x41, 0x30C, 0x61, 0x30C, 0x49, 0x30C, 0x69, 0x30C, 0x4F, 0x30C,
+    0x6F, 0x30C, 0x55, 0x30C, 0x75, 0x30C, 0xDC, 0x304, 0xFC, 0x304, 0xDC, 0x301,
+    0xFC, 0x301, 0xDC, 0x30C, 0xFC, 0x30C, 0xDC, 0x300, 0xFC, 0x300, 0xC4, 0x304,
+    0xE4, 0x304, 0x226, 0x304, 0x227, 0x304, 0xC6, 0x304, 0xE6, 0x304, 0x47, 0x30C,
+    0x67, 0x30C, 0x4B, 0x30C, 0x6B, 0x30C, 0x4F, 0x328, 0x6F, 0x328, 0x1EA, 0x304,
+    0x1EB, 0x304, 0x1B7, 0x30C, 0x292, 0x30C, 0x6A, 0x30C, 0x44, 0x5A, 0x44, 0x7A,
+    0x64, 0x7A, 0x47, 0x301, 0x67, 0x301, 0x4E, 0x300, 0x6E, 0x300, 0xC5, 0x301,
+    0xE5, 0x301, 0xC6, 0x301, 0xE6, 0x301, 0xD8, 0x301, 0xF8, 0x301, 0x41, 0x30F,
+    0x61, 0x30F, 0x41, 0x311, 0x61, 0x311, 0x45, 0x30F, 0x65, 0x30F, 0x45, 0x311,
+    0x65, 0x311, 0x49, 0x30F, 0x69, 0x30F, 0x49, 0x311, 0x69, 0x311, 0x4F, 0x30F,
+    0x6F, 0x30F, 0x4F, 0x311, 0x6F, 0x311, 0x52, 0x30F, 0x72, 0x30F, 0x52, 0x311,
+    0x72, 0x311, 0x55, 0x30F, 0x75, 0x30F, 0x55, 0x311, 0x75, 0x311, 0x53, 0x326,
+    0x73, 0x326, 0x54, 0x326, 0x74, 0x326, 0x48, 0x30C, 0x68, 0x30C, 0x41, 0x307,
+    0x61, 0x307, 0x45, 0x327, 0x65, 0x327, 0xD6, 0x304, 0xF6, 0x304, 0xD5, 0x304,
+    0xF5, 0x304, 0x4F, 0x307, 0x6F, 0x307, 0x22E, 0x304, 0x22F, 0x304, 0x59, 0x304,
+    0x79, 0x304, 0x68, 0x266, 0x6A, 0x72, 0x279, 0x27B, 0x281, 0x77, 0x79, 0x20,
+    0x306, 0x20, 0x307, 0x20, 0x30A, 0x20, 0x328, 0x20, 0x303, 0x20, 0x30B, 0x263,
+    0x6C, 0x73, 0x78, 0x295, 0x300, 0x301, 0x313, 0x308, 0x301, 0x2B9, 0x20, 0x345,
+    0x3B, 0x20, 0x301, 0xA8, 0x301, 0x391, 0x301, 0xB7, 0x395, 0x301, 0x397, 0x301,
+    0x399, 0x301, 0x39F, 0x301, 0x3A5, 0x301, 0x3A9, 0x301, 0x3CA, 0x301, 0x399, 0x308,
+    0x3A5, 0x308, 0x3B1, 0x301, 0x3B5, 0x301, 0x3B7, 0x301, 0x3B9, 0x301, 0x3CB, 0x301,
+    0x3B9, 0x308, 0x3C5, 0x308, 0x3BF, 0x301, 0x3C5, 0x301, 0x3C9, 0x301, 0x3B2, 0x3B8,
+    0x3A5, 0x3D2, 0x301, 0x3D2, 0x308, 0x3C6, 0x3C0, 0x3BA, 0x3C1, 0x3C2, 0x398, 0x3B5,
+    0x3A3, 0x415, 0x300, 0x415, 0x308, 0x413, 0x301, 0x406, 0x308, 0x41A, 0x301, 0x418,
+    0x300, 0x423, 0x306, 0x418, 0x306, 0x438, 0x306, 0x435, 0x300, 0x435, 0x308, 0x433,
+    0x301, 0x456, 0x308, 0x43A, 0x301, 0x438, 0x300, 0x443, 0x306, 0x474, 0x30F, 0x475,
+    0x30F, 0x416, 0x306, 0x436, 0x306, 0x410, 0x306, 0x430, 0x306, 0x410, 0x308, 0x430,
+    0x308, 0x415, 0x306, 0x435, 0x306, 0x4D8, 0x308, 0x4D9, 0x308, 0x416, 0x308, 0x436,
+    0x308, 0x417, 0x308, 0x437, 0x308, 0x418, 0x304, 0x438, 0x304, 0x418, 0x308, 0x438,
+    0x308, 0x41E, 0x308, 0x43E, 0x308, 0x4E8, 0x308, 0x4E9, 0x308, 0x42D, 0x308, 0x44D,
+    0x308, 0x423, 0x304, 0x443, 0x304, 0x423, 0x308, 0x443, 0x308, 0x423, 0x30B, 0x443,
+    0x30B, 0x427, 0x308, 0x447, 0x308, 0x42B, 0x308, 0x44B, 0x308, 0x565, 0x582, 0x627,
+    0x653, 0x627, 0x654, 0x648, 0x654, 0x627, 0x655, 0x64A, 0x654, 0x627, 0x674, 0x648,
+    0x674, 0x6C7, 0x674, 0x64A, 0x674, 0x6D5, 0x654, 0x6C1, 0x654, 0x6D2, 0x654, 0x928,
+    0x93C, 0x930, 0x93C, 0x933, 0x93C, 0x915, 0x93C, 0x916, 0x93C, 0x917, 0x93C, 0x91C,
+    0x93C, 0x921, 0x93C, 0x922, 0x93C, 0x92B, 0x93C, 0x92F, 0x93C, 0x9C7, 0x9BE, 0x9C7,
+    0x9D7, 0x9A1, 0x9BC, 0x9A2, 0x9BC, 0x9AF, 0x9BC, 0xA32, 0xA3C, 0xA38, 0xA3C, 0xA16,
+    0xA3C, 0xA17, 0xA3C, 0xA1C, 0xA3C, 0xA2B, 0xA3C, 0xB47, 0xB56, 0xB47, 0xB3E, 0xB47,
+    0xB57, 0xB21, 0xB3C, 0xB22, 0xB3C, 0xB92, 0xBD7, 0xBC6, 0xBBE, 0xBC7, 0xBBE, 0xBC6,
+    0xBD7, 0xC46, 0xC56, 0xCBF, 0xCD5, 0xCC6, 0xCD5, 0xCC6, 0xCD6, 0xCC6, 0xCC2, 0xCCA,
+    0xCD5, 0xD46, 0xD3E, 0xD47, 0xD3E, 0xD46, 0xD57, 0xDD9, 0xDCA, 0xDD9, 0xDCF, 0xDDC,
+    0xDCA, 0xDD9, 0xDDF, 0xE4D, 0xE32, 0xECD, 0xEB2, 0xEAB, 0xE99, 0xEAB, 0xEA1, 0xF0B,
+    0xF42, 0xFB7, 0xF4C, 0xFB7, 0xF51, 0xFB7, 0xF56, 0xFB7, 0xF5B, 0xFB7, 0xF40, 0xFB5,
+    0xF71, 0xF72, 0xF71, 0xF74, 0xFB2, 0xF80, 0xFB2, 0xF71, 0xF80, 0xFB3, 0xF80, 0xFB3,
+    0xF71, 0xF80, 0xF71, 0xF80, 0xF92, 0xFB7, 0xF9C, 0xFB7, 0xFA1, 0xFB7, 0xFA6, 0xFB7,
+    0xFAB, 0xFB7, 0xF90, 0xFB5, 0x1025, 0x102E, 0x10DC, 0x1B05, 0x1B35, 0x1B07, 0x1B35, 0x1B09,
+    0x1B35, 0x1B0B, 0x1B35, 0x1B0D, 0x1B35, 0x1B11, 0x1B35, 0x1B3A, 0x1B35, 0x1B3C, 0x1B35, 0x1B3E,
+    0x1B35, 0x1B3F, 0x1B35, 0x1B42, 0x1B35, 0x41, 0xC6, 0x42, 0x44, 0x45, 0x18E, 0x47,
+    0x48, 0x49, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x222, 0x50, 0x52, 0x54,
+    0x55, 0x57, 0x61, 0x250, 0x251, 0x1D02, 0x62, 0x64, 0x65, 0x259, 0x25B, 0x25C,
+    0x67, 0x6B, 0x6D, 0x14B, 0x6F, 0x254, 0x1D16, 0x1D17, 0x70, 0x74, 0x75, 0x1D1D,
+    0x26F, 0x76, 0x1D25, 0x3B2, 0x3B3, 0x3B4, 0x3C6, 0x3C7, 0x69, 0x72, 0x75, 0x76,
+    0x3B2, 0x3B3, 0x3C1, 0x3C6, 0x3C7, 0x43D, 0x252, 0x63, 0x255, 0xF0, 0x25C, 0x66,
+    0x25F, 0x261, 0x265, 0x268, 0x269, 0x26A, 0x1D7B, 0x29D, 0x26D, 0x1D85, 0x29F, 0x271,
+    0x270, 0x272, 0x273, 0x274, 0x275, 0x278, 0x282, 0x283, 0x1AB, 0x289, 0x28A, 0x1D1C,
+    0x28B, 0x28C, 0x7A, 0x290, 0x291, 0x292, 0x3B8, 0x41, 0x325, 0x61, 0x325, 0x42,
+    0x307, 0x62, 0x307, 0x42, 0x323, 0x62, 0x323, 0x42, 0x331, 0x62, 0x331, 0xC7,
+    0x301, 0xE7, 0x301, 0x44, 0x307, 0x64, 0x307, 0x44, 0x323, 0x64, 0x323, 0x44,
+    0x331, 0x64, 0x331, 0x44, 0x327, 0x64, 0x327, 0x44, 0x32D, 0x64, 0x32D, 0x112,
+    0x300, 0x113, 0x300, 0x112, 0x301, 0x113, 0x301, 0x45, 0x32D, 0x65, 0x32D, 0x45,
+    0x330, 0x65, 0x330, 0x228, 0x306, 0x229, 0x306, 0x46, 0x307, 0x66, 0x307, 0x47,
+    0x304, 0x67, 0x304, 0x48, 0x307, 0x68, 0x307, 0x48, 0x323, 0x68, 0x323, 0x48,
+    0x308, 0x68, 0x308, 0x48, 0x327, 0x68, 0x327, 0x48, 0x32E, 0x68, 0x32E, 0x49,
+    0x330, 0x69, 0x330, 0xCF, 0x301, 0xEF, 0x301, 0x4B, 0x301, 0x6B, 0x301, 0x4B,
+    0x323, 0x6B, 0x323, 0x4B, 0x331, 0x6B, 0x331, 0x4C, 0x323, 0x6C, 0x323, 0x1E36,
+    0x304, 0x1E37, 0x304, 0x4C, 0x331, 0x6C, 0x331, 0x4C, 0x32D, 0x6C, 0x32D, 0x4D,
+    0x301, 0x6D, 0x301, 0x4D, 0x307, 0x6D, 0x307, 0x4D, 0x323, 0x6D, 0x323, 0x4E,
+    0x307, 0x6E, 0x307, 0x4E, 0x323, 0x6E, 0x323, 0x4E, 0x331, 0x6E, 0x331, 0x4E,
+    0x32D, 0x6E, 0x32D, 0xD5, 0x301, 0xF5, 0x301, 0xD5, 0x308, 0xF5, 0x308, 0x14C,
+    0x300, 0x14D, 0x300, 0x14C, 0x301, 0x14D, 0x301, 0x50, 0x301, 0x70, 0x301, 0x50,
+    0x307, 0x70, 0x307, 0x52, 0x307, 0x72, 0x307, 0x52, 0x323, 0x72, 0x323, 0x1E5A,
+    0x304, 0x1E5B, 0x304, 0x52, 0x331, 0x72, 0x331, 0x53, 0x307, 0x73, 0x307, 0x53,
+    0x323, 0x73, 0x323, 0x15A, 0x307, 0x15B, 0x307, 0x160, 0x307, 0x161, 0x307, 0x1E62,
+    0x307, 0x1E63, 0x307, 0x54, 0x307, 0x74, 0x307, 0x54, 0x323, 0x74, 0x323, 0x54,
+    0x331, 0x74, 0x331, 0x54, 0x32D, 0x74, 0x32D, 0x55, 0x324, 0x75, 0x324, 0x55,
+    0x330, 0x75, 0x330, 0x55, 0x32D, 0x75, 0x32D, 0x168, 0x301, 0x169, 0x301, 0x16A,
+    0x308, 0x16B, 0x308, 0x56, 0x303, 0x76, 0x303, 0x56, 0x323, 0x76, 0x323, 0x57,
+    0x300, 0x77, 0x300, 0x57, 0x301, 0x77, 0x301, 0x57, 0x308, 0x77, 0x308, 0x57,
+    0x307, 0x77, 0x307, 0x57, 0x323, 0x77, 0x323, 0x58, 0x307, 0x78, 0x307, 0x58,
+    0x308, 0x78, 0x308, 0x59, 0x307, 0x79, 0x307, 0x5A, 0x302, 0x7A, 0x302, 0x5A,
+    0x323, 0x7A, 0x323, 0x5A, 0x331, 0x7A, 0x331, 0x68, 0x331, 0x74, 0x308, 0x77,
+    0x30A, 0x79, 0x30A, 0x61, 0x2BE, 0x17F, 0x307, 0x41, 0x323, 0x61, 0x323, 0x41,
+    0x309, 0x61, 0x309, 0xC2, 0x301, 0xE2, 0x301, 0xC2, 0x300, 0xE2, 0x300, 0xC2,
+    0x309, 0xE2, 0x309, 0xC2, 0x303, 0xE2, 0x303, 0x1EA0, 0x302, 0x1EA1, 0x302, 0x102,
+    0x301, 0x103, 0x301, 0x102, 0x300, 0x103, 0x300, 0x102, 0x309, 0x103, 0x309, 0x102,
+    0x303, 0x103, 0x303, 0x1EA0, 0x306, 0x1EA1, 0x306, 0x45, 0x323, 0x65, 0x323, 0x45,
+    0x309, 0x65, 0x309, 0x45, 0x303, 0x65, 0x303, 0xCA, 0x301, 0xEA, 0x301, 0xCA,
+    0x300, 0xEA, 0x300, 0xCA, 0x309, 0xEA, 0x309, 0xCA, 0x303, 0xEA, 0x303, 0x1EB8,
+    0x302, 0x1EB9, 0x302, 0x49, 0x309, 0x69, 0x309, 0x49, 0x323, 0x69, 0x323, 0x4F,
+    0x323, 0x6F, 0x323, 0x4F, 0x309, 0x6F, 0x309, 0xD4, 0x301, 0xF4, 0x301, 0xD4,
+    0x300, 0xF4, 0x300, 0xD4, 0x309, 0xF4, 0x309, 0xD4, 0x303, 0xF4, 0x303, 0x1ECC,
+    0x302, 0x1ECD, 0x302, 0x1A0, 0x301, 0x1A1, 0x301, 0x1A0, 0x300, 0x1A1, 0x300, 0x1A0,
+    0x309, 0x1A1, 0x309, 0x1A0, 0x303, 0x1A1, 0x303, 0x1A0, 0x323, 0x1A1, 0x323, 0x55,
+    0x323, 0x75, 0x323, 0x55, 0x309, 0x75, 0x309, 0x1AF, 0x301, 0x1B0, 0x301, 0x1AF,
+    0x300, 0x1B0, 0x300, 0x1AF, 0x309, 0x1B0, 0x309, 0x1AF, 0x303, 0x1B0, 0x303, 0x1AF,
+    0x323, 0x1B0, 0x323, 0x59, 0x300, 0x79, 0x300, 0x59, 0x323, 0x79, 0x323, 0x59,
+    0x309, 0x79, 0x309, 0x59, 0x303, 0x79, 0x303, 0x3B1, 0x313, 0x3B1, 0x314, 0x1F00,
+    0x300, 0x1F01, 0x300, 0x1F00, 0x301, 0x1F01, 0x301, 0x1F00, 0x342, 0x1F01, 0x342, 0x391,
+    0x313, 0x391, 0x314, 0x1F08, 0x300, 0x1F09, 0x300, 0x1F08, 0x301, 0x1F09, 0x301, 0x1F08,
+    0x342, 0x1F09, 0x342, 0x3B5, 0x313, 0x3B5, 0x314, 0x1F10, 0x300, 0x1F11, 0x300, 0x1F10,
+    0x301, 0x1F11, 0x301, 0x395, 0x313, 0x395, 0x314, 0x1F18, 0x300, 0x1F19, 0x300, 0x1F18,
+    0x301, 0x1F19, 0x301, 0x3B7, 0x313, 0x3B7, 0x314, 0x1F20, 0x300, 0x1F21, 0x300, 0x1F20,
+    0x301, 0x1F21, 0x301, 0x1F20, 0x342, 0x1F21, 0x342, 0x397, 0x313, 0x397, 0x314, 0x1F28,
+    0x300, 0x1F29, 0x300, 0x1F28, 0x301, 0x1F29, 0x301, 0x1F28, 0x342, 0x1F29, 0x342, 0x3B9,
+    0x313, 0x3B9, 0x314, 0x1F30, 0x300, 0x1F31, 0x300, 0x1F30, 0x301, 0x1F31, 0x301, 0x1F30,
+    0x342, 0x1F31, 0x342, 0x399, 0x313, 0x399, 0x314, 0x1F38, 0x300, 0x1F39, 0x300, 0x1F38,
+    0x301, 0x1F39, 0x301, 0x1F38, 0x342, 0x1F39, 0x342, 0x3BF, 0x313, 0x3BF, 0x314, 0x1F40,
+    0x300, 0x1F41, 0x300, 0x1F40, 0x301, 0x1F41, 0x301, 0x39F, 0x313, 0x39F, 0x314, 0x1F48,
+    0x300, 0x1F49, 0x300, 0x1F48, 0x301, 0x1F49, 0x301, 0x3C5, 0x313, 0x3C5, 0x314, 0x1F50,
+    0x300, 0x1F51, 0x300, 0x1F50, 0x301, 0x1F51, 0x301, 0x1F50, 0x342, 0x1F51, 0x342, 0x3A5,
+    0x314, 0x1F59, 0x300, 0x1F59, 0x301, 0x1F59, 0x342, 0x3C9, 0x313, 0x3C9, 0x314, 0x1F60,
+    0x300, 0x1F61, 0x300, 0x1F60, 0x301, 0x1F61, 0x301, 0x1F60, 0x342, 0x1F61, 0x342, 0x3A9,
+    0x313, 0x3A9, 0x314, 0x1F68, 0x300, 0x1F69, 0x300, 0x1F68, 0x301, 0x1F69, 0x301, 0x1F68,
+    0x342, 0x1F69, 0x342, 0x3B1, 0x300, 0x3AC, 0x3B5, 0x300, 0x3AD, 0x3B7, 0x300, 0x3AE,
+    0x3B9, 0x300, 0x3AF, 0x3BF, 0x300, 0x3CC, 0x3C5, 0x300, 0x3CD, 0x3C9, 0x300, 0x3CE,
+    0x1F00, 0x345, 0x1F01, 0x345, 0x1F02, 0x345, 0x1F03, 0x345, 0x1F04, 0x345, 0x1F05, 0x345,
+    0x1F06, 0x345, 0x1F07, 0x345, 0x1F08, 0x345, 0x1F09, 0x345, 0x1F0A, 0x345, 0x1F0B, 0x345,
+    0x1F0C, 0x345, 0x1F0D, 0x345, 0x1F0E, 0x345, 0x1F0F, 0x345, 0x1F20, 0x345, 0x1F21, 0x345,
+    0x1F22, 0x345, 0x1F23, 0x345, 0x1F24, 0x345, 0x1F25, 0x345, 0x1F26, 0x345, 0x1F27, 0x345,
+    0x1F28, 0x345, 0x1F29, 0x345, 0x1F2A, 0x345, 0x1F2B, 0x345, 0x1F2C, 0x345, 0x1F2D, 0x345,
+    0x1F2E, 0x345, 0x1F2F, 0x345, 0x1F60, 0x345, 0x1F61, 0x345, 0x1F62, 0x345, 0x1F63, 0x345,
+    0x1F64, 0x345, 0x1F65, 0x345, 0x1F66, 0x345, 0x1F67, 0x345, 0x1F68, 0x345, 0x1F69, 0x345,
+    0x1F6A, 0x345, 0x1F6B, 0x345, 0x1F6C, 0x345, 0x1F6D, 0x345, 0x1F6E, 0x345, 0x1F6F, 0x345,
+    0x3B1, 0x306, 0x3B1, 0x304, 0x1F70, 0x345, 0x3B1, 0x345, 0x3AC, 0x345, 0x3B1, 0x342,
+    0x1FB6, 0x345, 0x391, 0x306, 0x391, 0x304, 0x391, 0x300, 0x386, 0x391, 0x345, 0x20,
+    0x313, 0x3B9, 0x20, 0x313, 0x20, 0x342, 0xA8, 0x342, 0x1F74, 0x345, 0x3B7, 0x345,
+    0x3AE, 0x345, 0x3B7, 0x342, 0x1FC6, 0x345, 0x395, 0x300, 0x388, 0x397, 0x300, 0x389,
+    0x397, 0x345, 0x1FBF, 0x300, 0x1FBF, 0x301, 0x1FBF, 0x342, 0x3B9, 0x306, 0x3B9, 0x304,
+    0x3CA, 0x300, 0x390, 0x3B9, 0x342, 0x3CA, 0x342, 0x399, 0x306, 0x399, 0x304, 0x399,
+    0x300, 0x38A, 0x1FFE, 0x300, 0x1FFE, 0x301, 0x1FFE, 0x342, 0x3C5, 0x306, 0x3C5, 0x304,
+    0x3CB, 0x300, 0x3B0, 0x3C1, 0x313, 0x3C1, 0x314, 0x3C5, 0x342, 0x3CB, 0x342, 0x3A5,
+    0x306, 0x3A5, 0x304, 0x3A5, 0x300, 0x38E, 0x3A1, 0x314, 0xA8, 0x300, 0x385, 0x60,
+    0x1F7C, 0x345, 0x3C9, 0x345, 0x3CE, 0x345, 0x3C9, 0x342, 0x1FF6, 0x345, 0x39F, 0x300,
+    0x38C, 0x3A9, 0x300, 0x38F, 0x3A9, 0x345, 0xB4, 0x20, 0x314, 0x2002, 0x2003, 0x20,
+    0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x2010, 0x20, 0x333, 0x2E,
+    0x2E, 0x2E, 0x2E, 0x2E, 0x2E, 0x20, 0x2032, 0x2032, 0x2032, 0x2032, 0x2032, 0x2035,
+    0x2035, 0x2035, 0x2035, 0x2035, 0x21, 0x21, 0x20, 0x305, 0x3F, 0x3F, 0x3F, 0x21,
+    0x21, 0x3F, 0x2032, 0x2032, 0x2032, 0x2032, 0x20, 0x30, 0x69, 0x34, 0x35, 0x36,
+    0x37, 0x38, 0x39, 0x2B, 0x2212, 0x3D, 0x28, 0x29, 0x6E, 0x30, 0x31, 0x32,
+    0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x2B, 0x2212, 0x3D, 0x28, 0x29,
+    0x61, 0x65, 0x6F, 0x78, 0x259, 0x68, 0x6B, 0x6C, 0x6D, 0x6E, 0x70, 0x73,
+    0x74, 0x52, 0x73, 0x61, 0x2F, 0x63, 0x61, 0x2F, 0x73, 0x43, 0xB0, 0x43,
+    0x63, 0x2F, 0x6F, 0x63, 0x2F, 0x75, 0x190, 0xB0, 0x46, 0x67, 0x48, 0x48,
+    0x48, 0x68, 0x127, 0x49, 0x49, 0x4C, 0x6C, 0x4E, 0x4E, 0x6F, 0x50, 0x51,
+    0x52, 0x52, 0x52, 0x53, 0x4D, 0x54, 0x45, 0x4C, 0x54, 0x4D, 0x5A, 0x3A9,
+    0x5A, 0x4B, 0xC5, 0x42, 0x43, 0x65, 0x45, 0x46, 0x4D, 0x6F, 0x5D0, 0x5D1,
+    0x5D2, 0x5D3, 0x69, 0x46, 0x41, 0x58, 0x3C0, 0x3B3, 0x393, 0x3A0, 0x2211, 0x44,
+    0x64, 0x65, 0x69, 0x6A, 0x31, 0x2044, 0x37, 0x31, 0x2044, 0x39, 0x31, 0x2044,
+    0x31, 0x30, 0x31, 0x2044, 0x33, 0x32, 0x2044, 0x33, 0x31, 0x2044, 0x35, 0x32,
+    0x2044, 0x35, 0x33, 0x2044, 0x35, 0x34, 0x2044, 0x35, 0x31, 0x2044, 0x36, 0x35,
+    0x2044, 0x36, 0x31, 0x2044, 0x38, 0x33, 0x2044, 0x38, 0x35, 0x2044, 0x38, 0x37,
+    0x2044, 0x38, 0x31, 0x2044, 0x49, 0x49, 0x49, 0x49, 0x49, 0x49, 0x49, 0x56,
+    0x56, 0x56, 0x49, 0x56, 0x49, 0x49, 0x56, 0x49, 0x49, 0x49, 0x49, 0x58,
+    0x58, 0x58, 0x49, 0x58, 0x49, 0x49, 0x4C, 0x43, 0x44, 0x4D, 0x69, 0x69,
+    0x69, 0x69, 0x69, 0x69, 0x69, 0x76, 0x76, 0x76, 0x69, 0x76, 0x69, 0x69,
+    0x76, 0x69, 0x69, 0x69, 0x69, 0x78, 0x78, 0x78, 0x69, 0x78, 0x69, 0x69,
+    0x6C, 0x63, 0x64, 0x6D, 0x30, 0x2044, 0x33, 0x2190, 0x338, 0x2192, 0x338, 0x2194,
+    0x338, 0x21D0, 0x338, 0x21D4, 0x338, 0x21D2, 0x338, 0x2203, 0x338, 0x2208, 0x338, 0x220B,
+    0x338, 0x2223, 0x338, 0x2225, 0x338, 0x222B, 0x222B, 0x222B, 0x222B, 0x222B, 0x222E, 0x222E,
+    0x222E, 0x222E, 0x222E, 0x223C, 0x338, 0x2243, 0x338, 0x2245, 0x338, 0x2248, 0x338, 0x3D,
+    0x338, 0x2261, 0x338, 0x224D, 0x338, 0x3C, 0x338, 0x3E, 0x338, 0x2264, 0x338, 0x2265,
+    0x338, 0x2272, 0x338, 0x2273, 0x338, 0x2276, 0x338, 0x2277, 0x338, 0x227A, 0x338, 0x227B,
+    0x338, 0x2282, 0x338, 0x2283, 0x338, 0x2286, 0x338, 0x2287, 0x338, 0x22A2, 0x338, 0x22A8,
+    0x338, 0x22A9, 0x338, 0x22AB, 0x338, 0x227C, 0x338, 0x227D, 0x338, 0x2291, 0x338, 0x2292,
+    0x338, 0x22B2, 0x338, 0x22B3, 0x338, 0x22B4, 0x338, 0x22B5, 0x338, 0x3008, 0x3009, 0x31,
+    0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x31, 0x30, 0x31, 0x31,
+    0x31, 0x32, 0x31, 0x33, 0x31, 0x34, 0x31, 0x35, 0x31, 0x36, 0x31, 0x37,
+    0x31, 0x38, 0x31, 0x39, 0x32, 0x30, 0x28, 0x31, 0x29, 0x28, 0x32, 0x29,
+    0x28, 0x33, 0x29, 0x28, 0x34, 0x29, 0x28, 0x35, 0x29, 0x28, 0x36, 0x29,
+    0x28, 0x37, 0x29, 0x28, 0x38, 0x29, 0x28, 0x39, 0x29, 0x28, 0x31, 0x30,
+    0x29, 0x28, 0x31, 0x31, 0x29, 0x28, 0x31, 0x32, 0x29, 0x28, 0x31, 0x33,
+    0x29, 0x28, 0x31, 0x34, 0x29, 0x28, 0x31, 0x35, 0x29, 0x28, 0x31, 0x36,
+    0x29, 0x28, 0x31, 0x37, 0x29, 0x28, 0x31, 0x38, 0x29, 0x28, 0x31, 0x39,
+    0x29, 0x28, 0x32, 0x30, 0x29, 0x31, 0x2E, 0x32, 0x2E, 0x33, 0x2E, 0x34,
+    0x2E, 0x35, 0x2E, 0x36, 0x2E, 0x37, 0x2E, 0x38, 0x2E, 0x39, 0x2E, 0x31,
+    0x30, 0x2E, 0x31, 0x31, 0x2E, 0x31, 0x32, 0x2E, 0x31, 0x33, 0x2E, 0x31,
+    0x34, 0x2E, 0x31, 0x35, 0x2E, 0x31, 0x36, 0x2E, 0x31, 0x37, 0x2E, 0x31,
+    0x38, 0x2E, 0x31, 0x39, 0x2E, 0x32, 0x30, 0x2E, 0x28, 0x61, 0x29, 0x28,
+    0x62, 0x29, 0x28, 0x63, 0x29, 0x28, 0x64, 0x29, 0x28, 0x65, 0x29, 0x28,
+    0x66, 0x29, 0x28, 0x67, 0x29, 0x28, 0x68, 0x29, 0x28, 0x69, 0x29, 0x28,
+    0x6A, 0x29, 0x28, 0x6B, 0x29, 0x28, 0x6C, 0x29, 0x28, 0x6D, 0x29, 0x28,
+    0x6E, 0x29, 0x28, 0x6F, 0x29, 0x28, 0x70, 0x29, 0x28, 0x71, 0x29, 0x28,
+    0x72, 0x29, 0x28, 0x73, 0x29, 0x28, 0x74, 0x29, 0x28, 0x75, 0x29, 0x28,
+    0x76, 0x29, 0x28, 0x77, 0x29, 0x28, 0x78, 0x29, 0x28, 0x79, 0x29, 0x28,
+    0x7A, 0x29, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4A,
+    0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56,
+    0x57, 0x58, 0x59, 0x5A, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68,
+    0x69, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73, 0x74,
+    0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0x30, 0x222B, 0x222B, 0x222B, 0x222B, 0x3A,
+    0x3A, 0x3D, 0x3D, 0x3D, 0x3D, 0x3D, 0x3D, 0x2ADD, 0x338, 0x6A, 0x56, 0x2D61,
+    0x6BCD, 0x9F9F, 0x4E00, 0x4E28, 0x4E36, 0x4E3F, 0x4E59, 0x4E85, 0x4E8C, 0x4EA0, 0x4EBA, 0x513F,
+    0x5165, 0x516B, 0x5182, 0x5196, 0x51AB, 0x51E0, 0x51F5, 0x5200, 0x529B, 0x52F9, 0x5315, 0x531A,
+    0x5338, 0x5341, 0x535C, 0x5369, 0x5382, 0x53B6, 0x53C8, 0x53E3, 0x56D7, 0x571F, 0x58EB, 0x5902,
+    0x590A, 0x5915, 0x5927, 0x5973, 0x5B50, 0x5B80, 0x5BF8, 0x5C0F, 0x5C22, 0x5C38, 0x5C6E, 0x5C71,
+    0x5DDB, 0x5DE5, 0x5DF1, 0x5DFE, 0x5E72, 0x5E7A, 0x5E7F, 0x5EF4, 0x5EFE, 0x5F0B, 0x5F13, 0x5F50,
+    0x5F61, 0x5F73, 0x5FC3, 0x6208, 0x6236, 0x624B, 0x652F, 0x6534, 0x6587, 0x6597, 0x65A4, 0x65B9,
+    0x65E0, 0x65E5, 0x66F0, 0x6708, 0x6728, 0x6B20, 0x6B62, 0x6B79, 0x6BB3, 0x6BCB, 0x6BD4, 0x6BDB,
+    0x6C0F, 0x6C14, 0x6C34, 0x706B, 0x722A, 0x7236, 0x723B, 0x723F, 0x7247, 0x7259, 0x725B, 0x72AC,
+    0x7384, 0x7389, 0x74DC, 0x74E6, 0x7518, 0x751F, 0x7528, 0x7530, 0x758B, 0x7592, 0x7676, 0x767D,
+    0x76AE, 0x76BF, 0x76EE, 0x77DB, 0x77E2, 0x77F3, 0x793A, 0x79B8, 0x79BE, 0x7A74, 0x7ACB, 0x7AF9,
+    0x7C73, 0x7CF8, 0x7F36, 0x7F51, 0x7F8A, 0x7FBD, 0x8001, 0x800C, 0x8012, 0x8033, 0x807F, 0x8089,
+    0x81E3, 0x81EA, 0x81F3, 0x81FC, 0x820C, 0x821B, 0x821F, 0x826E, 0x8272, 0x8278, 0x864D, 0x866B,
+    0x8840, 0x884C, 0x8863, 0x897E, 0x898B, 0x89D2, 0x8A00, 0x8C37, 0x8C46, 0x8C55, 0x8C78, 0x8C9D,
+    0x8D64, 0x8D70, 0x8DB3, 0x8EAB, 0x8ECA, 0x8F9B, 0x8FB0, 0x8FB5, 0x9091, 0x9149, 0x91C6, 0x91CC,
+    0x91D1, 0x9577, 0x9580, 0x961C, 0x96B6, 0x96B9, 0x96E8, 0x9751, 0x975E, 0x9762, 0x9769, 0x97CB,
+    0x97ED, 0x97F3, 0x9801, 0x98A8, 0x98DB, 0x98DF, 0x9996, 0x9999, 0x99AC, 0x9AA8, 0x9AD8, 0x9ADF,
+    0x9B25, 0x9B2F, 0x9B32, 0x9B3C, 0x9B5A, 0x9CE5, 0x9E75, 0x9E7F, 0x9EA5, 0x9EBB, 0x9EC3, 0x9ECD,
+    0x9ED1, 0x9EF9, 0x9EFD, 0x9F0E, 0x9F13, 0x9F20, 0x9F3B, 0x9F4A, 0x9F52, 0x9F8D, 0x9F9C, 0x9FA0,
+    0x20, 0x3012, 0x5341, 0x5344, 0x5345, 0x304B, 0x3099, 0x304D, 0x3099, 0x304F, 0x3099, 0x3051,
+    0x3099, 0x3053, 0x3099, 0x3055, 0x3099, 0x3057, 0x3099, 0x3059, 0x3099, 0x305B, 0x3099, 0x305D,
+    0x3099, 0x305F, 0x3099, 0x3061, 0x3099, 0x3064, 0x3099, 0x3066, 0x3099, 0x3068, 0x3099, 0x306F,
+    0x3099, 0x306F, 0x309A, 0x3072, 0x3099, 0x3072, 0x309A, 0x3075, 0x3099, 0x3075, 0x309A, 0x3078,
+    0x3099, 0x3078, 0x309A, 0x307B, 0x3099, 0x307B, 0x309A, 0x3046, 0x3099, 0x20, 0x3099, 0x20,
+    0x309A, 0x309D, 0x3099, 0x3088, 0x308A, 0x30AB, 0x3099, 0x30AD, 0x3099, 0x30AF, 0x3099, 0x30B1,
+    0x3099, 0x30B3, 0x3099, 0x30B5, 0x3099, 0x30B7, 0x3099, 0x30B9, 0x3099, 0x30BB, 0x3099, 0x30BD,
+    0x3099, 0x30BF, 0x3099, 0x30C1, 0x3099, 0x30C4, 0x3099, 0x30C6, 0x3099, 0x30C8, 0x3099, 0x30CF,
+    0x3099, 0x30CF, 0x309A, 0x30D2, 0x3099, 0x30D2, 0x309A, 0x30D5, 0x3099, 0x30D5, 0x309A, 0x30D8,
+    0x3099, 0x30D8, 0x309A, 0x30DB, 0x3099, 0x30DB, 0x309A, 0x30A6, 0x3099, 0x30EF, 0x3099, 0x30F0,
+    0x3099, 0x30F1, 0x3099, 0x30F2, 0x3099, 0x30FD, 0x3099, 0x30B3, 0x30C8, 0x1100, 0x1101, 0x11AA,
+    0x1102, 0x11AC, 0x11AD, 0x1103, 0x1104, 0x1105, 0x11B0, 0x11B1, 0x11B2, 0x11B3, 0x11B4, 0x11B5,
+    0x111A, 0x1106, 0x1107, 0x1108, 0x1121, 0x1109, 0x110A, 0x110B, 0x110C, 0x110D, 0x110E, 0x110F,
+    0x1110, 0x1111, 0x1112, 0x1161, 0x1162, 0x1163, 0x1164, 0x1165, 0x1166, 0x1167, 0x1168, 0x1169,
+    0x116A, 0x116B, 0x116C, 0x116D, 0x116E, 0x116F, 0x1170, 0x1171, 0x1172, 0x1173, 0x1174, 0x1175,
+    0x1160, 0x1114, 0x1115, 0x11C7, 0x11C8, 0x11CC, 0x11CE, 0x11D3, 0x11D7, 0x11D9, 0x111C, 0x11DD,
+    0x11DF, 0x111D, 0x111E, 0x1120, 0x1122, 0x1123, 0x1127, 0x1129, 0x112B, 0x112C, 0x112D, 0x112E,
+    0x112F, 0x1132, 0x1136, 0x1140, 0x1147, 0x114C, 0x11F1, 0x11F2, 0x1157, 0x1158, 0x1159, 0x1184,
+    0x1185, 0x1188, 0x1191, 0x1192, 0x1194, 0x119E, 0x11A1, 0x4E00, 0x4E8C, 0x4E09, 0x56DB, 0x4E0A,
+    0x4E2D, 0x4E0B, 0x7532, 0x4E59, 0x4E19, 0x4E01, 0x5929, 0x5730, 0x4EBA, 0x28, 0x1100, 0x29,
+    0x28, 0x1102, 0x29, 0x28, 0x1103, 0x29, 0x28, 0x1105, 0x29, 0x28, 0x1106, 0x29,
+    0x28, 0x1107, 0x29, 0x28, 0x1109, 0x29, 0x28, 0x110B, 0x29, 0x28, 0x110C, 0x29,
+    0x28, 0x110E, 0x29, 0x28, 0x110F, 0x29, 0x28, 0x1110, 0x29, 0x28, 0x1111, 0x29,
+    0x28, 0x1112, 0x29, 0x28, 0x1100, 0x1161, 0x29, 0x28, 0x1102, 0x1161, 0x29, 0x28,
+    0x1103, 0x1161, 0x29, 0x28, 0x1105, 0x1161, 0x29, 0x28, 0x1106, 0x1161, 0x29, 0x28,
+    0x1107, 0x1161, 0x29, 0x28, 0x1109, 0x1161, 0x29, 0x28, 0x110B, 0x1161, 0x29, 0x28,
+    0x110C, 0x1161, 0x29, 0x28, 0x110E, 0x1161, 0x29, 0x28, 0x110F, 0x1161, 0x29, 0x28,
+    0x1110, 0x1161, 0x29, 0x28, 0x1111, 0x1161, 0x29, 0x28, 0x1112, 0x1161, 0x29, 0x28,
+    0x110C, 0x116E, 0x29, 0x28, 0x110B, 0x1169, 0x110C, 0x1165, 0x11AB, 0x29, 0x28, 0x110B,
+    0x1169, 0x1112, 0x116E, 0x29, 0x28, 0x4E00, 0x29, 0x28, 0x4E8C, 0x29, 0x28, 0x4E09,
+    0x29, 0x28, 0x56DB, 0x29, 0x28, 0x4E94, 0x29, 0x28, 0x516D, 0x29, 0x28, 0x4E03,
+    0x29, 0x28, 0x516B, 0x29, 0x28, 0x4E5D, 0x29, 0x28, 0x5341, 0x29, 0x28, 0x6708,
+    0x29, 0x28, 0x706B, 0x29, 0x28, 0x6C34, 0x29, 0x28, 0x6728, 0x29, 0x28, 0x91D1,
+    0x29, 0x28, 0x571F, 0x29, 0x28, 0x65E5, 0x29, 0x28, 0x682A, 0x29, 0x28, 0x6709,
+    0x29, 0x28, 0x793E, 0x29, 0x28, 0x540D, 0x29, 0x28, 0x7279, 0x29, 0x28, 0x8CA1,
+    0x29, 0x28, 0x795D, 0x29, 0x28, 0x52B4, 0x29, 0x28, 0x4EE3, 0x29, 0x28, 0x547C,
+    0x29, 0x28, 0x5B66, 0x29, 0x28, 0x76E3, 0x29, 0x28, 0x4F01, 0x29, 0x28, 0x8CC7,
+    0x29, 0x28, 0x5354, 0x29, 0x28, 0x796D, 0x29, 0x28, 0x4F11, 0x29, 0x28, 0x81EA,
+    0x29, 0x28, 0x81F3, 0x29, 0x554F, 0x5E7C, 0x6587, 0x7B8F, 0x50, 0x54, 0x45, 0x32,
+    0x31, 0x32, 0x32, 0x32, 0x33, 0x32, 0x34, 0x32, 0x35, 0x32, 0x36, 0x32,
+    0x37, 0x32, 0x38, 0x32, 0x39, 0x33, 0x30, 0x33, 0x31, 0x33, 0x32, 0x33,
+    0x33, 0x33, 0x34, 0x33, 0x35, 0x1100, 0x1102, 0x1103, 0x1105, 0x1106, 0x1107, 0x1109,
+    0x110B, 0x110C, 0x110E, 0x110F, 0x1110, 0x1111, 0x1112, 0x1100, 0x1161, 0x1102, 0x1161, 0x1103,
+    0x1161, 0x1105, 0x1161, 0x1106, 0x1161, 0x1107, 0x1161, 0x1109, 0x1161, 0x110B, 0x1161, 0x110C,
+    0x1161, 0x110E, 0x1161, 0x110F, 0x1161, 0x1110, 0x1161, 0x1111, 0x1161, 0x1112, 0x1161, 0x110E,
+    0x1161, 0x11B7, 0x1100, 0x1169, 0x110C, 0x116E, 0x110B, 0x1174, 0x110B, 0x116E, 0x4E00, 0x4E8C,
+    0x4E09, 0x56DB, 0x4E94, 0x516D, 0x4E03, 0x516B, 0x4E5D, 0x5341, 0x6708, 0x706B, 0x6C34, 0x6728,
+    0x91D1, 0x571F, 0x65E5, 0x682A, 0x6709, 0x793E, 0x540D, 0x7279, 0x8CA1, 0x795D, 0x52B4, 0x79D8,
+    0x7537, 0x5973, 0x9069, 0x512A, 0x5370, 0x6CE8, 0x9805, 0x4F11, 0x5199, 0x6B63, 0x4E0A, 0x4E2D,
+    0x4E0B, 0x5DE6, 0x53F3, 0x533B, 0x5B97, 0x5B66, 0x76E3, 0x4F01, 0x8CC7, 0x5354, 0x591C, 0x33,
+    0x36, 0x33, 0x37, 0x33, 0x38, 0x33, 0x39, 0x34, 0x30, 0x34, 0x31, 0x34,
+    0x32, 0x34, 0x33, 0x34, 0x34, 0x34, 0x35, 0x34, 0x36, 0x34, 0x37, 0x34,
+    0x38, 0x34, 0x39, 0x35, 0x30, 0x31, 0x6708, 0x32, 0x6708, 0x33, 0x6708, 0x34,
+    0x6708, 0x35, 0x6708, 0x36, 0x6708, 0x37, 0x6708, 0x38, 0x6708, 0x39, 0x6708, 0x31,
+    0x30, 0x6708, 0x31, 0x31, 0x6708, 0x31, 0x32, 0x6708, 0x48, 0x67, 0x65, 0x72,
+    0x67, 0x65, 0x56, 0x4C, 0x54, 0x44, 0x30A2, 0x30A4, 0x30A6, 0x30A8, 0x30AA, 0x30AB,
+    0x30AD, 0x30AF, 0x30B1, 0x30B3, 0x30B5, 0x30B7, 0x30B9, 0x30BB, 0x30BD, 0x30BF, 0x30C1, 0x30C4,
+    0x30C6, 0x30C8, 0x30CA, 0x30CB, 0x30CC, 0x30CD, 0x30CE, 0x30CF, 0x30D2, 0x30D5, 0x30D8, 0x30DB,
+    0x30DE, 0x30DF, 0x30E0, 0x30E1, 0x30E2, 0x30E4, 0x30E6, 0x30E8, 0x30E9, 0x30EA, 0x30EB, 0x30EC,
+    0x30ED, 0x30EF, 0x30F0, 0x30F1, 0x30F2, 0x4EE4, 0x548C, 0x30A2, 0x30CF, 0x309A, 0x30FC, 0x30C8,
+    0x30A2, 0x30EB, 0x30D5, 0x30A1, 0x30A2, 0x30F3, 0x30D8, 0x309A, 0x30A2, 0x30A2, 0x30FC, 0x30EB,
+    0x30A4, 0x30CB, 0x30F3, 0x30AF, 0x3099, 0x30A4, 0x30F3, 0x30C1, 0x30A6, 0x30A9, 0x30F3, 0x30A8,
+    0x30B9, 0x30AF, 0x30FC, 0x30C8, 0x3099, 0x30A8, 0x30FC, 0x30AB, 0x30FC, 0x30AA, 0x30F3, 0x30B9,
+    0x30AA, 0x30FC, 0x30E0, 0x30AB, 0x30A4, 0x30EA, 0x30AB, 0x30E9, 0x30C3, 0x30C8, 0x30AB, 0x30ED,
+    0x30EA, 0x30FC, 0x30AB, 0x3099, 0x30ED, 0x30F3, 0x30AB, 0x3099, 0x30F3, 0x30DE, 0x30AD, 0x3099,
+    0x30AB, 0x3099, 0x30AD, 0x3099, 0x30CB, 0x30FC, 0x30AD, 0x30E5, 0x30EA, 0x30FC, 0x30AD, 0x3099,
+    0x30EB, 0x30BF, 0x3099, 0x30FC, 0x30AD, 0x30ED, 0x30AD, 0x30ED, 0x30AF, 0x3099, 0x30E9, 0x30E0,
+    0x30AD, 0x30ED, 0x30E1, 0x30FC, 0x30C8, 0x30EB, 0x30AD, 0x30ED, 0x30EF, 0x30C3, 0x30C8, 0x30AF,
+    0x3099, 0x30E9, 0x30E0, 0x30AF, 0x3099, 0x30E9, 0x30E0, 0x30C8, 0x30F3, 0x30AF, 0x30EB, 0x30BB,
+    0x3099, 0x30A4, 0x30ED, 0x30AF, 0x30ED, 0x30FC, 0x30CD, 0x30B1, 0x30FC, 0x30B9, 0x30B3, 0x30EB,
+    0x30CA, 0x30B3, 0x30FC, 0x30DB, 0x309A, 0x30B5, 0x30A4, 0x30AF, 0x30EB, 0x30B5, 0x30F3, 0x30C1,
+    0x30FC, 0x30E0, 0x30B7, 0x30EA, 0x30F3, 0x30AF, 0x3099, 0x30BB, 0x30F3, 0x30C1, 0x30BB, 0x30F3,
+    0x30C8, 0x30BF, 0x3099, 0x30FC, 0x30B9, 0x30C6, 0x3099, 0x30B7, 0x30C8, 0x3099, 0x30EB, 0x30C8,
+    0x30F3, 0x30CA, 0x30CE, 0x30CE, 0x30C3, 0x30C8, 0x30CF, 0x30A4, 0x30C4, 0x30CF, 0x309A, 0x30FC,
+    0x30BB, 0x30F3, 0x30C8, 0x30CF, 0x309A, 0x30FC, 0x30C4, 0x30CF, 0x3099, 0x30FC, 0x30EC, 0x30EB,
+    0x30D2, 0x309A, 0x30A2, 0x30B9, 0x30C8, 0x30EB, 0x30D2, 0x309A, 0x30AF, 0x30EB, 0x30D2, 0x309A,
+    0x30B3, 0x30D2, 0x3099, 0x30EB, 0x30D5, 0x30A1, 0x30E9, 0x30C3, 0x30C8, 0x3099, 0x30D5, 0x30A3,
+    0x30FC, 0x30C8, 0x30D5, 0x3099, 0x30C3, 0x30B7, 0x30A7, 0x30EB, 0x30D5, 0x30E9, 0x30F3, 0x30D8,
+    0x30AF, 0x30BF, 0x30FC, 0x30EB, 0x30D8, 0x309A, 0x30BD, 0x30D8, 0x309A, 0x30CB, 0x30D2, 0x30D8,
+    0x30EB, 0x30C4, 0x30D8, 0x309A, 0x30F3, 0x30B9, 0x30D8, 0x309A, 0x30FC, 0x30B7, 0x3099, 0x30D8,
+    0x3099, 0x30FC, 0x30BF, 0x30DB, 0x309A, 0x30A4, 0x30F3, 0x30C8, 0x30DB, 0x3099, 0x30EB, 0x30C8,
+    0x30DB, 0x30F3, 0x30DB, 0x309A, 0x30F3, 0x30C8, 0x3099, 0x30DB, 0x30FC, 0x30EB, 0x30DB, 0x30FC,
+    0x30F3, 0x30DE, 0x30A4, 0x30AF, 0x30ED, 0x30DE, 0x30A4, 0x30EB, 0x30DE, 0x30C3, 0x30CF, 0x30DE,
+    0x30EB, 0x30AF, 0x30DE, 0x30F3, 0x30B7, 0x30E7, 0x30F3, 0x30DF, 0x30AF, 0x30ED, 0x30F3, 0x30DF,
+    0x30EA, 0x30DF, 0x30EA, 0x30CF, 0x3099, 0x30FC, 0x30EB, 0x30E1, 0x30AB, 0x3099, 0x30E1, 0x30AB,
+    0x3099, 0x30C8, 0x30F3, 0x30E1, 0x30FC, 0x30C8, 0x30EB, 0x30E4, 0x30FC, 0x30C8, 0x3099, 0x30E4,
+    0x30FC, 0x30EB, 0x30E6, 0x30A2, 0x30F3, 0x30EA, 0x30C3, 0x30C8, 0x30EB, 0x30EA, 0x30E9, 0x30EB,
+    0x30D2, 0x309A, 0x30FC, 0x30EB, 0x30FC, 0x30D5, 0x3099, 0x30EB, 0x30EC, 0x30E0, 0x30EC, 0x30F3,
+    0x30C8, 0x30B1, 0x3099, 0x30F3, 0x30EF, 0x30C3, 0x30C8, 0x30, 0x70B9, 0x31, 0x70B9, 0x32,
+    0x70B9, 0x33, 0x70B9, 0x34, 0x70B9, 0x35, 0x70B9, 0x36, 0x70B9, 0x37, 0x70B9, 0x38,
+    0x70B9, 0x39, 0x70B9, 0x31, 0x30, 0x70B9, 0x31, 0x31, 0x70B9, 0x31, 0x32, 0x70B9,
+    0x31, 0x33, 0x70B9, 0x31, 0x34, 0x70B9, 0x31, 0x35, 0x70B9, 0x31, 0x36, 0x70B9,
+    0x31, 0x37, 0x70B9, 0x31, 0x38, 0x70B9, 0x31, 0x39, 0x70B9, 0x32, 0x30, 0x70B9,
+    0x32, 0x31, 0x70B9, 0x32, 0x32, 0x70B9, 0x32, 0x33, 0x70B9, 0x32, 0x34, 0x70B9,
+    0x68, 0x50, 0x61, 0x64, 0x61, 0x41, 0x55, 0x62, 0x61, 0x72, 0x6F, 0x56,
+    0x70, 0x63, 0x64, 0x6D, 0x64, 0x6D, 0x32, 0x64, 0x6D, 0x33, 0x49, 0x55,
+    0x5E73, 0x6210, 0x662D, 0x548C, 0x5927, 0x6B63, 0x660E, 0x6CBB, 0x682A, 0x5F0F, 0x4F1A, 0x793E,
+    0x70, 0x41, 0x6E, 0x41, 0x3BC, 0x41, 0x6D, 0x41, 0x6B, 0x41, 0x4B, 0x42,
+    0x4D, 0x42, 0x47, 0x42, 0x63, 0x61, 0x6C, 0x6B, 0x63, 0x61, 0x6C, 0x70,
+    0x46, 0x6E, 0x46, 0x3BC, 0x46, 0x3BC, 0x67, 0x6D, 0x67, 0x6B, 0x67, 0x48,
+    0x7A, 0x6B, 0x48, 0x7A, 0x4D, 0x48, 0x7A, 0x47, 0x48, 0x7A, 0x54, 0x48,
+    0x7A, 0x3BC, 0x6C, 0x6D, 0x6C, 0x64, 0x6C, 0x6B, 0x6C, 0x66, 0x6D, 0x6E,
+    0x6D, 0x3BC, 0x6D, 0x6D, 0x6D, 0x63, 0x6D, 0x6B, 0x6D, 0x6D, 0x6D, 0x32,
+    0x63, 0x6D, 0x32, 0x6D, 0x32, 0x6B, 0x6D, 0x32, 0x6D, 0x6D, 0x33, 0x63,
+    0x6D, 0x33, 0x6D, 0x33, 0x6B, 0x6D, 0x33, 0x6D, 0x2215, 0x73, 0x6D, 0x2215,
+    0x73, 0x32, 0x50, 0x61, 0x6B, 0x50, 0x61, 0x4D, 0x50, 0x61, 0x47, 0x50,
+    0x61, 0x72, 0x61, 0x64, 0x72, 0x61, 0x64, 0x2215, 0x73, 0x72, 0x61, 0x64,
+    0x2215, 0x73, 0x32, 0x70, 0x73, 0x6E, 0x73, 0x3BC, 0x73, 0x6D, 0x73, 0x70,
+    0x56, 0x6E, 0x56, 0x3BC, 0x56, 0x6D, 0x56, 0x6B, 0x56, 0x4D, 0x56, 0x70,
+    0x57, 0x6E, 0x57, 0x3BC, 0x57, 0x6D, 0x57, 0x6B, 0x57, 0x4D, 0x57, 0x6B,
+    0x3A9, 0x4D, 0x3A9, 0x61, 0x2E, 0x6D, 0x2E, 0x42, 0x71, 0x63, 0x63, 0x63,
+    0x64, 0x43, 0x2215, 0x6B, 0x67, 0x43, 0x6F, 0x2E, 0x64, 0x42, 0x47, 0x79,
+    0x68, 0x61, 0x48, 0x50, 0x69, 0x6E, 0x4B, 0x4B, 0x4B, 0x4D, 0x6B, 0x74,
+    0x6C, 0x6D, 0x6C, 0x6E, 0x6C, 0x6F, 0x67, 0x6C, 0x78, 0x6D, 0x62, 0x6D,
+    0x69, 0x6C, 0x6D, 0x6F, 0x6C, 0x50, 0x48, 0x70, 0x2E, 0x6D, 0x2E, 0x50,
+    0x50, 0x4D, 0x50, 0x52, 0x73, 0x72, 0x53, 0x76, 0x57, 0x62, 0x56, 0x2215,
+    0x6D, 0x41, 0x2215, 0x6D, 0x31, 0x65E5, 0x32, 0x65E5, 0x33, 0x65E5, 0x34, 0x65E5,
+    0x35, 0x65E5, 0x36, 0x65E5, 0x37, 0x65E5, 0x38, 0x65E5, 0x39, 0x65E5, 0x31, 0x30,
+    0x65E5, 0x31, 0x31, 0x65E5, 0x31, 0x32, 0x65E5, 0x31, 0x33, 0x65E5, 0x31, 0x34,
+    0x65E5, 0x31, 0x35, 0x65E5, 0x31, 0x36, 0x65E5, 0x31, 0x37, 0x65E5, 0x31, 0x38,
+    0x65E5, 0x31, 0x39, 0x65E5, 0x32, 0x30, 0x65E5, 0x32, 0x31, 0x65E5, 0x32, 0x32,
+    0x65E5, 0x32, 0x33, 0x65E5, 0x32, 0x34, 0x65E5, 0x32, 0x35, 0x65E5, 0x32, 0x36,
+    0x65E5, 0x32, 0x37, 0x65E5, 0x32, 0x38, 0x65E5, 0x32, 0x39, 0x65E5, 0x33, 0x30,
+    0x65E5, 0x33, 0x31, 0x65E5, 0x67, 0x61, 0x6C, 0x44A, 0x44C, 0xA76F, 0x53, 0x43,
+    0x46, 0x51, 0x126, 0x153, 0xA727, 0xAB37, 0x26B, 0xAB52, 0x28D, 0x8C48, 0x66F4, 0x8ECA,
+    0x8CC8, 0x6ED1, 0x4E32, 0x53E5, 0x9F9C, 0x9F9C, 0x5951, 0x91D1, 0x5587, 0x5948, 0x61F6, 0x7669,
+    0x7F85, 0x863F, 0x87BA, 0x88F8, 0x908F, 0x6A02, 0x6D1B, 0x70D9, 0x73DE, 0x843D, 0x916A, 0x99F1,
+    0x4E82, 0x5375, 0x6B04, 0x721B, 0x862D, 0x9E1E, 0x5D50, 0x6FEB, 0x85CD, 0x8964, 0x62C9, 0x81D8,
+    0x881F, 0x5ECA, 0x6717, 0x6D6A, 0x72FC, 0x90CE, 0x4F86, 0x51B7, 0x52DE, 0x64C4, 0x6AD3, 0x7210,
+    0x76E7, 0x8001, 0x8606, 0x865C, 0x8DEF, 0x9732, 0x9B6F, 0x9DFA, 0x788C, 0x797F, 0x7DA0, 0x83C9,
+    0x9304, 0x9E7F, 0x8AD6, 0x58DF, 0x5F04, 0x7C60, 0x807E, 0x7262, 0x78CA, 0x8CC2, 0x96F7, 0x58D8,
+    0x5C62, 0x6A13, 0x6DDA, 0x6F0F, 0x7D2F, 0x7E37, 0x964B, 0x52D2, 0x808B, 0x51DC, 0x51CC, 0x7A1C,
+    0x7DBE, 0x83F1, 0x9675, 0x8B80, 0x62CF, 0x6A02, 0x8AFE, 0x4E39, 0x5BE7, 0x6012, 0x7387, 0x7570,
+    0x5317, 0x78FB, 0x4FBF, 0x5FA9, 0x4E0D, 0x6CCC, 0x6578, 0x7D22, 0x53C3, 0x585E, 0x7701, 0x8449,
+    0x8AAA, 0x6BBA, 0x8FB0, 0x6C88, 0x62FE, 0x82E5, 0x63A0, 0x7565, 0x4EAE, 0x5169, 0x51C9, 0x6881,
+    0x7CE7, 0x826F, 0x8AD2, 0x91CF, 0x52F5, 0x5442, 0x5973, 0x5EEC, 0x65C5, 0x6FFE, 0x792A, 0x95AD,
+    0x9A6A, 0x9E97, 0x9ECE, 0x529B, 0x66C6, 0x6B77, 0x8F62, 0x5E74, 0x6190, 0x6200, 0x649A, 0x6F23,
+    0x7149, 0x7489, 0x79CA, 0x7DF4, 0x806F, 0x8F26, 0x84EE, 0x9023, 0x934A, 0x5217, 0x52A3, 0x54BD,
+    0x70C8, 0x88C2, 0x8AAA, 0x5EC9, 0x5FF5, 0x637B, 0x6BAE, 0x7C3E, 0x7375, 0x4EE4, 0x56F9, 0x5BE7,
+    0x5DBA, 0x601C, 0x73B2, 0x7469, 0x7F9A, 0x8046, 0x9234, 0x96F6, 0x9748, 0x9818, 0x4F8B, 0x79AE,
+    0x91B4, 0x96B8, 0x60E1, 0x4E86, 0x50DA, 0x5BEE, 0x5C3F, 0x6599, 0x6A02, 0x71CE, 0x7642, 0x84FC,
+    0x907C, 0x9F8D, 0x6688, 0x962E, 0x5289, 0x677B, 0x67F3, 0x6D41, 0x6E9C, 0x7409, 0x7559, 0x786B,
+    0x7D10, 0x985E, 0x516D, 0x622E, 0x9678, 0x502B, 0x5D19, 0x6DEA, 0x8F2A, 0x5F8B, 0x6144, 0x6817,
+    0x7387, 0x9686, 0x5229, 0x540F, 0x5C65, 0x6613, 0x674E, 0x68A8, 0x6CE5, 0x7406, 0x75E2, 0x7F79,
+    0x88CF, 0x88E1, 0x91CC, 0x96E2, 0x533F, 0x6EBA, 0x541D, 0x71D0, 0x7498, 0x85FA, 0x96A3, 0x9C57,
+    0x9E9F, 0x6797, 0x6DCB, 0x81E8, 0x7ACB, 0x7B20, 0x7C92, 0x72C0, 0x7099, 0x8B58, 0x4EC0, 0x8336,
+    0x523A, 0x5207, 0x5EA6, 0x62D3, 0x7CD6, 0x5B85, 0x6D1E, 0x66B4, 0x8F3B, 0x884C, 0x964D, 0x898B,
+    0x5ED3, 0x5140, 0x55C0, 0x585A, 0x6674, 0x51DE, 0x732A, 0x76CA, 0x793C, 0x795E, 0x7965, 0x798F,
+    0x9756, 0x7CBE, 0x7FBD, 0x8612, 0x8AF8, 0x9038, 0x90FD, 0x98EF, 0x98FC, 0x9928, 0x9DB4, 0x90DE,
+    0x96B7, 0x4FAE, 0x50E7, 0x514D, 0x52C9, 0x52E4, 0x5351, 0x559D, 0x5606, 0x5668, 0x5840, 0x58A8,
+    0x5C64, 0x5C6E, 0x6094, 0x6168, 0x618E, 0x61F2, 0x654F, 0x65E2, 0x6691, 0x6885, 0x6D77, 0x6E1A,
+    0x6F22, 0x716E, 0x722B, 0x7422, 0x7891, 0x793E, 0x7949, 0x7948, 0x7950, 0x7956, 0x795D, 0x798D,
+    0x798E, 0x7A40, 0x7A81, 0x7BC0, 0x7DF4, 0x7E09, 0x7E41, 0x7F72, 0x8005, 0x81ED, 0x8279, 0x8279,
+    0x8457, 0x8910, 0x8996, 0x8B01, 0x8B39, 0x8CD3, 0x8D08, 0x8FB6, 0x9038, 0x96E3, 0x97FF, 0x983B,
+    0x6075, 0x242EE, 0x8218, 0x4E26, 0x51B5, 0x5168, 0x4F80, 0x5145, 0x5180, 0x52C7, 0x52FA, 0x559D,
+    0x5555, 0x5599, 0x55E2, 0x585A, 0x58B3, 0x5944, 0x5954, 0x5A62, 0x5B28, 0x5ED2, 0x5ED9, 0x5F69,
+    0x5FAD, 0x60D8, 0x614E, 0x6108, 0x618E, 0x6160, 0x61F2, 0x6234, 0x63C4, 0x641C, 0x6452, 0x6556,
+    0x6674, 0x6717, 0x671B, 0x6756, 0x6B79, 0x6BBA, 0x6D41, 0x6EDB, 0x6ECB, 0x6F22, 0x701E, 0x716E,
+    0x77A7, 0x7235, 0x72AF, 0x732A, 0x7471, 0x7506, 0x753B, 0x761D, 0x761F, 0x76CA, 0x76DB, 0x76F4,
+    0x774A, 0x7740, 0x78CC, 0x7AB1, 0x7BC0, 0x7C7B, 0x7D5B, 0x7DF4, 0x7F3E, 0x8005, 0x8352, 0x83EF,
+    0x8779, 0x8941, 0x8986, 0x8996, 0x8ABF, 0x8AF8, 0x8ACB, 0x8B01, 0x8AFE, 0x8AED, 0x8B39, 0x8B8A,
+    0x8D08, 0x8F38, 0x9072, 0x9199, 0x9276, 0x967C, 0x96E3, 0x9756, 0x97DB, 0x97FF, 0x980B, 0x983B,
+    0x9B12, 0x9F9C, 0x2284A, 0x22844, 0x233D5, 0x3B9D, 0x4018, 0x4039, 0x25249, 0x25CD0, 0x27ED3, 0x9F43,
+    0x9F8E, 0x66, 0x66, 0x66, 0x69, 0x66, 0x6C, 0x66, 0x66, 0x69, 0x66, 0x66,
+    0x6C, 0x73, 0x74, 0x73, 0x74, 0x574, 0x576, 0x574, 0x565, 0x574, 0x56B, 0x57E,
+    0x576, 0x574, 0x56D, 0x5D9, 0x5B4, 0x5F2, 0x5B7, 0x5E2, 0x5D0, 0x5D3, 0x5D4, 0x5DB,
+    0x5DC, 0x5DD, 0x5E8, 0x5EA, 0x2B, 0x5E9, 0x5C1, 0x5E9, 0x5C2, 0xFB49, 0x5C1, 0xFB49,
+    0x5C2, 0x5D0, 0x5B7, 0x5D0, 0x5B8, 0x5D0, 0x5BC, 0x5D1, 0x5BC, 0x5D2, 0x5BC, 0x5D3,
+    0x5BC, 0x5D4, 0x5BC, 0x5D5, 0x5BC, 0x5D6, 0x5BC, 0x5D8, 0x5BC, 0x5D9, 0x5BC, 0x5DA,
+    0x5BC, 0x5DB, 0x5BC, 0x5DC, 0x5BC, 0x5DE, 0x5BC, 0x5E0, 0x5BC, 0x5E1, 0x5BC, 0x5E3,
+    0x5BC, 0x5E4, 0x5BC, 0x5E6, 0x5BC, 0x5E7, 0x5BC, 0x5E8, 0x5BC, 0x5E9, 0x5BC, 0x5EA,
+    0x5BC, 0x5D5, 0x5B9, 0x5D1, 0x5BF, 0x5DB, 0x5BF, 0x5E4, 0x5BF, 0x5D0, 0x5DC, 0x671,
+    0x671, 0x67B, 0x67B, 0x67B, 0x67B, 0x67E, 0x67E, 0x67E, 0x67E, 0x680, 0x680, 0x680,
+    0x680, 0x67A, 0x67A, 0x67A, 0x67A, 0x67F, 0x67F, 0x67F, 0x67F, 0x679, 0x679, 0x679,
+    0x679, 0x6A4, 0x6A4, 0x6A4, 0x6A4, 0x6A6, 0x6A6, 0x6A6, 0x6A6, 0x684, 0x684, 0x684,
+    0x684, 0x683, 0x683, 0x683, 0x683, 0x686, 0x686, 0x686, 0x686, 0x687, 0x687, 0x687,
+    0x687, 0x68D, 0x68D, 0x68C, 0x68C, 0x68E, 0x68E, 0x688, 0x688, 0x698, 0x698, 0x691,
+    0x691, 0x6A9, 0x6A9, 0x6A9, 0x6A9, 0x6AF, 0x6AF, 0x6AF, 0x6AF, 0x6B3, 0x6B3, 0x6B3,
+    0x6B3, 0x6B1, 0x6B1, 0x6B1, 0x6B1, 0x6BA, 0x6BA, 0x6BB, 0x6BB, 0x6BB, 0x6BB, 0x6D5,
+    0x654, 0x6D5, 0x654, 0x6C1, 0x6C1, 0x6C1, 0x6C1, 0x6BE, 0x6BE, 0x6BE, 0x6BE, 0x6D2,
+    0x6D2, 0x6D2, 0x654, 0x6D2, 0x654, 0x6AD, 0x6AD, 0x6AD, 0x6AD, 0x6C7, 0x6C7, 0x6C6,
+    0x6C6, 0x6C8, 0x6C8, 0x6C7, 0x674, 0x6CB, 0x6CB, 0x6C5, 0x6C5, 0x6C9, 0x6C9, 0x6D0,
+    0x6D0, 0x6D0, 0x6D0, 0x649, 0x649, 0x64A, 0x654, 0x627, 0x64A, 0x654, 0x627, 0x64A,
+    0x654, 0x6D5, 0x64A, 0x654, 0x6D5, 0x64A, 0x654, 0x648, 0x64A, 0x654, 0x648, 0x64A,
+    0x654, 0x6C7, 0x64A, 0x654, 0x6C7, 0x64A, 0x654, 0x6C6, 0x64A, 0x654, 0x6C6, 0x64A,
+    0x654, 0x6C8, 0x64A, 0x654, 0x6C8, 0x64A, 0x654, 0x6D0, 0x64A, 0x654, 0x6D0, 0x64A,
+    0x654, 0x6D0, 0x64A, 0x654, 0x649, 0x64A, 0x654, 0x649, 0x64A, 0x654, 0x649, 0x6CC,
+    0x6CC, 0x6CC, 0x6CC, 0x64A, 0x654, 0x62C, 0x64A, 0x654, 0x62D, 0x64A, 0x654, 0x645,
+    0x64A, 0x654, 0x649, 0x64A, 0x654, 0x64A, 0x628, 0x62C, 0x628, 0x62D, 0x628, 0x62E,
+    0x628, 0x645, 0x628, 0x649, 0x628, 0x64A, 0x62A, 0x62C, 0x62A, 0x62D, 0x62A, 0x62E,
+    0x62A, 0x645, 0x62A, 0x649, 0x62A, 0x64A, 0x62B, 0x62C, 0x62B, 0x645, 0x62B, 0x649,
+    0x62B, 0x64A, 0x62C, 0x62D, 0x62C, 0x645, 0x62D, 0x62C, 0x62D, 0x645, 0x62E, 0x62C,
+    0x62E, 0x62D, 0x62E, 0x645, 0x633, 0x62C, 0x633, 0x62D, 0x633, 0x62E, 0x633, 0x645,
+    0x635, 0x62D, 0x635, 0x645, 0x636, 0x62C, 0x636, 0x62D, 0x636, 0x62E, 0x636, 0x645,
+    0x637, 0x62D, 0x637, 0x645, 0x638, 0x645, 0x639, 0x62C, 0x639, 0x645, 0x63A, 0x62C,
+    0x63A, 0x645, 0x641, 0x62C, 0x641, 0x62D, 0x641, 0x62E, 0x641, 0x645, 0x641, 0x649,
+    0x641, 0x64A, 0x642, 0x62D, 0x642, 0x645, 0x642, 0x649, 0x642, 0x64A, 0x643, 0x627,
+    0x643, 0x62C, 0x643, 0x62D, 0x643, 0x62E, 0x643, 0x644, 0x643, 0x645, 0x643, 0x649,
+    0x643, 0x64A, 0x644, 0x62C, 0x644, 0x62D, 0x644, 0x62E, 0x644, 0x645, 0x644, 0x649,
+    0x644, 0x64A, 0x645, 0x62C, 0x645, 0x62D, 0x645, 0x62E, 0x645, 0x645, 0x645, 0x649,
+    0x645, 0x64A, 0x646, 0x62C, 0x646, 0x62D, 0x646, 0x62E, 0x646, 0x645, 0x646, 0x649,
+    0x646, 0x64A, 0x647, 0x62C, 0x647, 0x645, 0x647, 0x649, 0x647, 0x64A, 0x64A, 0x62C,
+    0x64A, 0x62D, 0x64A, 0x62E, 0x64A, 0x645, 0x64A, 0x649, 0x64A, 0x64A, 0x630, 0x670,
+    0x631, 0x670, 0x649, 0x670, 0x20, 0x64C, 0x651, 0x20, 0x64D, 0x651, 0x20, 0x64E,
+    0x651, 0x20, 0x64F, 0x651, 0x20, 0x650, 0x651, 0x20, 0x651, 0x670, 0x64A, 0x654,
+    0x631, 0x64A, 0x654, 0x632, 0x64A, 0x654, 0x645, 0x64A, 0x654, 0x646, 0x64A, 0x654,
+    0x649, 0x64A, 0x654, 0x64A, 0x628, 0x631, 0x628, 0x632, 0x628, 0x645, 0x628, 0x646,
+    0x628, 0x649, 0x628, 0x64A, 0x62A, 0x631, 0x62A, 0x632, 0x62A, 0x645, 0x62A, 0x646,
+    0x62A, 0x649, 0x62A, 0x64A, 0x62B, 0x631, 0x62B, 0x632, 0x62B, 0x645, 0x62B, 0x646,
+    0x62B, 0x649, 0x62B, 0x64A, 0x641, 0x649, 0x641, 0x64A, 0x642, 0x649, 0x642, 0x64A,
+    0x643, 0x627, 0x643, 0x644, 0x643, 0x645, 0x643, 0x649, 0x643, 0x64A, 0x644, 0x645,
+    0x644, 0x649, 0x644, 0x64A, 0x645, 0x627, 0x645, 0x645, 0x646, 0x631, 0x646, 0x632,
+    0x646, 0x645, 0x646, 0x646, 0x646, 0x649, 0x646, 0x64A, 0x649, 0x670, 0x64A, 0x631,
+    0x64A, 0x632, 0x64A, 0x645, 0x64A, 0x646, 0x64A, 0x649, 0x64A, 0x64A, 0x64A, 0x654,
+    0x62C, 0x64A, 0x654, 0x62D, 0x64A, 0x654, 0x62E, 0x64A, 0x654, 0x645, 0x64A, 0x654,
+    0x647, 0x628, 0x62C, 0x628, 0x62D, 0x628, 0x62E, 0x628, 0x645, 0x628, 0x647, 0x62A,
+    0x62C, 0x62A, 0x62D, 0x62A, 0x62E, 0x62A, 0x645, 0x62A, 0x647, 0x62B, 0x645, 0x62C,
+    0x62D, 0x62C, 0x645, 0x62D, 0x62C, 0x62D, 0x645, 0x62E, 0x62C, 0x62E, 0x645, 0x633,
+    0x62C, 0x633, 0x62D, 0x633, 0x62E, 0x633, 0x645, 0x635, 0x62D, 0x635, 0x62E, 0x635,
+    0x645, 0x636, 0x62C, 0x636, 0x62D, 0x636, 0x62E, 0x636, 0x645, 0x637, 0x62D, 0x638,
+    0x645, 0x639, 0x62C, 0x639, 0x645, 0x63A, 0x62C, 0x63A, 0x645, 0x641, 0x62C, 0x641,
+    0x62D, 0x641, 0x62E, 0x641, 0x645, 0x642, 0x62D, 0x642, 0x645, 0x643, 0x62C, 0x643,
+    0x62D, 0x643, 0x62E, 0x643, 0x644, 0x643, 0x645, 0x644, 0x62C, 0x644, 0x62D, 0x644,
+    0x62E, 0x644, 0x645, 0x644, 0x647, 0x645, 0x62C, 0x645, 0x62D, 0x645, 0x62E, 0x645,
+    0x645, 0x646, 0x62C, 0x646, 0x62D, 0x646, 0x62E, 0x646, 0x645, 0x646, 0x647, 0x647,
+    0x62C, 0x647, 0x645, 0x647, 0x670, 0x64A, 0x62C, 0x64A, 0x62D, 0x64A, 0x62E, 0x64A,
+    0x645, 0x64A, 0x647, 0x64A, 0x654, 0x645, 0x64A, 0x654, 0x647, 0x628, 0x645, 0x628,
+    0x647, 0x62A, 0x645, 0x62A, 0x647, 0x62B, 0x645, 0x62B, 0x647, 0x633, 0x645, 0x633,
+    0x647, 0x634, 0x645, 0x634, 0x647, 0x643, 0x644, 0x643, 0x645, 0x644, 0x645, 0x646,
+    0x645, 0x646, 0x647, 0x64A, 0x645, 0x64A, 0x647, 0x640, 0x64E, 0x651, 0x640, 0x64F,
+    0x651, 0x640, 0x650, 0x651, 0x637, 0x649, 0x637, 0x64A, 0x639, 0x649, 0x639, 0x64A,
+    0x63A, 0x649, 0x63A, 0x64A, 0x633, 0x649, 0x633, 0x64A, 0x634, 0x649, 0x634, 0x64A,
+    0x62D, 0x649, 0x62D, 0x64A, 0x62C, 0x649, 0x62C, 0x64A, 0x62E, 0x649, 0x62E, 0x64A,
+    0x635, 0x649, 0x635, 0x64A, 0x636, 0x649, 0x636, 0x64A, 0x634, 0x62C, 0x634, 0x62D,
+    0x634, 0x62E, 0x634, 0x645, 0x634, 0x631, 0x633, 0x631, 0x635, 0x631, 0x636, 0x631,
+    0x637, 0x649, 0x637, 0x64A, 0x639, 0x649, 0x639, 0x64A, 0x63A, 0x649, 0x63A, 0x64A,
+    0x633, 0x649, 0x633, 0x64A, 0x634, 0x649, 0x634, 0x64A, 0x62D, 0x649, 0x62D, 0x64A,
+    0x62C, 0x649, 0x62C, 0x64A, 0x62E, 0x649, 0x62E, 0x64A, 0x635, 0x649, 0x635, 0x64A,
+    0x636, 0x649, 0x636, 0x64A, 0x634, 0x62C, 0x634, 0x62D, 0x634, 0x62E, 0x634, 0x645,
+    0x634, 0x631, 0x633, 0x631, 0x635, 0x631, 0x636, 0x631, 0x634, 0x62C, 0x634, 0x62D,
+    0x634, 0x62E, 0x634, 0x645, 0x633, 0x647, 0x634, 0x647, 0x637, 0x645, 0x633, 0x62C,
+    0x633, 0x62D, 0x633, 0x62E, 0x634, 0x62C, 0x634, 0x62D, 0x634, 0x62E, 0x637, 0x645,
+    0x638, 0x645, 0x627, 0x64B, 0x627, 0x64B, 0x62A, 0x62C, 0x645, 0x62A, 0x62D, 0x62C,
+    0x62A, 0x62D, 0x62C, 0x62A, 0x62D, 0x645, 0x62A, 0x62E, 0x645, 0x62A, 0x645, 0x62C,
+    0x62A, 0x645, 0x62D, 0x62A, 0x645, 0x62E, 0x62C, 0x645, 0x62D, 0x62C, 0x645, 0x62D,
+    0x62D, 0x645, 0x64A, 0x62D, 0x645, 0x649, 0x633, 0x62D, 0x62C, 0x633, 0x62C, 0x62D,
+    0x633, 0x62C, 0x649, 0x633, 0x645, 0x62D, 0x633, 0x645, 0x62D, 0x633, 0x645, 0x62C,
+    0x633, 0x645, 0x645, 0x633, 0x645, 0x645, 0x635, 0x62D, 0x62D, 0x635, 0x62D, 0x62D,
+    0x635, 0x645, 0x645, 0x634, 0x62D, 0x645, 0x634, 0x62D, 0x645, 0x634, 0x62C, 0x64A,
+    0x634, 0x645, 0x62E, 0x634, 0x645, 0x62E, 0x634, 0x645, 0x645, 0x634, 0x645, 0x645,
+    0x636, 0x62D, 0x649, 0x636, 0x62E, 0x645, 0x636, 0x62E, 0x645, 0x637, 0x645, 0x62D,
+    0x637, 0x645, 0x62D, 0x637, 0x645, 0x645, 0x637, 0x645, 0x64A, 0x639, 0x62C, 0x645,
+    0x639, 0x645, 0x645, 0x639, 0x645, 0x645, 0x639, 0x645, 0x649, 0x63A, 0x645, 0x645,
+    0x63A, 0x645, 0x64A, 0x63A, 0x645, 0x649, 0x641, 0x62E, 0x645, 0x641, 0x62E, 0x645,
+    0x642, 0x645, 0x62D, 0x642, 0x645, 0x645, 0x644, 0x62D, 0x645, 0x644, 0x62D, 0x64A,
+    0x644, 0x62D, 0x649, 0x644, 0x62C, 0x62C, 0x644, 0x62C, 0x62C, 0x644, 0x62E, 0x645,
+    0x644, 0x62E, 0x645, 0x644, 0x645, 0x62D, 0x644, 0x645, 0x62D, 0x645, 0x62D, 0x62C,
+    0x645, 0x62D, 0x645, 0x645, 0x62D, 0x64A, 0x645, 0x62C, 0x62D, 0x645, 0x62C, 0x645,
+    0x645, 0x62E, 0x62C, 0x645, 0x62E, 0x645, 0x645, 0x62C, 0x62E, 0x647, 0x645, 0x62C,
+    0x647, 0x645, 0x645, 0x646, 0x62D, 0x645, 0x646, 0x62D, 0x649, 0x646, 0x62C, 0x645,
+    0x646, 0x62C, 0x645, 0x646, 0x62C, 0x649, 0x646, 0x645, 0x64A, 0x646, 0x645, 0x649,
+    0x64A, 0x645, 0x645, 0x64A, 0x645, 0x645, 0x628, 0x62E, 0x64A, 0x62A, 0x62C, 0x64A,
+    0x62A, 0x62C, 0x649, 0x62A, 0x62E, 0x64A, 0x62A, 0x62E, 0x649, 0x62A, 0x645, 0x64A,
+    0x62A, 0x645, 0x649, 0x62C, 0x645, 0x64A, 0x62C, 0x62D, 0x649, 0x62C, 0x645, 0x649,
+    0x633, 0x62E, 0x649, 0x635, 0x62D, 0x64A, 0x634, 0x62D, 0x64A, 0x636, 0x62D, 0x64A,
+    0x644, 0x62C, 0x64A, 0x644, 0x645, 0x64A, 0x64A, 0x62D, 0x64A, 0x64A, 0x62C, 0x64A,
+    0x64A, 0x645, 0x64A, 0x645, 0x645, 0x64A, 0x642, 0x645, 0x64A, 0x646, 0x62D, 0x64A,
+    0x642, 0x645, 0x62D, 0x644, 0x62D, 0x645, 0x639, 0x645, 0x64A, 0x643, 0x645, 0x64A,
+    0x646, 0x62C, 0x62D, 0x645, 0x62E, 0x64A, 0x644, 0x62C, 0x645, 0x643, 0x645, 0x645,
+    0x644, 0x62C, 0x645, 0x646, 0x62C, 0x62D, 0x62C, 0x62D, 0x64A, 0x62D, 0x62C, 0x64A,
+    0x645, 0x62C, 0x64A, 0x641, 0x645, 0x64A, 0x628, 0x62D, 0x64A, 0x643, 0x645, 0x645,
+    0x639, 0x62C, 0x645, 0x635, 0x645, 0x645, 0x633, 0x62E, 0x64A, 0x646, 0x62C, 0x64A,
+    0x635, 0x644, 0x6D2, 0x642, 0x644, 0x6D2, 0x627, 0x644, 0x644, 0x647, 0x627, 0x643,
+    0x628, 0x631, 0x645, 0x62D, 0x645, 0x62F, 0x635, 0x644, 0x639, 0x645, 0x631, 0x633,
+    0x648, 0x644, 0x639, 0x644, 0x64A, 0x647, 0x648, 0x633, 0x644, 0x645, 0x635, 0x644,
+    0x649, 0x635, 0x644, 0x649, 0x20, 0x627, 0x644, 0x644, 0x647, 0x20, 0x639, 0x644,
+    0x64A, 0x647, 0x20, 0x648, 0x633, 0x644, 0x645, 0x62C, 0x644, 0x20, 0x62C, 0x644,
+    0x627, 0x644, 0x647, 0x631, 0x6CC, 0x627, 0x644, 0x2C, 0x3001, 0x3002, 0x3A, 0x3B,
+    0x21, 0x3F, 0x3016, 0x3017, 0x2E, 0x2E, 0x2E, 0x2E, 0x2E, 0x2014, 0x2013, 0x5F,
+    0x5F, 0x28, 0x29, 0x7B, 0x7D, 0x3014, 0x3015, 0x3010, 0x3011, 0x300A, 0x300B, 0x3008,
+    0x3009, 0x300C, 0x300D, 0x300E, 0x300F, 0x5B, 0x5D, 0x20, 0x305, 0x20, 0x305, 0x20,
+    0x305, 0x20, 0x305, 0x5F, 0x5F, 0x5F, 0x2C, 0x3001, 0x2E, 0x3B, 0x3A, 0x3F,
+    0x21, 0x2014, 0x28, 0x29, 0x7B, 0x7D, 0x3014, 0x3015, 0x23, 0x26, 0x2A, 0x2B,
+    0x2D, 0x3C, 0x3E, 0x3D, 0x5C, 0x24, 0x25, 0x40, 0x20, 0x64B, 0x640, 0x64B,
+    0x20, 0x64C, 0x20, 0x64D, 0x20, 0x64E, 0x640, 0x64E, 0x20, 0x64F, 0x640, 0x64F,
+    0x20, 0x650, 0x640, 0x650, 0x20, 0x651, 0x640, 0x651, 0x20, 0x652, 0x640, 0x652,
+    0x621, 0x627, 0x653, 0x627, 0x653, 0x627, 0x654, 0x627, 0x654, 0x648, 0x654, 0x648,
+    0x654, 0x627, 0x655, 0x627, 0x655, 0x64A, 0x654, 0x64A, 0x654, 0x64A, 0x654, 0x64A,
+    0x654, 0x627, 0x627, 0x628, 0x628, 0x628, 0x628, 0x629, 0x629, 0x62A, 0x62A, 0x62A,
+    0x62A, 0x62B, 0x62B, 0x62B, 0x62B, 0x62C, 0x62C, 0x62C, 0x62C, 0x62D, 0x62D, 0x62D,
+    0x62D, 0x62E, 0x62E, 0x62E, 0x62E, 0x62F, 0x62F, 0x630, 0x630, 0x631, 0x631, 0x632,
+    0x632, 0x633, 0x633, 0x633, 0x633, 0x634, 0x634, 0x634, 0x634, 0x635, 0x635, 0x635,
+    0x635, 0x636, 0x636, 0x636, 0x636, 0x637, 0x637, 0x637, 0x637, 0x638, 0x638, 0x638,
+    0x638, 0x639, 0x639, 0x639, 0x639, 0x63A, 0x63A, 0x63A, 0x63A, 0x641, 0x641, 0x641,
+    0x641, 0x642, 0x642, 0x642, 0x642, 0x643, 0x643, 0x643, 0x643, 0x644, 0x644, 0x644,
+    0x644, 0x645, 0x645, 0x645, 0x645, 0x646, 0x646, 0x646, 0x646, 0x647, 0x647, 0x647,
+    0x647, 0x648, 0x648, 0x649, 0x649, 0x64A, 0x64A, 0x64A, 0x64A, 0x644, 0x627, 0x653,
+    0x644, 0x627, 0x653, 0x644, 0x627, 0x654, 0x644, 0x627, 0x654, 0x644, 0x627, 0x655,
+    0x644, 0x627, 0x655, 0x644, 0x627, 0x644, 0x627, 0x21, 0x22, 0x23, 0x24, 0x25,
+    0x26, 0x27, 0x28, 0x29, 0x2A, 0x2B, 0x2C, 0x2D, 0x2E, 0x2F, 0x30, 0x31,
+    0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x3A, 0x3B, 0x3C, 0x3D,
+    0x3E, 0x3F, 0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49,
+    0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55,
+    0x56, 0x57, 0x58, 0x59, 0x5A, 0x5B, 0x5C, 0x5D, 0x5E, 0x5F, 0x60, 0x61,
+    0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6A, 0x6B, 0x6C, 0x6D,
+    0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79,
+    0x7A, 0x7B, 0x7C, 0x7D, 0x7E, 0x2985, 0x2986, 0x3002, 0x300C, 0x300D, 0x3001, 0x30FB,
+    0x30F2, 0x30A1, 0x30A3, 0x30A5, 0x30A7, 0x30A9, 0x30E3, 0x30E5, 0x30E7, 0x30C3, 0x30FC, 0x30A2,
+    0x30A4, 0x30A6, 0x30A8, 0x30AA, 0x30AB, 0x30AD, 0x30AF, 0x30B1, 0x30B3, 0x30B5, 0x30B7, 0x30B9,
+    0x30BB, 0x30BD, 0x30BF, 0x30C1, 0x30C4, 0x30C6, 0x30C8, 0x30CA, 0x30CB, 0x30CC, 0x30CD, 0x30CE,
+    0x30CF, 0x30D2, 0x30D5, 0x30D8, 0x30DB, 0x30DE, 0x30DF, 0x30E0, 0x30E1, 0x30E2, 0x30E4, 0x30E6,
+    0x30E8, 0x30E9, 0x30EA, 0x30EB, 0x30EC, 0x30ED, 0x30EF, 0x30F3, 0x3099, 0x309A, 0x1160, 0x1100,
+    0x1101, 0x11AA, 0x1102, 0x11AC, 0x11AD, 0x1103, 0x1104, 0x1105, 0x11B0, 0x11B1, 0x11B2, 0x11B3,
+    0x11B4, 0x11B5, 0x111A, 0x1106, 0x1107, 0x1108, 0x1121, 0x1109, 0x110A, 0x110B, 0x110C, 0x110D,
+    0x110E, 0x110F, 0x1110, 0x1111, 0x1112, 0x1161, 0x1162, 0x1163, 0x1164, 0x1165, 0x1166, 0x1167,
+    0x1168, 0x1169, 0x116A, 0x116B, 0x116C, 0x116D, 0x116E, 0x116F, 0x1170, 0x1171, 0x1172, 0x1173,
+    0x1174, 0x1175, 0xA2, 0xA3, 0xAC, 0x20, 0x304, 0xA6, 0xA5, 0x20A9, 0x2502, 0x2190,
+    0x2191, 0x2192, 0x2193, 0x25A0, 0x25CB, 0x105D2, 0x307, 0x105DA, 0x307, 0x2D0, 0x2D1, 0xE6,
+    0x299, 0x253, 0x2A3, 0xAB66, 0x2A5, 0x2A4, 0x256, 0x257, 0x1D91, 0x258, 0x25E, 0x2A9,
+    0x264, 0x262, 0x260, 0x29B, 0x127, 0x29C, 0x267, 0x284, 0x2AA, 0x2AB, 0x26C, 0x1DF04,
+    0xA78E, 0x26E, 0x1DF05, 0x28E, 0x1DF06, 0xF8, 0x276, 0x277, 0x71, 0x27A, 0x1DF08, 0x27D,
+    0x27E, 0x280, 0x2A8, 0x2A6, 0xAB67, 0x2A7, 0x288, 0x2C71, 0x28F, 0x2A1, 0x2A2, 0x298,
+    0x1C0, 0x1C1, 0x1C2, 0x1DF0A, 0x1DF1E, 0x11099, 0x110BA, 0x1109B, 0x110BA, 0x110A5, 0x110BA, 0x11131,
+    0x11127, 0x11132, 0x11127, 0x11347, 0x1133E, 0x11347, 0x11357, 0x11382, 0x113C9, 0x11384, 0x113BB, 0x1138B,
+    0x113C2, 0x11390, 0x113C9, 0x113C2, 0x113C2, 0x113C2, 0x113B8, 0x113C2, 0x113C9, 0x114B9, 0x114BA, 0x114B9,
+    0x114B0, 0x114B9, 0x114BD, 0x115B8, 0x115AF, 0x115B9, 0x115AF, 0x11935, 0x11930, 0x1611E, 0x1611E, 0x1611E,
+    0x16129, 0x1611E, 0x1611F, 0x16129, 0x1611F, 0x1611E, 0x16120, 0x16121, 0x1611F, 0x16122, 0x1611F, 0x16121,
+    0x16120, 0x16D67, 0x16D67, 0x16D63, 0x16D67, 0x16D69, 0x16D67, 0x41, 0x42, 0x43, 0x44, 0x45,
+    0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51,
+    0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0x30, 0x31, 0x32,
+    0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x1D157, 0x1D165, 0x1D158, 0x1D165, 0x1D15F,
+    0x1D16E, 0x1D15F, 0x1D16F, 0x1D15F, 0x1D170, 0x1D15F, 0x1D171, 0x1D15F, 0x1D172, 0x1D1B9, 0x1D165, 0x1D1BA,
+    0x1D165, 0x1D1BB, 0x1D16E, 0x1D1BC, 0x1D16E, 0x1D1BB, 0x1D16F, 0x1D1BC, 0x1D16F, 0x41, 0x42, 0x43,
+    0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F,
+    0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0x61,
+    0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6A, 0x6B, 0x6C, 0x6D,
+    0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79,
+    0x7A, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B,
+    0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57,
+    0x58, 0x59, 0x5A, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x69, 0x6A,
+    0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76,
+    0x77, 0x78, 0x79, 0x7A, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48,
+    0x49, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53, 0x54,
+    0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66,
+    0x67, 0x68, 0x69, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72,
+    0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0x41, 0x43, 0x44, 0x47,
+    0x4A, 0x4B, 0x4E, 0x4F, 0x50, 0x51, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58,
+    0x59, 0x5A, 0x61, 0x62, 0x63, 0x64, 0x66, 0x68, 0x69, 0x6A, 0x6B, 0x6C,
+    0x6D, 0x6E, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79,
+    0x7A, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B,
+    0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57,
+    0x58, 0x59, 0x5A, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69,
+    0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75,
+    0x76, 0x77, 0x78, 0x79, 0x7A, 0x41, 0x42, 0x44, 0x45, 0x46, 0x47, 0x4A,
+    0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x53, 0x54, 0x55, 0x56, 0x57,
+    0x58, 0x59, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6A,
+    0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76,
+    0x77, 0x78, 0x79, 0x7A, 0x41, 0x42, 0x44, 0x45, 0x46, 0x47, 0x49, 0x4A,
+    0x4B, 0x4C, 0x4D, 0x4F, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x61,
+    0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6A, 0x6B, 0x6C, 0x6D,
+    0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79,
+    0x7A, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B,
+    0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57,
+    0x58, 0x59, 0x5A, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69,
+    0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75,
+    0x76, 0x77, 0x78, 0x79, 0x7A, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47,
+    0x48, 0x49, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53,
+    0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0x61, 0x62, 0x63, 0x64, 0x65,
+    0x66, 0x67, 0x68, 0x69, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71,
+    0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0x41, 0x42, 0x43,
+    0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F,
+    0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0x61,
+    0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6A, 0x6B, 0x6C, 0x6D,
+    0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79,
+    0x7A, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B,
+    0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57,
+    0x58, 0x59, 0x5A, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69,
+    0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75,
+    0x76, 0x77, 0x78, 0x79, 0x7A, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47,
+    0x48, 0x49, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53,
+    0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0x61, 0x62, 0x63, 0x64, 0x65,
+    0x66, 0x67, 0x68, 0x69, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71,
+    0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0x41, 0x42, 0x43,
+    0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F,
+    0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0x61,
+    0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6A, 0x6B, 0x6C, 0x6D,
+    0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79,
+    0x7A, 0x131, 0x237, 0x391, 0x392, 0x393, 0x394, 0x395, 0x396, 0x397, 0x398, 0x399,
+    0x39A, 0x39B, 0x39C, 0x39D, 0x39E, 0x39F, 0x3A0, 0x3A1, 0x398, 0x3A3, 0x3A4, 0x3A5,
+    0x3A6, 0x3A7, 0x3A8, 0x3A9, 0x2207, 0x3B1, 0x3B2, 0x3B3, 0x3B4, 0x3B5, 0x3B6, 0x3B7,
+    0x3B8, 0x3B9, 0x3BA, 0x3BB, 0x3BC, 0x3BD, 0x3BE, 0x3BF, 0x3C0, 0x3C1, 0x3C2, 0x3C3,
+    0x3C4, 0x3C5, 0x3C6, 0x3C7, 0x3C8, 0x3C9, 0x2202, 0x3B5, 0x3B8, 0x3BA, 0x3C6, 0x3C1,
+    0x3C0, 0x391, 0x392, 0x393, 0x394, 0x395, 0x396, 0x397, 0x398, 0x399, 0x39A, 0x39B,
+    0x39C, 0x39D, 0x39E, 0x39F, 0x3A0, 0x3A1, 0x398, 0x3A3, 0x3A4, 0x3A5, 0x3A6, 0x3A7,
+    0x3A8, 0x3A9, 0x2207, 0x3B1, 0x3B2, 0x3B3, 0x3B4, 0x3B5, 0x3B6, 0x3B7, 0x3B8, 0x3B9,
+    0x3BA, 0x3BB, 0x3BC, 0x3BD, 0x3BE, 0x3BF, 0x3C0, 0x3C1, 0x3C2, 0x3C3, 0x3C4, 0x3C5,
+    0x3C6, 0x3C7, 0x3C8, 0x3C9, 0x2202, 0x3B5, 0x3B8, 0x3BA, 0x3C6, 0x3C1, 0x3C0, 0x391,
+    0x392, 0x393, 0x394, 0x395, 0x396, 0x397, 0x398, 0x399, 0x39A, 0x39B, 0x39C, 0x39D,
+    0x39E, 0x39F, 0x3A0, 0x3A1, 0x398, 0x3A3, 0x3A4, 0x3A5, 0x3A6, 0x3A7, 0x3A8, 0x3A9,
+    0x2207, 0x3B1, 0x3B2, 0x3B3, 0x3B4, 0x3B5, 0x3B6, 0x3B7, 0x3B8, 0x3B9, 0x3BA, 0x3BB,
+    0x3BC, 0x3BD, 0x3BE, 0x3BF, 0x3C0, 0x3C1, 0x3C2, 0x3C3, 0x3C4, 0x3C5, 0x3C6, 0x3C7,
+    0x3C8, 0x3C9, 0x2202, 0x3B5, 0x3B8, 0x3BA, 0x3C6, 0x3C1, 0x3C0, 0x391, 0x392, 0x393,
+    0x394, 0x395, 0x396, 0x397, 0x398, 0x399, 0x39A, 0x39B, 0x39C, 0x39D, 0x39E, 0x39F,
+    0x3A0, 0x3A1, 0x398, 0x3A3, 0x3A4, 0x3A5, 0x3A6, 0x3A7, 0x3A8, 0x3A9, 0x2207, 0x3B1,
+    0x3B2, 0x3B3, 0x3B4, 0x3B5, 0x3B6, 0x3B7, 0x3B8, 0x3B9, 0x3BA, 0x3BB, 0x3BC, 0x3BD,
+    0x3BE, 0x3BF, 0x3C0, 0x3C1, 0x3C2, 0x3C3, 0x3C4, 0x3C5, 0x3C6, 0x3C7, 0x3C8, 0x3C9,
+    0x2202, 0x3B5, 0x3B8, 0x3BA, 0x3C6, 0x3C1, 0x3C0, 0x391, 0x392, 0x393, 0x394, 0x395,
+    0x396, 0x397, 0x398, 0x399, 0x39A, 0x39B, 0x39C, 0x39D, 0x39E, 0x39F, 0x3A0, 0x3A1,
+    0x398, 0x3A3, 0x3A4, 0x3A5, 0x3A6, 0x3A7, 0x3A8, 0x3A9, 0x2207, 0x3B1, 0x3B2, 0x3B3,
+    0x3B4, 0x3B5, 0x3B6, 0x3B7, 0x3B8, 0x3B9, 0x3BA, 0x3BB, 0x3BC, 0x3BD, 0x3BE, 0x3BF,
+    0x3C0, 0x3C1, 0x3C2, 0x3C3, 0x3C4, 0x3C5, 0x3C6, 0x3C7, 0x3C8, 0x3C9, 0x2202, 0x3B5,
+    0x3B8, 0x3BA, 0x3C6, 0x3C1, 0x3C0, 0x3DC, 0x3DD, 0x30, 0x31, 0x32, 0x33, 0x34,
+    0x35, 0x36, 0x37, 0x38, 0x39, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36,
+    0x37, 0x38, 0x39, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38,
+    0x39, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x30,
+    0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x430, 0x431, 0x432,
+    0x433, 0x434, 0x435, 0x436, 0x437, 0x438, 0x43A, 0x43B, 0x43C, 0x43E, 0x43F, 0x440,
+    0x441, 0x442, 0x443, 0x444, 0x445, 0x446, 0x447, 0x448, 0x44B, 0x44D, 0x44E, 0xA689,
+    0x4D9, 0x456, 0x458, 0x4E9, 0x4AF, 0x4CF, 0x430, 0x431, 0x432, 0x433, 0x434, 0x435,
+    0x436, 0x437, 0x438, 0x43A, 0x43B, 0x43E, 0x43F, 0x441, 0x443, 0x444, 0x445, 0x446,
+    0x447, 0x448, 0x44A, 0x44B, 0x491, 0x456, 0x455, 0x45F, 0x4AB, 0xA651, 0x4B1, 0x627,
+    0x628, 0x62C, 0x62F, 0x648, 0x632, 0x62D, 0x637, 0x64A, 0x643, 0x644, 0x645, 0x646,
+    0x633, 0x639, 0x641, 0x635, 0x642, 0x631, 0x634, 0x62A, 0x62B, 0x62E, 0x630, 0x636,
+    0x638, 0x63A, 0x66E, 0x6BA, 0x6A1, 0x66F, 0x628, 0x62C, 0x647, 0x62D, 0x64A, 0x643,
+    0x644, 0x645, 0x646, 0x633, 0x639, 0x641, 0x635, 0x642, 0x634, 0x62A, 0x62B, 0x62E,
+    0x636, 0x63A, 0x62C, 0x62D, 0x64A, 0x644, 0x646, 0x633, 0x639, 0x635, 0x642, 0x634,
+    0x62E, 0x636, 0x63A, 0x6BA, 0x66F, 0x628, 0x62C, 0x647, 0x62D, 0x637, 0x64A, 0x643,
+    0x645, 0x646, 0x633, 0x639, 0x641, 0x635, 0x642, 0x634, 0x62A, 0x62B, 0x62E, 0x636,
+    0x638, 0x63A, 0x66E, 0x6A1, 0x627, 0x628, 0x62C, 0x62F, 0x647, 0x648, 0x632, 0x62D,
+    0x637, 0x64A, 0x644, 0x645, 0x646, 0x633, 0x639, 0x641, 0x635, 0x642, 0x631, 0x634,
+    0x62A, 0x62B, 0x62E, 0x630, 0x636, 0x638, 0x63A, 0x628, 0x62C, 0x62F, 0x648, 0x632,
+    0x62D, 0x637, 0x64A, 0x644, 0x645, 0x646, 0x633, 0x639, 0x641, 0x635, 0x642, 0x631,
+    0x634, 0x62A, 0x62B, 0x62E, 0x630, 0x636, 0x638, 0x63A, 0x30, 0x2E, 0x30, 0x2C,
+    0x31, 0x2C, 0x32, 0x2C, 0x33, 0x2C, 0x34, 0x2C, 0x35, 0x2C, 0x36, 0x2C,
+    0x37, 0x2C, 0x38, 0x2C, 0x39, 0x2C, 0x28, 0x41, 0x29, 0x28, 0x42, 0x29,
+    0x28, 0x43, 0x29, 0x28, 0x44, 0x29, 0x28, 0x45, 0x29, 0x28, 0x46, 0x29,
+    0x28, 0x47, 0x29, 0x28, 0x48, 0x29, 0x28, 0x49, 0x29, 0x28, 0x4A, 0x29,
+    0x28, 0x4B, 0x29, 0x28, 0x4C, 0x29, 0x28, 0x4D, 0x29, 0x28, 0x4E, 0x29,
+    0x28, 0x4F, 0x29, 0x28, 0x50, 0x29, 0x28, 0x51, 0x29, 0x28, 0x52, 0x29,
+    0x28, 0x53, 0x29, 0x28, 0x54, 0x29, 0x28, 0x55, 0x29, 0x28, 0x56, 0x29,
+    0x28, 0x57, 0x29, 0x28, 0x58, 0x29, 0x28, 0x59, 0x29, 0x28, 0x5A, 0x29,
+    0x3014, 0x53, 0x3015, 0x43, 0x52, 0x43, 0x44, 0x57, 0x5A, 0x41, 0x42, 0x43,
+    0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F,
+    0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0x48,
+    0x56, 0x4D, 0x56, 0x53, 0x44, 0x53, 0x53, 0x50, 0x50, 0x56, 0x57, 0x43,
+    0x4D, 0x43, 0x4D, 0x44, 0x4D, 0x52, 0x44, 0x4A, 0x307B, 0x304B, 0x30B3, 0x30B3,
+    0x30B5, 0x624B, 0x5B57, 0x53CC, 0x30C6, 0x3099, 0x4E8C, 0x591A, 0x89E3, 0x5929, 0x4EA4, 0x6620,
+    0x7121, 0x6599, 0x524D, 0x5F8C, 0x518D, 0x65B0, 0x521D, 0x7D42, 0x751F, 0x8CA9, 0x58F0, 0x5439,
+    0x6F14, 0x6295, 0x6355, 0x4E00, 0x4E09, 0x904A, 0x5DE6, 0x4E2D, 0x53F3, 0x6307, 0x8D70, 0x6253,
+    0x7981, 0x7A7A, 0x5408, 0x6E80, 0x6709, 0x6708, 0x7533, 0x5272, 0x55B6, 0x914D, 0x3014, 0x672C,
+    0x3015, 0x3014, 0x4E09, 0x3015, 0x3014, 0x4E8C, 0x3015, 0x3014, 0x5B89, 0x3015, 0x3014, 0x70B9,
+    0x3015, 0x3014, 0x6253, 0x3015, 0x3014, 0x76D7, 0x3015, 0x3014, 0x52DD, 0x3015, 0x3014, 0x6557,
+    0x3015, 0x5F97, 0x53EF, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38,
+    0x39, 0x4E3D, 0x4E38, 0x4E41, 0x20122, 0x4F60, 0x4FAE, 0x4FBB, 0x5002, 0x507A, 0x5099, 0x50E7,
+    0x50CF, 0x349E, 0x2063A, 0x514D, 0x5154, 0x5164, 0x5177, 0x2051C, 0x34B9, 0x5167, 0x518D, 0x2054B,
+    0x5197, 0x51A4, 0x4ECC, 0x51AC, 0x51B5, 0x291DF, 0x51F5, 0x5203, 0x34DF, 0x523B, 0x5246, 0x5272,
+    0x5277, 0x3515, 0x52C7, 0x52C9, 0x52E4, 0x52FA, 0x5305, 0x5306, 0x5317, 0x5349, 0x5351, 0x535A,
+    0x5373, 0x537D, 0x537F, 0x537F, 0x537F, 0x20A2C, 0x7070, 0x53CA, 0x53DF, 0x20B63, 0x53EB, 0x53F1,
+    0x5406, 0x549E, 0x5438, 0x5448, 0x5468, 0x54A2, 0x54F6, 0x5510, 0x5553, 0x5563, 0x5584, 0x5584,
+    0x5599, 0x55AB, 0x55B3, 0x55C2, 0x5716, 0x5606, 0x5717, 0x5651, 0x5674, 0x5207, 0x58EE, 0x57CE,
+    0x57F4, 0x580D, 0x578B, 0x5832, 0x5831, 0x58AC, 0x214E4, 0x58F2, 0x58F7, 0x5906, 0x591A, 0x5922,
+    0x5962, 0x216A8, 0x216EA, 0x59EC, 0x5A1B, 0x5A27, 0x59D8, 0x5A66, 0x36EE, 0x36FC, 0x5B08, 0x5B3E,
+    0x5B3E, 0x219C8, 0x5BC3, 0x5BD8, 0x5BE7, 0x5BF3, 0x21B18, 0x5BFF, 0x5C06, 0x5F53, 0x5C22, 0x3781,
+    0x5C60, 0x5C6E, 0x5CC0, 0x5C8D, 0x21DE4, 0x5D43, 0x21DE6, 0x5D6E, 0x5D6B, 0x5D7C, 0x5DE1, 0x5DE2,
+    0x382F, 0x5DFD, 0x5E28, 0x5E3D, 0x5E69, 0x3862, 0x22183, 0x387C, 0x5EB0, 0x5EB3, 0x5EB6, 0x5ECA,
+    0x2A392, 0x5EFE, 0x22331, 0x22331, 0x8201, 0x5F22, 0x5F22, 0x38C7, 0x232B8, 0x261DA, 0x5F62, 0x5F6B,
+    0x38E3, 0x5F9A, 0x5FCD, 0x5FD7, 0x5FF9, 0x6081, 0x393A, 0x391C, 0x6094, 0x226D4, 0x60C7, 0x6148,
+    0x614C, 0x614E, 0x614C, 0x617A, 0x618E, 0x61B2, 0x61A4, 0x61AF, 0x61DE, 0x61F2, 0x61F6, 0x6210,
+    0x621B, 0x625D, 0x62B1, 0x62D4, 0x6350, 0x22B0C, 0x633D, 0x62FC, 0x6368, 0x6383, 0x63E4, 0x22BF1,
+    0x6422, 0x63C5, 0x63A9, 0x3A2E, 0x6469, 0x647E, 0x649D, 0x6477, 0x3A6C, 0x654F, 0x656C, 0x2300A,
+    0x65E3, 0x66F8, 0x6649, 0x3B19, 0x6691, 0x3B08, 0x3AE4, 0x5192, 0x5195, 0x6700, 0x669C, 0x80AD,
+    0x43D9, 0x6717, 0x671B, 0x6721, 0x675E, 0x6753, 0x233C3, 0x3B49, 0x67FA, 0x6785, 0x6852, 0x6885,
+    0x2346D, 0x688E, 0x681F, 0x6914, 0x3B9D, 0x6942, 0x69A3, 0x69EA, 0x6AA8, 0x236A3, 0x6ADB, 0x3C18,
+    0x6B21, 0x238A7, 0x6B54, 0x3C4E, 0x6B72, 0x6B9F, 0x6BBA, 0x6BBB, 0x23A8D, 0x21D0B, 0x23AFA, 0x6C4E,
+    0x23CBC, 0x6CBF, 0x6CCD, 0x6C67, 0x6D16, 0x6D3E, 0x6D77, 0x6D41, 0x6D69, 0x6D78, 0x6D85, 0x23D1E,
+    0x6D34, 0x6E2F, 0x6E6E, 0x3D33, 0x6ECB, 0x6EC7, 0x23ED1, 0x6DF9, 0x6F6E, 0x23F5E, 0x23F8E, 0x6FC6,
+    0x7039, 0x701E, 0x701B, 0x3D96, 0x704A, 0x707D, 0x7077, 0x70AD, 0x20525, 0x7145, 0x24263, 0x719C,
+    0x243AB, 0x7228, 0x7235, 0x7250, 0x24608, 0x7280, 0x7295, 0x24735, 0x24814, 0x737A, 0x738B, 0x3EAC,
+    0x73A5, 0x3EB8, 0x3EB8, 0x7447, 0x745C, 0x7471, 0x7485, 0x74CA, 0x3F1B, 0x7524, 0x24C36, 0x753E,
+    0x24C92, 0x7570, 0x2219F, 0x7610, 0x24FA1, 0x24FB8, 0x25044, 0x3FFC, 0x4008, 0x76F4, 0x250F3, 0x250F2,
+    0x25119, 0x25133, 0x771E, 0x771F, 0x771F, 0x774A, 0x4039, 0x778B, 0x4046, 0x4096, 0x2541D, 0x784E,
+    0x788C, 0x78CC, 0x40E3, 0x25626, 0x7956, 0x2569A, 0x256C5, 0x798F, 0x79EB, 0x412F, 0x7A40, 0x7A4A,
+    0x7A4F, 0x2597C, 0x25AA7, 0x25AA7, 0x7AEE, 0x4202, 0x25BAB, 0x7BC6, 0x7BC9, 0x4227, 0x25C80, 0x7CD2,
+    0x42A0, 0x7CE8, 0x7CE3, 0x7D00, 0x25F86, 0x7D63, 0x4301, 0x7DC7, 0x7E02, 0x7E45, 0x4334, 0x26228,
+    0x26247, 0x4359, 0x262D9, 0x7F7A, 0x2633E, 0x7F95, 0x7FFA, 0x8005, 0x264DA, 0x26523, 0x8060, 0x265A8,
+    0x8070, 0x2335F, 0x43D5, 0x80B2, 0x8103, 0x440B, 0x813E, 0x5AB5, 0x267A7, 0x267B5, 0x23393, 0x2339C,
+    0x8201, 0x8204, 0x8F9E, 0x446B, 0x8291, 0x828B, 0x829D, 0x52B3, 0x82B1, 0x82B3, 0x82BD, 0x82E6,
+    0x26B3C, 0x82E5, 0x831D, 0x8363, 0x83AD, 0x8323, 0x83BD, 0x83E7, 0x8457, 0x8353, 0x83CA, 0x83CC,
+    0x83DC, 0x26C36, 0x26D6B, 0x26CD5, 0x452B, 0x84F1, 0x84F3, 0x8516, 0x273CA, 0x8564, 0x26F2C, 0x455D,
+    0x4561, 0x26FB1, 0x270D2, 0x456B, 0x8650, 0x865C, 0x8667, 0x8669, 0x86A9, 0x8688, 0x870E, 0x86E2,
+    0x8779, 0x8728, 0x876B, 0x8786, 0x45D7, 0x87E1, 0x8801, 0x45F9, 0x8860, 0x8863, 0x27667, 0x88D7,
+    0x88DE, 0x4635, 0x88FA, 0x34BB, 0x278AE, 0x27966, 0x46BE, 0x46C7, 0x8AA0, 0x8AED, 0x8B8A, 0x8C55,
+    0x27CA8, 0x8CAB, 0x8CC1, 0x8D1B, 0x8D77, 0x27F2F, 0x20804, 0x8DCB, 0x8DBC, 0x8DF0, 0x208DE, 0x8ED4,
+    0x8F38, 0x285D2, 0x285ED, 0x9094, 0x90F1, 0x9111, 0x2872E, 0x911B, 0x9238, 0x92D7, 0x92D8, 0x927C,
+    0x93F9, 0x9415, 0x28BFA, 0x958B, 0x4995, 0x95B7, 0x28D77, 0x49E6, 0x96C3, 0x5DB2, 0x9723, 0x29145,
+    0x2921A, 0x4A6E, 0x4A76, 0x97E0, 0x2940A, 0x4AB2, 0x29496, 0x980B, 0x980B, 0x9829, 0x295B6, 0x98E2,
+    0x4B33, 0x9929, 0x99A7, 0x99C2, 0x99FE, 0x4BCE, 0x29B30, 0x9B12, 0x9C40, 0x9CFD, 0x4CCE, 0x4CED,
+    0x9D67, 0x2A0CE, 0x4CF8, 0x2A105, 0x2A20E, 0x2A291, 0x9EBB, 0x4D56, 0x9EF9, 0x9EFE, 0x9F05, 0x9F0F,
+    0x9F16, 0x9F3B, 0x2A600,
+];
+
+/// The primary composites `(first, second, composite)`, sorted by `(first, second)`. Hangul syllables are composed algorithmically.
+#[rustfmt::skip]
+pub(super) const COMPOSITIONS: &[(u32, u32, u32)] = &[
+    (0x3C, 0x338, 0x226E), (0x3D, 0x338, 0x2260), (0x3E, 0x338, 0x226F), (0x41, 0x300, 0xC0), (0x41, 0x301, 0xC1),
+    (0x41, 0x302, 0xC2), (0x41, 0x303, 0xC3), (0x41, 0x304, 0x100), (0x41, 0x306, 0x102), (0x41, 0x307, 0x226),
+    (0x41, 0x308, 0xC4), (0x41, 0x309, 0x1EA2), (0x41, 0x30A, 0xC5), (0x41, 0x30C, 0x1CD), (0x41, 0x30F, 0x200),
+    (0x41, 0x311, 0x202), (0x41, 0x323, 0x1EA0), (0x41, 0x325, 0x1E00), (0x41, 0x328, 0x104), (0x42, 0x307, 0x1E02),
+    (0x42, 0x323, 0x1E04), (0x42, 0x331, 0x1E06), (0x43, 0x301, 0x106), (0x43, 0x302, 0x108), (0x43, 0x307, 0x10A),
+    (0x43, 0x30C, 0x10C), (0x43, 0x327, 0xC7), (0x44, 0x307, 0x1E0A), (0x44, 0x30C, 0x10E), (0x44, 0x323, 0x1E0C),
+    (0x44, 0x327, 0x1E10), (0x44, 0x32D, 0x1E12), (0x44, 0x331, 0x1E0E), (0x45, 0x300, 0xC8), (0x45, 0x301, 0xC9),
+    (0x45, 0x302, 0xCA), (0x45, 0x303, 0x1EBC), (0x45, 0x304, 0x112), (0x45, 0x306, 0x114), (0x45, 0x307, 0x116),
+    (0x45, 0x308, 0xCB), (0x45, 0x309, 0x1EBA), (0x45, 0x30C, 0x11A), (0x45, 0x30F, 0x204), (0x45, 0x311, 0x206),
+    (0x45, 0x323, 0x1EB8), (0x45, 0x327, 0x228), (0x45, 0x328, 0x118), (0x45, 0x32D, 0x1E18), (0x45, 0x330, 0x1E1A),
+    (0x46, 0x307, 0x1E1E), (0x47, 0x301, 0x1F4), (0x47, 0x302, 0x11C), (0x47, 0x304, 0x1E20), (0x47, 0x306, 0x11E),
+    (0x47, 0x307, 0x120), (0x47, 0x30C, 0x1E6), (0x47, 0x327, 0x122), (0x48, 0x302, 0x124), (0x48, 0x307, 0x1E22),
+    (0x48, 0x308, 0x1E26), (0x48, 0x30C, 0x21E), (0x48, 0x323, 0x1E24), (0x48, 0x327, 0x1E28), (0x48, 0x32E, 0x1E2A),
+    (0x49, 0x300, 0xCC), (0x49, 0x301, 0xCD), (0x49, 0x302, 0xCE), (0x49, 0x303, 0x128), (0x49, 0x304, 0x12A),
+    (0x49, 0x306, 0x12C), (0x49, 0x307, 0x130), (0x49, 0x308, 0xCF), (0x49, 0x309, 0x1EC8), (0x49, 0x30C, 0x1CF),
+    (0x49, 0x30F, 0x208), (0x49, 0x311, 0x20A), (0x49, 0x323, 0x1ECA), (0x49, 0x328, 0x12E), (0x49, 0x330, 0x1E2C),
+    (0x4A, 0x302, 0x134), (0x4B, 0x301, 0x1E30), (0x4B, 0x30C, 0x1E8), (0x4B, 0x323, 0x1E32), (0x4B, 0x327, 0x136),
+    (0x4B, 0x331, 0x1E34), (0x4C, 0x301, 0x139), (0x4C, 0x30C, 0x13D), (0x4C, 0x323, 0x1E36), (0x4C, 0x327, 0x13B),
+    (0x4C, 0x32D, 0x1E3C), (0x4C, 0x331, 0x1E3A), (0x4D, 0x301, 0x1E3E), (0x4D, 0x307, 0x1E40), (0x4D, 0x323, 0x1E42),
+    (0x4E, 0x300, 0x1F8), (0x4E, 0x301, 0x143), (0x4E, 0x303, 0xD1), (0x4E, 0x307, 0x1E44), (0x4E, 0x30C, 0x147),
+    (0x4E, 0x323, 0x1E46), (0x4E, 0x327, 0x145), (0x4E, 0x32D, 0x1E4A), (0x4E, 0x331, 0x1E48), (0x4F, 0x300, 0xD2),
+    (0x4F, 0x301, 0xD3), (0x4F, 0x302, 0xD4), (0x4F, 0x303, 0xD5), (0x4F, 0x304, 0x14C), (0x4F, 0x306, 0x14E),
+    (0x4F, 0x307, 0x22E), (0x4F, 0x308, 0xD6), (0x4F, 0x309, 0x1ECE), (0x4F, 0x30B, 0x150), (0x4F, 0x30C, 0x1D1),
+    (0x4F, 0x30F, 0x20C), (0x4F, 0x311, 0x20E), (0x4F, 0x31B, 0x1A0), (0x4F, 0x323, 0x1ECC), (0x4F, 0x328, 0x1EA),
+    (0x50, 0x301, 0x1E54), (0x50, 0x307, 0x1E56), (0x52, 0x301, 0x154), (0x52, 0x307, 0x1E58), (0x52, 0x30C, 0x158),
+    (0x52, 0x30F, 0x210), (0x52, 0x311, 0x212), (0x52, 0x323, 0x1E5A), (0x52, 0x327, 0x156), (0x52, 0x331, 0x1E5E),
+    (0x53, 0x301, 0x15A), (0x53, 0x302, 0x15C), (0x53, 0x307, 0x1E60), (0x53, 0x30C, 0x160), (0x53, 0x323, 0x1E62),
+    (0x53, 0x326, 0x218), (0x53, 0x327, 0x15E), (0x54, 0x307, 0x1E6A), (0x54, 0x30C, 0x164), (0x54, 0x323, 0x1E6C),
+    (0x54, 0x326, 0x21A), (0x54, 0x327, 0x162), (0x54, 0x32D, 0x1E70), (0x54, 0x331, 0x1E6E), (0x55, 0x300, 0xD9),
+    (0x55, 0x301, 0xDA), (0x55, 0x302, 0xDB), (0x55, 0x303, 0x168), (0x55, 0x304, 0x16A), (0x55, 0x306, 0x16C),
+    (0x55, 0x308, 0xDC), (0x55, 0x309, 0x1EE6), (0x55, 0x30A, 0x16E), (0x55, 0x30B, 0x170), (0x55, 0x30C, 0x1D3),
+    (0x55, 0x30F, 0x214), (0x55, 0x311, 0x216), (0x55, 0x31B, 0x1AF), (0x55, 0x323, 0x1EE4), (0x55, 0x324, 0x1E72),
+    (0x55, 0x328, 0x172), (0x55, 0x32D, 0x1E76), (0x55, 0x330, 0x1E74), (0x56, 0x303, 0x1E7C), (0x56, 0x323, 0x1E7E),
+    (0x57, 0x300, 0x1E80), (0x57, 0x301, 0x1E82), (0x57, 0x302, 0x174), (0x57, 0x307, 0x1E86), (0x57, 0x308, 0x1E84),
+    (0x57, 0x323, 0x1E88), (0x58, 0x307, 0x1E8A), (0x58, 0x308, 0x1E8C), (0x59, 0x300, 0x1EF2), (0x59, 0x301, 0xDD),
+    (0x59, 0x302, 0x176), (0x59, 0x303, 0x1EF8), (0x59, 0x304, 0x232), (0x59, 0x307, 0x1E8E), (0x59, 0x308, 0x178),
+    (0x59, 0x309, 0x1EF6), (0x59, 0x323, 0x1EF4), (0x5A, 0x301, 0x179), (0x5A, 0x302, 0x1E90), (0x5A, 0x307, 0x17B),
+    (0x5A, 0x30C, 0x17D), (0x5A, 0x323, 0x1E92), (0x5A, 0x331, 0x1E94), (0x61, 0x300, 0xE0), (0x61, 0x301, 0xE1),
+    (0x61, 0x302, 0xE2), (0x61, 0x303, 0xE3), (0x61, 0x304, 0x101), (0x61, 0x306, 0x103), (0x61, 0x307, 0x227),
+    (0x61, 0x308, 0xE4), (0x61, 0x309, 0x1EA3), (0x61, 0x30A, 0xE5), (0x61, 0x30C, 0x1CE), (0x61, 0x30F, 0x201),
+    (0x61, 0x311, 0x203), (0x61, 0x323, 0x1EA1), (0x61, 0x325, 0x1E01), (0x61, 0x328, 0x105), (0x62, 0x307, 0x1E03),
+    (0x62, 0x323, 0x1E05), (0x62, 0x331, 0x1E07), (0x63, 0x301, 0x107), (0x63, 0x302, 0x109), (0x63, 0x307, 0x10B),
+    (0x63, 0x30C, 0x10D), (0x63, 0x327, 0xE7), (0x64, 0x307, 0x1E0B), (0x64, 0x30C, 0x10F), (0x64, 0x323, 0x1E0D),
+    (0x64, 0x327, 0x1E11), (0x64, 0x32D, 0x1E13), (0x64, 0x331, 0x1E0F), (0x65, 0x300, 0xE8), (0x65, 0x301, 0xE9),
+    (0x65, 0x302, 0xEA), (0x65, 0x303, 0x1EBD), (0x65, 0x304, 0x113), (0x65, 0x306, 0x115), (0x65, 0x307, 0x117),
+    (0x65, 0x308, 0xEB), (0x65, 0x309, 0x1EBB), (0x65, 0x30C, 0x11B), (0x65, 0x30F, 0x205), (0x65, 0x311, 0x207),
+    (0x65, 0x323, 0x1EB9), (0x65, 0x327, 0x229), (0x65, 0x328, 0x119), (0x65, 0x32D, 0x1E19), (0x65, 0x330, 0x1E1B),
+    (0x66, 0x307, 0x1E1F), (0x67, 0x301, 0x1F5), (0x67, 0x302, 0x11D), (0x67, 0x304, 0x1E21), (0x67, 0x306, 0x11F),
+    (0x67, 0x307, 0x121), (0x67, 0x30C, 0x1E7), (0x67, 0x327, 0x123), (0x68, 0x302, 0x125), (0x68, 0x307, 0x1E23),
+    (0x68, 0x308, 0x1E27), (0x68, 0x30C, 0x21F), (0x68, 0x323, 0x1E25), (0x68, 0x327, 0x1E29), (0x68, 0x32E, 0x1E2B),
+    (0x68, 0x331, 0x1E96), (0x69, 0x300, 0xEC), (0x69, 0x301, 0xED), (0x69, 0x302, 0xEE), (0x69, 0x303, 0x129),
+    (0x69, 0x304, 0x12B), (0x69, 0x306, 0x12D), (0x69, 0x308, 0xEF), (0x69, 0x309, 0x1EC9), (0x69, 0x30C, 0x1D0),
+    (0x69, 0x30F, 0x209), (0x69, 0x311, 0x20B), (0x69, 0x323, 0x1ECB), (0x69, 0x328, 0x12F), (0x69, 0x330, 0x1E2D),
+    (0x6A, 0x302, 0x135), (0x6A, 0x30C, 0x1F0), (0x6B, 0x301, 0x1E31), (0x6B, 0x30C, 0x1E9), (0x6B, 0x323, 0x1E33),
+    (0x6B, 0x327, 0x137), (0x6B, 0x331, 0x1E35), (0x6C, 0x301, 0x13A), (0x6C, 0x30C, 0x13E), (0x6C, 0x323, 0x1E37),
+    (0x6C, 0x327, 0x13C), (0x6C, 0x32D, 0x1E3D), (0x6C, 0x331, 0x1E3B), (0x6D, 0x301, 0x1E3F), (0x6D, 0x307, 0x1E41),
+    (0x6D, 0x323, 0x1E43), (0x6E, 0x300, 0x1F9), (0x6E, 0x301, 0x144), (0x6E, 0x303, 0xF1), (0x6E, 0x307, 0x1E45),
+    (0x6E, 0x30C, 0x148), (0x6E, 0x323, 0x1E47), (0x6E, 0x327, 0x146), (0x6E, 0x32D, 0x1E4B), (0x6E, 0x331, 0x1E49),
+    (0x6F, 0x300, 0xF2), (0x6F, 0x301, 0xF3), (0x6F, 0x302, 0xF4), (0x6F, 0x303, 0xF5), (0x6F, 0x304, 0x14D),
+    (0x6F, 0x306, 0x14F), (0x6F, 0x307, 0x22F), (0x6F, 0x308, 0xF6), (0x6F, 0x309, 0x1ECF), (0x6F, 0x30B, 0x151),
+    (0x6F, 0x30C, 0x1D2), (0x6F, 0x30F, 0x20D), (0x6F, 0x311, 0x20F), (0x6F, 0x31B, 0x1A1), (0x6F, 0x323, 0x1ECD),
+    (0x6F, 0x328, 0x1EB), (0x70, 0x301, 0x1E55), (0x70, 0x307, 0x1E57), (0x72, 0x301, 0x155), (0x72, 0x307, 0x1E59),
+    (0x72, 0x30C, 0x159), (0x72, 0x30F, 0x211), (0x72, 0x311, 0x213), (0x72, 0x323, 0x1E5B), (0x72, 0x327, 0x157),
+    (0x72, 0x331, 0x1E5F), (0x73, 0x301, 0x15B), (0x73, 0x302, 0x15D), (0x73, 0x307, 0x1E61), (0x73, 0x30C, 0x161),
+    (0x73, 0x323, 0x1E63), (0x73, 0x326, 0x219), (0x73, 0x327, 0x15F), (0x74, 0x307, 0x1E6B), (0x74, 0x308, 0x1E97),
+    (0x74, 0x30C, 0x165), (0x74, 0x323, 0x1E6D), (0x74, 0x326, 0x21B), (0x74, 0x327, 0x163), (0x74, 0x32D, 0x1E71),
+    (0x74, 0x331, 0x1E6F), (0x75, 0x300, 0xF9), (0x75, 0x301, 0xFA), (0x75, 0x302, 0xFB), (0x75, 0x303, 0x169),
+    (0x75, 0x304, 0x16B), (0x75, 0x306, 0x16D), (0x75, 0x308, 0xFC), (0x75, 0x309, 0x1EE7), (0x75, 0x30A, 0x16F),
+    (0x75, 0x30B, 0x171), (0x75, 0x30C, 0x1D4), (0x75, 0x30F, 0x215), (0x75, 0x311, 0x217), (0x75, 0x31B, 0x1B0),
+    (0x75, 0x323, 0x1EE5), (0x75, 0x324, 0x1E73), (0x75, 0x328, 0x173), (0x75, 0x32D, 0x1E77), (0x75, 0x330, 0x1E75),
+    (0x76, 0x303, 0x1E7D), (0x76, 0x323, 0x1E7F), (0x77, 0x300, 0x1E81), (0x77, 0x301, 0x1E83), (0x77, 0x302, 0x175),
+    (0x77, 0x307, 0x1E87), (0x77, 0x308, 0x1E85), (0x77, 0x30A, 0x1E98), (0x77, 0x323, 0x1E89), (0x78, 0x307, 0x1E8B),
+    (0x78, 0x308, 0x1E8D), (0x79, 0x300, 0x1EF3), (0x79, 0x301, 0xFD), (0x79, 0x302, 0x177), (0x79, 0x303, 0x1EF9),
+    (0x79, 0x304, 0x233), (0x79, 0x307, 0x1E8F), (0x79, 0x308, 0xFF), (0x79, 0x309, 0x1EF7), (0x79, 0x30A, 0x1E99),
+    (0x79, 0x323, 0x1EF5), (0x7A, 0x301, 0x17A), (0x7A, 0x302, 0x1E91), (0x7A, 0x307, 0x17C), (0x7A, 0x30C, 0x17E),
+    (0x7A, 0x323, 0x1E93), (0x7A, 0x331, 0x1E95), (0xA8, 0x300, 0x1FED), (0xA8, 0x301, 0x385), (0xA8, 0x342, 0x1FC1),
+    (0xC2, 0x300, 0x1EA6), (0xC2, 0x301, 0x1EA4), (0xC2, 0x303, 0x1EAA), (0xC2, 0x309, 0x1EA8), (0xC4, 0x304, 0x1DE),
+    (0xC5, 0x301, 0x1FA), (0xC6, 0x301, 0x1FC), (0xC6, 0x304, 0x1E2), (0xC7, 0x301, 0x1E08), (0xCA, 0x300, 0x1EC0),
+    (0xCA, 0x301, 0x1EBE), (0xCA, 0x303, 0x1EC4), (0xCA, 0x309, 0x1EC2), (0xCF, 0x301, 0x1E2E), (0xD4, 0x300, 0x1ED2),
+    (0xD4, 0x301, 0x1ED0), (0xD4, 0x303, 0x1ED6), (0xD4, 0x309, 0x1ED4), (0xD5, 0x301, 0x1E4C), (0xD5, 0x304, 0x22C),
+    (0xD5, 0x308, 0x1E4E), (0xD6, 0x304, 0x22A), (0xD8, 0x301, 0x1FE), (0xDC, 0x300, 0x1DB), (0xDC, 0x301, 0x1D7),
+    (0xDC, 0x304, 0x1D5), (0xDC, 0x30C, 0x1D9), (0xE2, 0x300, 0x1EA7), (0xE2, 0x301, 0x1EA5), (0xE2, 0x303, 0x1EAB),
+    (0xE2, 0x309, 0x1EA9), (0xE4, 0x304, 0x1DF), (0xE5, 0x301, 0x1FB), (0xE6, 0x301, 0x1FD), (0xE6, 0x304, 0x1E3),
+    (0xE7, 0x301, 0x1E09), (0xEA, 0x300, 0x1EC1), (0xEA, 0x301, 0x1EBF), (0xEA, 0x303, 0x1EC5), (0xEA, 0x309, 0x1EC3),
+    (0xEF, 0x301, 0x1E2F), (0xF4, 0x300, 0x1ED3), (0xF4, 0x301, 0x1ED1), (0xF4, 0x303, 0x1ED7), (0xF4, 0x309, 0x1ED5),
+    (0xF5, 0x301, 0x1E4D), (0xF5, 0x304, 0x22D), (0xF5, 0x308, 0x1E4F), (0xF6, 0x304, 0x22B), (0xF8, 0x301, 0x1FF),
+    (0xFC, 0x300, 0x1DC), (0xFC, 0x301, 0x1D8), (0xFC, 0x304, 0x1D6), (0xFC, 0x30C, 0x1DA), (0x102, 0x300, 0x1EB0),
+    (0x102, 0x301, 0x1EAE), (0x102, 0x303, 0x1EB4), (0x102, 0x309, 0x1EB2), (0x103, 0x300, 0x1EB1), (0x103, 0x301, 0x1EAF),
+    (0x103, 0x303, 0x1EB5), (0x103, 0x309, 0x1EB3), (0x112, 0x300, 0x1E14), (0x112, 0x301, 0x1E16), (0x113, 0x300, 0x1E15),
+    (0x113, 0x301, 0x1E17), (0x14C, 0x300, 0x1E50), (0x14C, 0x301, 0x1E52), (0x14D, 0x300, 0x1E51), (0x14D, 0x301, 0x1E53),
+    (0x15A, 0x307, 0x1E64), (0x15B, 0x307, 0x1E65), (0x160, 0x307, 0x1E66), (0x161, 0x307, 0x1E67), (0x168, 0x301, 0x1E78),
+    (0x169, 0x301, 0x1E79), (0x16A, 0x308, 0x1E7A), (0x16B, 0x308, 0x1E7B), (0x17F, 0x307, 0x1E9B), (0x1A0, 0x300, 0x1EDC),
+    (0x1A0, 0x301, 0x1EDA), (0x1A0, 0x303, 0x1EE0), (0x1A0, 0x309, 0x1EDE), (0x1A0, 0x323, 0x1EE2), (0x1A1, 0x300, 0x1EDD),
+    (0x1A1, 0x301, 0x1EDB), (0x1A1, 0x303, 0x1EE1), (0x1A1, 0x309, 0x1EDF), (0x1A1, 0x323, 0x1EE3), (0x1AF, 0x300, 0x1EEA),
+    (0x1AF, 0x301, 0x1EE8), (0x1AF, 0x303, 0x1EEE), (0x1AF, 0x309, 0x1EEC), (0x1AF, 0x323, 0x1EF0), (0x1B0, 0x300, 0x1EEB),
+    (0x1B0, 0x301, 0x1EE9), (0x1B0, 0x303, 0x1EEF), (0x1B0, 0x309, 0x1EED), (0x1B0, 0x323, 0x1EF1), (0x1B7, 0x30C, 0x1EE),
+    (0x1EA, 0x304, 0x1EC), (0x1EB, 0x304, 0x1ED), (0x226, 0x304, 0x1E0), (0x227, 0x304, 0x1E1), (0x228, 0x306, 0x1E1C),
+    (0x229, 0x306, 0x1E1D), (0x22E, 0x304, 0x230), (0x22F, 0x304, 0x231), (0x292, 0x30C, 0x1EF), (0x391, 0x300, 0x1FBA),
+    (0x391, 0x301, 0x386), (0x391, 0x304, 0x1FB9), (0x391, 0x306, 0x1FB8), (0x391, 0x313, 0x1F08), (0x391, 0x314, 0x1F09),
+    (0x391, 0x345, 0x1FBC), (0x395, 0x300, 0x1FC8), (0x395, 0x301, 0x388), (0x395, 0x313, 0x1F18), (0x395, 0x314, 0x1F19),
+    (0x397, 0x300, 0x1FCA), (0x397, 0x301, 0x389), (0x397, 0x313, 0x1F28), (0x397, 0x314, 0x1F29), (0x397, 0x345, 0x1FCC),
+    (0x399, 0x300, 0x1FDA), (0x399, 0x301, 0x38A), (0x399, 0x304, 0x1FD9), (0x399, 0x306, 0x1FD8), (0x399, 0x308, 0x3AA),
+    (0x399, 0x313, 0x1F38), (0x399, 0x314, 0x1F39), (0x39F, 0x300, 0x1FF8), (0x39F, 0x301, 0x38C), (0x39F, 0x313, 0x1F48),
+    (0x39F, 0x314, 0x1F49), (0x3A1, 0x314, 0x1FEC), (0x3A5, 0x300, 0x1FEA), (0x3A5, 0x301, 0x38E), (0x3A5, 0x304, 0x1FE9),
+    (0x3A5, 0x306, 0x1FE8), (0x3A5, 0x308, 0x3AB), (0x3A5, 0x314, 0x1F59), (0x3A9, 0x300, 0x1FFA), (0x3A9, 0x301, 0x38F),
+    (0x3A9, 0x313, 0x1F68), (0x3A9, 0x314, 0x1F69), (0x3A9, 0x345, 0x1FFC), (0x3AC, 0x345, 0x1FB4), (0x3AE, 0x345, 0x1FC4),
+    (0x3B1, 0x300, 0x1F70), (0x3B1, 0x301, 0x3AC), (0x3B1, 0x304, 0x1FB1), (0x3B1, 0x306, 0x1FB0), (0x3B1, 0x313, 0x1F00),
+    (0x3B1, 0x314, 0x1F01), (0x3B1, 0x342, 0x1FB6), (0x3B1, 0x345, 0x1FB3), (0x3B5, 0x300, 0x1F72), (0x3B5, 0x301, 0x3AD),
+    (0x3B5, 0x313, 0x1F10), (0x3B5, 0x314, 0x1F11), (0x3B7, 0x300, 0x1F74), (0x3B7, 0x301, 0x3AE), (0x3B7, 0x313, 0x1F20),
+    (0x3B7, 0x314, 0x1F21), (0x3B7, 0x342, 0x1FC6), (0x3B7, 0x345, 0x1FC3), (0x3B9, 0x300, 0x1F76), (0x3B9, 0x301, 0x3AF),
+    (0x3B9, 0x304, 0x1FD1), (0x3B9, 0x306, 0x1FD0), (0x3B9, 0x308, 0x3CA), (0x3B9, 0x313, 0x1F30), (0x3B9, 0x314, 0x1F31),
+    (0x3B9, 0x342, 0x1FD6), (0x3BF, 0x300, 0x1F78), (0x3BF, 0x301, 0x3CC), (0x3BF, 0x313, 0x1F40), (0x3BF, 0x314, 0x1F41),
+    (0x3C1, 0x313, 0x1FE4), (0x3C1, 0x314, 0x1FE5), (0x3C5, 0x300, 0x1F7A), (0x3C5, 0x301, 0x3CD), (0x3C5, 0x304, 0x1FE1),
+    (0x3C5, 0x306, 0x1FE0), (0x3C5, 0x308, 0x3CB), (0x3C5, 0x313, 0x1F50), (0x3C5, 0x314, 0x1F51), (0x3C5, 0x342, 0x1FE6),
+    (0x3C9, 0x300, 0x1F7C), (0x3C9, 0x301, 0x3CE), (0x3C9, 0x313, 0x1F60), (0x3C9, 0x314, 0x1F61), (0x3C9, 0x342, 0x1FF6),
+    (0x3C9, 0x345, 0x1FF3), (0x3CA, 0x300, 0x1FD2), (0x3CA, 0x301, 0x390), (0x3CA, 0x342, 0x1FD7), (0x3CB, 0x300, 0x1FE2),
+    (0x3CB, 0x301, 0x3B0), (0x3CB, 0x342, 0x1FE7), (0x3CE, 0x345, 0x1FF4), (0x3D2, 0x301, 0x3D3), (0x3D2, 0x308, 0x3D4),
+    (0x406, 0x308, 0x407), (0x410, 0x306, 0x4D0), (0x410, 0x308, 0x4D2), (0x413, 0x301, 0x403), (0x415, 0x300, 0x400),
+    (0x415, 0x306, 0x4D6), (0x415, 0x308, 0x401), (0x416, 0x306, 0x4C1), (0x416, 0x308, 0x4DC), (0x417, 0x308, 0x4DE),
+    (0x418, 0x300, 0x40D), (0x418, 0x304, 0x4E2), (0x418, 0x306, 0x419), (0x418, 0x308, 0x4E4), (0x41A, 0x301, 0x40C),
+    (0x41E, 0x308, 0x4E6), (0x423, 0x304, 0x4EE), (0x423, 0x306, 0x40E), (0x423, 0x308, 0x4F0), (0x423, 0x30B, 0x4F2),
+    (0x427, 0x308, 0x4F4), (0x42B, 0x308, 0x4F8), (0x42D, 0x308, 0x4EC), (0x430, 0x306, 0x4D1), (0x430, 0x308, 0x4D3),
+    (0x433, 0x301, 0x453), (0x435, 0x300, 0x450), (0x435, 0x306, 0x4D7), (0x435, 0x308, 0x451), (0x436, 0x306, 0x4C2),
+    (0x436, 0x308, 0x4DD), (0x437, 0x308, 0x4DF), (0x438, 0x300, 0x45D), (0x438, 0x304, 0x4E3), (0x438, 0x306, 0x439),
+    (0x438, 0x308, 0x4E5), (0x43A, 0x301, 0x45C), (0x43E, 0x308, 0x4E7), (0x443, 0x304, 0x4EF), (0x443, 0x306, 0x45E),
+    (0x443, 0x308, 0x4F1), (0x443, 0x30B, 0x4F3), (0x447, 0x308, 0x4F5), (0x44B, 0x308, 0x4F9), (0x44D, 0x308, 0x4ED),
+    (0x456, 0x308, 0x457), (0x474, 0x30F, 0x476), (0x475, 0x30F, 0x477), (0x4D8, 0x308, 0x4DA), (0x4D9, 0x308, 0x4DB),
+    (0x4E8, 0x308, 0x4EA), (0x4E9, 0x308, 0x4EB), (0x627, 0x653, 0x622), (0x627, 0x654, 0x623), (0x627, 0x655, 0x625),
+    (0x648, 0x654, 0x624), (0x64A, 0x654, 0x626), (0x6C1, 0x654, 0x6C2), (0x6D2, 0x654, 0x6D3), (0x6D5, 0x654, 0x6C0),
+    (0x928, 0x93C, 0x929), (0x930, 0x93C, 0x931), (0x933, 0x93C, 0x934), (0x9C7, 0x9BE, 0x9CB), (0x9C7, 0x9D7, 0x9CC),
+    (0xB47, 0xB3E, 0xB4B), (0xB47, 0xB56, 0xB48), (0xB47, 0xB57, 0xB4C), (0xB92, 0xBD7, 0xB94), (0xBC6, 0xBBE, 0xBCA),
+    (0xBC6, 0xBD7, 0xBCC), (0xBC7, 0xBBE, 0xBCB), (0xC46, 0xC56, 0xC48), (0xCBF, 0xCD5, 0xCC0), (0xCC6, 0xCC2, 0xCCA),
+    (0xCC6, 0xCD5, 0xCC7), (0xCC6, 0xCD6, 0xCC8), (0xCCA, 0xCD5, 0xCCB), (0xD46, 0xD3E, 0xD4A), (0xD46, 0xD57, 0xD4C),
+    (0xD47, 0xD3E, 0xD4B), (0xDD9, 0xDCA, 0xDDA), (0xDD9, 0xDCF, 0xDDC), (0xDD9, 0xDDF, 0xDDE), (0xDDC, 0xDCA, 0xDDD),
+    (0x1025, 0x102E, 0x1026), (0x1B05, 0x1B35, 0x1B06), (0x1B07, 0x1B35, 0x1B08), (0x1B09, 0x1B35, 0x1B0A), (0x1B0B, 0x1B35, 0x1B0C),
+    (0x1B0D, 0x1B35, 0x1B0E), (0x1B11, 0x1B35, 0x1B12), (0x1B3A, 0x1B35, 0x1B3B), (0x1B3C, 0x1B35, 0x1B3D), (0x1B3E, 0x1B35, 0x1B40),
+    (0x1B3F, 0x1B35, 0x1B41), (0x1B42, 0x1B35, 0x1B43), (0x1E36, 0x304, 0x1E38), (0x1E37, 0x304, 0x1E39), (0x1E5A, 0x304, 0x1E5C),
+    (0x1E5B, 0x304, 0x1E5D), (0x1E62, 0x307, 0x1E68), (0x1E63, 0x307, 0x1E69), (0x1EA0, 0x302, 0x1EAC), (0x1EA0, 0x306, 0x1EB6),
+    (0x1EA1, 0x302, 0x1EAD), (0x1EA1, 0x306, 0x1EB7), (0x1EB8, 0x302, 0x1EC6), (0x1EB9, 0x302, 0x1EC7), (0x1ECC, 0x302, 0x1ED8),
+    (0x1ECD, 0x302, 0x1ED9), (0x1F00, 0x300, 0x1F02), (0x1F00, 0x301, 0x1F04), (0x1F00, 0x342, 0x1F06), (0x1F00, 0x345, 0x1F80),
+    (0x1F01, 0x300, 0x1F03), (0x1F01, 0x301, 0x1F05), (0x1F01, 0x342, 0x1F07), (0x1F01, 0x345, 0x1F81), (0x1F02, 0x345, 0x1F82),
+    (0x1F03, 0x345, 0x1F83), (0x1F04, 0x345, 0x1F84), (0x1F05, 0x345, 0x1F85), (0x1F06, 0x345, 0x1F86), (0x1F07, 0x345, 0x1F87),
+    (0x1F08, 0x300, 0x1F0A), (0x1F08, 0x301, 0x1F0C), (0x1F08, 0x342, 0x1F0E), (0x1F08, 0x345, 0x1F88), (0x1F09, 0x300, 0x1F0B),
+    (0x1F09, 0x301, 0x1F0D), (0x1F09, 0x342, 0x1F0F), (0x1F09, 0x345, 0x1F89), (0x1F0A, 0x345, 0x1F8A), (0x1F0B, 0x345, 0x1F8B),
+    (0x1F0C, 0x345, 0x1F8C), (0x1F0D, 0x345, 0x1F8D), (0x1F0E, 0x345, 0x1F8E), (0x1F0F, 0x345, 0x1F8F), (0x1F10, 0x300, 0x1F12),
+    (0x1F10, 0x301, 0x1F14), (0x1F11, 0x300, 0x1F13), (0x1F11, 0x301, 0x1F15), (0x1F18, 0x300, 0x1F1A), (0x1F18, 0x301, 0x1F1C),
+    (0x1F19, 0x300, 0x1F1B), (0x1F19, 0x301, 0x1F1D), (0x1F20, 0x300, 0x1F22), (0x1F20, 0x301, 0x1F24), (0x1F20, 0x342, 0x1F26),
+    (0x1F20, 0x345, 0x1F90), (0x1F21, 0x300, 0x1F23), (0x1F21, 0x301, 0x1F25), (0x1F21, 0x342, 0x1F27), (0x1F21, 0x345, 0x1F91),
+    (0x1F22, 0x345, 0x1F92), (0x1F23, 0x345, 0x1F93), (0x1F24, 0x345, 0x1F94), (0x1F25, 0x345, 0x1F95), (0x1F26, 0x345, 0x1F96),
+    (0x1F27, 0x345, 0x1F97), (0x1F28, 0x300, 0x1F2A), (0x1F28, 0x301, 0x1F2C), (0x1F28, 0x342, 0x1F2E), (0x1F28, 0x345, 0x1F98),
+    (0x1F29, 0x300, 0x1F2B), (0x1F29, 0x301, 0x1F2D), (0x1F29, 0x342, 0x1F2F), (0x1F29, 0x345, 0x1F99), (0x1F2A, 0x345, 0x1F9A),
+    (0x1F2B, 0x345, 0x1F9B), (0x1F2C, 0x345, 0x1F9C), (0x1F2D, 0x345, 0x1F9D), (0x1F2E, 0x345, 0x1F9E), (0x1F2F, 0x345, 0x1F9F),
+    (0x1F30, 0x300, 0x1F32), (0x1F30, 0x301, 0x1F34), (0x1F30, 0x342, 0x1F36), (0x1F31, 0x300, 0x1F33), (0x1F31, 0x301, 0x1F35),
+    (0x1F31, 0x342, 0x1F37), (0x1F38, 0x300, 0x1F3A), (0x1F38, 0x301, 0x1F3C), (0x1F38, 0x342, 0x1F3E), (0x1F39, 0x300, 0x1F3B),
+    (0x1F39, 0x301, 0x1F3D), (0x1F39, 0x342, 0x1F3F), (0x1F40, 0x300, 0x1F42), (0x1F40, 0x301, 0x1F44), (0x1F41, 0x300, 0x1F43),
+    (0x1F41, 0x301, 0x1F45), (0x1F48, 0x300, 0x1F4A), (0x1F48, 0x301, 0x1F4C), (0x1F49, 0x300, 0x1F4B), (0x1F49, 0x301, 0x1F4D),
+    (0x1F50, 0x300, 0x1F52), (0x1F50, 0x301, 0x1F54), (0x1F50, 0x342, 0x1F56), (0x1F51, 0x300, 0x1F53), (0x1F51, 0x301, 0x1F55),
+    (0x1F51, 0x342, 0x1F57), (0x1F59, 0x300, 0x1F5B), (0x1F59, 0x301, 0x1F5D), (0x1F59, 0x342, 0x1F5F), (0x1F60, 0x300, 0x1F62),
+    (0x1F60, 0x301, 0x1F64), (0x1F60, 0x342, 0x1F66), (0x1F60, 0x345, 0x1FA0), (0x1F61, 0x300, 0x1F63), (0x1F61, 0x301, 0x1F65),
+    (0x1F61, 0x342, 0x1F67), (0x1F61, 0x345, 0x1FA1), (0x1F62, 0x345, 0x1FA2), (0x1F63, 0x345, 0x1FA3), (0x1F64, 0x345, 0x1FA4),
+    (0x1F65, 0x345, 0x1FA5), (0x1F66, 0x345, 0x1FA6), (0x1F67, 0x345, 0x1FA7), (0x1F68, 0x300, 0x1F6A), (0x1F68, 0x301, 0x1F6C),
+    (0x1F68, 0x342, 0x1F6E), (0x1F68, 0x345, 0x1FA8), (0x1F69, 0x300, 0x1F6B), (0x1F69, 0x301, 0x1F6D), (0x1F69, 0x342, 0x1F6F),
+    (0x1F69, 0x345, 0x1FA9), (0x1F6A, 0x345, 0x1FAA), (0x1F6B, 0x345, 0x1FAB), (0x1F6C, 0x345, 0x1FAC), (0x1F6D, 0x345, 0x1FAD),
+    (0x1F6E, 0x345, 0x1FAE), (0x1F6F, 0x345, 0x1FAF), (0x1F70, 0x345, 0x1FB2), (0x1F74, 0x345, 0x1FC2), (0x1F7C, 0x345, 0x1FF2),
+    (0x1FB6, 0x345, 0x1FB7), (0x1FBF, 0x300, 0x1FCD), (0x1FBF, 0x301, 0x1FCE), (0x1FBF, 0x342, 0x1FCF), (0x1FC6, 0x345, 0x1FC7),
+    (0x1FF6, 0x345, 0x1FF7), (0x1FFE, 0x300, 0x1FDD), (0x1FFE, 0x301, 0x1FDE), (0x1FFE, 0x342, 0x1FDF), (0x2190, 0x338, 0x219A),
+    (0x2192, 0x338, 0x219B), (0x2194, 0x338, 0x21AE), (0x21D0, 0x338, 0x21CD), (0x21D2, 0x338, 0x21CF), (0x21D4, 0x338, 0x21CE),
+    (0x2203, 0x338, 0x2204), (0x2208, 0x338, 0x2209), (0x220B, 0x338, 0x220C), (0x2223, 0x338, 0x2224), (0x2225, 0x338, 0x2226),
+    (0x223C, 0x338, 0x2241), (0x2243, 0x338, 0x2244), (0x2245, 0x338, 0x2247), (0x2248, 0x338, 0x2249), (0x224D, 0x338, 0x226D),
+    (0x2261, 0x338, 0x2262), (0x2264, 0x338, 0x2270), (0x2265, 0x338, 0x2271), (0x2272, 0x338, 0x2274), (0x2273, 0x338, 0x2275),
+    (0x2276, 0x338, 0x2278), (0x2277, 0x338, 0x2279), (0x227A, 0x338, 0x2280), (0x227B, 0x338, 0x2281), (0x227C, 0x338, 0x22E0),
+    (0x227D, 0x338, 0x22E1), (0x2282, 0x338, 0x2284), (0x2283, 0x338, 0x2285), (0x2286, 0x338, 0x2288), (0x2287, 0x338, 0x2289),
+    (0x2291, 0x338, 0x22E2), (0x2292, 0x338, 0x22E3), (0x22A2, 0x338, 0x22AC), (0x22A8, 0x338, 0x22AD), (0x22A9, 0x338, 0x22AE),
+    (0x22AB, 0x338, 0x22AF), (0x22B2, 0x338, 0x22EA), (0x22B3, 0x338, 0x22EB), (0x22B4, 0x338, 0x22EC), (0x22B5, 0x338, 0x22ED),
+    (0x3046, 0x3099, 0x3094), (0x304B, 0x3099, 0x304C), (0x304D, 0x3099, 0x304E), (0x304F, 0x3099, 0x3050), (0x3051, 0x3099, 0x3052),
+    (0x3053, 0x3099, 0x3054), (0x3055, 0x3099, 0x3056), (0x3057, 0x3099, 0x3058), (0x3059, 0x3099, 0x305A), (0x305B, 0x3099, 0x305C),
+    (0x305D, 0x3099, 0x305E), (0x305F, 0x3099, 0x3060), (0x3061, 0x3099, 0x3062), (0x3064, 0x3099, 0x3065), (0x3066, 0x3099, 0x3067),
+    (0x3068, 0x3099, 0x3069), (0x306F, 0x3099, 0x3070), (0x306F, 0x309A, 0x3071), (0x3072, 0x3099, 0x3073), (0x3072, 0x309A, 0x3074),
+    (0x3075, 0x3099, 0x3076), (0x3075, 0x309A, 0x3077), (0x3078, 0x3099, 0x3079), (0x3078, 0x309A, 0x307A), (0x307B, 0x3099, 0x307C),
+    (0x307B, 0x309A, 0x307D), (0x309D, 0x3099, 0x309E), (0x30A6, 0x3099, 0x30F4), (0x30AB, 0x3099, 0x30AC), (0x30AD, 0x3099, 0x30AE),
+    (0x30AF, 0x3099, 0x30B0), (0x30B1, 0x3099, 0x30B2), (0x30B3, 0x3099, 0x30B4), (0x30B5, 0x3099, 0x30B6), (0x30B7, 0x3099, 0x30B8),
+    (0x30B9, 0x3099, 0x30BA), (0x30BB, 0x3099, 0x30BC), (0x30BD, 0x3099, 0x30BE), (0x30BF, 0x3099, 0x30C0), (0x30C1, 0x3099, 0x30C2),
+    (0x30C4, 0x3099, 0x30C5), (0x30C6, 0x3099, 0x30C7), (0x30C8, 0x3099, 0x30C9), (0x30CF, 0x3099, 0x30D0), (0x30CF, 0x309A, 0x30D1),
+    (0x30D2, 0x3099, 0x30D3), (0x30D2, 0x309A, 0x30D4), (0x30D5, 0x3099, 0x30D6), (0x30D5, 0x309A, 0x30D7), (0x30D8, 0x3099, 0x30D9),
+    (0x30D8, 0x309A, 0x30DA), (0x30DB, 0x3099, 0x30DC), (0x30DB, 0x309A, 0x30DD), (0x30EF, 0x3099, 0x30F7), (0x30F0, 0x3099, 0x30F8),
+    (0x30F1, 0x3099, 0x30F9), (0x30F2, 0x3099, 0x30FA), (0x30FD, 0x3099, 0x30FE), (0x105D2, 0x307, 0x105C9), (0x105DA, 0x307, 0x105E4),
+    (0x11099, 0x110BA, 0x1109A), (0x1109B, 0x110BA, 0x1109C), (0x110A5, 0x110BA, 0x110AB), (0x11131, 0x11127, 0x1112E), (0x11132, 0x11127, 0x1112F),
+    (0x11347, 0x1133E, 0x1134B), (0x11347, 0x11357, 0x1134C), (0x11382, 0x113C9, 0x11383), (0x11384, 0x113BB, 0x11385), (0x1138B, 0x113C2, 0x1138E),
+    (0x11390, 0x113C9, 0x11391), (0x113C2, 0x113B8, 0x113C7), (0x113C2, 0x113C2, 0x113C5), (0x113C2, 0x113C9, 0x113C8), (0x114B9, 0x114B0, 0x114BC),
+    (0x114B9, 0x114BA, 0x114BB), (0x114B9, 0x114BD, 0x114BE), (0x115B8, 0x115AF, 0x115BA), (0x115B9, 0x115AF, 0x115BB), (0x11935, 0x11930, 0x11938),
+    (0x1611E, 0x1611E, 0x16121), (0x1611E, 0x1611F, 0x16123), (0x1611E, 0x16120, 0x16125), (0x1611E, 0x16129, 0x16122), (0x16121, 0x1611F, 0x16126),
+    (0x16121, 0x16120, 0x16128), (0x16122, 0x1611F, 0x16127), (0x16129, 0x1611F, 0x16124), (0x16D63, 0x16D67, 0x16D69), (0x16D67, 0x16D67, 0x16D68),
+    (0x16D69, 0x16D67, 0x16D6A),
+];
+
+/// The ranges of chars that are never in NFC (`NFC_Quick_Check=No`)
+#[rustfmt::skip]
+pub(super) const NFC_NO: &[(u32, u32)] = &[
+    (0x340, 0x341), (0x343, 0x344), (0x374, 0x374), (0x37E, 0x37E), (0x387, 0x387), (0x958, 0x95F), (0x9DC, 0x9DD), (0x9DF, 0x9DF),
+    (0xA33, 0xA33), (0xA36, 0xA36), (0xA59, 0xA5B), (0xA5E, 0xA5E), (0xB5C, 0xB5D), (0xF43, 0xF43), (0xF4D, 0xF4D), (0xF52, 0xF52),
+    (0xF57, 0xF57), (0xF5C, 0xF5C), (0xF69, 0xF69), (0xF73, 0xF73), (0xF75, 0xF76), (0xF78, 0xF78), (0xF81, 0xF81), (0xF93, 0xF93),
+    (0xF9D, 0xF9D), (0xFA2, 0xFA2), (0xFA7, 0xFA7), (0xFAC, 0xFAC), (0xFB9, 0xFB9), (0x1F71, 0x1F71), (0x1F73, 0x1F73), (0x1F75, 0x1F75),
+    (0x1F77, 0x1F77), (0x1F79, 0x1F79), (0x1F7B, 0x1F7B), (0x1F7D, 0x1F7D), (0x1FBB, 0x1FBB), (0x1FBE, 0x1FBE), (0x1FC9, 0x1FC9), (0x1FCB, 0x1FCB),
+    (0x1FD3, 0x1FD3), (0x1FDB, 0x1FDB), (0x1FE3, 0x1FE3), (0x1FEB, 0x1FEB), (0x1FEE, 0x1FEF), (0x1FF9, 0x1FF9), (0x1FFB, 0x1FFB), (0x1FFD, 0x1FFD),
+    (0x2000, 0x2001), (0x2126, 0x2126), (0x212A, 0x212B), (0x2329, 0x232A), (0x2ADC, 0x2ADC), (0xF900, 0xFA0D), (0xFA10, 0xFA10), (0xFA12, 0xFA12),
+    (0xFA15, 0xFA1E), (0xFA20, 0xFA20), (0xFA22, 0xFA22), (0xFA25, 0xFA26), (0xFA2A, 0xFA6D), (0xFA70, 0xFAD9), (0xFB1D, 0xFB1D), (0xFB1F, 0xFB1F),
+    (0xFB2A, 0xFB36), (0xFB38, 0xFB3C), (0xFB3E, 0xFB3E), (0xFB40, 0xFB41), (0xFB43, 0xFB44), (0xFB46, 0xFB4E), (0x1D15E, 0x1D164), (0x1D1BB, 0x1D1C0),
+    (0x2F800, 0x2FA1D),
+];
+
+/// The ranges of chars that are never in NFKC (`NFKC_Quick_Check=No`)
+#[rustfmt::skip]
+pub(super) const NFKC_NO: &[(u32, u32)] = &[
+    (0xA0, 0xA0), (0xA8, 0xA8), (0xAA, 0xAA), (0xAF, 0xAF), (0xB2, 0xB5), (0xB8, 0xBA), (0xBC, 0xBE), (0x132, 0x133),
+    (0x13F, 0x140), (0x149, 0x149), (0x17F, 0x17F), (0x1C4, 0x1CC), (0x1F1, 0x1F3), (0x2B0, 0x2B8), (0x2D8, 0x2DD), (0x2E0, 0x2E4),
+    (0x340, 0x341), (0x343, 0x344), (0x374, 0x374), (0x37A, 0x37A), (0x37E, 0x37E), (0x384, 0x385), (0x387, 0x387), (0x3D0, 0x3D6),
+    (0x3F0, 0x3F2), (0x3F4, 0x3F5), (0x3F9, 0x3F9), (0x587, 0x587), (0x675, 0x678), (0x958, 0x95F), (0x9DC, 0x9DD), (0x9DF, 0x9DF),
+    (0xA33, 0xA33), (0xA36, 0xA36), (0xA59, 0xA5B), (0xA5E, 0xA5E), (0xB5C, 0xB5D), (0xE33, 0xE33), (0xEB3, 0xEB3), (0xEDC, 0xEDD),
+    (0xF0C, 0xF0C), (0xF43, 0xF43), (0xF4D, 0xF4D), (0xF52, 0xF52), (0xF57, 0xF57), (0xF5C, 0xF5C), (0xF69, 0xF69), (0xF73, 0xF73),
+    (0xF75, 0xF79), (0xF81, 0xF81), (0xF93, 0xF93), (0xF9D, 0xF9D), (0xFA2, 0xFA2), (0xFA7, 0xFA7), (0xFAC, 0xFAC), (0xFB9, 0xFB9),
+    (0x10FC, 0x10FC), (0x1D2C, 0x1D2E), (0x1D30, 0x1D3A), (0x1D3C, 0x1D4D), (0x1D4F, 0x1D6A), (0x1D78, 0x1D78), (0x1D9B, 0x1DBF), (0x1E9A, 0x1E9B),
+    (0x1F71, 0x1F71), (0x1F73, 0x1F73), (0x1F75, 0x1F75), (0x1F77, 0x1F77), (0x1F79, 0x1F79), (0x1F7B, 0x1F7B), (0x1F7D, 0x1F7D), (0x1FBB, 0x1FBB),
+    (0x1FBD, 0x1FC1), (0x1FC9, 0x1FC9), (0x1FCB, 0x1FCB), (0x1FCD, 0x1FCF), (0x1FD3, 0x1FD3), (0x1FDB, 0x1FDB), (0x1FDD, 0x1FDF), (0x1FE3, 0x1FE3),
+    (0x1FEB, 0x1FEB), (0x1FED, 0x1FEF), (0x1FF9, 0x1FF9), (0x1FFB, 0x1FFB), (0x1FFD, 0x1FFE), (0x2000, 0x200A), (0x2011, 0x2011), (0x2017, 0x2017),
+    (0x2024, 0x2026), (0x202F, 0x202F), (0x2033, 0x2034), (0x2036, 0x2037), (0x203C, 0x203C), (0x203E, 0x203E), (0x2047, 0x2049), (0x2057, 0x2057),
+    (0x205F, 0x205F), (0x2070, 0x2071), (0x2074, 0x208E), (0x2090, 0x209C), (0x20A8, 0x20A8), (0x2100, 0x2103), (0x2105, 0x2107), (0x2109, 0x2113),
+    (0x2115, 0x2116), (0x2119, 0x211D), (0x2120, 0x2122), (0x2124, 0x2124), (0x2126, 0x2126), (0x2128, 0x2128), (0x212A, 0x212D), (0x212F, 0x2131),
+    (0x2133, 0x2139), (0x213B, 0x2140), (0x2145, 0x2149), (0x2150, 0x217F), (0x2189, 0x2189), (0x222C, 0x222D), (0x222F, 0x2230), (0x2329, 0x232A),
+    (0x2460, 0x24EA), (0x2A0C, 0x2A0C), (0x2A74, 0x2A76), (0x2ADC, 0x2ADC), (0x2C7C, 0x2C7D), (0x2D6F, 0x2D6F), (0x2E9F, 0x2E9F), (0x2EF3, 0x2EF3),
+    (0x2F00, 0x2FD5), (0x3000, 0x3000), (0x3036, 0x3036), (0x3038, 0x303A), (0x309B, 0x309C), (0x309F, 0x309F), (0x30FF, 0x30FF), (0x3131, 0x318E),
+    (0x3192, 0x319F), (0x3200, 0x321E), (0x3220, 0x3247), (0x3250, 0x327E), (0x3280, 0x33FF), (0xA69C, 0xA69D), (0xA770, 0xA770), (0xA7F1, 0xA7F4),
+    (0xA7F8, 0xA7F9), (0xAB5C, 0xAB5F), (0xAB69, 0xAB69), (0xF900, 0xFA0D), (0xFA10, 0xFA10), (0xFA12, 0xFA12), (0xFA15, 0xFA1E), (0xFA20, 0xFA20),
+    (0xFA22, 0xFA22), (0xFA25, 0xFA26), (0xFA2A, 0xFA6D), (0xFA70, 0xFAD9), (0xFB00, 0xFB06), (0xFB13, 0xFB17), (0xFB1D, 0xFB1D), (0xFB1F, 0xFB36),
+    (0xFB38, 0xFB3C), (0xFB3E, 0xFB3E), (0xFB40, 0xFB41), (0xFB43, 0xFB44), (0xFB46, 0xFBB1), (0xFBD3, 0xFD3D), (0xFD50, 0xFD8F), (0xFD92, 0xFDC7),
+    (0xFDF0, 0xFDFC), (0xFE10, 0xFE19), (0xFE30, 0xFE44), (0xFE47, 0xFE52), (0xFE54, 0xFE66), (0xFE68, 0xFE6B), (0xFE70, 0xFE72), (0xFE74, 0xFE74),
+    (0xFE76, 0xFEFC), (0xFF01, 0xFFBE), (0xFFC2, 0xFFC7), (0xFFCA, 0xFFCF), (0xFFD2, 0xFFD7), (0xFFDA, 0xFFDC), (0xFFE0, 0xFFE6), (0xFFE8, 0xFFEE),
+    (0x10781, 0x10785), (0x10787, 0x107B0), (0x107B2, 0x107BA), (0x1CCD6, 0x1CCF9), (0x1D15E, 0x1D164), (0x1D1BB, 0x1D1C0), (0x1D400, 0x1D454), (0x1D456, 0x1D49C),
+    (0x1D49E, 0x1D49F), (0x1D4A2, 0x1D4A2), (0x1D4A5, 0x1D4A6), (0x1D4A9, 0x1D4AC), (0x1D4AE, 0x1D4B9), (0x1D4BB, 0x1D4BB), (0x1D4BD, 0x1D4C3), (0x1D4C5, 0x1D505),
+    (0x1D507, 0x1D50A), (0x1D50D, 0x1D514), (0x1D516, 0x1D51C), (0x1D51E, 0x1D539), (0x1D53B, 0x1D53E), (0x1D540, 0x1D544), (0x1D546, 0x1D546), (0x1D54A, 0x1D550),
+    (0x1D552, 0x1D6A5), (0x1D6A8, 0x1D7CB), (0x1D7CE, 0x1D7FF), (0x1E030, 0x1E06D), (0x1EE00, 0x1EE03), (0x1EE05, 0x1EE1F), (0x1EE21, 0x1EE22), (0x1EE24, 0x1EE24),
+    (0x1EE27, 0x1EE27), (0x1EE29, 0x1EE32), (0x1EE34, 0x1EE37), (0x1EE39, 0x1EE39), (0x1EE3B, 0x1EE3B), (0x1EE42, 0x1EE42), (0x1EE47, 0x1EE47), (0x1EE49, 0x1EE49),
+    (0x1EE4B, 0x1EE4B), (0x1EE4D, 0x1EE4F), (0x1EE51, 0x1EE52), (0x1EE54, 0x1EE54), (0x1EE57, 0x1EE57), (0x1EE59, 0x1EE59), (0x1EE5B, 0x1EE5B), (0x1EE5D, 0x1EE5D),
+    (0x1EE5F, 0x1EE5F), (0x1EE61, 0x1EE62), (0x1EE64, 0x1EE64), (0x1EE67, 0x1EE6A), (0x1EE6C, 0x1EE72), (0x1EE74, 0x1EE77), (0x1EE79, 0x1EE7C), (0x1EE7E, 0x1EE7E),
+    (0x1EE80, 0x1EE89), (0x1EE8B, 0x1EE9B), (0x1EEA1, 0x1EEA3), (0x1EEA5, 0x1EEA9), (0x1EEAB, 0x1EEBB), (0x1F100, 0x1F10A), (0x1F110, 0x1F12E), (0x1F130, 0x1F14F),
+    (0x1F16A, 0x1F16C), (0x1F190, 0x1F190), (0x1F200, 0x1F202), (0x1F210, 0x1F23B), (0x1F240, 0x1F248), (0x1F250, 0x1F251), (0x1FBF0, 0x1FBF9), (0x2F800, 0x2FA1D),
+];
+
+/// The ranges of chars that are the second char of a primary composite (`NFC_Quick_Check=Maybe`).
+/// Hangul vowels and trailing consonants, which compose algorithmically, are left out.
+#[rustfmt::skip]
+pub(super) const COMPOSES_WITH_PREVIOUS: &[(u32, u32)] = &[
+    (0x300, 0x304), (0x306, 0x30C), (0x30F, 0x30F), (0x311, 0x311), (0x313, 0x314), (0x31B, 0x31B), (0x323, 0x328), (0x32D, 0x32E),
+    (0x330, 0x331), (0x338, 0x338), (0x342, 0x342), (0x345, 0x345), (0x653, 0x655), (0x93C, 0x93C), (0x9BE, 0x9BE), (0x9D7, 0x9D7),
+    (0xB3E, 0xB3E), (0xB56, 0xB57), (0xBBE, 0xBBE), (0xBD7, 0xBD7), (0xC56, 0xC56), (0xCC2, 0xCC2), (0xCD5, 0xCD6), (0xD3E, 0xD3E),
+    (0xD57, 0xD57), (0xDCA, 0xDCA), (0xDCF, 0xDCF), (0xDDF, 0xDDF), (0x102E, 0x102E), (0x1B35, 0x1B35), (0x3099, 0x309A), (0x110BA, 0x110BA),
+    (0x11127, 0x11127), (0x1133E, 0x1133E), (0x11357, 0x11357), (0x113B8, 0x113B8), (0x113BB, 0x113BB), (0x113C2, 0x113C2), (0x113C9, 0x113C9), (0x114B0, 0x114B0),
+    (0x114BA, 0x114BA), (0x114BD, 0x114BD), (0x115AF, 0x115AF), (0x11930, 0x11930), (0x1611E, 0x16120), (0x16129, 0x16129), (0x16D67, 0x16D67),
+];
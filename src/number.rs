@@ -0,0 +1,199 @@
+use crate::len::LenType;
+use crate::{CapacityError, String};
+
+/// Options for the number formatting methods of `String`, such as [`String::push_int_fmt()`].
+///
+/// These methods are written by hand rather than going through `core::fmt`, which saves a good deal of code size on small targets.
+///
+/// ```
+/// use nstr::{NumFormat, String};
+///
+/// let mut s = String::<32>::new();
+/// s.push_int_fmt(-42, NumFormat::new().width(6).zero_pad(true)).unwrap();
+/// s.push_uint_fmt(255, NumFormat::new().radix(16).uppercase(true).width(4)).unwrap();
+/// assert_eq!(s, "-00042  FF");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NumFormat {
+    radix: u32,
+    width: usize,
+    zero_pad: bool,
+    plus_sign: bool,
+    uppercase: bool,
+}
+
+impl NumFormat {
+    /// Decimal, with no padding, a sign only for negative numbers, and lowercase digits.
+    pub const fn new() -> Self {
+        NumFormat { radix: 10, width: 0, zero_pad: false, plus_sign: false, uppercase: false }
+    }
+
+    /// Sets the radix of integers, from 2 to 36. Floats are always written in decimal.
+    ///
+    /// Panics if `radix` is out of range.
+    pub const fn radix(self, radix: u32) -> Self {
+        assert!(radix >= 2 && radix <= 36, "radix must be between 2 and 36");
+        NumFormat { radix, ..self }
+    }
+
+    /// Sets the minimum width of the output, sign included. Shorter output is padded on the left.
+    pub const fn width(self, width: usize) -> Self {
+        NumFormat { width, ..self }
+    }
+
+    /// Pads with zeros after the sign, rather than with spaces before it.
+    pub const fn zero_pad(self, zero_pad: bool) -> Self {
+        NumFormat { zero_pad, ..self }
+    }
+
+    /// Writes a `+` before positive numbers (and zero).
+    pub const fn plus_sign(self, plus_sign: bool) -> Self {
+        NumFormat { plus_sign, ..self }
+    }
+
+    /// Writes the digits above 9 as `A`-`Z` rather than `a`-`z`.
+    pub const fn uppercase(self, uppercase: bool) -> Self {
+        NumFormat { uppercase, ..self }
+    }
+}
+
+impl Default for NumFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Writes the digits of `v` to the end of `buf`, returning them.
+fn digits(mut v: u64, radix: u32, uppercase: bool, buf: &mut [u8; 64]) -> &[u8] {
+    let radix = radix as u64;
+    let letters = if uppercase { b'A' } else { b'a' };
+    let mut i = buf.len();
+    loop {
+        let d = (v % radix) as u8;
+        i -= 1;
+        buf[i] = if d < 10 { b'0' + d } else { letters + d - 10 };
+        v /= radix;
+        if v == 0 {
+            return &buf[i..];
+        }
+    }
+}
+
+/// Fractional digits beyond this many are written as zeros, as `10^19` is the largest power of ten in a `u64`.
+const MAX_PRECISION: usize = 19;
+
+impl<const N: usize, L: LenType> String<N, L> {
+    /// Appends `body_len` bytes written by `body`, after the sign and padding asked for by `format`.
+    /// Nothing is written if the result does not fit.
+    fn push_number(&mut self, negative: bool, body_len: usize, zero_pad: bool, format: NumFormat, body: impl FnOnce(&mut Self)) -> Result<(), CapacityError> {
+        let sign = if negative { Some(b'-') } else if format.plus_sign { Some(b'+') } else { None };
+        let len = sign.map_or(0, |_| 1) + body_len;
+        let pad = format.width.saturating_sub(len);
+        if len + pad > N - self.len() {
+            return Err(CapacityError::new(len + pad, N - self.len()));
+        }
+        let zero_pad = zero_pad && format.zero_pad;
+        self.push_repeated(b' ', if zero_pad { 0 } else { pad });
+        if let Some(sign) = sign {
+            self.push_ascii(&[sign]);
+        }
+        self.push_repeated(b'0', if zero_pad { pad } else { 0 });
+        body(self);
+        Ok(())
+    }
+
+    // these write through the `Vec` rather than `push`, whose panic message would pull `core::fmt` back in,
+    // the caller has already checked that there is room
+
+    fn push_ascii(&mut self, bytes: &[u8]) {
+        self.vec.extend_from_slice(bytes);
+    }
+
+    fn push_repeated(&mut self, b: u8, count: usize) {
+        (0..count).for_each(|_| self.vec.push(b));
+    }
+
+    /// Appends a float in decimal with `precision` digits after the point, without using `core::fmt`.
+    ///
+    /// The last digit is rounded half away from zero, and like any `f64` only about 17 significant digits are exact.
+    /// Values of `1e19` or more are written with their first 19 digits followed by zeros.
+    pub fn push_float(&mut self, v: f64, precision: usize) -> Result<(), CapacityError> {
+        self.push_float_fmt(v, precision, NumFormat::new())
+    }
+
+    /// Version of [`String::push_float()`] with formatting options. The radix and case options are ignored.
+    pub fn push_float_fmt(&mut self, v: f64, precision: usize, format: NumFormat) -> Result<(), CapacityError> {
+        if v.is_nan() {
+            return self.push_number(false, 3, false, NumFormat { plus_sign: false, ..format }, |s| s.push_ascii(b"NaN"));
+        }
+        let negative = v.is_sign_negative();
+        let a = if negative { -v } else { v };
+        if a.is_infinite() {
+            return self.push_number(negative, 3, false, format, |s| s.push_ascii(b"inf"));
+        }
+
+        // split into integer and fractional parts, keeping huge values within a u64 by counting trailing zeros
+        let mut zeros = 0;
+        let mut int = a;
+        while int >= 1e19 {
+            int /= 10.0;
+            zeros += 1;
+        }
+        let mut int = int as u64;
+        let frac = if zeros == 0 { a - int as f64 } else { 0.0 };
+
+        let exact = precision.min(MAX_PRECISION);
+        let scale = 10u64.pow(exact as u32);
+        let mut frac = (frac * scale as f64 + 0.5) as u64;
+        if frac >= scale {
+            frac -= scale;
+            int += 1;
+        }
+
+        let buf = &mut [0; 64];
+        let int = digits(int, 10, false, buf);
+        let frac_buf = &mut [0; 64];
+        let frac = digits(frac, 10, false, frac_buf);
+        let body_len = int.len() + zeros + if precision > 0 { 1 + precision } else { 0 };
+        self.push_number(negative, body_len, true, format, |s| {
+            s.push_ascii(int);
+            s.push_repeated(b'0', zeros);
+            if precision > 0 {
+                s.push_ascii(b".");
+                // `frac` has no leading zeros, restore them, then pad beyond what a u64 holds
+                s.push_repeated(b'0', exact - frac.len());
+                s.push_ascii(frac);
+                s.push_repeated(b'0', precision - exact);
+            }
+        })
+    }
+
+    /// Appends an unsigned integer in lowercase hexadecimal, without a prefix and without using `core::fmt`.
+    pub fn push_hex(&mut self, v: u64) -> Result<(), CapacityError> {
+        self.push_uint_fmt(v, NumFormat::new().radix(16))
+    }
+
+    /// Appends a signed integer in decimal, without using `core::fmt`.
+    pub fn push_int(&mut self, v: i64) -> Result<(), CapacityError> {
+        self.push_int_fmt(v, NumFormat::new())
+    }
+
+    /// Version of [`String::push_int()`] with formatting options.
+    pub fn push_int_fmt(&mut self, v: i64, format: NumFormat) -> Result<(), CapacityError> {
+        let buf = &mut [0; 64];
+        let digits = digits(v.unsigned_abs(), format.radix, format.uppercase, buf);
+        self.push_number(v < 0, digits.len(), true, format, |s| s.push_ascii(digits))
+    }
+
+    /// Appends an unsigned integer in decimal, without using `core::fmt`.
+    pub fn push_uint(&mut self, v: u64) -> Result<(), CapacityError> {
+        self.push_uint_fmt(v, NumFormat::new())
+    }
+
+    /// Version of [`String::push_uint()`] with formatting options.
+    pub fn push_uint_fmt(&mut self, v: u64, format: NumFormat) -> Result<(), CapacityError> {
+        let buf = &mut [0; 64];
+        let digits = digits(v, format.radix, format.uppercase, buf);
+        self.push_number(false, digits.len(), true, format, |s| s.push_ascii(digits))
+    }
+}
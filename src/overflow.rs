@@ -0,0 +1,135 @@
+use crate::len::LenType;
+use crate::{CapacityError, String};
+use core::fmt::{Error, Write};
+
+/// What to do when a write would grow a `String` beyond its capacity `N`.
+///
+/// Passed to the `*_with` methods of `String` (e.g. [`String::push_str_with()`]), to [`String::writer()`] and to [`ToString::to_string_with()`](crate::ToString::to_string_with).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Overflow {
+    /// Panic, like `push` and `push_str` do
+    Panic,
+    /// Return a `CapacityError` and leave the string unchanged, like the `try_*` methods do
+    Error,
+    /// Keep as much of the result as fits, cut at a `char` boundary, and discard the rest
+    Truncate,
+    /// Like `Truncate`, but end the string with the given marker (such as `"…"`) so that it is visible that text was cut off.
+    /// Enough of the result is discarded to make room for the marker.
+    TruncateWith(&'static str),
+}
+
+/// Returns the largest index `<= i` that lies on a `char` boundary of `s`.
+fn floor_char_boundary(s: &str, i: usize) -> usize {
+    if i >= s.len() {
+        return s.len();
+    }
+    let mut i = i;
+    while !s.is_char_boundary(i) {
+        i -= 1;
+    }
+    i
+}
+
+impl<const N: usize, L: LenType> String<N, L> {
+    /// Version of [`String::push()`] with an explicit overflow policy.
+    pub fn push_with(&mut self, c: char, overflow: Overflow) -> Result<(), CapacityError> {
+        let dst = &mut [0; 4];
+        self.push_str_with(c.encode_utf8(dst), overflow)
+    }
+
+    /// Version of [`String::push_str()`] with an explicit overflow policy.
+    ///
+    /// Only `Overflow::Error` ever returns `Err`; the truncating policies always succeed.
+    pub fn push_str_with(&mut self, s: &str, overflow: Overflow) -> Result<(), CapacityError> {
+        self.insert_str_with(self.len(), s, overflow)
+    }
+
+    /// Version of [`String::insert_str()`] with an explicit overflow policy.
+    ///
+    /// The truncating policies cut the string that *would* have been produced, so when inserting into the middle of a string it is the end of the existing text that is lost first.
+    ///
+    /// Panics if `index` does not lie on a `char` boundary, whatever the policy.
+    pub fn insert_str_with(&mut self, index: usize, s: &str, overflow: Overflow) -> Result<(), CapacityError> {
+        let marker = match overflow {
+            Overflow::Panic => {
+                self.insert_str(index, s);
+                return Ok(());
+            }
+            Overflow::Error => return self.try_insert_str(index, s),
+            Overflow::Truncate => "",
+            Overflow::TruncateWith(marker) => marker,
+        };
+        assert!(self.is_char_boundary(index));
+        if self.len() + s.len() <= N {
+            self.insert_str(index, s);
+            return Ok(());
+        }
+
+        // the result would be head + s + tail, keep the first `limit` bytes of that
+        let limit = N.saturating_sub(marker.len());
+        if limit <= index {
+            self.truncate(floor_char_boundary(self, limit));
+        } else if limit <= index + s.len() {
+            self.truncate(index);
+            self.push_str(&s[..floor_char_boundary(s, limit - index)]);
+        } else {
+            let tail = floor_char_boundary(&self[index..], limit - index - s.len());
+            self.truncate(index + tail);
+            self.insert_str(index, s);
+        }
+        self.push_str(&marker[..floor_char_boundary(marker, N - self.len())]);
+        Ok(())
+    }
+
+    /// Returns a `core::fmt::Write` adapter that appends to this string using the given overflow policy.
+    ///
+    /// With the truncating policies, everything written after the first truncation is discarded, so the string always ends with a clean cut (and the marker, if any).
+    pub fn writer(&mut self, overflow: Overflow) -> OverflowWriter<'_, N, L> {
+        OverflowWriter { string: self, overflow, truncated: false, error: None }
+    }
+}
+
+/// `core::fmt::Write` adapter returned by [`String::writer()`], applying an overflow policy to every write.
+pub struct OverflowWriter<'a, const N: usize, L: LenType = usize> {
+    string: &'a mut String<N, L>,
+    overflow: Overflow,
+    truncated: bool,
+    error: Option<CapacityError>,
+}
+
+impl<const N: usize, L: LenType> OverflowWriter<'_, N, L> {
+    /// Returns true if any output has been cut off by a truncating policy.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Returns the error that stopped the last write with `Overflow::Error`, if any.
+    pub fn error(&self) -> Option<CapacityError> {
+        self.error
+    }
+}
+
+impl<const N: usize, L: LenType> Write for OverflowWriter<'_, N, L> {
+    fn write_str(&mut self, s: &str) -> Result<(), Error> {
+        if self.truncated {
+            return Ok(());
+        }
+        match self.overflow {
+            Overflow::Panic => self.string.push_str(s),
+            Overflow::Error => {
+                if let Err(e) = self.string.try_push_str(s) {
+                    self.error = Some(e);
+                    return Err(Error);
+                }
+            }
+            Overflow::Truncate | Overflow::TruncateWith(_) => {
+                if self.string.len() + s.len() > N {
+                    self.truncated = true;
+                }
+                // truncating policies never fail
+                let _ = self.string.push_str_with(s, self.overflow);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,538 @@
+use crate::len::LenType;
+use crate::methods::Compactor;
+use crate::String;
+use core::ops::Range;
+
+/// A pattern that can be searched for in a string, used by [`String::find()`], [`String::split()`], [`String::replace()`] and friends.
+///
+/// This is a simplified, stable version of the unstable `core::str::pattern::Pattern`. It is implemented for:
+/// - `char`, matching that char
+/// - `&str` and `&String<M>`, matching that substring
+/// - `&[char]`, `[char; K]` and `&[char; K]`, matching any of the chars
+/// - `FnMut(char) -> bool`, matching any char for which the closure returns true
+///
+/// Matches are non-overlapping and found from the left (or from the right, for the `r*` methods). An empty substring matches between every pair of chars.
+pub trait Pattern {
+    /// Returns the byte range of the first match in `haystack`.
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>>;
+
+    /// Returns the byte range of the last match in `haystack`.
+    fn rfind_in(&mut self, haystack: &str) -> Option<Range<usize>>;
+
+    /// Returns the length of the match at the start of `haystack`, if there is one.
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        self.find_in(haystack).filter(|m| m.start == 0).map(|m| m.end)
+    }
+
+    /// Returns the length of the match at the end of `haystack`, if there is one.
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        self.rfind_in(haystack).filter(|m| m.end == haystack.len()).map(|m| m.len())
+    }
+}
+
+/// Returns the byte range of the first char in `haystack` matching `f`.
+fn find_char<F: FnMut(char) -> bool>(haystack: &str, mut f: F) -> Option<Range<usize>> {
+    haystack.char_indices().find(|&(_, c)| f(c)).map(|(i, c)| i..i + c.len_utf8())
+}
+
+/// Returns the byte range of the last char in `haystack` matching `f`.
+fn rfind_char<F: FnMut(char) -> bool>(haystack: &str, mut f: F) -> Option<Range<usize>> {
+    haystack.char_indices().rev().find(|&(_, c)| f(c)).map(|(i, c)| i..i + c.len_utf8())
+}
+
+/// Returns the length of the first char of `haystack`, if it matches `f`.
+fn prefix_char<F: FnMut(char) -> bool>(haystack: &str, mut f: F) -> Option<usize> {
+    haystack.chars().next().filter(|&c| f(c)).map(char::len_utf8)
+}
+
+/// Returns the length of the last char of `haystack`, if it matches `f`.
+fn suffix_char<F: FnMut(char) -> bool>(haystack: &str, mut f: F) -> Option<usize> {
+    haystack.chars().next_back().filter(|&c| f(c)).map(char::len_utf8)
+}
+
+impl Pattern for char {
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        find_char(haystack, |c| c == *self)
+    }
+
+    fn rfind_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        rfind_char(haystack, |c| c == *self)
+    }
+
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        prefix_char(haystack, |c| c == *self)
+    }
+
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        suffix_char(haystack, |c| c == *self)
+    }
+}
+
+impl Pattern for &str {
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        haystack.find(*self).map(|i| i..i + self.len())
+    }
+
+    fn rfind_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        haystack.rfind(*self).map(|i| i..i + self.len())
+    }
+
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        haystack.starts_with(*self).then_some(self.len())
+    }
+
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        haystack.ends_with(*self).then_some(self.len())
+    }
+}
+
+impl<const M: usize, L: LenType> Pattern for &String<M, L> {
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        self.as_str().find_in(haystack)
+    }
+
+    fn rfind_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        self.as_str().rfind_in(haystack)
+    }
+
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        self.as_str().prefix_len(haystack)
+    }
+
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        self.as_str().suffix_len(haystack)
+    }
+}
+
+impl Pattern for &[char] {
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        find_char(haystack, |c| self.contains(&c))
+    }
+
+    fn rfind_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        rfind_char(haystack, |c| self.contains(&c))
+    }
+
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        prefix_char(haystack, |c| self.contains(&c))
+    }
+
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        suffix_char(haystack, |c| self.contains(&c))
+    }
+}
+
+impl<const K: usize> Pattern for [char; K] {
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        find_char(haystack, |c| self.contains(&c))
+    }
+
+    fn rfind_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        rfind_char(haystack, |c| self.contains(&c))
+    }
+
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        prefix_char(haystack, |c| self.contains(&c))
+    }
+
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        suffix_char(haystack, |c| self.contains(&c))
+    }
+}
+
+impl<const K: usize> Pattern for &[char; K] {
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        find_char(haystack, |c| self.contains(&c))
+    }
+
+    fn rfind_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        rfind_char(haystack, |c| self.contains(&c))
+    }
+
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        prefix_char(haystack, |c| self.contains(&c))
+    }
+
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        suffix_char(haystack, |c| self.contains(&c))
+    }
+}
+
+impl<F: FnMut(char) -> bool> Pattern for F {
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        find_char(haystack, self)
+    }
+
+    fn rfind_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        rfind_char(haystack, self)
+    }
+
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        prefix_char(haystack, self)
+    }
+
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        suffix_char(haystack, self)
+    }
+}
+
+/// Iterator over the matches of a pattern and their byte offsets, returned by [`String::match_indices()`].
+pub struct MatchIndices<'a, P> {
+    haystack: &'a str,
+    pattern: P,
+    position: usize,
+    finished: bool,
+}
+
+impl<'a, P: Pattern> Iterator for MatchIndices<'a, P> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let m = next_match(&mut self.pattern, self.haystack, &mut self.position, &mut self.finished)?;
+        Some((m.start, &self.haystack[m]))
+    }
+}
+
+/// Finds the next match in `haystack` at or after `*position`, and moves `*position` past it.
+///
+/// This is the search loop behind `MatchIndices`, shared with the in-place methods that cannot hold a borrow of the string.
+pub(crate) fn next_match<P: Pattern>(pattern: &mut P, haystack: &str, position: &mut usize, finished: &mut bool) -> Option<Range<usize>> {
+    if *finished {
+        return None;
+    }
+    let Some(m) = pattern.find_in(&haystack[*position..]) else {
+        *finished = true;
+        return None;
+    };
+    let (start, end) = (*position + m.start, *position + m.end);
+    if start == end {
+        // step over the next char, so an empty match cannot be found at the same place again
+        match haystack[end..].chars().next() {
+            Some(c) => *position = end + c.len_utf8(),
+            None => *finished = true,
+        }
+    } else {
+        *position = end;
+    }
+    Some(start..end)
+}
+
+/// Iterator over the matches of a pattern and their byte offsets, from the end of the string, returned by [`String::rmatch_indices()`].
+pub struct RMatchIndices<'a, P> {
+    haystack: &'a str,
+    pattern: P,
+    position: usize,
+    finished: bool,
+}
+
+impl<'a, P: Pattern> Iterator for RMatchIndices<'a, P> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let Some(m) = self.pattern.rfind_in(&self.haystack[..self.position]) else {
+            self.finished = true;
+            return None;
+        };
+        if m.is_empty() {
+            // step over the previous char, so an empty match cannot be found at the same place again
+            match self.haystack[..m.start].chars().next_back() {
+                Some(c) => self.position = m.start - c.len_utf8(),
+                None => self.finished = true,
+            }
+        } else {
+            self.position = m.start;
+        }
+        Some((m.start, &self.haystack[m.clone()]))
+    }
+}
+
+/// Iterator over the matches of a pattern, returned by [`String::matches()`].
+pub struct Matches<'a, P>(MatchIndices<'a, P>);
+
+impl<'a, P: Pattern> Iterator for Matches<'a, P> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, m)| m)
+    }
+}
+
+/// Iterator over the matches of a pattern, from the end of the string, returned by [`String::rmatches()`].
+pub struct RMatches<'a, P>(RMatchIndices<'a, P>);
+
+impl<'a, P: Pattern> Iterator for RMatches<'a, P> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, m)| m)
+    }
+}
+
+/// Iterator over the substrings separated by a pattern, returned by [`String::split()`] and [`String::split_terminator()`].
+pub struct Split<'a, P> {
+    matches: MatchIndices<'a, P>,
+    start: usize,
+    finished: bool,
+    allow_trailing_empty: bool,
+}
+
+impl<'a, P> Split<'a, P> {
+    /// Returns the rest of the string, ending the iteration.
+    fn finish(&mut self) -> Option<&'a str> {
+        if self.finished {
+            return None;
+        }
+        self.finished = true;
+        let rest = &self.matches.haystack[self.start..];
+        if !self.allow_trailing_empty && rest.is_empty() {
+            None
+        } else {
+            Some(rest)
+        }
+    }
+}
+
+impl<'a, P: Pattern> Iterator for Split<'a, P> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        match self.matches.next() {
+            Some((i, m)) => {
+                let piece = &self.matches.haystack[self.start..i];
+                self.start = i + m.len();
+                Some(piece)
+            }
+            None => self.finish(),
+        }
+    }
+}
+
+impl<P> core::fmt::Debug for Split<'_, P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Split").field("remainder", &&self.matches.haystack[self.start..]).field("finished", &self.finished).finish()
+    }
+}
+
+/// Iterator over the substrings separated by a pattern, from the end of the string, returned by [`String::rsplit()`].
+pub struct RSplit<'a, P> {
+    matches: RMatchIndices<'a, P>,
+    end: usize,
+    finished: bool,
+}
+
+impl<'a, P> RSplit<'a, P> {
+    /// Returns the rest of the string, ending the iteration.
+    fn finish(&mut self) -> Option<&'a str> {
+        if self.finished {
+            return None;
+        }
+        self.finished = true;
+        Some(&self.matches.haystack[..self.end])
+    }
+}
+
+impl<'a, P: Pattern> Iterator for RSplit<'a, P> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        match self.matches.next() {
+            Some((i, m)) => {
+                let piece = &self.matches.haystack[i + m.len()..self.end];
+                self.end = i;
+                Some(piece)
+            }
+            None => self.finish(),
+        }
+    }
+}
+
+/// Iterator over at most `n` substrings separated by a pattern, returned by [`String::splitn()`].
+pub struct SplitN<'a, P> {
+    split: Split<'a, P>,
+    count: usize,
+}
+
+impl<'a, P: Pattern> Iterator for SplitN<'a, P> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.count {
+            0 => None,
+            1 => {
+                self.count = 0;
+                self.split.finish()
+            }
+            _ => {
+                self.count -= 1;
+                self.split.next()
+            }
+        }
+    }
+}
+
+/// Iterator over at most `n` substrings separated by a pattern, from the end of the string, returned by [`String::rsplitn()`].
+pub struct RSplitN<'a, P> {
+    split: RSplit<'a, P>,
+    count: usize,
+}
+
+impl<'a, P: Pattern> Iterator for RSplitN<'a, P> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.count {
+            0 => None,
+            1 => {
+                self.count = 0;
+                self.split.finish()
+            }
+            _ => {
+                self.count -= 1;
+                self.split.next()
+            }
+        }
+    }
+}
+
+impl<const N: usize, L: LenType> String<N, L> {
+    /// [`str::contains()`](https://doc.rust-lang.org/std/primitive.str.html#method.contains)
+    pub fn contains<P: Pattern>(&self, mut pat: P) -> bool {
+        pat.find_in(self).is_some()
+    }
+
+    /// [`str::ends_with()`](https://doc.rust-lang.org/std/primitive.str.html#method.ends_with)
+    pub fn ends_with<P: Pattern>(&self, mut pat: P) -> bool {
+        pat.suffix_len(self).is_some()
+    }
+
+    /// [`str::find()`](https://doc.rust-lang.org/std/primitive.str.html#method.find)
+    pub fn find<P: Pattern>(&self, mut pat: P) -> Option<usize> {
+        pat.find_in(self).map(|m| m.start)
+    }
+
+    /// [`str::match_indices()`](https://doc.rust-lang.org/std/primitive.str.html#method.match_indices)
+    pub fn match_indices<P: Pattern>(&self, pat: P) -> MatchIndices<'_, P> {
+        MatchIndices { haystack: self.as_str(), pattern: pat, position: 0, finished: false }
+    }
+
+    /// [`str::matches()`](https://doc.rust-lang.org/std/primitive.str.html#method.matches)
+    pub fn matches<P: Pattern>(&self, pat: P) -> Matches<'_, P> {
+        Matches(self.match_indices(pat))
+    }
+
+    /// [`std::string::String::remove_matches()`](https://doc.rust-lang.org/std/string/struct.String.html#method.remove_matches)
+    pub fn remove_matches<P: Pattern>(&mut self, mut pat: P) {
+        // the compactor keeps the string valid if the pattern panics, and moves down whatever follows the last match when dropped
+        let mut compactor = Compactor::new(self);
+        while !compactor.rest().is_empty() {
+            let rest = compactor.rest();
+            let Some(m) = pat.find_in(rest) else {
+                break;
+            };
+            // keep everything before the match, and skip over the match itself
+            let mut keep = m.start;
+            if m.is_empty() {
+                // an empty match removes nothing, so keep the next char and search again after it
+                keep += rest[keep..].chars().next().map_or(0, char::len_utf8);
+            }
+            compactor.keep(keep);
+            compactor.skip(m.end.saturating_sub(keep));
+        }
+    }
+
+    /// [`str::rfind()`](https://doc.rust-lang.org/std/primitive.str.html#method.rfind)
+    pub fn rfind<P: Pattern>(&self, mut pat: P) -> Option<usize> {
+        pat.rfind_in(self).map(|m| m.start)
+    }
+
+    /// [`str::rmatch_indices()`](https://doc.rust-lang.org/std/primitive.str.html#method.rmatch_indices)
+    pub fn rmatch_indices<P: Pattern>(&self, pat: P) -> RMatchIndices<'_, P> {
+        RMatchIndices { haystack: self.as_str(), pattern: pat, position: self.len(), finished: false }
+    }
+
+    /// [`str::rmatches()`](https://doc.rust-lang.org/std/primitive.str.html#method.rmatches)
+    pub fn rmatches<P: Pattern>(&self, pat: P) -> RMatches<'_, P> {
+        RMatches(self.rmatch_indices(pat))
+    }
+
+    /// [`str::rsplit()`](https://doc.rust-lang.org/std/primitive.str.html#method.rsplit)
+    pub fn rsplit<P: Pattern>(&self, pat: P) -> RSplit<'_, P> {
+        RSplit { matches: self.rmatch_indices(pat), end: self.len(), finished: false }
+    }
+
+    /// [`str::rsplit_once()`](https://doc.rust-lang.org/std/primitive.str.html#method.rsplit_once)
+    pub fn rsplit_once<P: Pattern>(&self, mut pat: P) -> Option<(&str, &str)> {
+        let m = pat.rfind_in(self)?;
+        Some((&self[..m.start], &self[m.end..]))
+    }
+
+    /// [`str::rsplitn()`](https://doc.rust-lang.org/std/primitive.str.html#method.rsplitn)
+    pub fn rsplitn<P: Pattern>(&self, n: usize, pat: P) -> RSplitN<'_, P> {
+        RSplitN { split: self.rsplit(pat), count: n }
+    }
+
+    /// [`str::split()`](https://doc.rust-lang.org/std/primitive.str.html#method.split)
+    pub fn split<P: Pattern>(&self, pat: P) -> Split<'_, P> {
+        Split { matches: self.match_indices(pat), start: 0, finished: false, allow_trailing_empty: true }
+    }
+
+    /// [`str::split_once()`](https://doc.rust-lang.org/std/primitive.str.html#method.split_once)
+    pub fn split_once<P: Pattern>(&self, mut pat: P) -> Option<(&str, &str)> {
+        let m = pat.find_in(self)?;
+        Some((&self[..m.start], &self[m.end..]))
+    }
+
+    /// [`str::split_terminator()`](https://doc.rust-lang.org/std/primitive.str.html#method.split_terminator)
+    pub fn split_terminator<P: Pattern>(&self, pat: P) -> Split<'_, P> {
+        Split { matches: self.match_indices(pat), start: 0, finished: false, allow_trailing_empty: false }
+    }
+
+    /// [`str::splitn()`](https://doc.rust-lang.org/std/primitive.str.html#method.splitn)
+    pub fn splitn<P: Pattern>(&self, n: usize, pat: P) -> SplitN<'_, P> {
+        SplitN { split: self.split(pat), count: n }
+    }
+
+    /// [`str::starts_with()`](https://doc.rust-lang.org/std/primitive.str.html#method.starts_with)
+    pub fn starts_with<P: Pattern>(&self, mut pat: P) -> bool {
+        pat.prefix_len(self).is_some()
+    }
+
+    /// [`str::trim_end_matches()`](https://doc.rust-lang.org/std/primitive.str.html#method.trim_end_matches)
+    pub fn trim_end_matches<P: Pattern>(&self, mut pat: P) -> &str {
+        let mut s = self.as_str();
+        while let Some(n) = pat.suffix_len(s).filter(|&n| n > 0) {
+            s = &s[..s.len() - n];
+        }
+        s
+    }
+
+    /// [`str::trim_matches()`](https://doc.rust-lang.org/std/primitive.str.html#method.trim_matches)
+    pub fn trim_matches<P: Pattern>(&self, mut pat: P) -> &str {
+        let mut s = self.as_str();
+        while let Some(n) = pat.suffix_len(s).filter(|&n| n > 0) {
+            s = &s[..s.len() - n];
+        }
+        while let Some(n) = pat.prefix_len(s).filter(|&n| n > 0) {
+            s = &s[n..];
+        }
+        s
+    }
+
+    /// [`str::trim_start_matches()`](https://doc.rust-lang.org/std/primitive.str.html#method.trim_start_matches)
+    pub fn trim_start_matches<P: Pattern>(&self, mut pat: P) -> &str {
+        let mut s = self.as_str();
+        while let Some(n) = pat.prefix_len(s).filter(|&n| n > 0) {
+            s = &s[n..];
+        }
+        s
+    }
+}
@@ -0,0 +1,159 @@
+use crate::len::LenType;
+use crate::methods::utf8_char_width;
+use crate::{CapacityError, PercentDecodeError, String};
+
+/// The set of bytes that [`String::percent_encode_into()`] writes as `%XX` escapes.
+///
+/// Bytes outside ASCII, ASCII control characters and `%` are always in the set. The predefined sets follow
+/// [RFC 3986](https://datatracker.ietf.org/doc/html/rfc3986), and can be adjusted with [`add`](Self::add) and [`remove`](Self::remove).
+///
+/// ```
+/// use nstr::{PercentEncodeSet, String};
+///
+/// let mut url = String::<64>::from("/files/");
+/// url.percent_encode_into("a b/ü", PercentEncodeSet::COMPONENT).unwrap();
+/// url.push_str("?q=");
+/// url.percent_encode_into("1+1=2", PercentEncodeSet::FORM).unwrap();
+/// assert_eq!(url, "/files/a%20b%2F%C3%BC?q=1%2B1%3D2");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PercentEncodeSet {
+    /// Bit `b` is set if ASCII byte `b` is encoded
+    ascii: u128,
+    space_as_plus: bool,
+}
+
+impl PercentEncodeSet {
+    /// Encodes everything but the unreserved characters: ASCII letters and digits, `-`, `.`, `_` and `~`.
+    /// This is safe anywhere in a URL, such as for a single path segment.
+    pub const COMPONENT: Self = Self::keeping(b"-._~");
+    /// For a whole path: also leaves the sub-delimiters `!$&'()*+,;=`, and `:`, `@` and `/` unencoded.
+    pub const PATH: Self = Self::keeping(b"-._~!$&'()*+,;=:@/");
+    /// For a key or a value in a query string: also leaves `!$'()*,;:@/?` unencoded, but not `&`, `=` or `+`.
+    pub const QUERY: Self = Self::keeping(b"-._~!$'()*,;:@/?");
+    /// For a user name or password: also leaves the sub-delimiters `!$&'()*+,;=` unencoded, but not `:`.
+    pub const USERINFO: Self = Self::keeping(b"-._~!$&'()*+,;=");
+    /// For `application/x-www-form-urlencoded` bodies: encodes everything but ASCII letters and digits and `*-._`, and writes a space as `+`.
+    pub const FORM: Self = PercentEncodeSet { space_as_plus: true, ..Self::keeping(b"*-._") };
+
+    /// Encodes everything but ASCII letters and digits and the given bytes.
+    const fn keeping(keep: &[u8]) -> Self {
+        let mut ascii = u128::MAX;
+        let mut b = 0u8;
+        while b < 128 {
+            if b.is_ascii_alphanumeric() {
+                ascii &= !(1 << b);
+            }
+            b += 1;
+        }
+        let mut i = 0;
+        while i < keep.len() {
+            ascii &= !(1 << keep[i]);
+            i += 1;
+        }
+        PercentEncodeSet { ascii, space_as_plus: false }
+    }
+
+    /// Returns this set with the ASCII byte `b` added. Panics if `b` is not ASCII.
+    pub const fn add(self, b: u8) -> Self {
+        assert!(b.is_ascii(), "only ASCII bytes can be added or removed");
+        PercentEncodeSet { ascii: self.ascii | (1 << b), ..self }
+    }
+
+    /// Returns this set with the ASCII byte `b` removed, so that it is written as is.
+    /// Panics if `b` is not ASCII, or is a control character or `%`.
+    pub const fn remove(self, b: u8) -> Self {
+        assert!(b.is_ascii() && !b.is_ascii_control() && b != b'%', "only printable ASCII bytes other than `%` can be removed");
+        PercentEncodeSet { ascii: self.ascii & !(1 << b), ..self }
+    }
+
+    /// Returns true if `b` is encoded.
+    pub const fn contains(&self, b: u8) -> bool {
+        !b.is_ascii() || self.ascii & (1 << b) != 0
+    }
+}
+
+/// Decodes the byte at `i`, returning it and the index of the next one, or `None` for a malformed `%` escape.
+fn decode_at(bytes: &[u8], i: usize, plus_as_space: bool) -> Option<(u8, usize)> {
+    match bytes[i] {
+        b'%' => {
+            let hex = |b: u8| (b as char).to_digit(16);
+            let hi = hex(*bytes.get(i + 1)?)?;
+            let lo = hex(*bytes.get(i + 2)?)?;
+            Some(((hi * 16 + lo) as u8, i + 3))
+        }
+        b'+' if plus_as_space => Some((b' ', i + 1)),
+        b => Some((b, i + 1)),
+    }
+}
+
+impl<const N: usize, L: LenType> String<N, L> {
+    /// Appends `s` percent-encoded: every byte in `set` is written as `%XX`, with uppercase hex digits.
+    ///
+    /// Nothing is written if the result does not fit.
+    pub fn percent_encode_into(&mut self, s: &str, set: PercentEncodeSet) -> Result<(), CapacityError> {
+        let plus = |b: u8| b == b' ' && set.space_as_plus;
+        let requested = s.bytes().map(|b| if set.contains(b) && !plus(b) { 3 } else { 1 }).sum();
+        if requested > N - self.len() {
+            return Err(CapacityError::new(requested, N - self.len()));
+        }
+        const HEX: &[u8; 16] = b"0123456789ABCDEF";
+        for b in s.bytes() {
+            if plus(b) {
+                self.vec.push(b'+');
+            } else if set.contains(b) {
+                self.vec.extend_from_slice(&[b'%', HEX[(b >> 4) as usize], HEX[(b & 0xF) as usize]]);
+            } else {
+                self.vec.push(b);
+            }
+        }
+        Ok(())
+    }
+
+    /// Decodes the `%XX` escapes in the string, in place.
+    ///
+    /// Fails on a malformed escape, or if the decoded bytes are not valid UTF-8, in which case the string is left unchanged.
+    /// A `+` is left as it is; use [`String::percent_decode_form()`] for `application/x-www-form-urlencoded` input.
+    pub fn percent_decode(&mut self) -> Result<(), PercentDecodeError> {
+        self.percent_decode_inner(false)
+    }
+
+    /// Version of [`String::percent_decode()`] for `application/x-www-form-urlencoded` input, which also decodes `+` to a space.
+    pub fn percent_decode_form(&mut self) -> Result<(), PercentDecodeError> {
+        self.percent_decode_inner(true)
+    }
+
+    fn percent_decode_inner(&mut self, plus_as_space: bool) -> Result<(), PercentDecodeError> {
+        // first check the escapes, and that each decoded char is valid UTF-8, without writing anything
+        let bytes = self.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            let start = i;
+            let mut buf = [0; 4];
+            let (b, next) = decode_at(bytes, i, plus_as_space).ok_or(PercentDecodeError::InvalidEscape { index: i })?;
+            (buf[0], i) = (b, next);
+            let width = utf8_char_width(b);
+            for byte in &mut buf[1..width] {
+                if i == bytes.len() {
+                    return Err(PercentDecodeError::InvalidUtf8 { index: start });
+                }
+                let (b, next) = decode_at(bytes, i, plus_as_space).ok_or(PercentDecodeError::InvalidEscape { index: i })?;
+                (*byte, i) = (b, next);
+            }
+            if core::str::from_utf8(&buf[..width]).is_err() {
+                return Err(PercentDecodeError::InvalidUtf8 { index: start });
+            }
+        }
+
+        // the decoded text is never longer, so it can be written over the start of the buffer
+        let len = self.len();
+        let (mut read, mut write) = (0, 0);
+        while read < len {
+            let (b, next) = decode_at(&self.vec.items[..len], read, plus_as_space).expect("escapes were checked above");
+            self.vec.items[write] = b;
+            (read, write) = (next, write + 1);
+        }
+        self.vec.set_len(write);
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use crate::len::{from_usize, LenType};
+use crate::{String, Vec};
+use ::serde::de::{self, Deserialize, Deserializer, IgnoredAny, SeqAccess, Visitor};
+use ::serde::ser::{Serialize, Serializer};
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+
+/// Implementation of `serde::Serialize` for `String`, as a string
+impl<const N: usize, L: LenType> Serialize for String<N, L> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self)
+    }
+}
+
+/// Implementation of `serde::Deserialize` for `String`, from a string (or UTF-8 bytes)
+///
+/// A string longer than `N` bytes is an `invalid_length` error.
+impl<'de, const N: usize, L: LenType> Deserialize<'de> for String<N, L> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(StringVisitor(PhantomData))
+    }
+}
+
+struct StringVisitor<const N: usize, L>(PhantomData<L>);
+
+impl<const N: usize, L: LenType> Visitor<'_> for StringVisitor<N, L> {
+    type Value = String<N, L>;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "a string of at most {} bytes", N)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        String::try_from(v).map_err(|_| E::invalid_length(v.len(), &self))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        let s = core::str::from_utf8(v).map_err(|_| E::invalid_value(de::Unexpected::Bytes(v), &self))?;
+        self.visit_str(s)
+    }
+}
+
+/// Implementation of `serde::Serialize` for `Vec`, as a sequence of its items
+impl<T: Serialize, const N: usize, L: LenType> Serialize for Vec<T, N, L> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+/// Implementation of `serde::Deserialize` for `Vec`, from a sequence
+///
+/// A sequence of more than `N` items is an `invalid_length` error.
+/// `T` does not need to be `Copy`. The items are moved into their slots as they arrive, and only the slots after them are filled with `T::default()`,
+/// as every slot of the `[T; N]` array behind `Vec::items` has to hold a value.
+impl<'de, T: Deserialize<'de> + Default, const N: usize, L: LenType> Deserialize<'de> for Vec<T, N, L> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(VecVisitor(PhantomData))
+    }
+}
+
+struct VecVisitor<T, const N: usize, L>(PhantomData<(T, L)>);
+
+/// The items deserialized so far, dropped if the sequence fails (or a `Deserialize` impl panics) before they are all in place.
+struct Filled<'a, T> {
+    slots: &'a mut [MaybeUninit<T>],
+    len: usize,
+}
+
+impl<T> Drop for Filled<'_, T> {
+    fn drop(&mut self) {
+        for slot in &mut self.slots[..self.len] {
+            // Safety: the first `len` slots were written
+            unsafe { slot.assume_init_drop() };
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de> + Default, const N: usize, L: LenType> Visitor<'de> for VecVisitor<T, N, L> {
+    type Value = Vec<T, N, L>;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "a sequence of at most {} items", N)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        const { assert!(N <= L::MAX, "the length type cannot hold the capacity N") };
+        if let Some(requested) = seq.size_hint().filter(|&len| len > N) {
+            return Err(de::Error::invalid_length(requested, &self));
+        }
+        let mut slots = [const { MaybeUninit::<T>::uninit() }; N];
+        let mut filled = Filled { slots: &mut slots, len: 0 };
+        while filled.len < N {
+            match seq.next_element()? {
+                Some(item) => filled.slots[filled.len].write(item),
+                None => break,
+            };
+            filled.len += 1;
+        }
+        // the items are not stored, so the rest of the sequence is only looked at to report its length
+        if filled.len == N && seq.next_element::<IgnoredAny>()?.is_some() {
+            let mut requested = N + 1;
+            while seq.next_element::<IgnoredAny>()?.is_some() {
+                requested += 1;
+            }
+            return Err(de::Error::invalid_length(requested, &self));
+        }
+        let len = filled.len;
+        while filled.len < N {
+            filled.slots[filled.len].write(T::default());
+            filled.len += 1;
+        }
+        core::mem::forget(filled);
+        // Safety: every slot was written above
+        let items = slots.map(|slot| unsafe { slot.assume_init() });
+        Ok(Vec { items, len: from_usize(len) })
+    }
+}
@@ -1,24 +1,93 @@
-use crate::{String, DEFAULT_BUFFER_SIZE};
+use crate::len::LenType;
+use crate::{CapacityError, Overflow, String};
 use core::fmt::{Write, Error};
 
 pub trait ToString {
     /// convert a type to a String
+    ///
+    /// Panics if the formatted value does not fit in `N` bytes.
     fn to_string<const N: usize>(&self) -> String<N>;
+
+    /// convert a type to a String, using the given policy if the formatted value does not fit in `N` bytes
+    ///
+    /// Panics if the `Display` impl returns an error of its own, as `alloc::string::ToString::to_string` does.
+    fn to_string_with<const N: usize>(&self, overflow: Overflow) -> Result<String<N>, CapacityError>;
 }
 
-impl<const N: usize> Write for String<N> {
+/// Writes that do not fit are rejected whole, as with `Overflow::Error`: the fragment is not written and `Err` is returned.
+/// Use [`String::writer()`] to format with a different overflow policy.
+impl<const N: usize, L: LenType> Write for String<N, L> {
     fn write_str(&mut self, s: &str) -> Result<(), Error> {
-        let remaining_capacity = N - self.len;
-        let bytes_to_write = s.len().min(remaining_capacity);
-        let bytes_to_copy = s.as_bytes().len().min(remaining_capacity);
-        self.chars[self.len..self.len + bytes_to_copy].copy_from_slice(&s.as_bytes()[..bytes_to_copy]);
-        self.len += bytes_to_write;
-        if bytes_to_write < s.len() {
-            Err(Error)
-        } else {
-            Ok(())
+        self.try_push_str(s).map_err(|_| Error)
+    }
+}
+
+impl<const N: usize, L: LenType> String<N, L> {
+    /// Formats `args` into a new string, failing if the output does not fit in `N` bytes.
+    ///
+    /// This is what [`nformat!`](crate::nformat) and [`try_nformat!`](crate::try_nformat) expand to.
+    /// Panics if a formatting trait impl returns an error of its own, as `alloc::format!` does.
+    pub fn try_from_fmt(args: core::fmt::Arguments<'_>) -> Result<Self, CapacityError> {
+        format_with(args, Overflow::Error)
+    }
+}
+
+/// Formats `args` into a new string, applying `overflow` if the output does not fit in `N` bytes.
+///
+/// Only running out of space is reported as a `CapacityError`; an error returned by a formatting trait impl
+/// on its own is a bug in that impl and panics, like in `alloc::fmt::format`.
+fn format_with<const N: usize, L: LenType>(args: core::fmt::Arguments<'_>, overflow: Overflow) -> Result<String<N, L>, CapacityError> {
+    let mut s = String::new();
+    let mut writer = s.writer(overflow);
+    if writer.write_fmt(args).is_err() {
+        match writer.error() {
+            Some(e) => return Err(e),
+            None => panic!("a formatting trait implementation returned an error when the underlying stream did not"),
         }
     }
+    Ok(s)
+}
+
+/// Creates a `String` using interpolation of runtime expressions, like `alloc::format!`.
+///
+/// The capacity can be given first, followed by a `;`, or left to be inferred from the type the result is used as.
+/// Panics if the output does not fit; see [`try_nformat!`](crate::try_nformat) for a fallible version.
+///
+/// ```
+/// use nstr::{nformat, String};
+///
+/// let s = nformat!(16; "{}-{:02}", "id", 7);
+/// assert_eq!(s, "id-07");
+///
+/// let t: String<8> = nformat!("{:>4}", 42);
+/// assert_eq!(t, "  42");
+/// ```
+#[macro_export]
+macro_rules! nformat {
+    ($n:expr; $($arg:tt)*) => {
+        $crate::String::<{ $n }>::try_from_fmt(::core::format_args!($($arg)*)).expect("String is full")
+    };
+    ($($arg:tt)*) => {
+        $crate::String::try_from_fmt(::core::format_args!($($arg)*)).expect("String is full")
+    };
+}
+
+/// Fallible version of [`nformat!`](crate::nformat), returning `Result<String<N>, CapacityError>`.
+///
+/// ```
+/// use nstr::try_nformat;
+///
+/// assert_eq!(try_nformat!(8; "{}", 1234).unwrap(), "1234");
+/// assert!(try_nformat!(2; "{}", 1234).is_err());
+/// ```
+#[macro_export]
+macro_rules! try_nformat {
+    ($n:expr; $($arg:tt)*) => {
+        $crate::String::<{ $n }>::try_from_fmt(::core::format_args!($($arg)*))
+    };
+    ($($arg:tt)*) => {
+        $crate::String::try_from_fmt(::core::format_args!($($arg)*))
+    };
 }
 
 impl<T> ToString for T 
@@ -26,8 +95,10 @@ where
     T: core::fmt::Display,
 {
     fn to_string<const N: usize>(&self) -> String<N> {
-        let mut s = String::<N>::new();
-        write!(s, "{}", self).unwrap();
-        s
+        self.to_string_with(Overflow::Panic).expect("String is full")
     }
-}
\ No newline at end of file
+
+    fn to_string_with<const N: usize>(&self, overflow: Overflow) -> Result<String<N>, CapacityError> {
+        format_with(format_args!("{}", self), overflow)
+    }
+}
@@ -0,0 +1,71 @@
+use crate::len::LenType;
+use crate::{CapacityError, String, Vec};
+use ufmt::{uDebug, uDisplay, uWrite, Formatter};
+
+/// Implementation of `ufmt::uWrite` for `String`, so that it can be the target of `uwrite!`
+///
+/// Each piece of text that does not fit is rejected whole with a `CapacityError`. The pieces of the same `uwrite!` written
+/// before it are kept, so a failed `uwrite!` can leave the first part of its output in the string.
+impl<const N: usize, L: LenType> uWrite for String<N, L> {
+    type Error = CapacityError;
+
+    fn write_str(&mut self, s: &str) -> Result<(), CapacityError> {
+        self.try_push_str(s)
+    }
+
+    fn write_char(&mut self, c: char) -> Result<(), CapacityError> {
+        self.try_push(c)
+    }
+}
+
+/// Implementation of `ufmt::uDisplay` for `String`
+impl<const N: usize, L: LenType> uDisplay for String<N, L> {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str(self)
+    }
+}
+
+/// Implementation of `ufmt::uDebug` for `String`, quoted and escaped as by `core::fmt::Debug` for `str`
+impl<const N: usize, L: LenType> uDebug for String<N, L> {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str("\"")?;
+        // write runs of chars that need no escaping in one go
+        let mut start = 0;
+        for (i, c) in self.char_indices() {
+            let escape = c.escape_debug();
+            // like `str`, and unlike `char`, the `Debug` of a string does not escape single quotes; it does escape every grapheme
+            // extender (such as a combining accent), unlike `str::escape_debug`, which only escapes one at the start of the string
+            if escape.len() == 1 || c == '\'' {
+                continue;
+            }
+            f.write_str(&self[start..i])?;
+            for e in escape {
+                f.write_char(e)?;
+            }
+            start = i + c.len_utf8();
+        }
+        f.write_str(&self[start..])?;
+        f.write_str("\"")
+    }
+}
+
+/// Implementation of `ufmt::uDisplay` for `Vec`, in the same `[a, b]` form as its `core::fmt::Display`
+impl<T: uDisplay, const N: usize, L: LenType> uDisplay for Vec<T, N, L> {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str("[")?;
+        for (i, item) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            item.fmt(f)?;
+        }
+        f.write_str("]")
+    }
+}
+
+/// Implementation of `ufmt::uDebug` for `Vec`, as a list of its items
+impl<T: uDebug, const N: usize, L: LenType> uDebug for Vec<T, N, L> {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        <[T] as uDebug>::fmt(self, f)
+    }
+}
@@ -0,0 +1,113 @@
+use crate::len::LenType;
+use crate::{CapacityError, FromUtf16Error, String, Vec};
+use core::char::{decode_utf16, REPLACEMENT_CHARACTER};
+
+/// Reads UTF-16 code units from little-endian (`be == false`) or big-endian bytes, ignoring a trailing odd byte.
+fn units(bytes: &[u8], be: bool) -> impl Iterator<Item = u16> + Clone + '_ {
+    bytes.chunks_exact(2).map(move |b| if be { u16::from_be_bytes([b[0], b[1]]) } else { u16::from_le_bytes([b[0], b[1]]) })
+}
+
+impl<const N: usize, L: LenType> String<N, L> {
+    /// Builds a string from chars, checking up front that they fit so that the error reports the full size needed.
+    fn from_chars(chars: impl Iterator<Item = char> + Clone) -> Result<Self, CapacityError> {
+        let requested = chars.clone().map(char::len_utf8).sum();
+        if requested > N {
+            return Err(CapacityError::new(requested, N));
+        }
+        let mut s = Self::new();
+        chars.for_each(|c| s.push(c));
+        Ok(s)
+    }
+
+    /// Decodes UTF-16 code units, failing on an unpaired surrogate.
+    fn from_utf16_units(units: impl Iterator<Item = u16> + Clone) -> Result<Self, FromUtf16Error> {
+        decode_utf16(units.clone()).try_for_each(|c| c.map(drop)).map_err(FromUtf16Error::Utf16)?;
+        Self::from_chars(decode_utf16(units).map(|c| c.unwrap_or(REPLACEMENT_CHARACTER))).map_err(FromUtf16Error::Capacity)
+    }
+
+    /// Encodes the string as UTF-16 code units, failing if more than `M` are needed.
+    ///
+    /// Chars outside the Basic Multilingual Plane take two units (a surrogate pair).
+    pub fn encode_utf16_into<const M: usize>(&self) -> Result<Vec<u16, M>, CapacityError> {
+        let requested = self.encode_utf16().count();
+        if requested > M {
+            return Err(CapacityError::new(requested, M));
+        }
+        let mut v = Vec::new();
+        self.encode_utf16().for_each(|u| v.push(u));
+        Ok(v)
+    }
+
+    /// Encodes the string as big-endian UTF-16 bytes, failing if more than `M` bytes are needed.
+    pub fn encode_utf16be_into<const M: usize>(&self) -> Result<Vec<u8, M>, CapacityError> {
+        self.encode_utf16_bytes(u16::to_be_bytes)
+    }
+
+    /// Encodes the string as little-endian UTF-16 bytes, failing if more than `M` bytes are needed.
+    pub fn encode_utf16le_into<const M: usize>(&self) -> Result<Vec<u8, M>, CapacityError> {
+        self.encode_utf16_bytes(u16::to_le_bytes)
+    }
+
+    fn encode_utf16_bytes<const M: usize>(&self, to_bytes: fn(u16) -> [u8; 2]) -> Result<Vec<u8, M>, CapacityError> {
+        let requested = 2 * self.encode_utf16().count();
+        if requested > M {
+            return Err(CapacityError::new(requested, M));
+        }
+        let mut v = Vec::new();
+        self.encode_utf16().for_each(|u| v.extend_from_slice(&to_bytes(u)));
+        Ok(v)
+    }
+
+    /// [`std::string::String::from_utf16()`](https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf16)
+    ///
+    /// Fails on an unpaired surrogate, or if the decoded string is longer than `N` bytes.
+    pub fn from_utf16(v: &[u16]) -> Result<Self, FromUtf16Error> {
+        Self::from_utf16_units(v.iter().copied())
+    }
+
+    /// [`std::string::String::from_utf16_lossy()`](https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf16_lossy)
+    ///
+    /// Unpaired surrogates are replaced with `U+FFFD REPLACEMENT CHARACTER`. Fails if the decoded string is longer than `N` bytes.
+    pub fn from_utf16_lossy(v: &[u16]) -> Result<Self, CapacityError> {
+        Self::from_chars(decode_utf16(v.iter().copied()).map(|c| c.unwrap_or(REPLACEMENT_CHARACTER)))
+    }
+
+    /// [`std::string::String::from_utf16be()`](https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf16be)
+    ///
+    /// Decodes big-endian UTF-16 bytes. Fails on an odd number of bytes, an unpaired surrogate, or if the decoded string is longer than `N` bytes.
+    pub fn from_utf16be(v: &[u8]) -> Result<Self, FromUtf16Error> {
+        if v.len() % 2 != 0 {
+            return Err(FromUtf16Error::OddLength);
+        }
+        Self::from_utf16_units(units(v, true))
+    }
+
+    /// [`std::string::String::from_utf16be_lossy()`](https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf16be_lossy)
+    ///
+    /// Unpaired surrogates and a trailing odd byte are replaced with `U+FFFD REPLACEMENT CHARACTER`.
+    pub fn from_utf16be_lossy(v: &[u8]) -> Result<Self, CapacityError> {
+        Self::from_utf16_bytes_lossy(v, true)
+    }
+
+    /// [`std::string::String::from_utf16le()`](https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf16le)
+    ///
+    /// Decodes little-endian UTF-16 bytes. Fails on an odd number of bytes, an unpaired surrogate, or if the decoded string is longer than `N` bytes.
+    pub fn from_utf16le(v: &[u8]) -> Result<Self, FromUtf16Error> {
+        if v.len() % 2 != 0 {
+            return Err(FromUtf16Error::OddLength);
+        }
+        Self::from_utf16_units(units(v, false))
+    }
+
+    /// [`std::string::String::from_utf16le_lossy()`](https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf16le_lossy)
+    ///
+    /// Unpaired surrogates and a trailing odd byte are replaced with `U+FFFD REPLACEMENT CHARACTER`.
+    pub fn from_utf16le_lossy(v: &[u8]) -> Result<Self, CapacityError> {
+        Self::from_utf16_bytes_lossy(v, false)
+    }
+
+    fn from_utf16_bytes_lossy(v: &[u8], be: bool) -> Result<Self, CapacityError> {
+        let odd = (v.len() % 2 != 0).then_some(REPLACEMENT_CHARACTER);
+        Self::from_chars(decode_utf16(units(v, be)).map(|c| c.unwrap_or(REPLACEMENT_CHARACTER)).chain(odd))
+    }
+}
@@ -0,0 +1,126 @@
+use crate::len::LenType;
+use crate::{CapacityError, String};
+use core::char::REPLACEMENT_CHARACTER;
+
+const REPLACEMENT: &str = "\u{FFFD}";
+
+/// Incremental, lossy UTF-8 decoder, for bytes that arrive in chunks (from a UART, a radio, flash pages…).
+///
+/// A codepoint split across two chunks is held back until the rest of it arrives, and each invalid sequence is replaced with `U+FFFD REPLACEMENT CHARACTER`, as in [`String::from_utf8_lossy()`].
+///
+/// ```
+/// let mut decoder = nstr::Utf8Decoder::new();
+/// let mut s = nstr::String::<16>::new();
+/// decoder.decode(b"caf\xC3", &mut s).unwrap();
+/// assert_eq!(s, "caf");
+/// decoder.decode(b"\xA9 \xFF", &mut s).unwrap();
+/// decoder.finish(&mut s).unwrap();
+/// assert_eq!(s, "café \u{FFFD}");
+/// assert_eq!(decoder.replacements(), 1);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Utf8Decoder {
+    partial: [u8; 4],
+    partial_len: usize,
+    replacements: usize,
+}
+
+impl Utf8Decoder {
+    /// Creates a decoder with nothing pending.
+    pub const fn new() -> Self {
+        Utf8Decoder { partial: [0; 4], partial_len: 0, replacements: 0 }
+    }
+
+    /// Decodes the next chunk of bytes, appending the text to `out`.
+    ///
+    /// An incomplete codepoint at the end of the chunk is kept until the next call to `decode` (or `finish`).
+    /// If the text does not fit, a `CapacityError` is returned and both `out` and the decoder are left unchanged, so the chunk can be retried.
+    pub fn decode<const N: usize, L: LenType>(&mut self, bytes: &[u8], out: &mut String<N, L>) -> Result<(), CapacityError> {
+        let mut requested = 0;
+        let mut dry_run = *self;
+        dry_run.run(bytes, |s| requested += s.len());
+        if requested > out.capacity() - out.len() {
+            return Err(CapacityError::new(requested, out.capacity() - out.len()));
+        }
+        self.run(bytes, |s| out.push_str(s));
+        Ok(())
+    }
+
+    /// Ends the input, appending `U+FFFD` to `out` if an incomplete codepoint was still pending.
+    pub fn finish<const N: usize, L: LenType>(&mut self, out: &mut String<N, L>) -> Result<(), CapacityError> {
+        if self.partial_len > 0 {
+            out.try_push(REPLACEMENT_CHARACTER)?;
+            self.partial_len = 0;
+            self.replacements += 1;
+        }
+        Ok(())
+    }
+
+    /// Returns the number of bytes of an incomplete codepoint held back for the next chunk.
+    pub fn pending(&self) -> usize {
+        self.partial_len
+    }
+
+    /// Returns the number of `U+FFFD` replacements made so far.
+    pub fn replacements(&self) -> usize {
+        self.replacements
+    }
+
+    fn run(&mut self, mut bytes: &[u8], mut emit: impl FnMut(&str)) {
+        // first complete (or give up on) a codepoint left over from the previous chunk
+        while self.partial_len > 0 {
+            let Some((&b, rest)) = bytes.split_first() else {
+                return;
+            };
+            self.partial[self.partial_len] = b;
+            match core::str::from_utf8(&self.partial[..=self.partial_len]) {
+                Ok(s) => {
+                    emit(s);
+                    self.partial_len = 0;
+                    bytes = rest;
+                }
+                Err(e) if e.error_len().is_none() => {
+                    self.partial_len += 1;
+                    bytes = rest;
+                }
+                Err(_) => {
+                    // the pending bytes were a dead end, `b` starts afresh
+                    emit(REPLACEMENT);
+                    self.replacements += 1;
+                    self.partial_len = 0;
+                }
+            }
+        }
+
+        for chunk in bytes.utf8_chunks() {
+            emit(chunk.valid());
+            let invalid = chunk.invalid();
+            if invalid.is_empty() {
+                continue;
+            }
+            if core::str::from_utf8(invalid).is_err_and(|e| e.error_len().is_none()) {
+                // only the last chunk can end in an incomplete codepoint
+                self.partial[..invalid.len()].copy_from_slice(invalid);
+                self.partial_len = invalid.len();
+            } else {
+                emit(REPLACEMENT);
+                self.replacements += 1;
+            }
+        }
+    }
+}
+
+impl<const N: usize, L: LenType> String<N, L> {
+    /// [`std::string::String::from_utf8_lossy()`](https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf8_lossy)
+    ///
+    /// Each invalid sequence is replaced with `U+FFFD REPLACEMENT CHARACTER`, which takes 3 bytes. Fails if the result is longer than `N` bytes.
+    /// Use a [`Utf8Decoder`] to decode input that arrives in chunks, or to count the replacements.
+    pub fn from_utf8_lossy(v: &[u8]) -> Result<Self, CapacityError> {
+        let mut s = Self::new();
+        let mut decoder = Utf8Decoder::new();
+        decoder.decode(v, &mut s)?;
+        // report the whole length needed, not just the final replacement
+        decoder.finish(&mut s).map_err(|_| CapacityError::new(s.len() + REPLACEMENT.len(), N))?;
+        Ok(s)
+    }
+}
@@ -1,49 +1,62 @@
 use core::ops::{RangeBounds, Index};
+use crate::len::{from_usize, to_usize, LenType};
 
+/// A vector stored inline, holding at most `N` items.
+///
+/// The length is stored as an `L`, which defaults to `usize`; see [`LenType`] for using a smaller type.
+/// The layout is `#[repr(C)]`: the `N` items followed by the length.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Vec<T, const N: usize> {
+#[repr(C)]
+pub struct Vec<T, const N: usize, L: LenType = usize> {
     pub items: [T; N],
-    pub len: usize,
+    pub len: L,
 }
 
-impl <T, const N: usize> core::fmt::Display for Vec<T, N> 
+impl<T, const N: usize, L: LenType> core::fmt::Display for Vec<T, N, L> 
 where T: core::fmt::Display + Default + Copy + PartialEq {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let mut i = 0;
         write!(f, "[")?;
-        while i < self.len {
+        while i < self.len() {
             write!(f, "{}", self.items[i])?;
             i += 1;
 
-            if i < self.len {
+            if i < self.len() {
                 write!(f, ", ")?;
             }
         }
-        write!(f, "]");
-        Ok(())
+        write!(f, "]")
     }
 }
 
-impl <T, const N: usize> core::fmt::Debug for Vec<T, N> 
+impl<T, const N: usize, L: LenType> core::fmt::Debug for Vec<T, N, L> 
 where T: core::fmt::Display + Default + Copy + PartialEq {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{} - {}", self, self.capacity())
     }
 }
 
-impl<T, const N: usize> Vec<T, N> 
+impl<T, const N: usize, L: LenType> Default for Vec<T, N, L> 
+where T: Default + Copy + PartialEq {
+    fn default() -> Self {
+        Vec::<T, N, L>::new()
+    }
+}
+
+impl<T, const N: usize, L: LenType> Vec<T, N, L> 
 where T: Default + Copy + PartialEq {
     pub fn new() -> Self {
-        Vec::<T, N> {
+        const { assert!(N <= L::MAX, "the length type cannot hold the capacity N") };
+        Vec::<T, N, L> {
             items: [Default::default(); N],
-            len: 0,
+            len: L::ZERO,
         }
     }
 
     /// [`std::vec::Vec::append`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.append)
     pub fn append(&mut self, item: T) {
-        self.items[self.len] = item;
-        self.len += 1;
+        self.items[self.len()] = item;
+        self.set_len(self.len() + 1);
     }
 
     /// [`std::vec::Vec::as_mut_ptr`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.as_mut_ptr)
@@ -58,12 +71,13 @@ where T: Default + Copy + PartialEq {
 
     /// [`std::vec::Vec::as_slice`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.as_slice)
     pub fn as_slice(&self) -> &[T] {
-        &self.items[..self.len]
+        &self.items[..self.len()]
     }
 
     /// [`std::vec::Vec::as_mut_slice`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.as_mut_slice)
     pub fn as_mut_slice(&mut self) -> &mut [T] {
-        &mut self.items[..self.len]
+        let len = self.len();
+        &mut self.items[..len]
     }
 
     /// [`std::vec::Vec::capacity`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.capacity)
@@ -73,13 +87,13 @@ where T: Default + Copy + PartialEq {
 
     /// [`std::vec::Vec::clear`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.clear)
     pub fn clear(&mut self) {
-        self.len = 0;
+        self.set_len(0);
     }
 
     /// [`std::vec::Vec::contains`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.contains)
     pub fn contains(&self, item: &T) -> bool {
         let mut i = 0;
-        while i < self.len {
+        while i < self.len() {
             if self.items[i] == *item {
                 return true;
             }
@@ -91,9 +105,9 @@ where T: Default + Copy + PartialEq {
     /// [`std::vec::Vec::dedup`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.dedup)
     pub fn dedup(&mut self) {
         let mut i = 0;
-        while i < self.len {
+        while i < self.len() {
             let mut j = i + 1;
-            while j < self.len {
+            while j < self.len() {
                 if self.items[i] == self.items[j] {
                     self.remove(j);
                 } else {
@@ -108,9 +122,9 @@ where T: Default + Copy + PartialEq {
     pub fn dedup_by<F>(&mut self, mut same_bucket: F) 
     where F: FnMut(&T, &T) -> bool {
         let mut i = 0;
-        while i < self.len {
+        while i < self.len() {
             let mut j = i + 1;
-            while j < self.len {
+            while j < self.len() {
                 if same_bucket(&self.items[i], &self.items[j]) {
                     self.remove(j);
                 } else {
@@ -126,9 +140,9 @@ where T: Default + Copy + PartialEq {
     where F: FnMut(&T) -> K,
           K: PartialEq<K> {
         let mut i = 0;
-        while i < self.len {
+        while i < self.len() {
             let mut j = i + 1;
-            while j < self.len {
+            while j < self.len() {
                 if key(&self.items[i]) == key(&self.items[j]) {
                     self.remove(j);
                 } else {
@@ -141,7 +155,7 @@ where T: Default + Copy + PartialEq {
 
     /// [`std::vec::Vec::drain`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.drain)
     pub fn drain(&mut self) -> core::slice::IterMut<'_, T> {
-        self.len = 0;
+        self.set_len(0);
         self.items.iter_mut()
     }
 
@@ -162,7 +176,7 @@ where T: Default + Copy + PartialEq {
         let end = match range.end_bound() {
             core::ops::Bound::Included(i) => *i + 1,
             core::ops::Bound::Excluded(i) => *i,
-            core::ops::Bound::Unbounded => self.len,
+            core::ops::Bound::Unbounded => self.len(),
         };
         let len = end - start;
         let mut i = 0;
@@ -176,7 +190,7 @@ where T: Default + Copy + PartialEq {
     pub fn extract(&mut self, item: &T) -> Option<T> 
     where T: PartialEq<T> {
         let mut i = 0;
-        while i < self.len {
+        while i < self.len() {
             if self.items[i] == *item {
                 return Some(self.remove(i));
             }
@@ -189,7 +203,7 @@ where T: Default + Copy + PartialEq {
     pub fn extract_if<F>(&mut self, mut f: F) -> Option<T> 
     where F: FnMut(&T) -> bool {
         let mut i = 0;
-        while i < self.len {
+        while i < self.len() {
             if f(&self.items[i]) {
                 return Some(self.remove(i));
             }
@@ -199,24 +213,32 @@ where T: Default + Copy + PartialEq {
     }
 
     /// [`std::vec::Vec::from_raw_parts`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.from_raw_parts)
+    ///
+    /// Panics if `len` is larger than `N`. Versions up to 0.3.1 accepted such a length, leaving a `Vec` whose slice methods panic.
     pub fn from_raw_parts(items: [T; N], len: usize) -> Self {
-        Vec::<T, N> {
+        const { assert!(N <= L::MAX, "the length type cannot hold the capacity N") };
+        assert!(len <= N, "length is larger than the capacity");
+        Vec::<T, N, L> {
             items,
-            len,
+            len: from_usize(len),
         }
     }
 
     /// [`std::vec::Vec::from_raw_parts_in`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.from_raw_parts_in)
+    ///
+    /// Panics if `len` is larger than `N`. Versions up to 0.3.1 accepted such a length, leaving a `Vec` whose slice methods panic.
     pub fn from_raw_parts_in(items: [T; N], len: usize, _capacity: usize) -> Self {
-        Vec::<T, N> {
+        const { assert!(N <= L::MAX, "the length type cannot hold the capacity N") };
+        assert!(len <= N, "length is larger than the capacity");
+        Vec::<T, N, L> {
             items,
-            len,
+            len: from_usize(len),
         }
     }
 
     /// [`std::vec::Vec::get`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.get)
     pub fn get(&self, index: usize) -> Option<&T> {
-        if index < self.len {
+        if index < self.len() {
             Some(&self.items[index])
         } else {
             None
@@ -225,16 +247,18 @@ where T: Default + Copy + PartialEq {
 
     /// [`std::vec::Vec::insert`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.insert)
     pub fn insert(&mut self, index: usize, item: T) {
-        self.items.copy_within(index..self.len, index + 1);
+        let len = self.len();
+        self.items.copy_within(index..len, index + 1);
         self.items[index] = item;
-        self.len += 1;
+        self.set_len(len + 1);
     }
 
     /// [`std::vec::Vec::insert_many`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.insert_many)
     pub fn insert_many(&mut self, index: usize, items: &[T]) {
-        self.items.copy_within(index..self.len, index + items.len());
+        let len = self.len();
+        self.items.copy_within(index..len, index + items.len());
         self.items[index..index + items.len()].copy_from_slice(items);
-        self.len += items.len();
+        self.set_len(len + items.len());
     }
 
     /// [`std::vec::Vec::into_flattened`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.into_flattened)
@@ -244,29 +268,29 @@ where T: Default + Copy + PartialEq {
 
     /// [`std::vec::Vec::into_raw_parts`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.into_raw_parts)
     pub fn into_raw_parts(self) -> ([T; N], usize) {
-        (self.items, self.len)
+        (self.items, self.len())
     }
 
     /// [`std::vec::Vec::into_raw_parts_in`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.into_raw_parts_in)
     pub fn into_raw_parts_in(self, _capacity: usize) -> ([T; N], usize) {
-        (self.items, self.len)
+        (self.items, self.len())
     }
 
     /// [`std::vec::Vec::is_empty`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.is_empty)
     pub fn is_empty(&self) -> bool {
-        self.len == 0
+        self.len() == 0
     }
 
     /// [`std::vec::Vec::len`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.len)
-    pub fn len(&self) -> usize {
-        self.len
+    pub const fn len(&self) -> usize {
+        to_usize(&self.len)
     }
 
     /// [`std::vec::Vec::pop`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.pop)
     pub fn pop(&mut self) -> Option<T> {
-        if self.len > 0 {
-            self.len -= 1;
-            Some(self.items[self.len])
+        if !self.is_empty() {
+            self.set_len(self.len() - 1);
+            Some(self.items[self.len()])
         } else {
             None
         }
@@ -279,7 +303,7 @@ where T: Default + Copy + PartialEq {
 
     /// [`std::vec::Vec::push_within_capacity`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.push_within_capacity)
     pub fn push_within_capacity(&mut self, item: T) -> bool {
-        if self.len < N {
+        if self.len() < N {
             self.append(item);
             true
         } else {
@@ -290,35 +314,36 @@ where T: Default + Copy + PartialEq {
     /// [`std::vec::Vec::remove`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.remove)
     pub fn remove(&mut self, index: usize) -> T {
         let item = self.items[index];
-        self.items.copy_within(index + 1..self.len, index);
-        self.len -= 1;
+        let len = self.len();
+        self.items.copy_within(index + 1..len, index);
+        self.set_len(len - 1);
         item
     }
 
     /// [`std::vec::Vec::resize`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.resize)
     pub fn resize(&mut self, new_len: usize, item: T) {
-        if new_len > self.len {
-            let mut i = self.len;
+        if new_len > self.len() {
+            let mut i = self.len();
             while i < new_len {
                 self.append(item);
                 i += 1;
             }
         } else {
-            self.len = new_len;
+            self.set_len(new_len);
         }
     }
 
     /// [`std::vec::Vec::resize_with`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.resize_with)
     pub fn resize_with<F>(&mut self, new_len: usize, mut f: F) 
     where F: FnMut() -> T {
-        if new_len > self.len {
-            let mut i = self.len;
+        if new_len > self.len() {
+            let mut i = self.len();
             while i < new_len {
                 self.append(f());
                 i += 1;
             }
         } else {
-            self.len = new_len;
+            self.set_len(new_len);
         }
     }
 
@@ -326,7 +351,7 @@ where T: Default + Copy + PartialEq {
     pub fn retain<F>(&mut self, mut f: F) 
     where F: FnMut(&T) -> bool {
         let mut i = 0;
-        while i < self.len {
+        while i < self.len() {
             if !f(&self.items[i]) {
                 self.remove(i);
             } else {
@@ -339,7 +364,7 @@ where T: Default + Copy + PartialEq {
     pub fn retain_mut<F>(&mut self, mut f: F) 
     where F: FnMut(&mut T) -> bool {
         let mut i = 0;
-        while i < self.len {
+        while i < self.len() {
             if !f(&mut self.items[i]) {
                 self.remove(i);
             } else {
@@ -349,39 +374,43 @@ where T: Default + Copy + PartialEq {
     }
 
     /// [`std::vec::Vec::set_len`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.set_len)
+    ///
+    /// `new_len` must be at most `N`; this is checked in debug builds.
     pub fn set_len(&mut self, new_len: usize) {
-        self.len = new_len;
+        const { assert!(N <= L::MAX, "the length type cannot hold the capacity N") };
+        debug_assert!(new_len <= N, "length is larger than the capacity");
+        self.len = from_usize(new_len);
     }
 
     /// [`std::vec::Vec::spare_capacity_mut`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.spare_capacity_mut)
     pub fn spare_capacity_mut(&self) -> usize {
-        N - self.len
+        N - self.len()
     }
 
     /// [`std::vec::Vec::split_off`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.split_off)
-    pub fn split_off(&mut self, at: usize) -> Vec<T, N> {
-        let mut other = Vec::<T, N>::new();
-        other.extend_from_within(at..self.len);
-        self.len = at;
+    pub fn split_off(&mut self, at: usize) -> Vec<T, N, L> {
+        let mut other = Vec::<T, N, L>::new();
+        other.extend_from_within(at..self.len());
+        self.set_len(at);
         other
     }
 
     /// [`std::vec::Vec::swap_remove`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.swap_remove)
     pub fn swap_remove(&mut self, index: usize) -> T {
         let item = self.items[index];
-        self.items[index] = self.items[self.len - 1];
-        self.len -= 1;
+        self.items[index] = self.items[self.len() - 1];
+        self.set_len(self.len() - 1);
         item
     }
 
     /// [`std::vec::Vec::truncate`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.truncate)
     pub fn truncate(&mut self, new_len: usize) {
-        self.len = new_len;
+        self.set_len(new_len);
     }
 
     /// [`std::vec::Vec::try_push`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.try_push)
     pub fn try_push(&mut self, item: T) -> Result<(), T> {
-        if self.len < N {
+        if self.len() < N {
             self.append(item);
             Ok(())
         } else {
@@ -391,15 +420,15 @@ where T: Default + Copy + PartialEq {
 
 }
 
-impl<T, const N: usize> core::ops::Deref for Vec<T, N> {
+impl<T, const N: usize, L: LenType> core::ops::Deref for Vec<T, N, L> {
     type Target = [T];
 
     fn deref(&self) -> &[T] {
-        &self.items[..self.len]
+        &self.items[..to_usize(&self.len)]
     }
 }
 
-impl<T, const N: usize> IntoIterator for Vec<T, N> {
+impl<T, const N: usize, L: LenType> IntoIterator for Vec<T, N, L> {
     type Item = T;
     type IntoIter = core::array::IntoIter<T, N>;
 
@@ -409,7 +438,7 @@ impl<T, const N: usize> IntoIterator for Vec<T, N> {
     }
 }
 
-impl<T, const N: usize> Index<usize> for Vec<T, N> {
+impl<T, const N: usize, L: LenType> Index<usize> for Vec<T, N, L> {
     type Output = T;
 
     /// [`std::vec::Vec::index`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.index)
@@ -418,11 +447,23 @@ impl<T, const N: usize> Index<usize> for Vec<T, N> {
     }
 }
 
-impl<T, const N: usize> Index<core::ops::Range<usize>> for Vec<T, N> {
+impl<T, const N: usize, L: LenType> Index<core::ops::Range<usize>> for Vec<T, N, L> {
     type Output = [T];
 
     /// [`std::vec::Vec::index`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.index)
     fn index(&self, index: core::ops::Range<usize>) -> &Self::Output {
         &self.items[index]
     }
-}
\ No newline at end of file
+}
+/// Implementation of `core::fmt::Write` for `Vec<u8, N>`, writing the text as UTF-8 bytes
+///
+/// Writes that do not fit are rejected whole: the fragment is not written and `Err` is returned.
+impl<const N: usize, L: LenType> core::fmt::Write for Vec<u8, N, L> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        if s.len() > N - self.len() {
+            return Err(core::fmt::Error);
+        }
+        self.extend_from_slice(s.as_bytes());
+        Ok(())
+    }
+}
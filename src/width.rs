@@ -0,0 +1,104 @@
+use crate::len::LenType;
+use crate::{CapacityError, String};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Where [`String::pad_to_width()`] puts the text within the padding.
+///
+/// Requires the `width` feature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Align {
+    /// Text on the left, padding on the right
+    Left,
+    /// Text on the right, padding on the left
+    Right,
+    /// Text in the middle, with any odd column of padding on the right
+    Center,
+}
+
+/// Returns the length in bytes of the longest prefix of `s` that is at most `cols` columns wide and `max_len` bytes long.
+///
+/// The prefix ends on a grapheme cluster boundary, so emoji sequences, flags and combining sequences are never split.
+fn prefix_len(s: &str, cols: usize, max_len: usize) -> usize {
+    // the widths of the text before the previous cluster and before the current one
+    let (mut before_prev, mut before) = (0, 0);
+    let (mut prev, mut len) = (0, 0);
+    for (i, g) in s.grapheme_indices(true) {
+        let end = i + g.len();
+        // a cluster is measured together with the one before it, as it can change its width (as an alef after a lam does)
+        let width = before_prev + s[prev..end].width();
+        if width > cols || end > max_len {
+            break;
+        }
+        (before_prev, before, prev, len) = (before, width, i, end);
+    }
+    len
+}
+
+impl<const N: usize, L: LenType> String<N, L> {
+    /// Returns the number of columns the string takes on a terminal or character display, as measured by `unicode-width`.
+    ///
+    /// Wide and fullwidth chars (East Asian Width `W` and `F`, such as CJK ideographs) take 2 columns, and combining marks and other zero-width chars none.
+    /// Sequences are measured as a whole: an emoji ZWJ sequence, an emoji with the emoji presentation selector U+FE0F, and a flag take 2 columns.
+    /// Control characters take 1 column, and ambiguous-width chars 1 column too.
+    ///
+    /// ```
+    /// assert_eq!(nstr::String::<16>::from("abc").display_width(), 3);
+    /// assert_eq!(nstr::String::<16>::from("日本").display_width(), 4);
+    /// assert_eq!(nstr::String::<16>::from("e\u{301}").display_width(), 1);
+    /// assert_eq!(nstr::String::<16>::from("👩\u{200D}🔬").display_width(), 2);
+    /// ```
+    pub fn display_width(&self) -> usize {
+        self.as_str().width()
+    }
+
+    /// Pads the string with spaces to `cols` columns, placing the text as `align` says. Does nothing if it is already that wide.
+    ///
+    /// Nothing is written if the padding does not fit.
+    pub fn pad_to_width(&mut self, cols: usize, align: Align) -> Result<(), CapacityError> {
+        let pad = cols.saturating_sub(self.display_width());
+        if pad > N - self.len() {
+            return Err(CapacityError::new(pad, N - self.len()));
+        }
+        let left = match align {
+            Align::Left => 0,
+            Align::Right => pad,
+            Align::Center => pad / 2,
+        };
+        let len = self.len();
+        self.vec.items.copy_within(..len, left);
+        self.vec.items[..left].fill(b' ');
+        self.vec.items[left + len..len + pad].fill(b' ');
+        self.vec.set_len(len + pad);
+        Ok(())
+    }
+
+    /// Shortens the string to at most `cols` columns, cutting at a grapheme cluster boundary. Does nothing if it is already narrow enough.
+    ///
+    /// A cluster is kept or removed whole, so combining accents stay with their base char and emoji sequences are never split.
+    pub fn truncate_to_width(&mut self, cols: usize) {
+        if self.display_width() > cols {
+            self.truncate(prefix_len(self, cols, N));
+        }
+    }
+
+    /// Like [`String::truncate_to_width()`], but if anything is cut off the string ends with `ellipsis` (such as `"…"`), within the `cols` columns.
+    ///
+    /// Enough text is removed to make room for the ellipsis, both in columns and in bytes, so this never overflows.
+    ///
+    /// ```
+    /// let mut s = nstr::String::<32>::from("Temperature: 21.5°C");
+    /// s.truncate_to_width_with(16, "…");
+    /// assert_eq!(s, "Temperature: 21…");
+    /// assert_eq!(s.display_width(), 16);
+    /// ```
+    pub fn truncate_to_width_with(&mut self, cols: usize, ellipsis: &str) {
+        if self.display_width() <= cols {
+            return;
+        }
+        let ellipsis = &ellipsis[..prefix_len(ellipsis, cols, N)];
+        let ellipsis_width = ellipsis.width();
+        self.truncate(prefix_len(self, cols - ellipsis_width, N - ellipsis.len()));
+        self.push_str(ellipsis);
+    }
+}
@@ -0,0 +1,221 @@
+//! Generates the Unicode tables of nstr from the Unicode Character Database, as compiled into the ICU4X data crates.
+//!
+//! Run from the root of the repository with `cargo run --manifest-path tools/unicode-tables/Cargo.toml`.
+//! It overwrites `src/normalize/tables.rs` and `src/case/tables.rs`.
+//!
+//! To move to a new version of Unicode, bump the ICU4X crates in `Cargo.toml` to a release built from it,
+//! update `UNICODE_VERSION` and `NEWEST_CHAR` below and `UNICODE_VERSION` in `src/lib.rs`, and run the generator again.
+
+use icu_normalizer::properties::{CanonicalCombiningClassMap, CanonicalComposition, CanonicalDecomposition, Decomposed};
+use icu_normalizer::{ComposingNormalizer, ComposingNormalizerBorrowed, DecomposingNormalizer, DecomposingNormalizerBorrowed};
+use icu_properties::props::{CaseIgnorable, Cased, GeneralCategory};
+use icu_properties::{CodePointMapData, CodePointSetData};
+use std::fmt::Write;
+use std::path::Path;
+
+/// The version of Unicode the ICU4X data is built from
+const UNICODE_VERSION: &str = "17.0.0";
+
+/// A char first assigned in `UNICODE_VERSION`, to catch data from an older version
+const NEWEST_CHAR: char = '\u{16EA0}';
+
+// Hangul syllables are decomposed and composed algorithmically, so they are left out of the tables
+const HANGUL_SYLLABLES: std::ops::Range<u32> = 0xAC00..0xAC00 + 11172;
+
+fn main() {
+    assert_ne!(
+        CodePointMapData::<GeneralCategory>::new().get(NEWEST_CHAR),
+        GeneralCategory::Unassigned,
+        "the ICU4X data is older than Unicode {UNICODE_VERSION}"
+    );
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+    write(&root.join("src/normalize/tables.rs"), &normalization_tables());
+    write(&root.join("src/case/tables.rs"), &case_tables());
+}
+
+fn write(path: &Path, tables: &str) {
+    let mut out = format!("// Generated by tools/unicode-tables from the Unicode Character Database, version {UNICODE_VERSION}. Do not edit by hand.\n");
+    out.push_str(tables);
+    std::fs::write(path, out).unwrap_or_else(|e| panic!("cannot write {}: {e}", path.display()));
+}
+
+/// Appends a table to `out`, with `per_line` items on each line.
+fn table(out: &mut String, doc: &str, decl: &str, items: &[String], per_line: usize) {
+    out.push('\n');
+    for line in doc.lines() {
+        writeln!(out, "/// {line}").unwrap();
+    }
+    writeln!(out, "#[rustfmt::skip]\n{decl} = &[").unwrap();
+    for chunk in items.chunks(per_line) {
+        writeln!(out, "    {},", chunk.join(", ")).unwrap();
+    }
+    out.push_str("];\n");
+}
+
+fn chars() -> impl Iterator<Item = char> {
+    (0..=char::MAX as u32).filter_map(char::from_u32)
+}
+
+/// Formats the runs of consecutive chars in `chars` as `(first, last)`.
+fn runs(chars: impl IntoIterator<Item = u32>) -> Vec<String> {
+    let mut ranges: Vec<(u32, u32)> = Vec::new();
+    for c in chars {
+        match ranges.last_mut() {
+            Some((_, last)) if *last + 1 == c => *last = c,
+            _ => ranges.push((c, c)),
+        }
+    }
+    ranges.iter().map(|(first, last)| format!("({first:#X}, {last:#X})")).collect()
+}
+
+fn normalization_tables() -> String {
+    let classes = CanonicalCombiningClassMap::new();
+    let canonical = CanonicalDecomposition::new();
+    let composition = CanonicalComposition::new();
+    let nfd = DecomposingNormalizer::new_nfd();
+    let nfkd = DecomposingNormalizer::new_nfkd();
+    let nfc = ComposingNormalizer::new_nfc();
+    let nfkc = ComposingNormalizer::new_nfkc();
+
+    // runs of chars with the same non-zero combining class
+    let mut ranges: Vec<(u32, u32, u8)> = Vec::new();
+    for c in chars() {
+        let (c, class) = (c as u32, classes.get32_u8(c as u32));
+        match ranges.last_mut() {
+            _ if class == 0 => {}
+            Some((_, last, last_class)) if *last + 1 == c && *last_class == class => *last = c,
+            _ => ranges.push((c, c, class)),
+        }
+    }
+
+    // canonical mappings are one level deep, as in the UCD; compatibility mappings are fully decomposed,
+    // as ICU4X only exposes the raw mapping of canonical decompositions
+    let mut decompositions = Vec::new();
+    let mut data: Vec<u32> = Vec::new();
+    let mut compositions = Vec::new();
+    for c in chars().filter(|&c| !HANGUL_SYLLABLES.contains(&(c as u32))) {
+        let mut buf = [0; 4];
+        let s = &*c.encode_utf8(&mut buf);
+        let (mapping, compatibility) = if normalized(&nfd, s) != s {
+            match canonical.decompose(c) {
+                Decomposed::Singleton(a) => (vec![a], false),
+                Decomposed::Expansion(a, b) => {
+                    if composition.compose(a, b) == Some(c) {
+                        compositions.push((a as u32, b as u32, c as u32));
+                    }
+                    (vec![a, b], false)
+                }
+                Decomposed::Default => unreachable!("{c:?} has no canonical mapping"),
+            }
+        } else if normalized(&nfkd, s) != s {
+            (normalized(&nfkd, s).chars().collect(), true)
+        } else {
+            continue;
+        };
+        let start = u16::try_from(data.len()).expect("DECOMPOSITION_DATA is too long for u16 offsets");
+        let len = u8::try_from(mapping.len()).expect("decomposition is too long for a u8 length");
+        decompositions.push((c as u32, compatibility, start, len));
+        data.extend(mapping.iter().map(|&d| d as u32));
+    }
+    compositions.sort_unstable();
+    let mut seconds: Vec<u32> = compositions.iter().map(|&(_, b, _)| b).collect();
+    seconds.sort_unstable();
+    seconds.dedup();
+    let (nfc_no, nfkc_no) = (runs(changed(&nfc)), runs(changed(&nfkc)));
+
+    let mut out = String::new();
+    table(
+        &mut out,
+        "The canonical combining class of each range of chars `(first, last, class)`, for the chars whose class is not zero",
+        "pub(super) const COMBINING_CLASSES: &[(u32, u32, u8)]",
+        &ranges.iter().map(|(first, last, class)| format!("({first:#X}, {last:#X}, {class})")).collect::<Vec<_>>(),
+        6,
+    );
+    table(
+        &mut out,
+        "The decomposition mapping of each char `(char, is_compatibility, start, len)`, as a slice of `DECOMPOSITION_DATA`.\n\
+         Canonical mappings are one level deep: the chars they map to may decompose further. Compatibility mappings are fully decomposed.\n\
+         Hangul syllables are decomposed algorithmically.",
+        "pub(super) const DECOMPOSITIONS: &[(u32, bool, u16, u8)]",
+        &decompositions.iter().map(|(c, compatibility, start, len)| format!("({c:#X}, {compatibility}, {start}, {len})")).collect::<Vec<_>>(),
+        5,
+    );
+    table(
+        &mut out,
+        "The chars of all the decomposition mappings",
+        "pub(super) const DECOMPOSITION_DATA: &[u32]",
+        &data.iter().map(|d| format!("{d:#X}")).collect::<Vec<_>>(),
+        12,
+    );
+    table(
+        &mut out,
+        "The primary composites `(first, second, composite)`, sorted by `(first, second)`. Hangul syllables are composed algorithmically.",
+        "pub(super) const COMPOSITIONS: &[(u32, u32, u32)]",
+        &compositions.iter().map(|(a, b, c)| format!("({a:#X}, {b:#X}, {c:#X})")).collect::<Vec<_>>(),
+        5,
+    );
+    table(
+        &mut out,
+        "The ranges of chars that are never in NFC (`NFC_Quick_Check=No`)",
+        "pub(super) const NFC_NO: &[(u32, u32)]",
+        &nfc_no,
+        8,
+    );
+    table(
+        &mut out,
+        "The ranges of chars that are never in NFKC (`NFKC_Quick_Check=No`)",
+        "pub(super) const NFKC_NO: &[(u32, u32)]",
+        &nfkc_no,
+        8,
+    );
+    table(
+        &mut out,
+        "The ranges of chars that are the second char of a primary composite (`NFC_Quick_Check=Maybe`).\n\
+         Hangul vowels and trailing consonants, which compose algorithmically, are left out.",
+        "pub(super) const COMPOSES_WITH_PREVIOUS: &[(u32, u32)]",
+        &runs(seconds),
+        8,
+    );
+    out
+}
+
+/// Returns the chars that change even on their own, which are the `No` value of the quick check property of the form.
+fn changed(normalizer: &ComposingNormalizerBorrowed<'_>) -> Vec<u32> {
+    let mut changed = Vec::new();
+    for c in chars() {
+        let mut buf = [0; 4];
+        let s = &*c.encode_utf8(&mut buf);
+        if normalizer.normalize(s) != s {
+            changed.push(c as u32);
+        }
+    }
+    changed
+}
+
+fn normalized(normalizer: &DecomposingNormalizerBorrowed<'_>, s: &str) -> String {
+    normalizer.normalize(s).into_owned()
+}
+
+fn case_tables() -> String {
+    let ranges = |set: icu_properties::CodePointSetDataBorrowed<'_>| {
+        set.iter_ranges().map(|r| format!("({:#X}, {:#X})", r.start(), r.end())).collect::<Vec<_>>()
+    };
+    let mut out = String::new();
+    table(
+        &mut out,
+        "The ranges of chars with the `Cased` property: lowercase, uppercase and titlecase letters, and a few symbols",
+        "pub(super) const CASED: &[(u32, u32)]",
+        &ranges(CodePointSetData::new::<Cased>()),
+        8,
+    );
+    table(
+        &mut out,
+        "The ranges of chars with the `Case_Ignorable` property\n\
+         (general categories `Mn`, `Me`, `Cf`, `Lm` and `Sk`, and word break properties `MidLetter`, `MidNumLet` and `Single_Quote`)",
+        "pub(super) const CASE_IGNORABLE: &[(u32, u32)]",
+        &ranges(CodePointSetData::new::<CaseIgnorable>()),
+        8,
+    );
+    out
+}